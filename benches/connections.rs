@@ -0,0 +1,62 @@
+//! Latency of small requests with and without connection pooling.
+//!
+//! A local server answers every request with an empty list, so the numbers
+//! show the cost of the connection rather than of the daemon. Polling a Unix
+//! socket saves less than TCP, and TLS saves much more.
+//!
+//! Run with `cargo bench --bench connections`.
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+use tokio::runtime::Runtime;
+
+use docker_client::DockerClient;
+
+/// Start a server answering `[]` to every request, keeping connections alive.
+fn serve(runtime: &Runtime) -> String {
+    let listener = runtime.block_on(TcpListener::bind("127.0.0.1:0")).unwrap();
+    let address = listener.local_addr().unwrap();
+
+    runtime.spawn(async move {
+        loop {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            tokio::spawn(async move {
+                let mut request = Vec::new();
+                let mut buffer = [0u8; 1024];
+                loop {
+                    while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+                        match socket.read(&mut buffer).await {
+                            Ok(0) | Err(_) => return,
+                            Ok(read) => request.extend_from_slice(&buffer[..read]),
+                        }
+                    }
+                    request.clear();
+
+                    if socket.write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 2\r\n\r\n[]").await.is_err() {
+                        return;
+                    }
+                }
+            });
+        }
+    });
+
+    format!("tcp://{}", address)
+}
+
+fn polling(c: &mut Criterion) {
+    let runtime = Runtime::new().unwrap();
+    let host = serve(&runtime);
+
+    let mut group = c.benchmark_group("image_list");
+    for (name, max_idle) in [("pooled", 16), ("unpooled", 0)] {
+        let client = DockerClient::stream(host.clone(), None).with_max_idle_connections(max_idle);
+        group.bench_function(name, |b| {
+            b.iter(|| runtime.block_on(client.get_image_list()).unwrap())
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, polling);
+criterion_main!(benches);
@@ -0,0 +1,57 @@
+//! Deserialization of daemon responses.
+//!
+//! Bodies are decoded with `serde_json::from_slice` like the client does. List
+//! fixtures are repeated to the size of a busy host.
+//!
+//! Run with `cargo bench --bench deserialize`, add `--features simd-json` to
+//! compare with simd-json.
+
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use docker_client::container::{ShortContainerInfo, Stats};
+use docker_client::container::inspect::ContainerInfo;
+use docker_client::image::{ShortImageInfo, ImageInfo};
+use docker_client::networks::inspect::NetworkInfo;
+use docker_client::volume::VolumesList;
+
+const LIST_SIZE: usize = 500;
+
+/// Repeat the records of a list fixture until it has `LIST_SIZE` records.
+fn repeat(fixture: &str) -> Vec<u8> {
+    let records: Vec<Value> = serde_json::from_str(fixture).unwrap();
+    let list: Vec<&Value> = records.iter().cycle().take(LIST_SIZE).collect();
+    serde_json::to_vec(&list).unwrap()
+}
+
+fn bench<T>(c: &mut Criterion, name: &str, body: &[u8])
+    where T: DeserializeOwned
+{
+    let mut group = c.benchmark_group(name);
+    group.throughput(Throughput::Bytes(body.len() as u64));
+    group.bench_function("from_slice", |b| {
+        b.iter(|| serde_json::from_slice::<T>(body).unwrap())
+    });
+    #[cfg(feature = "simd-json")]
+    group.bench_function("simd_json", |b| {
+        b.iter(|| simd_json::serde::from_slice::<T>(&mut body.to_vec()).unwrap())
+    });
+    group.finish();
+}
+
+fn lists(c: &mut Criterion) {
+    bench::<Vec<ShortContainerInfo>>(c, "container_list", &repeat(include_str!("../tests/fixtures/container_list_v1.40.json")));
+    bench::<Vec<ShortImageInfo>>(c, "image_list", &repeat(include_str!("../tests/fixtures/image_list_v1.40.json")));
+    bench::<Vec<NetworkInfo>>(c, "network_list", &repeat(include_str!("../tests/fixtures/network_list_v1.44.json")));
+    bench::<VolumesList>(c, "volume_list", include_bytes!("../tests/fixtures/volume_list_v1.40.json"));
+}
+
+fn inspect(c: &mut Criterion) {
+    bench::<ContainerInfo>(c, "container_inspect", include_bytes!("../tests/fixtures/container_inspect_v1.44.json"));
+    bench::<ImageInfo>(c, "image_inspect", include_bytes!("../tests/fixtures/image_inspect_v1.44.json"));
+    bench::<Stats>(c, "container_stats", include_bytes!("../tests/fixtures/container_stats_v1.44.json"));
+}
+
+criterion_group!(benches, lists, inspect);
+criterion_main!(benches);
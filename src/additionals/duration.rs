@@ -0,0 +1,170 @@
+use std::convert::TryFrom;
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
+use serde::{Deserialize, Serialize};
+
+/// Duration written as in Go, e.g. `1h30m` or `1.5s`
+///
+/// The daemon accepts such strings in `until` filters and the docker CLI shows
+/// health check intervals with them.
+///
+/// # Examples
+///
+/// ```rust
+/// use docker_client::additionals::duration::GoDuration;
+/// use std::time::Duration;
+///
+/// let duration: GoDuration = "1h30m".parse().unwrap();
+///
+/// assert_eq!(Duration::from(duration), Duration::from_secs(5400));
+/// assert_eq!(duration.to_string(), "1h30m0s");
+/// assert_eq!(GoDuration::from(Duration::from_millis(1500)).to_string(), "1.5s");
+/// assert!("10 days".parse::<GoDuration>().is_err());
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[serde(try_from = "String", into = "String")]
+pub struct GoDuration(Duration);
+
+const NANOS_PER_MICRO: u128 = 1_000;
+const NANOS_PER_MILLI: u128 = 1_000_000;
+const NANOS_PER_SECOND: u128 = 1_000_000_000;
+
+impl GoDuration {
+
+    /// Return the duration
+    pub fn as_duration(&self) -> Duration {
+        self.0
+    }
+
+}
+
+impl From<Duration> for GoDuration {
+    fn from(duration: Duration) -> Self {
+        GoDuration(duration)
+    }
+}
+
+impl From<GoDuration> for Duration {
+    fn from(duration: GoDuration) -> Self {
+        duration.0
+    }
+}
+
+impl FromStr for GoDuration {
+    type Err = String;
+
+    /// Parse a sequence of decimal numbers with units `ns`, `us` (or `µs`), `ms`, `s`, `m` and `h`.
+    /// Negative durations are rejected.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("invalid duration `{}`", s);
+
+        let mut rest = s.trim().strip_prefix('+').unwrap_or(s.trim());
+        if rest == "0" {
+            return Ok(GoDuration::default());
+        }
+        if rest.is_empty() {
+            return Err(invalid());
+        }
+
+        let mut nanos: u128 = 0;
+        while !rest.is_empty() {
+            let number_end = rest.find(|c: char| !c.is_ascii_digit() && c != '.').unwrap_or(rest.len());
+            let (number, tail) = rest.split_at(number_end);
+
+            let unit_end = tail.find(|c: char| c.is_ascii_digit() || c == '.').unwrap_or(tail.len());
+            let (unit, tail) = tail.split_at(unit_end);
+
+            let scale = match unit {
+                "ns" => 1,
+                "us" | "\u{b5}s" | "\u{3bc}s" => NANOS_PER_MICRO,
+                "ms" => NANOS_PER_MILLI,
+                "s" => NANOS_PER_SECOND,
+                "m" => 60 * NANOS_PER_SECOND,
+                "h" => 3600 * NANOS_PER_SECOND,
+                _ => return Err(invalid()),
+            };
+
+            let (whole, fraction) = number.split_once('.').unwrap_or((number, ""));
+            if whole.is_empty() && fraction.is_empty() {
+                return Err(invalid());
+            }
+
+            let whole: u128 = match whole {
+                "" => 0,
+                _ => whole.parse().map_err(|_| invalid())?,
+            };
+            // Digits beyond nanoseconds can not change the result.
+            let fraction = &fraction[..fraction.len().min(18)];
+            let fraction_nanos = match fraction {
+                "" => 0,
+                _ => fraction.parse::<u128>().map_err(|_| invalid())? * scale / 10u128.pow(fraction.len() as u32),
+            };
+
+            nanos = whole.checked_mul(scale)
+                .and_then(|whole| nanos.checked_add(whole + fraction_nanos))
+                .ok_or_else(invalid)?;
+            rest = tail;
+        }
+
+        let secs = u64::try_from(nanos / NANOS_PER_SECOND).map_err(|_| invalid())?;
+        Ok(GoDuration(Duration::new(secs, (nanos % NANOS_PER_SECOND) as u32)))
+    }
+}
+
+impl TryFrom<String> for GoDuration {
+    type Error = String;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl From<GoDuration> for String {
+    fn from(duration: GoDuration) -> Self {
+        duration.to_string()
+    }
+}
+
+impl fmt::Display for GoDuration {
+
+    /// Format like Go `time.Duration.String`, e.g. `1h30m0s`, `2.5ms` or `0s`
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let nanos = self.0.as_nanos();
+
+        if nanos == 0 {
+            return write!(f, "0s");
+        }
+        if nanos < NANOS_PER_MICRO {
+            return write!(f, "{}ns", nanos);
+        }
+        if nanos < NANOS_PER_MILLI {
+            return write!(f, "{}\u{b5}s", decimal(nanos, NANOS_PER_MICRO));
+        }
+        if nanos < NANOS_PER_SECOND {
+            return write!(f, "{}ms", decimal(nanos, NANOS_PER_MILLI));
+        }
+
+        let secs = self.0.as_secs();
+        let seconds = decimal(u128::from(secs % 60) * NANOS_PER_SECOND + u128::from(self.0.subsec_nanos()), NANOS_PER_SECOND);
+
+        match (secs / 3600, secs / 60 % 60) {
+            (0, 0) => write!(f, "{}s", seconds),
+            (0, minutes) => write!(f, "{}m{}s", minutes, seconds),
+            (hours, minutes) => write!(f, "{}h{}m{}s", hours, minutes, seconds),
+        }
+    }
+
+}
+
+/// Write `value / unit` with the fraction trimmed of trailing zeros.
+fn decimal(value: u128, unit: u128) -> String {
+    let digits = unit.to_string().len() - 1;
+    let fraction = format!("{:0width$}", value % unit, width = digits);
+    let fraction = fraction.trim_end_matches('0');
+
+    match fraction {
+        "" => format!("{}", value / unit),
+        _ => format!("{}.{}", value / unit, fraction),
+    }
+}
@@ -0,0 +1,166 @@
+//!
+//! Filters types.
+//!
+//! The module provides [FiltersBuilder](struct.FiltersBuilder.html) and [Filters](struct.Filters.html) types
+//! used by list, prune and events requests.
+//!
+//! Filters are serialized to docker `map[string][]string` JSON, e.g. `{"label":["env=prod"],"status":["exited"]}`.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use docker_client::additionals::filters::Filters;
+//!
+//! let filters = Filters::new()
+//!     .label("com.example.env", Some("prod".to_string()))
+//!     .status("exited")
+//!     .build();
+//!
+//! assert_eq!(filters.get("status"), Some(&vec!["exited".to_string()]));
+//! ```
+
+use std::collections::{BTreeMap, HashMap};
+use serde::{Deserialize, Serialize};
+use crate::additionals::duration::GoDuration;
+
+/// `FiltersBuilder` struct.
+#[derive(Debug, Default, Clone)]
+pub struct FiltersBuilder {
+
+    filters: BTreeMap<String, Vec<String>>
+
+}
+
+impl FiltersBuilder {
+
+    /// Creates a new default instance of `FiltersBuilder` to construct a `Filters`.
+    pub fn new() -> Self {
+        FiltersBuilder::default()
+    }
+
+    /// Append value of filter `key`.
+    pub fn filter<T, U>(mut self, key: T, value: U) -> Self
+        where
+            T: Into<String>,
+            U: Into<String>
+    {
+        self.filters.entry(key.into()).or_default().push(value.into());
+
+        self
+    }
+
+    /// Append `label` filter, `key` or `key=value`.
+    pub fn label<T>(self, key: T, value: Option<String>) -> Self
+        where T: Into<String>
+    {
+        match value {
+            Some(v) => self.filter("label", format!("{}={}", key.into(), v)),
+            None => self.filter("label", key),
+        }
+    }
+
+    /// Append `name` filter.
+    pub fn name<T>(self, name: T) -> Self
+        where T: Into<String>
+    {
+        self.filter("name", name)
+    }
+
+    /// Append `id` filter.
+    pub fn id<T>(self, id: T) -> Self
+        where T: Into<String>
+    {
+        self.filter("id", id)
+    }
+
+    /// Append `status` filter.
+    pub fn status<T>(self, status: T) -> Self
+        where T: Into<String>
+    {
+        self.filter("status", status)
+    }
+
+    /// Set `dangling` filter.
+    pub fn dangling(mut self, v: bool) -> Self {
+        self.filters.insert("dangling".to_string(), vec![v.to_string()]);
+
+        self
+    }
+
+    /// Set `until` filter, a timestamp or duration like `24h`.
+    pub fn until<T>(mut self, until: T) -> Self
+        where T: Into<String>
+    {
+        self.filters.insert("until".to_string(), vec![until.into()]);
+
+        self
+    }
+
+    /// Set `until` filter to objects older than `age`, relative to the clock of the daemon.
+    pub fn older_than<T>(self, age: T) -> Self
+        where T: Into<GoDuration>
+    {
+        self.until(age.into().to_string())
+    }
+
+    /// Build `Filters` from `FiltersBuilder`
+    pub fn build(self) -> Filters {
+        Filters {
+            filters: self.filters
+        }
+    }
+
+}
+
+/// `Filters` struct.
+#[derive(Serialize, Deserialize, Default, Clone, Debug, PartialEq, Eq)]
+#[serde(transparent)]
+pub struct Filters {
+
+    filters: BTreeMap<String, Vec<String>>
+
+}
+
+impl Filters {
+
+    /// Creates a new default instance of `FiltersBuilder` to construct a `Filters`.
+    pub fn new() -> FiltersBuilder {
+        FiltersBuilder::default()
+    }
+
+    /// Return values of filter `key`
+    pub fn get(&self, key: &str) -> Option<&Vec<String>> {
+        self.filters.get(key)
+    }
+
+    /// Return `label` filters by key, with the value if one is set
+    #[deprecated(note = "use `get(\"label\")`, which returns every filter value")]
+    pub fn label(&self) -> HashMap<String, Option<String>> {
+        self.get("label")
+            .into_iter()
+            .flatten()
+            .map(|label| match label.split_once('=') {
+                Some((key, value)) => (key.to_string(), Some(value.to_string())),
+                None => (label.clone(), None),
+            })
+            .collect()
+    }
+
+    /// Return `true` if no filter is set
+    pub fn is_empty(&self) -> bool {
+        self.filters.is_empty()
+    }
+
+    /// Return `filters=...` query parameter, `None` if no filter is set
+    pub fn query(&self) -> Option<String> {
+        if self.is_empty() {
+            return None;
+        }
+
+        Some(format!(
+            "filters={}",
+            super::query::encode(serde_json::to_string(self).unwrap().as_str())
+        ))
+    }
+
+}
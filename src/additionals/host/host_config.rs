@@ -1,17 +1,42 @@
 
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use crate::additionals::serde_helpers::nullable_default;
+use crate::client::ApiVersion;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct PortBinding {
 
-    #[serde(rename = "HostIP", skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "HostIp", alias = "HostIP", skip_serializing_if = "Option::is_none")]
     host_ip: Option<String>,
 
     #[serde(rename = "HostPort")]
     host_port: String
 }
 
+impl PortBinding {
+
+    /// Return host address the port is published on, empty or `None` for all addresses
+    pub fn host_ip(&self) -> Option<&str> {
+        self.host_ip.as_deref()
+    }
+
+    /// Return host port, empty when the daemon picks a free one
+    pub fn host_port(&self) -> &str {
+        &self.host_port
+    }
+
+    /// Return host port as number, `None` when the daemon picks a free one
+    pub fn host_port_number(&self) -> Option<u16> {
+        self.host_port.parse().ok()
+    }
+
+    pub(crate) fn new(host_ip: Option<String>, host_port: String) -> Self {
+        PortBinding { host_ip, host_port }
+    }
+
+}
+
 #[derive(Debug, Default)]
 pub struct HostConfigBuilder {
 
@@ -23,6 +48,10 @@ pub struct HostConfigBuilder {
 
     auto_remove: Option<bool>,
 
+    annotations: HashMap<String, String>,
+
+    cgroupns_mode: Option<String>,
+
 }
 
 impl HostConfigBuilder {
@@ -63,30 +92,106 @@ impl HostConfigBuilder {
         self
     }
 
+    /// Add OCI annotation, requires API 1.43
+    pub fn annotation<T, U>(mut self, key: T, value: U) -> Self
+        where
+            T: Into<String>,
+            U: Into<String>
+    {
+        self.annotations.insert(key.into(), value.into());
+
+        self
+    }
+
+    /// Set cgroup namespace mode, `private` or `host`, requires API 1.41
+    pub fn cgroupns_mode<T>(mut self, mode: T) -> Self
+        where T: Into<String>
+    {
+        self.cgroupns_mode = Some(mode.into());
+
+        self
+    }
+
     pub fn build(self) -> HostConfig {
         HostConfig {
             binds: self.binds,
             port_bindings: self.port_bindings,
             sysctls: self.sysctls,
-            auto_remove: self.auto_remove.unwrap_or(false)
+            auto_remove: self.auto_remove.unwrap_or(false),
+            annotations: self.annotations,
+            cgroupns_mode: self.cgroupns_mode
         }
     }
 
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct HostConfig {
 
-    #[serde(rename = "Binds", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "Binds", skip_serializing_if = "Vec::is_empty", deserialize_with = "nullable_default", default)]
     binds: Vec<String>,
 
-    #[serde(rename = "PortBindings", skip_serializing_if = "HashMap::is_empty")]
+    #[serde(rename = "PortBindings", skip_serializing_if = "HashMap::is_empty", deserialize_with = "nullable_default", default)]
     port_bindings: HashMap<String, Vec<PortBinding>>,
 
-    #[serde(rename = "Sysctls", skip_serializing_if = "HashMap::is_empty")]
+    #[serde(rename = "Sysctls", skip_serializing_if = "HashMap::is_empty", deserialize_with = "nullable_default", default)]
     sysctls: HashMap<String, String>,
 
-    #[serde(rename = "AutoRemove")]
-    auto_remove: bool
+    #[serde(rename = "AutoRemove", default)]
+    auto_remove: bool,
+
+    #[serde(rename = "Annotations", skip_serializing_if = "HashMap::is_empty", deserialize_with = "nullable_default", default)]
+    annotations: HashMap<String, String>,
+
+    #[serde(rename = "CgroupnsMode", skip_serializing_if = "Option::is_none", default)]
+    cgroupns_mode: Option<String>
 
-}
\ No newline at end of file
+}
+
+impl HostConfig {
+
+    /// Return volume bindings, `host:container:mode`
+    pub fn binds(&self) -> &Vec<String> {
+        &self.binds
+    }
+
+    /// Return port bindings by container port
+    pub fn port_bindings(&self) -> &HashMap<String, Vec<PortBinding>> {
+        &self.port_bindings
+    }
+
+    /// Return kernel parameters
+    pub fn sysctls(&self) -> &HashMap<String, String> {
+        &self.sysctls
+    }
+
+    /// Return `true` if the container is removed when it exits
+    pub fn auto_remove(&self) -> bool {
+        self.auto_remove
+    }
+
+    /// Return OCI annotations, empty before API 1.43
+    pub fn annotations(&self) -> &HashMap<String, String> {
+        &self.annotations
+    }
+
+    /// Return cgroup namespace mode, `None` before API 1.41
+    pub fn cgroupns_mode(&self) -> Option<&str> {
+        self.cgroupns_mode.as_deref()
+    }
+
+    /// Return minimum API version required by the set fields
+    pub(crate) fn required_api_version(&self) -> Option<ApiVersion> {
+        let mut required = None;
+
+        if self.cgroupns_mode.is_some() {
+            required = required.max(Some(ApiVersion::new(1, 41)));
+        }
+        if !self.annotations.is_empty() {
+            required = required.max(Some(ApiVersion::new(1, 43)));
+        }
+
+        required
+    }
+
+}
@@ -1,5 +1,12 @@
 pub mod network;
 pub mod mount;
 pub mod host;
+pub mod filters;
+pub mod port;
+pub mod duration;
 
-pub mod serde_helpers;
\ No newline at end of file
+pub mod serde_helpers;
+
+pub(crate) mod query;
+
+pub(crate) mod timestamp;
\ No newline at end of file
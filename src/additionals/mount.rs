@@ -1,49 +1,153 @@
 use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
 use serde::{Serialize, Deserialize};
 
-#[derive(Debug, Serialize, Deserialize)]
+/// Type of a mount
+///
+/// Unknown types reported by newer daemons are kept in `Other`.
+///
+/// # Examples
+///
+/// ```rust
+/// use docker_client::additionals::mount::MountType;
+///
+/// let mount_type: MountType = "tmpfs".parse().unwrap();
+///
+/// assert_eq!(mount_type, MountType::Tmpfs);
+/// assert_eq!(MountType::from("overlay".to_string()), MountType::Other("overlay".to_string()));
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[serde(from = "String", into = "String")]
+pub enum MountType {
+
+    /// Path of the host mounted into the container
+    Bind,
+
+    /// Named or anonymous volume
+    Volume,
+
+    /// Temporary file system in memory
+    Tmpfs,
+
+    /// Named pipe of the host, on Windows
+    Npipe,
+
+    /// Volume of a swarm cluster
+    Cluster,
+
+    /// Content of an image, requires API 1.46
+    Image,
+
+    /// Type unknown to this crate
+    Other(String),
+
+}
+
+impl MountType {
+
+    /// Return type as string slice
+    pub fn as_str(&self) -> &str {
+        match self {
+            MountType::Bind => "bind",
+            MountType::Volume => "volume",
+            MountType::Tmpfs => "tmpfs",
+            MountType::Npipe => "npipe",
+            MountType::Cluster => "cluster",
+            MountType::Image => "image",
+            MountType::Other(mount_type) => mount_type.as_str(),
+        }
+    }
+
+}
+
+/// Empty type, old daemons do not report it
+impl Default for MountType {
+    fn default() -> Self {
+        MountType::Other(String::new())
+    }
+}
+
+impl From<String> for MountType {
+    fn from(mount_type: String) -> Self {
+        match mount_type.as_str() {
+            "bind" => MountType::Bind,
+            "volume" => MountType::Volume,
+            "tmpfs" => MountType::Tmpfs,
+            "npipe" => MountType::Npipe,
+            "cluster" => MountType::Cluster,
+            "image" => MountType::Image,
+            _ => MountType::Other(mount_type),
+        }
+    }
+}
+
+impl From<MountType> for String {
+    fn from(mount_type: MountType) -> Self {
+        match mount_type {
+            MountType::Other(mount_type) => mount_type,
+            mount_type => mount_type.as_str().to_string(),
+        }
+    }
+}
+
+impl FromStr for MountType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(MountType::from(s.to_string()))
+    }
+}
+
+impl fmt::Display for MountType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
 pub struct BindOptions {
 
-    #[serde(rename(deserialize = "Propagation"))]
+    #[serde(rename = "Propagation")]
     propagation: String,
 
-    #[serde(rename(deserialize = "NonRecursive"))]
+    #[serde(rename = "NonRecursive")]
     non_recursive: bool
 
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
 pub struct DriverConfig {
 
-    #[serde(rename(deserialize = "Name"))]
+    #[serde(rename = "Name")]
     name: String,
 
-    #[serde(rename(deserialize = "Options"))]
+    #[serde(rename = "Options")]
     options: HashMap<String, String>
 
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
 pub struct VolumeOptions {
 
-    #[serde(rename(deserialize = "NoCopy"))]
+    #[serde(rename = "NoCopy")]
     no_copy: bool,
 
-    #[serde(rename(deserialize = "Labels"))]
+    #[serde(rename = "Labels")]
     labels: HashMap<String, String>,
 
-    #[serde(rename(deserialize = "DriverConfig"))]
+    #[serde(rename = "DriverConfig")]
     driver_config: DriverConfig
 
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
 pub struct TmpfsOptions {
 
-    #[serde(rename(deserialize = "SizeBytes"))]
+    #[serde(rename = "SizeBytes")]
     size_bytes: i64,
 
-    #[serde(rename(deserialize = "Mode"))]
+    #[serde(rename = "Mode")]
     mode: i32
 
 }
@@ -52,31 +156,31 @@ fn default_read_only() -> bool {
     false
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
 pub struct Mount {
 
-    #[serde(rename(deserialize = "Target"))]
+    #[serde(rename = "Target")]
     target: Option<String>,
 
-    #[serde(rename(deserialize = "Source"))]
+    #[serde(rename = "Source")]
     source: String,
 
-    #[serde(rename(deserialize = "Type"))]
-    mount_type: String,
+    #[serde(rename = "Type")]
+    mount_type: MountType,
 
-    #[serde(rename(deserialize = "ReadOnly"), default = "default_read_only")]
+    #[serde(rename = "ReadOnly", default = "default_read_only")]
     read_only: bool,
 
-    #[serde(rename(deserialize = "Consistency"), default = "String::new")]
+    #[serde(rename = "Consistency", default = "String::new")]
     consistency: String,
 
-    #[serde(rename(deserialize = "BindOptions"))]
+    #[serde(rename = "BindOptions")]
     bind_options: Option<BindOptions>,
 
-    #[serde(rename(deserialize = "VolumeOptions"))]
+    #[serde(rename = "VolumeOptions")]
     volume_options: Option<VolumeOptions>,
 
-    #[serde(rename(deserialize = "TmpfsOptions"), skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "TmpfsOptions", skip_serializing_if = "Option::is_none")]
     tmpfs_options: Option<TmpfsOptions>
 
 }
\ No newline at end of file
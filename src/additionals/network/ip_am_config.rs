@@ -11,7 +11,7 @@ pub struct IPAMConfigBuilder {
 
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
 pub struct IPAMConfig {
 
     #[serde(rename = "IPV4Address")]
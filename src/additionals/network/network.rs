@@ -35,7 +35,7 @@ pub struct NetworkBuilder {
 }
 
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
 pub struct Network {
 
     #[serde(rename = "IPAMConfig", skip_serializing_if = "Option::is_none")]
@@ -1,6 +1,8 @@
 use std::collections::HashMap;
 use super::Network;
 use serde::{Deserialize, Serialize};
+use crate::additionals::host::host_config::PortBinding;
+use crate::additionals::port::{ExposedPort, PortMap, host_port_for, nullable_port_map};
 
 #[derive(Clone, Default, Debug)]
 pub struct NetworkSettingsBuilder {
@@ -9,11 +11,38 @@ pub struct NetworkSettingsBuilder {
 
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
 pub struct NetworkSettings {
 
     #[serde(rename = "Networks")]
-    networks: HashMap<String, Network>
+    networks: HashMap<String, Network>,
+
+    #[serde(rename = "Ports", skip_serializing_if = "HashMap::is_empty", deserialize_with = "nullable_port_map", default)]
+    ports: PortMap,
+
+}
+
+impl NetworkSettings {
+
+    /// Return networks the container is connected to
+    pub fn networks(&self) -> &HashMap<String, Network> {
+        &self.networks
+    }
+
+    /// Return published ports by container port, empty bindings for exposed but unpublished ports
+    pub fn ports(&self) -> &PortMap {
+        &self.ports
+    }
+
+    /// Return bindings of container `port`, empty if it is not published
+    pub fn bindings_for(&self, port: &ExposedPort) -> &[PortBinding] {
+        self.ports.get(port).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    /// Return first host port published for container `port`
+    pub fn host_port_for(&self, port: &ExposedPort) -> Option<u16> {
+        host_port_for(&self.ports, port)
+    }
 
 }
 
@@ -31,7 +60,8 @@ impl NetworkSettingsBuilder {
 
     pub fn build(self) -> NetworkSettings {
         NetworkSettings {
-            networks: self.networks
+            networks: self.networks,
+            ports: HashMap::new(),
         }
     }
 
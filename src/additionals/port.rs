@@ -0,0 +1,111 @@
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::fmt;
+use std::str::FromStr;
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::additionals::host::host_config::PortBinding;
+
+/// Port of a container with its protocol, e.g. `80/tcp`
+///
+/// # Examples
+///
+/// ```rust
+/// use docker_client::additionals::port::ExposedPort;
+///
+/// let port: ExposedPort = "53/udp".parse().unwrap();
+///
+/// assert_eq!(port, ExposedPort::udp(53));
+/// assert_eq!("8080".parse::<ExposedPort>().unwrap(), ExposedPort::tcp(8080));
+/// assert_eq!(ExposedPort::tcp(80).to_string(), "80/tcp");
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[serde(try_from = "String", into = "String")]
+pub struct ExposedPort {
+    port: u16,
+    protocol: String,
+}
+
+impl ExposedPort {
+
+    /// Creates a new `ExposedPort` of `protocol`, e.g. `sctp`
+    pub fn new<T>(port: u16, protocol: T) -> Self
+        where T: Into<String>
+    {
+        ExposedPort { port, protocol: protocol.into() }
+    }
+
+    /// Creates a new TCP `ExposedPort`
+    pub fn tcp(port: u16) -> Self {
+        ExposedPort::new(port, "tcp")
+    }
+
+    /// Creates a new UDP `ExposedPort`
+    pub fn udp(port: u16) -> Self {
+        ExposedPort::new(port, "udp")
+    }
+
+    /// Return port number
+    pub fn port(&self) -> u16 {
+        self.port
+    }
+
+    /// Return protocol, e.g. `tcp`
+    pub fn protocol(&self) -> &str {
+        &self.protocol
+    }
+
+}
+
+impl FromStr for ExposedPort {
+    type Err = String;
+
+    /// Parse `port/protocol`, the protocol defaults to `tcp`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (port, protocol) = s.trim().split_once('/').unwrap_or((s.trim(), "tcp"));
+
+        Ok(ExposedPort {
+            port: port.parse().map_err(|_| format!("invalid port `{}`", s))?,
+            protocol: protocol.to_ascii_lowercase(),
+        })
+    }
+}
+
+impl TryFrom<String> for ExposedPort {
+    type Error = String;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl From<ExposedPort> for String {
+    fn from(port: ExposedPort) -> Self {
+        port.to_string()
+    }
+}
+
+impl fmt::Display for ExposedPort {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.port, self.protocol)
+    }
+}
+
+/// Published ports by container port, exposed but unpublished ports have no bindings
+pub type PortMap = HashMap<ExposedPort, Vec<PortBinding>>;
+
+/// Return first host port published for `port`.
+pub(crate) fn host_port_for(ports: &PortMap, port: &ExposedPort) -> Option<u16> {
+    ports.get(port)?.iter().find_map(PortBinding::host_port_number)
+}
+
+/// Deserialize `Ports` of inspect, where unpublished ports are `null`.
+pub(crate) fn nullable_port_map<'de, D>(deserializer: D) -> Result<PortMap, D::Error>
+    where D: Deserializer<'de>
+{
+    let ports: Option<HashMap<ExposedPort, Option<Vec<PortBinding>>>> = Option::deserialize(deserializer)?;
+
+    Ok(ports.unwrap_or_default().into_iter()
+        .map(|(port, bindings)| (port, bindings.unwrap_or_default()))
+        .collect())
+}
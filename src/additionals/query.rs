@@ -0,0 +1,44 @@
+/// Percent-encode a query parameter value.
+///
+/// Every byte except unreserved characters (RFC 3986) is encoded.
+pub(crate) fn encode(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => result.push(byte as char),
+            _ => result.push_str(format!("%{:02X}", byte).as_str()),
+        }
+    }
+
+    result
+}
+
+/// Percent-decode a URL component.
+///
+/// Malformed escapes are kept as they are, invalid UTF-8 is replaced.
+pub(crate) fn decode(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut result = Vec::with_capacity(bytes.len());
+
+    let mut index = 0;
+    while index < bytes.len() {
+        let escaped = match bytes[index] {
+            b'%' => value.get(index + 1..index + 3).and_then(|hex| u8::from_str_radix(hex, 16).ok()),
+            _ => None,
+        };
+
+        match escaped {
+            Some(byte) => {
+                result.push(byte);
+                index += 3;
+            },
+            None => {
+                result.push(bytes[index]);
+                index += 1;
+            },
+        }
+    }
+
+    String::from_utf8_lossy(&result).into_owned()
+}
@@ -1,8 +1,8 @@
 use std::collections::HashMap;
 use serde::{Deserializer, Deserialize, Serialize};
 
-#[derive(Debug, Deserialize, Serialize, Clone)]
-pub struct EmptyObject;
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
+pub struct EmptyObject {}
 
 pub fn nullable_priority_hash<'de, D>(deserializer: D) -> Result<HashMap<String, EmptyObject>, D::Error>
     where D: Deserializer<'de>
@@ -17,3 +17,12 @@ pub fn nullable_priority_vec<'de, D>(deserializer: D) -> Result<Vec<String>, D::
     let opt = Option::deserialize(deserializer)?;
     Ok(opt.unwrap_or(Vec::new()))
 }
+
+pub fn nullable_default<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: Deserialize<'de> + Default
+{
+    let opt = Option::deserialize(deserializer)?;
+    Ok(opt.unwrap_or_default())
+}
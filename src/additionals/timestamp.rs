@@ -0,0 +1,92 @@
+//! Conversion of daemon timestamps.
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, TimeZone, Utc};
+
+/// Parse an RFC 3339 timestamp as returned by the daemon.
+///
+/// Go zero time (`0001-01-01T00:00:00Z`) is used by the daemon for unset
+/// values and is returned as `None`.
+#[cfg(feature = "chrono")]
+pub(crate) fn parse_rfc3339(value: &str) -> Option<DateTime<Utc>> {
+    let time = DateTime::parse_from_rfc3339(value).ok()?.with_timezone(&Utc);
+
+    match time.timestamp() {
+        GO_ZERO_TIME => None,
+        _ => Some(time),
+    }
+}
+
+/// Convert seconds since epoch into `DateTime<Utc>`.
+#[cfg(feature = "chrono")]
+pub(crate) fn from_unix(seconds: i64) -> Option<DateTime<Utc>> {
+    Utc.timestamp_opt(seconds, 0).single()
+}
+
+/// Parse an RFC 3339 timestamp with up to nanosecond precision into `SystemTime`.
+///
+/// Times before the epoch are returned as `None`.
+pub(crate) fn parse_system_time(value: &str) -> Option<SystemTime> {
+    let bytes = value.as_bytes();
+    if bytes.len() < 20 || bytes[4] != b'-' || bytes[7] != b'-' || (bytes[10] != b'T' && bytes[10] != b't')
+        || bytes[13] != b':' || bytes[16] != b':' {
+        return None;
+    }
+
+    let number = |range: std::ops::Range<usize>| -> Option<i64> { value.get(range)?.parse().ok() };
+
+    let (year, month, day) = (number(0..4)?, number(5..7)?, number(8..10)?);
+    let (hour, minute, second) = (number(11..13)?, number(14..16)?, number(17..19)?);
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) || hour > 23 || minute > 59 || second > 60 {
+        return None;
+    }
+
+    let mut rest = &value[19..];
+    let mut nanos = 0u32;
+    if let Some(fraction) = rest.strip_prefix('.') {
+        let digits = fraction.bytes().take_while(u8::is_ascii_digit).count();
+        if digits == 0 {
+            return None;
+        }
+        for (i, digit) in fraction[..digits].bytes().enumerate().take(9) {
+            nanos += (digit - b'0') as u32 * 10u32.pow(8 - i as u32);
+        }
+        rest = &fraction[digits..];
+    }
+
+    let offset = match rest {
+        "Z" | "z" => 0,
+        _ if rest.len() == 6 && rest.as_bytes()[3] == b':' => {
+            let sign = match rest.as_bytes()[0] {
+                b'+' => 1,
+                b'-' => -1,
+                _ => return None,
+            };
+            sign * (rest[1..3].parse::<i64>().ok()? * 3600 + rest[4..6].parse::<i64>().ok()? * 60)
+        },
+        _ => return None,
+    };
+
+    let seconds = days_from_civil(year, month, day) * 86400 + hour * 3600 + minute * 60 + second - offset;
+    if seconds < 0 {
+        return None;
+    }
+
+    Some(UNIX_EPOCH + Duration::new(seconds as u64, nanos))
+}
+
+/// Return days since epoch for a proleptic Gregorian date.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = if year >= 0 { year } else { year - 399 } / 400;
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    era * 146097 + day_of_era - 719468
+}
+
+#[cfg(feature = "chrono")]
+const GO_ZERO_TIME: i64 = -62135596800;
@@ -0,0 +1,360 @@
+//!
+//! Blocking client.
+//!
+//! The module provides [DockerClient](struct.DockerClient.html), a synchronous version of
+//! [docker_client::DockerClient](../client/struct.DockerClient.html) for CLI tools and build scripts
+//! which don't use async. Each call drives a tokio runtime owned by the client until the request
+//! completes, so the client must not be used from within an async runtime.
+//!
+//! Methods not wrapped by the blocking client are reachable with `block_on`.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use docker_client::blocking::DockerClient;
+//!
+//! fn main() {
+//!     let client = DockerClient::new().unwrap();
+//!
+//!     match client.version() {
+//!         Ok(version) => { println!("Engine {}", version.version()); },
+//!         Err(e) => { println!("Error: {:?}", e); },
+//!     }
+//! }
+//! ```
+
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::runtime::{Builder, Runtime};
+
+use crate::client::{Auth, AuthStatus, DockerError, Ping};
+use crate::container::{Create, CreatedContainer, ContainerRefId, Killer, Remover, ShortContainerInfo, StartOptions, WaitCondition, WaitStatus};
+use crate::container::inspect::{Inspect, ContainerInfo};
+use crate::container::processes_list::{ProcessesList, TopList};
+use crate::exec::ExecOutput;
+use crate::image::{ShortImageInfo, ImageInfo, RemovedImage, PushedImage, PullSummary};
+use crate::volume::{VolumeCreator, VolumeInfo, VolumesList};
+use crate::networks::create::CreatedNetwork;
+use crate::networks::inspect::NetworkInfo;
+use crate::additionals::filters::Filters;
+use crate::system::{Version, SystemInfo, DiskUsage};
+
+/// Blocking `DockerClient`
+///
+/// Clones share the runtime, the connection pool and settings.
+#[derive(Debug, Clone)]
+pub struct DockerClient {
+    inner: crate::DockerClient,
+    runtime: Arc<Runtime>,
+}
+
+impl DockerClient {
+
+    /// Creates a new blocking client with the default settings of `docker_client::DockerClient::new`
+    pub fn new() -> Result<DockerClient, DockerError> {
+        DockerClient::from_async(crate::DockerClient::new())
+    }
+
+    /// Creates a new blocking client sending requests with `client`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use docker_client::blocking;
+    /// use std::time::Duration;
+    ///
+    /// let client = docker_client::DockerClient::builder()
+    ///     .host("tcp://127.0.0.1:2375")
+    ///     .timeout(Duration::from_secs(30))
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let client = blocking::DockerClient::from_async(client).unwrap();
+    /// assert_eq!(client.as_async().timeout(), Some(Duration::from_secs(30)));
+    /// ```
+    pub fn from_async(client: crate::DockerClient) -> Result<DockerClient, DockerError> {
+        Ok(DockerClient { inner: client, runtime: Arc::new(runtime()?) })
+    }
+
+    /// Return the async client sending the requests
+    pub fn as_async(&self) -> &crate::DockerClient {
+        &self.inner
+    }
+
+    /// Run future returned by `f` to completion
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use docker_client::blocking::DockerClient;
+    ///
+    /// let client = DockerClient::new().unwrap();
+    ///
+    /// match client.block_on(|client| client.image_history("alpine")) {
+    ///     Ok(history) => { println!("{} layers", history.len()); },
+    ///     Err(e) => { println!("Error: {:?}", e); },
+    /// }
+    /// ```
+    pub fn block_on<'a, F, R>(&'a self, f: F) -> R::Output
+        where
+            F: FnOnce(&'a crate::DockerClient) -> R,
+            R: Future
+    {
+        self.runtime.block_on(f(&self.inner))
+    }
+
+    /// Ping the daemon, see `docker_client::DockerClient::ping`
+    pub fn ping(&self) -> Result<Ping, DockerError> {
+        self.block_on(|client| client.ping())
+    }
+
+    /// Get version of the daemon, see `docker_client::DockerClient::version`
+    pub fn version(&self) -> Result<Version, DockerError> {
+        self.block_on(|client| client.version())
+    }
+
+    /// Get system information of the daemon, see `docker_client::DockerClient::info`
+    pub fn info(&self) -> Result<SystemInfo, DockerError> {
+        self.block_on(|client| client.info())
+    }
+
+    /// Get disk usage of the daemon, see `docker_client::DockerClient::disk_usage`
+    pub fn disk_usage(&self) -> Result<DiskUsage, DockerError> {
+        self.block_on(|client| client.disk_usage())
+    }
+
+    /// Validate registry credentials, see `docker_client::DockerClient::check_auth`
+    pub fn check_auth(&self, auth: Auth) -> Result<AuthStatus, DockerError> {
+        self.block_on(|client| client.check_auth(auth))
+    }
+
+    /// Log in to a registry, see `docker_client::DockerClient::login`
+    pub fn login(self, auth: Auth) -> Result<DockerClient, DockerError> {
+        let inner = self.runtime.block_on(self.inner.login(auth))?;
+
+        Ok(DockerClient { inner, runtime: self.runtime })
+    }
+
+    /// Get list of containers, see `docker_client::DockerClient::containers_list`
+    pub fn containers_list(&self, request: crate::container::list::Request) -> Result<Vec<ShortContainerInfo>, DockerError> {
+        self.block_on(|client| client.containers_list(request))
+    }
+
+    /// Create a container, see `docker_client::DockerClient::create_container`
+    pub fn create_container(&self, request: Create) -> Result<CreatedContainer, DockerError> {
+        self.block_on(|client| client.create_container(request))
+    }
+
+    /// Start a container, see `docker_client::DockerClient::start_container`
+    pub fn start_container<T, U>(&self, id: T, detach_keys: U) -> Result<(), DockerError>
+        where
+            T: Into<ContainerRefId>,
+            U: Into<String>
+    {
+        self.block_on(|client| client.start_container(id, detach_keys))
+    }
+
+    /// Start a container with options, see `docker_client::DockerClient::start_container_with`
+    pub fn start_container_with<T>(&self, id: T, options: StartOptions) -> Result<(), DockerError>
+        where T: Into<ContainerRefId>
+    {
+        self.block_on(|client| client.start_container_with(id, options))
+    }
+
+    /// Stop a container, see `docker_client::DockerClient::stop_container`
+    pub fn stop_container<T>(&self, id: T, wait: Option<i32>) -> Result<(), DockerError>
+        where T: Into<ContainerRefId>
+    {
+        self.block_on(|client| client.stop_container(id, wait))
+    }
+
+    /// Stop a container with a timeout, see `docker_client::DockerClient::stop_container_with_timeout`
+    pub fn stop_container_with_timeout<T>(&self, id: T, timeout: Duration) -> Result<(), DockerError>
+        where T: Into<ContainerRefId>
+    {
+        self.block_on(|client| client.stop_container_with_timeout(id, timeout))
+    }
+
+    /// Restart a container, see `docker_client::DockerClient::restart_container`
+    pub fn restart_container<T>(&self, id: T, timeout: Option<Duration>) -> Result<(), DockerError>
+        where T: Into<ContainerRefId>
+    {
+        self.block_on(|client| client.restart_container(id, timeout))
+    }
+
+    /// Pause a container, see `docker_client::DockerClient::pause_container`
+    pub fn pause_container<T>(&self, id: T) -> Result<(), DockerError>
+        where T: Into<ContainerRefId>
+    {
+        self.block_on(|client| client.pause_container(id))
+    }
+
+    /// Unpause a container, see `docker_client::DockerClient::unpause_container`
+    pub fn unpause_container<T>(&self, id: T) -> Result<(), DockerError>
+        where T: Into<ContainerRefId>
+    {
+        self.block_on(|client| client.unpause_container(id))
+    }
+
+    /// Rename a container, see `docker_client::DockerClient::rename_container`
+    pub fn rename_container<T, U>(&self, id: T, new_name: U) -> Result<(), DockerError>
+        where
+            T: Into<ContainerRefId>,
+            U: Into<String>
+    {
+        self.block_on(|client| client.rename_container(id, new_name))
+    }
+
+    /// Kill a container, see `docker_client::DockerClient::kill_container`
+    pub fn kill_container(&self, killer: Killer) -> Result<(), DockerError> {
+        self.block_on(|client| client.kill_container(killer))
+    }
+
+    /// Remove a container, see `docker_client::DockerClient::remove_container`
+    pub fn remove_container(&self, remover: Remover) -> Result<(), DockerError> {
+        self.block_on(|client| client.remove_container(remover))
+    }
+
+    /// Inspect a container, see `docker_client::DockerClient::inspect_container`
+    pub fn inspect_container(&self, request: Inspect) -> Result<ContainerInfo, DockerError> {
+        self.block_on(|client| client.inspect_container(request))
+    }
+
+    /// Return `true` if the container exists, see `docker_client::DockerClient::container_exists`
+    pub fn container_exists<T>(&self, id: T) -> Result<bool, DockerError>
+        where T: Into<ContainerRefId>
+    {
+        self.block_on(|client| client.container_exists(id))
+    }
+
+    /// Return `true` if the container is running, see `docker_client::DockerClient::is_running`
+    pub fn is_running<T>(&self, id: T) -> Result<bool, DockerError>
+        where T: Into<ContainerRefId>
+    {
+        self.block_on(|client| client.is_running(id))
+    }
+
+    /// Wait for a container, see `docker_client::DockerClient::wait_container`
+    pub fn wait_container<T>(&self, id: T, condition: WaitCondition) -> Result<WaitStatus, DockerError>
+        where T: Into<ContainerRefId>
+    {
+        self.block_on(|client| client.wait_container(id, condition))
+    }
+
+    /// Get logs of a container, see `docker_client::DockerClient::container_logs`
+    pub fn container_logs(&self, request: crate::container::logs::Request) -> Result<Vec<String>, DockerError> {
+        self.block_on(|client| client.container_logs(request))
+    }
+
+    /// List processes of a container, see `docker_client::DockerClient::top`
+    pub fn top(&self, request: ProcessesList) -> Result<TopList, DockerError> {
+        self.block_on(|client| client.top(request))
+    }
+
+    /// Run a command in a container, see `docker_client::DockerClient::exec_shell`
+    pub fn exec_shell<T, U>(&self, id: T, command: U) -> Result<ExecOutput, DockerError>
+        where
+            T: Into<ContainerRefId>,
+            U: Into<String>
+    {
+        self.block_on(|client| client.exec_shell(id, command))
+    }
+
+    /// Get list of images, see `docker_client::DockerClient::images_list`
+    pub fn images_list(&self, request: crate::image::list::Request) -> Result<Vec<ShortImageInfo>, DockerError> {
+        self.block_on(|client| client.images_list(request))
+    }
+
+    /// Pull an image, see `docker_client::DockerClient::pull_image`
+    pub fn pull_image(&self, request: crate::image::create::Request) -> Result<PullSummary, DockerError> {
+        self.block_on(|client| client.pull_image(request))
+    }
+
+    /// Inspect an image, see `docker_client::DockerClient::inspect_image`
+    pub fn inspect_image<T>(&self, name: T) -> Result<ImageInfo, DockerError>
+        where T: Into<String>
+    {
+        self.block_on(|client| client.inspect_image(name))
+    }
+
+    /// Tag an image, see `docker_client::DockerClient::tag_image`
+    pub fn tag_image<T, U, V>(&self, name: T, repo: U, tag: V) -> Result<(), DockerError>
+        where
+            T: Into<String>,
+            U: Into<String>,
+            V: Into<String>
+    {
+        self.block_on(|client| client.tag_image(name, repo, tag))
+    }
+
+    /// Push an image, see `docker_client::DockerClient::push_image`
+    pub fn push_image<T>(&self, name: T) -> Result<PushedImage, DockerError>
+        where T: Into<String>
+    {
+        self.block_on(|client| client.push_image(name))
+    }
+
+    /// Remove an image, see `docker_client::DockerClient::remove_image`
+    pub fn remove_image<T>(&self, name: T, force: bool, no_prune: bool) -> Result<Vec<RemovedImage>, DockerError>
+        where T: Into<String>
+    {
+        self.block_on(|client| client.remove_image(name, force, no_prune))
+    }
+
+    /// Create a volume, see `docker_client::DockerClient::create_volume`
+    pub fn create_volume(&self, volume: VolumeCreator) -> Result<(), DockerError> {
+        self.block_on(|client| client.create_volume(volume))
+    }
+
+    /// Inspect a volume, see `docker_client::DockerClient::inspect_volume`
+    pub fn inspect_volume<T>(&self, name: T) -> Result<VolumeInfo, DockerError>
+        where T: Into<String>
+    {
+        self.block_on(|client| client.inspect_volume(name))
+    }
+
+    /// Remove a volume, see `docker_client::DockerClient::remove_volume`
+    pub fn remove_volume<T>(&self, name: T, force: bool) -> Result<(), DockerError>
+        where T: Into<String>
+    {
+        self.block_on(|client| client.remove_volume(name, force))
+    }
+
+    /// Get list of volumes, see `docker_client::DockerClient::volumes_list`
+    pub fn volumes_list(&self, filters: Filters) -> Result<VolumesList, DockerError> {
+        self.block_on(|client| client.volumes_list(filters))
+    }
+
+    /// Create a network, see `docker_client::DockerClient::create_network`
+    pub fn create_network(&self, request: crate::networks::create::Request) -> Result<CreatedNetwork, DockerError> {
+        self.block_on(|client| client.create_network(request))
+    }
+
+    /// Inspect a network, see `docker_client::DockerClient::inspect_network`
+    pub fn inspect_network(&self, request: crate::networks::inspect::Request) -> Result<NetworkInfo, DockerError> {
+        self.block_on(|client| client.inspect_network(request))
+    }
+
+    /// Get list of networks, see `docker_client::DockerClient::networks_list`
+    pub fn networks_list(&self, filters: Filters) -> Result<Vec<NetworkInfo>, DockerError> {
+        self.block_on(|client| client.networks_list(filters))
+    }
+
+    /// Remove a network, see `docker_client::DockerClient::remove_network`
+    pub fn remove_network<T>(&self, id: T) -> Result<(), DockerError>
+        where T: Into<String>
+    {
+        self.block_on(|client| client.remove_network(id))
+    }
+
+}
+
+/// Build the runtime driving requests of a blocking client.
+fn runtime() -> Result<Runtime, DockerError> {
+    Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .map_err(DockerError::Io)
+}
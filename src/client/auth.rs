@@ -0,0 +1,206 @@
+use std::fmt;
+use serde::{Deserialize, Serialize};
+
+/// Registry credentials
+///
+/// Either username and password or a token obtained by a `docker login` token flow.
+/// `Debug` output redacts the password and tokens.
+///
+/// # Examples
+///
+/// ```rust
+/// use docker_client::client::Auth;
+///
+/// let auth = Auth::builder()
+///     .identity_token("eyJhbGciOi...")
+///     .server_address("ghcr.io")
+///     .build();
+///
+/// assert!(!format!("{:?}", auth).contains("eyJhbGciOi"));
+/// ```
+#[derive(Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Auth {
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub username: String,
+
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub password: String,
+
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub email: String,
+
+    #[serde(rename = "serveraddress", skip_serializing_if = "Option::is_none")]
+    pub server_address: Option<String>,
+
+    #[serde(rename = "identitytoken", default, skip_serializing_if = "Option::is_none")]
+    pub identity_token: Option<String>,
+
+    #[serde(rename = "registrytoken", default, skip_serializing_if = "Option::is_none")]
+    pub registry_token: Option<String>,
+}
+
+impl Auth {
+
+    /// Creates a new default instance of `AuthBuilder` to construct an `Auth`.
+    pub fn builder() -> AuthBuilder {
+        AuthBuilder::default()
+    }
+
+    /// Return credentials encoded as sent in `X-Registry-Auth` header
+    ///
+    /// The daemon decodes the header as URL-safe base64: tokens of cloud registries
+    /// are long enough to contain bytes which standard base64 writes as `+` or `/`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use docker_client::client::Auth;
+    ///
+    /// let auth = Auth::builder().identity_token("eyJ>?~").server_address("ghcr.io").build();
+    /// let decoded = base64::decode_config(auth.encode(), base64::URL_SAFE).unwrap();
+    ///
+    /// assert_eq!(decoded, br#"{"serveraddress":"ghcr.io","identitytoken":"eyJ>?~"}"#);
+    /// ```
+    pub fn encode(&self) -> String {
+        base64::encode_config(serde_json::to_string(self).unwrap(), base64::URL_SAFE)
+    }
+
+}
+
+impl fmt::Debug for Auth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let redact = |token: &Option<String>| token.as_ref().map(|_| "***");
+
+        f.debug_struct("Auth")
+            .field("username", &self.username)
+            .field("password", &"***")
+            .field("email", &self.email)
+            .field("server_address", &self.server_address)
+            .field("identity_token", &redact(&self.identity_token))
+            .field("registry_token", &redact(&self.registry_token))
+            .finish()
+    }
+}
+
+/// `Auth` builder
+#[derive(Default)]
+pub struct AuthBuilder {
+    auth: Auth,
+}
+
+impl AuthBuilder {
+
+    /// Creates a new default instance of `AuthBuilder` to construct an `Auth`.
+    pub fn new() -> Self {
+        AuthBuilder::default()
+    }
+
+    /// Set username
+    pub fn username<T>(mut self, username: T) -> Self
+        where T: Into<String>
+    {
+        self.auth.username = username.into();
+
+        self
+    }
+
+    /// Set password
+    pub fn password<T>(mut self, password: T) -> Self
+        where T: Into<String>
+    {
+        self.auth.password = password.into();
+
+        self
+    }
+
+    /// Set email
+    pub fn email<T>(mut self, email: T) -> Self
+        where T: Into<String>
+    {
+        self.auth.email = email.into();
+
+        self
+    }
+
+    /// Set registry address, e.g. `ghcr.io`
+    pub fn server_address<T>(mut self, address: T) -> Self
+        where T: Into<String>
+    {
+        self.auth.server_address = Some(address.into());
+
+        self
+    }
+
+    /// Set identity token returned by `/auth`, used instead of the password
+    pub fn identity_token<T>(mut self, token: T) -> Self
+        where T: Into<String>
+    {
+        self.auth.identity_token = Some(token.into());
+
+        self
+    }
+
+    /// Set bearer token sent to the registry as is
+    pub fn registry_token<T>(mut self, token: T) -> Self
+        where T: Into<String>
+    {
+        self.auth.registry_token = Some(token.into());
+
+        self
+    }
+
+    /// Build `Auth` from `AuthBuilder`
+    pub fn build(self) -> Auth {
+        self.auth
+    }
+
+}
+
+/// Result of `DockerClient::check_auth`
+///
+/// `Debug` output redacts the identity token.
+#[derive(Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct AuthStatus {
+    #[serde(rename = "Status", default)]
+    status: String,
+
+    #[serde(rename = "IdentityToken", default, skip_serializing_if = "String::is_empty")]
+    identity_token: String,
+}
+
+impl AuthStatus {
+
+    /// Return status reported by the registry, e.g. `Login Succeeded`
+    pub fn status(&self) -> &str {
+        &self.status
+    }
+
+    /// Return identity token to use instead of the password, if the registry issued one
+    pub fn identity_token(&self) -> Option<&str> {
+        match self.identity_token.is_empty() {
+            true => None,
+            false => Some(&self.identity_token),
+        }
+    }
+
+    /// Return credentials for later requests: `auth` with the identity token instead
+    /// of the password if the registry issued one, `auth` as is otherwise.
+    pub fn credentials(&self, mut auth: Auth) -> Auth {
+        if let Some(token) = self.identity_token() {
+            auth.password.clear();
+            auth.identity_token = Some(token.to_string());
+        }
+
+        auth
+    }
+
+}
+
+impl fmt::Debug for AuthStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AuthStatus")
+            .field("status", &self.status)
+            .field("identity_token", &self.identity_token().map(|_| "***"))
+            .finish()
+    }
+}
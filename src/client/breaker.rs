@@ -0,0 +1,90 @@
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::client::DockerError;
+
+/// Circuit breaker for an unavailable daemon
+///
+/// After `threshold` consecutive transport failures (refused or failed connections
+/// and timeouts) requests fail with `DockerError::Unavailable` without being sent,
+/// until `cooldown` has passed. The next request is sent again: a failure opens
+/// the breaker for another cooldown, any response from the daemon closes it.
+///
+/// Clones of a client share the breaker.
+///
+/// # Examples
+///
+/// ```rust
+/// use docker_client::DockerClient;
+/// use docker_client::client::CircuitBreaker;
+/// use std::time::Duration;
+///
+/// let client = DockerClient::new()
+///     .with_circuit_breaker(CircuitBreaker::new(3, Duration::from_secs(10)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct CircuitBreaker {
+    threshold: u32,
+    cooldown: Duration,
+    state: Arc<Mutex<State>>,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    failures: u32,
+    open_until: Option<Instant>,
+}
+
+impl CircuitBreaker {
+
+    /// Creates a new `CircuitBreaker`, a `threshold` of zero is treated as one
+    pub fn new(threshold: u32, cooldown: Duration) -> Self {
+        CircuitBreaker {
+            threshold: threshold.max(1),
+            cooldown,
+            state: Arc::new(Mutex::new(State::default())),
+        }
+    }
+
+    /// Return count of consecutive failures which opens the breaker
+    pub fn threshold(&self) -> u32 {
+        self.threshold
+    }
+
+    /// Return how long the breaker stays open
+    pub fn cooldown(&self) -> Duration {
+        self.cooldown
+    }
+
+    /// Return `true` if requests currently fail fast
+    pub fn is_open(&self) -> bool {
+        let state = self.state.lock().unwrap();
+        state.open_until.is_some_and(|until| Instant::now() < until)
+    }
+
+    /// Return error if the breaker is open
+    pub(crate) fn check(&self) -> Result<(), DockerError> {
+        match self.is_open() {
+            true => Err(DockerError::Unavailable),
+            false => Ok(()),
+        }
+    }
+
+    /// Count the outcome of a request which was sent
+    pub(crate) fn record<T>(&self, result: &Result<T, DockerError>) {
+        let mut state = self.state.lock().unwrap();
+
+        match result {
+            Err(e) if e.is_connection() => {
+                state.failures = state.failures.saturating_add(1);
+                if state.failures >= self.threshold {
+                    state.open_until = Some(Instant::now() + self.cooldown);
+                }
+            },
+            _ => {
+                state.failures = 0;
+                state.open_until = None;
+            },
+        }
+    }
+}
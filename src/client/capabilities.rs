@@ -0,0 +1,73 @@
+use crate::client::{ApiVersion, DockerError, Ping};
+use crate::system::Version;
+
+/// Features of the daemon, see `DockerClient::capabilities`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Capabilities {
+
+    api_version: ApiVersion,
+
+    min_api_version: Option<ApiVersion>,
+
+    experimental: bool,
+
+    builder_version: Option<String>,
+
+    os: String,
+
+    arch: String,
+
+}
+
+impl Capabilities {
+
+    pub(crate) fn new(version: Version, ping: &Ping) -> Self {
+        Capabilities {
+            api_version: ping.api_version().unwrap_or_else(|| version.api_version()),
+            min_api_version: version.min_api_version(),
+            experimental: version.experimental() || ping.experimental(),
+            builder_version: ping.builder_version().map(String::from),
+            os: version.os().to_string(),
+            arch: version.arch().to_string(),
+        }
+    }
+
+    /// Return newest API version of the daemon
+    pub fn api_version(&self) -> ApiVersion {
+        self.api_version
+    }
+
+    /// Return oldest API version accepted by the daemon, unknown to API versions before 1.25
+    pub fn min_api_version(&self) -> Option<ApiVersion> {
+        self.min_api_version
+    }
+
+    /// Return `true` if experimental features of the daemon are enabled
+    pub fn experimental(&self) -> bool {
+        self.experimental
+    }
+
+    /// Return default builder of the daemon, `1` or `2` for BuildKit, unknown to API versions before 1.31
+    pub fn builder_version(&self) -> Option<&str> {
+        self.builder_version.as_deref()
+    }
+
+    /// Return operating system of the daemon, e.g. `linux`
+    pub fn os(&self) -> &str {
+        &self.os
+    }
+
+    /// Return architecture of the daemon, e.g. `amd64`
+    pub fn arch(&self) -> &str {
+        &self.arch
+    }
+
+    /// Return `DockerError::FeatureUnavailable` unless experimental features are enabled
+    pub fn require_experimental(&self, feature: &str) -> Result<(), DockerError> {
+        match self.experimental {
+            true => Ok(()),
+            false => Err(DockerError::FeatureUnavailable(feature.to_string())),
+        }
+    }
+
+}
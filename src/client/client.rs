@@ -1,65 +1,315 @@
 use serde_json as json;
 
-use crate::container::{Killer, Remover, CreatedContainer, WaitCondition, WaitStatus, Create};
+use crate::container::{Killer, Remover, CreatedContainer, WaitCondition, WaitStatus, Create, PrunedContainers};
 use crate::container::FSChanges;
+use crate::container::Stats;
+use crate::container::{Config, ContainerId, ContainerRefId, EnsureAction, EnsuredContainer, Checkpoint, PathStat, StartOptions, Upload};
+use crate::additionals::host::host_config::HostConfigBuilder;
+use crate::container::logs::LogLine;
+use crate::container::multi_logs::{MultiLogs, MultiLogStream, Selector};
+use crate::container::watchdog::Watchdog;
+use crate::reconcile::{ContainerSpec, Reconciled};
+use crate::container::attach::{AttachOptions, AttachedContainer};
 use crate::container::{ShortContainerInfo};
-use crate::container::inspect::{Inspect, ContainerInfo};
+use crate::container::inspect::{Inspect, ContainerInfo, HealthStatus};
 use crate::container::processes_list::{ProcessesList, TopList};
+use crate::exec::ExecOutput;
 
-use crate::client::DockerError;
+use crate::client::{DockerError, ErrorMessage, ApiVersion, DeserializeMode, Auth, AuthStatus, DockerConfig, CircuitBreaker, RetryPolicy, Transport, Proxy, Endpoint, EndpointError, DockerEnv, Raw, Capabilities, Ping};
+use crate::client::decode::unknown_fields;
+use crate::client::retry::{self, Idempotent};
+use crate::client::proxy::ProxyConnector;
 use crate::client::response::DockerResponse;
+use crate::client::stream::{chunks, json_lines, log_lines, parse_lines};
+use crate::events::{Event, ContainerEvent, EventsFilter, EventsRequest};
+
+use futures::{Stream, StreamExt};
+
+use tokio::io::AsyncWriteExt;
 
 use hyper::{Client, Request};
 
-use serde::{Deserialize, Serialize};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
 
 #[cfg(feature = "unix-socket")]
 use hyperlocal::UnixConnector;
 
 use hyper::Uri;
+use hyper::body::Bytes;
+use hyper::header::{HeaderMap, HeaderName, HeaderValue, USER_AGENT};
 
-use crate::image::ShortImageInfo;
-use crate::volume::{VolumeCreator, VolumeInfo, DeletedInfo, VolumesList};
+use crate::image::{ShortImageInfo, ImageInfo, ImageHistory, RemovedImage, PrunedImages, ImageRef, ImageId, Progress, PushedImage, PullSummary, MultiPullStream};
+use crate::image::build::{BuildOptions, BuiltImage};
+use crate::image::DistributionInfo;
+use crate::volume::{VolumeCreator, VolumeInfo, DeletedInfo, VolumesList, VolumeRef};
+use crate::networks::NetworkRef;
+use crate::swarm::{Service, CreatedService, UpdatedService, ObjectVersion, Task, Secret, SwarmConfig, UnlockKey};
+use crate::additionals::filters::Filters;
+use crate::additionals::duration::GoDuration;
+use crate::gc::{GarbageCollector, GcReport};
+use crate::system::{Version, SystemInfo, DiskUsage};
+use crate::additionals::query::encode;
 use hyper::client::HttpConnector;
+#[cfg(feature = "tls")]
+use crate::client::TlsConfig;
+#[cfg(feature = "tls")]
+use crate::client::tls::{HttpsClient, TlsConnector};
+#[cfg(feature = "tls")]
+use crate::client::env::default_config_dir;
 
+use std::collections::HashMap;
 use std::env;
-use std::path::Path;
+use std::sync::Arc;
+use std::pin::Pin;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 #[derive(Debug, Clone)]
 pub enum ClientConfig {
     TCP {
-        client: Client<HttpConnector, hyper::Body>,
+        client: Client<ProxyConnector, hyper::Body>,
+    },
+    #[cfg(feature = "tls")]
+    HTTPS {
+        client: HttpsClient,
+        tls: TlsConnector,
     },
     #[cfg(feature = "unix-socket")]
     UNIX {
         client: Client<UnixConnector, hyper::Body>,
+    },
+    Custom {
+        transport: Arc<dyn Transport>,
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct Auth {
-    pub username: String,
-    pub password: String,
-    pub email: String,
+/// Connection and settings shared by clones of a `DockerClient`.
+#[derive(Debug, Clone)]
+struct Shared {
+    host: String,
+    config: ClientConfig,
+    auth: Option<Auth>,
+    api_version: Option<ApiVersion>,
+    deserialize_mode: DeserializeMode,
+    connect_timeout: Option<Duration>,
+    read_timeout: Option<Duration>,
+    timeout: Option<Duration>,
+    max_idle_connections: usize,
+    breaker: Option<CircuitBreaker>,
+    retry: Option<RetryPolicy>,
+    proxy: Option<Proxy>,
+    headers: HeaderMap,
+    config_dir: Option<PathBuf>
+}
+
+impl Shared {
+
+    /// Build the HTTP client again after a connection setting changed.
+    fn rebuild_client(&mut self) {
+        match self.config {
+            ClientConfig::TCP { ref mut client } => *client = tcp_client(self.connect_timeout, self.max_idle_connections, self.proxy.clone()),
+            #[cfg(feature = "tls")]
+            ClientConfig::HTTPS { ref mut client, ref tls } => *client = https_client(tls, self.connect_timeout, self.max_idle_connections, self.proxy.clone()),
+            #[cfg(feature = "unix-socket")]
+            ClientConfig::UNIX { ref mut client } => *client = unix_client(self.max_idle_connections),
+            ClientConfig::Custom {..} => {},
+        }
+    }
 
-    #[serde(rename = "serveraddress", skip_serializing_if = "Option::is_none")]
-    pub server_address: Option<String>
 }
 
 /// `DockerClient` struct.
+///
+/// Clones share the connection pool and settings, cloning only bumps a reference count.
 #[derive(Debug, Clone)]
 pub struct DockerClient {
-    host: String,
-    config: ClientConfig,
-    auth: Option<Auth>
+    inner: Arc<Shared>,
+}
+
+/// Builder of a `DockerClient` with its connection settings
+///
+/// # Examples
+///
+/// ```rust
+/// use docker_client::DockerClient;
+/// use std::time::Duration;
+///
+/// let client = DockerClient::builder()
+///     .host("tcp://127.0.0.1:2375")
+///     .connect_timeout(Duration::from_secs(2))
+///     .read_timeout(Duration::from_secs(30))
+///     .max_idle_connections(4)
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(client.read_timeout(), Some(Duration::from_secs(30)));
+/// assert!(DockerClient::builder().host("ftp://example.com").build().is_err());
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct DockerClientBuilder {
+    host: Option<String>,
+    auth: Option<Auth>,
+    api_version: Option<ApiVersion>,
+    connect_timeout: Option<Duration>,
+    read_timeout: Option<Duration>,
+    timeout: Option<Duration>,
+    max_idle_connections: Option<usize>,
+    retry: Option<RetryPolicy>,
+    proxy: Option<Proxy>,
+    headers: HeaderMap,
+    #[cfg(feature = "tls")]
+    tls: Option<TlsConfig>,
+}
+
+impl DockerClientBuilder {
+
+    /// Creates a new default `DockerClientBuilder`, connecting to `Endpoint::default()`.
+    pub fn new() -> Self {
+        DockerClientBuilder::default()
+    }
+
+    /// Set address of the daemon, as in `DOCKER_HOST`, e.g. `tcp://10.0.0.2:2375`
+    pub fn host<T>(mut self, host: T) -> Self
+        where T: Into<String>
+    {
+        self.host = Some(host.into());
+
+        self
+    }
+
+    /// Set endpoint of the daemon
+    pub fn endpoint(mut self, endpoint: Endpoint) -> Self {
+        self.host = Some(endpoint.to_string());
+
+        self
+    }
+
+    /// Set registry credentials
+    pub fn auth(mut self, auth: Auth) -> Self {
+        self.auth = Some(auth);
+
+        self
+    }
+
+    /// Set API version of the daemon, see `DockerClient::with_api_version`
+    pub fn api_version(mut self, version: ApiVersion) -> Self {
+        self.api_version = Some(version);
+
+        self
+    }
+
+    /// Set how long to wait for a TCP connection, see `DockerClient::with_connect_timeout`
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+
+        self
+    }
+
+    /// Set how long to wait for a complete response, see `DockerClient::with_read_timeout`
+    pub fn read_timeout(mut self, timeout: Duration) -> Self {
+        self.read_timeout = Some(timeout);
+
+        self
+    }
+
+    /// Set how long any request may take, see `DockerClient::with_timeout`
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
+    /// Set how many idle connections are kept for reuse, see `DockerClient::with_max_idle_connections`
+    pub fn max_idle_connections(mut self, max: usize) -> Self {
+        self.max_idle_connections = Some(max);
+
+        self
+    }
+
+    /// Set retry policy of transient failures, see `RetryPolicy`
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry = Some(policy);
+
+        self
+    }
+
+    /// Set proxy of TCP and TLS connections, see `DockerClient::with_proxy`
+    pub fn proxy(mut self, proxy: Proxy) -> Self {
+        self.proxy = Some(proxy);
+
+        self
+    }
+
+    /// Set `User-Agent` of requests, see `DockerClient::with_user_agent`
+    pub fn user_agent(mut self, value: HeaderValue) -> Self {
+        self.headers.insert(USER_AGENT, value);
+
+        self
+    }
+
+    /// Add a header sent with every request, see `DockerClient::with_header`
+    pub fn header(mut self, name: HeaderName, value: HeaderValue) -> Self {
+        self.headers.insert(name, value);
+
+        self
+    }
+
+    /// Set TLS settings of `https` endpoints, see `DockerClient::connect` for the default
+    #[cfg(feature = "tls")]
+    pub fn tls(mut self, tls: TlsConfig) -> Self {
+        self.tls = Some(tls);
+
+        self
+    }
+
+    /// Build `DockerClient` from `DockerClientBuilder`
+    ///
+    /// Return `EndpointError` if the host can not be parsed or its transport is not supported.
+    /// No connection is made before the first request.
+    pub fn build(self) -> Result<DockerClient, EndpointError> {
+        let endpoint = match self.host {
+            Some(host) => host.parse()?,
+            None => Endpoint::default(),
+        };
+
+        #[cfg(feature = "tls")]
+        let mut client = match (&endpoint, &self.tls) {
+            (Endpoint::Https { .. }, Some(tls)) => DockerClient::https(endpoint.authority().unwrap_or_default(), tls, None)?,
+            _ => DockerClient::connect(endpoint.clone())?,
+        };
+
+        #[cfg(not(feature = "tls"))]
+        let mut client = DockerClient::connect(endpoint)?;
+
+        let inner = Arc::make_mut(&mut client.inner);
+        inner.auth = self.auth;
+        inner.api_version = self.api_version;
+        inner.connect_timeout = self.connect_timeout;
+        inner.read_timeout = self.read_timeout;
+        inner.timeout = self.timeout;
+        inner.max_idle_connections = self.max_idle_connections.unwrap_or(MAX_IDLE_CONNECTIONS);
+        inner.retry = self.retry;
+        if self.proxy.is_some() {
+            inner.proxy = self.proxy;
+        }
+        inner.headers.extend(self.headers);
+        inner.rebuild_client();
+
+        Ok(client)
+    }
+
 }
 
 impl DockerClient {
 
     /// Connect to docker
     ///
-    /// # Arguments
-    /// * `sock` - connection address
+    /// Connects to `DOCKER_HOST` if set, otherwise to `Endpoint::default()`.
+    ///
+    /// # Panics
+    /// Panics if `DOCKER_HOST` can not be parsed or its transport is not supported,
+    /// see `DockerClient::connect`.
     ///
     /// # Examples
     ///
@@ -71,390 +321,5306 @@ impl DockerClient {
     /// }
     /// ```
     pub fn new() -> DockerClient {
+        let endpoint = match env::var("DOCKER_HOST").ok() {
+            Some(host) => host.parse().unwrap_or_else(|e| panic!("DOCKER_HOST: {}", e)),
+            None => Endpoint::default(),
+        };
 
-        match env::var("DOCKER_HOST").ok() {
-            Some(host) => {
-                #[cfg(feature = "unix-socket")]
-                if let Some(path) = host.strip_prefix("unix://") {
-                    return DockerClient::unix(path, None);
-                }
-                DockerClient::stream(host, None)
-            },
-            #[cfg(feature = "unix-socket")]
-            None => {
-                DockerClient::unix("/var/run/docker.sock", None)
-            },
-            None => {
-                DockerClient::stream("tcp://localhost:2375", None)
-            }
-        }
-
+        DockerClient::connect(endpoint).unwrap_or_else(|e| panic!("DOCKER_HOST: {}", e))
     }
 
-    pub fn with_auth(auth: Auth) -> Self {
-        let mut ctx = Self::new();
-        ctx.auth = Some(auth);
-        ctx
+    /// Creates a new default `DockerClientBuilder` to construct a `DockerClient`
+    pub fn builder() -> DockerClientBuilder {
+        DockerClientBuilder::default()
     }
 
-    pub fn registry_auth(&self) -> String {
-        //let auth = self.auth.clone().unwrap();
-        base64::encode(
-            json::to_string(&self.auth.as_ref().unwrap()).unwrap()
-        )
+    /// Connect to docker like the docker CLI does
+    ///
+    /// Reads `DOCKER_HOST`, `DOCKER_API_VERSION`, `DOCKER_TLS_VERIFY`, `DOCKER_CERT_PATH`
+    /// and `DOCKER_CONFIG`, see `DockerEnv`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use docker_client::DockerClient;
+    ///
+    /// match DockerClient::from_env() {
+    ///     Ok(client) => { println!("API version {:?}", client.api_version()); },
+    ///     Err(e) => { println!("Error: {}", e); },
+    /// }
+    /// ```
+    pub fn from_env() -> Result<DockerClient, EndpointError> {
+        DockerClient::from_docker_env(&DockerEnv::read()?)
     }
 
-    #[cfg(feature = "unix-socket")]
-    pub fn unix<T>(host: T, auth: Option<Auth>) -> DockerClient
-        where T: Into<String>
-    {
-        DockerClient {
-            host: host.into(),
-            config: ClientConfig::UNIX {
-                client: Client::builder()
-                    .pool_max_idle_per_host(0)
-                    .build:: < _, hyper::Body>(UnixConnector::default())
+    /// Connect to docker with settings of `env`
+    ///
+    /// With feature `tls-rustls` or `tls-native`, `https` endpoints use the certificates of `env.cert_path()`.
+    pub fn from_docker_env(env: &DockerEnv) -> Result<DockerClient, EndpointError> {
+        let client = match env.endpoint() {
+            #[cfg(feature = "tls")]
+            Endpoint::Https { .. } => {
+                let dir = env.cert_path().cloned().unwrap_or_else(|| env.config_dir().clone());
+                DockerClient::https(env.endpoint().authority().unwrap_or_default(), &TlsConfig::from_cert_path(dir, env.tls_verify()), None)?
             },
-            auth
-        }
-    }
+            endpoint => DockerClient::connect(endpoint.clone())?,
+        };
+        let mut client = client.with_config_dir(env.config_dir());
 
-    pub fn stream<T>(host: T, auth: Option<Auth>) -> DockerClient
-        where T: Into<String>
-    {
-        DockerClient {
-            host: host.into().strip_prefix("tcp://").unwrap().to_string(),
-            config: ClientConfig::TCP {
-                client: Client::builder()
-                    .pool_max_idle_per_host(0)
-                    .build::<_, hyper::Body>(HttpConnector::new())
-            },
-            auth
+        if let Some(version) = env.api_version() {
+            client = client.with_api_version(version);
         }
+
+        Ok(client)
     }
 
-    pub fn make_uri<T>(&self, path: T) -> hyper::Uri
-        where T: Into<String>
-    {
-        match self.config {
-            ClientConfig::TCP {..} => {
-                Uri::builder().scheme("http")
-                    .authority(self.host.as_str())
-                    .path_and_query(path.into().as_str())
-                    .build()
-                    .unwrap()
+    /// Connect to the daemon at `endpoint`
+    ///
+    /// `https` endpoints need feature `tls-rustls` or `tls-native` and verify the daemon against `ca.pem`
+    /// of `DOCKER_CERT_PATH`, or of `~/.docker` if unset, see `DockerClient::https`.
+    ///
+    /// Return `EndpointError::UnsupportedTransport` for endpoints the client can not
+    /// connect over yet: `npipe`, `ssh`, `fd`, `https` without a TLS feature and
+    /// `unix` without the `unix-socket` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use docker_client::DockerClient;
+    /// use docker_client::client::{Endpoint, EndpointError};
+    ///
+    /// let client = DockerClient::connect("tcp://127.0.0.1:2375".parse().unwrap());
+    /// assert!(client.is_ok());
+    ///
+    /// match DockerClient::connect("ssh://user@build-host".parse().unwrap()) {
+    ///     Err(EndpointError::UnsupportedTransport(Endpoint::Ssh { .. })) => {},
+    ///     _ => unreachable!(),
+    /// }
+    /// ```
+    pub fn connect(endpoint: Endpoint) -> Result<DockerClient, EndpointError> {
+        match endpoint {
+            Endpoint::Tcp { .. } => Ok(DockerClient::stream(endpoint.authority().unwrap_or_default(), None)),
+            #[cfg(feature = "tls")]
+            Endpoint::Https { .. } => {
+                let dir = env::var_os("DOCKER_CERT_PATH").map(PathBuf::from).unwrap_or_else(default_config_dir);
+                DockerClient::https(endpoint.authority().unwrap_or_default(), &TlsConfig::from_cert_path(dir, true), None)
             },
             #[cfg(feature = "unix-socket")]
-            ClientConfig::UNIX {..} => {
-                hyperlocal::Uri::new(self.host.as_str(), path.into().as_str()).into()
-            }
+            Endpoint::Unix(ref path) => Ok(DockerClient::unix(path.to_string_lossy(), None)),
+            _ => Err(EndpointError::UnsupportedTransport(endpoint)),
         }
     }
 
-    async fn execute_async(&self, request: hyper::Request<hyper::Body>) -> Result<DockerResponse, DockerError> {
-        let config = self.config.clone();
-        let response = match config {
-            ClientConfig::TCP { ref client, ..} => client.request(request).await,
-            #[cfg(feature = "unix-socket")]
-            ClientConfig::UNIX { ref client, ..} => client.request(request).await
-        };
-
-        match response {
-            Ok(resp) => Ok(
-                DockerResponse {
-                    status: resp.status().as_u16(),
-                    body: hyper::body::to_bytes(resp.into_body()).await.unwrap()
-                }
-            ),
-            Err(_) => Err(DockerError::ClosedConnection)
-        }
+    pub fn with_auth(auth: Auth) -> Self {
+        let mut ctx = Self::new();
+        Arc::make_mut(&mut ctx.inner).auth = Some(auth);
+        ctx
     }
 
-}
-
-
-impl DockerClient {
+    /// Set API version of the daemon
+    ///
+    /// Request paths are prefixed with the version, e.g. `/v1.41/containers/json`.
+    /// Requests using fields of newer API versions fail with `DockerError::BadParameters`
+    /// before they are sent. Without a version paths are unversioned and no check is done,
+    /// see `negotiate_version`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use docker_client::DockerClient;
+    /// use docker_client::client::ApiVersion;
+    ///
+    /// let client = DockerClient::new().with_api_version(ApiVersion::new(1, 41));
+    ///
+    /// assert!(client.supports(ApiVersion::new(1, 40)));
+    /// assert!(!client.supports(ApiVersion::new(1, 43)));
+    /// ```
+    pub fn with_api_version(mut self, version: ApiVersion) -> Self {
+        Arc::make_mut(&mut self.inner).api_version = Some(version);
+        self
+    }
 
-    pub async fn top(&self, request: ProcessesList) -> Result<TopList, DockerError> {
+    /// Return API version of the daemon if set
+    pub fn api_version(&self) -> Option<ApiVersion> {
+        self.inner.api_version
+    }
 
-        let uri = self.make_uri(request.get_path());
-        let request = Request::get(uri).body(hyper::Body::empty()).unwrap();
+    /// Return `true` if the daemon supports `version`, always `true` if no version is set
+    pub fn supports(&self, version: ApiVersion) -> bool {
+        self.inner.api_version.is_none_or(|current| current >= version)
+    }
 
-        self.execute_async(request).await
-            .and_then(|response| {
-                match response.status {
-                    200 => Ok(json::from_str(response.body_as_string().as_str()).unwrap()),
-                    404 => Err(DockerError::NotFound(json::from_str(response.body_as_string().as_str()).unwrap())),
-                    500 => Err(DockerError::ServerError(json::from_str(response.body_as_string().as_str()).unwrap())),
-                    _ => Err(DockerError::UnknownStatus),
-                }
-            })
-            .map_err(|e| e)
+    /// Set deserialization mode of responses
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use docker_client::DockerClient;
+    /// use docker_client::client::DeserializeMode;
+    ///
+    /// let client = DockerClient::new().with_deserialize_mode(DeserializeMode::Strict);
+    /// ```
+    pub fn with_deserialize_mode(mut self, mode: DeserializeMode) -> Self {
+        Arc::make_mut(&mut self.inner).deserialize_mode = mode;
+        self
     }
 
-    pub async fn containers_list(&self, request: crate::container::list::Request) -> Result<Vec<ShortContainerInfo>, DockerError> {
+    /// Return deserialization mode of responses
+    pub fn deserialize_mode(&self) -> DeserializeMode {
+        self.inner.deserialize_mode
+    }
 
-        let uri = self.make_uri(request.get_path());
-        let request = Request::get(uri).body(hyper::Body::empty()).unwrap();
+    /// Set how long to wait for a TCP connection to the daemon
+    ///
+    /// A dead or unreachable daemon fails with `DockerError::Timeout` after `timeout`.
+    /// Unix sockets connect locally and are not affected.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use docker_client::DockerClient;
+    /// use std::time::Duration;
+    ///
+    /// let client = DockerClient::new()
+    ///     .with_connect_timeout(Duration::from_secs(2))
+    ///     .with_read_timeout(Duration::from_secs(30));
+    /// ```
+    pub fn with_connect_timeout(mut self, timeout: Duration) -> Self {
+        let inner = Arc::make_mut(&mut self.inner);
+        inner.connect_timeout = Some(timeout);
+        inner.rebuild_client();
 
-        self.execute_async(request).await
-            .and_then(|response| {
-                match response.status {
-                    200 => Ok(json::from_str(response.body_as_string().as_str()).unwrap()),
-                    400 => Err(DockerError::BadParameters(json::from_str(response.body_as_string().as_str()).unwrap())),
-                    500 => Err(DockerError::ServerError(json::from_str(response.body_as_string().as_str()).unwrap())),
-                    _ => Err(DockerError::UnknownStatus),
-                }
-            })
-            .map_err(|e| e)
+        self
+    }
 
+    /// Return connect timeout if set
+    pub fn connect_timeout(&self) -> Option<Duration> {
+        self.inner.connect_timeout
     }
 
-    /// Create a container
+    /// Set how many idle connections are kept for reuse
     ///
-    /// # Arguments
-    /// * `Config` is container to create.
+    /// Connections are pooled by default, so frequent calls like polling stats or
+    /// lists skip connecting to the daemon. Zero disables pooling: every request
+    /// opens a new connection.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// extern crate tokio;
     /// use docker_client::DockerClient;
-    /// use docker_client::container::{Create, Config};
     ///
-    /// #[tokio::main]
-    /// async fn main() {
-    ///
-    ///    let client = DockerClient::new();
+    /// let client = DockerClient::new().with_max_idle_connections(0);
     ///
-    ///     let config = Create::new().config(Config::with_image("alpine").build()).name("hi").build();
-    ///     match client.create_container(config).await {
-    ///         Ok(_) => {}
-    ///         Err(_) => {}
-    ///     }
-    /// }
+    /// assert_eq!(client.max_idle_connections(), 0);
     /// ```
-    pub async fn create_container(&self, request: Create) -> Result<CreatedContainer, DockerError> {
+    pub fn with_max_idle_connections(mut self, max: usize) -> Self {
+        let inner = Arc::make_mut(&mut self.inner);
+        inner.max_idle_connections = max;
+        inner.rebuild_client();
 
-        let uri = self.make_uri(request.get_path());
+        self
+    }
 
-        let request = Request::post(uri)
-            .header("Content-Type", "application/json")
-            .body(hyper::Body::from(request.body()))
-            .unwrap();
+    /// Return how many idle connections are kept for reuse
+    pub fn max_idle_connections(&self) -> usize {
+        self.inner.max_idle_connections
+    }
 
-        self.execute_async(request).await
-            .and_then(|response| {
-                match response.status {
-                    201 => Ok(json::from_str(response.body_as_string().as_str()).unwrap()),
-                    400 => Err(DockerError::BadParameters(json::from_str(response.body_as_string().as_str()).unwrap())),
-                    404 => Err(DockerError::NotFound(json::from_str(response.body_as_string().as_str()).unwrap())),
-                    409 => Err(DockerError::ContainerExists(json::from_str(response.body_as_string().as_str()).unwrap())),
-                    500 => Err(DockerError::ServerError(json::from_str(response.body_as_string().as_str()).unwrap())),
-                    _ => Err(DockerError::UnknownStatus),
-                }
-            })
-            .map_err(|e| e)
+    /// Set how long to wait for a complete response
+    ///
+    /// Requests whose response takes longer fail with `DockerError::Timeout`.
+    /// Streams (`events`) and requests which last as long as an operation in the
+    /// daemon (stop, wait, attach, exec start, pull, push and build) are exempt.
+    pub fn with_read_timeout(mut self, timeout: Duration) -> Self {
+        Arc::make_mut(&mut self.inner).read_timeout = Some(timeout);
+        self
     }
 
-    /// Returns which files in a container's filesystem have been added, deleted, or modified.
+    /// Return read timeout if set
+    pub fn read_timeout(&self) -> Option<Duration> {
+        self.inner.read_timeout
+    }
+
+    /// Set how long any request may take, including the ones exempt from the read timeout
     ///
-    /// # Arguments
-    /// * `id` - ID or name of the container.
+    /// A request still running after `timeout` is cancelled, its connection is closed,
+    /// and the call fails with `DockerError::Timeout`. Streaming calls must receive the
+    /// response head within `timeout`, the stream itself is not bounded.
+    ///
+    /// Clones are cheap, so a single call can be bounded without changing the client.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use docker_client::DockerClient;
+    /// # use docker_client::{DockerClient, DockerError};
+    /// use std::time::Duration;
     ///
     /// # #[tokio::main]
     /// async fn main() {
     ///    let client = DockerClient::new();
     ///
-    ///     let changes = client.get_fs_changes("test").await.unwrap_or(Vec::new());
-    ///
-    ///     for change in &changes {
-    ///         println!("{:?}", change);
+    ///     match client.clone().with_timeout(Duration::from_secs(60)).stop_container("test-container", Some(30)).await {
+    ///         Ok(_) => {},
+    ///         Err(DockerError::Timeout) => { println!("Daemon did not answer within a minute."); },
+    ///         Err(e) => { println!("Error: {:?}", e); },
     ///     }
+    ///
     /// }
     /// ```
-    pub async fn get_fs_changes<T>(&self, id: T) -> Result<Vec<FSChanges>, DockerError>
-        where T: Into<String>
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        Arc::make_mut(&mut self.inner).timeout = Some(timeout);
+        self
+    }
+
+    /// Return timeout of requests if set
+    pub fn timeout(&self) -> Option<Duration> {
+        self.inner.timeout
+    }
+
+    /// Set directory of the docker CLI configuration, holding `config.json`
+    ///
+    /// Without credentials set on the client, pulls and pushes use the credentials
+    /// of the registry of the image found there, see `DockerConfig`.
+    pub fn with_config_dir<T>(mut self, dir: T) -> Self
+        where T: Into<PathBuf>
     {
+        Arc::make_mut(&mut self.inner).config_dir = Some(dir.into());
+        self
+    }
+
+    /// Return directory of the docker CLI configuration if set
+    pub fn config_dir(&self) -> Option<&Path> {
+        self.inner.config_dir.as_deref()
+    }
+
+    /// Fail fast while the daemon is down, see `CircuitBreaker`
+    pub fn with_circuit_breaker(mut self, breaker: CircuitBreaker) -> Self {
+        Arc::make_mut(&mut self.inner).breaker = Some(breaker);
+        self
+    }
+
+    /// Return circuit breaker if set
+    pub fn circuit_breaker(&self) -> Option<&CircuitBreaker> {
+        self.inner.breaker.as_ref()
+    }
+
+    /// Retry transient failures of idempotent requests, see `RetryPolicy`
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        Arc::make_mut(&mut self.inner).retry = Some(policy);
+        self
+    }
+
+    /// Return retry policy if set
+    pub fn retry_policy(&self) -> Option<&RetryPolicy> {
+        self.inner.retry.as_ref()
+    }
+
+    /// Connect to TCP and TLS daemons through `proxy`
+    ///
+    /// TCP and TLS clients use the proxies of `HTTP_PROXY`, `HTTPS_PROXY` and `NO_PROXY`
+    /// by default. Unix sockets are always reached directly.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use docker_client::DockerClient;
+    /// use docker_client::client::Proxy;
+    ///
+    /// let proxy = Proxy::new("http://proxy.corp:3128").unwrap().no_proxy(".corp");
+    /// let client = DockerClient::stream("tcp://10.0.0.2:2375", None).with_proxy(proxy);
+    ///
+    /// assert!(client.proxy().is_some());
+    /// ```
+    pub fn with_proxy(mut self, proxy: Proxy) -> Self {
+        let inner = Arc::make_mut(&mut self.inner);
+        inner.proxy = Some(proxy);
+        inner.rebuild_client();
+
+        self
+    }
+
+    /// Return proxy of TCP and TLS connections if set
+    pub fn proxy(&self) -> Option<&Proxy> {
+        self.inner.proxy.as_ref()
+    }
+
+    /// Set `User-Agent` of requests, `docker-client/<version>` by default
+    pub fn with_user_agent(self, value: HeaderValue) -> Self {
+        self.with_header(USER_AGENT, value)
+    }
+
+    /// Send header `name` with every request, e.g. for an auditing proxy in front of the daemon
+    ///
+    /// A header of the same name set earlier is replaced. Headers an endpoint sets itself,
+    /// like `X-Registry-Auth` or `Content-Type`, are never replaced. To send a header with
+    /// some calls only, set it on a clone of the client.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use docker_client::DockerClient;
+    /// use hyper::header::{HeaderName, HeaderValue};
+    ///
+    /// let client = DockerClient::new()
+    ///     .with_user_agent(HeaderValue::from_static("deploy-bot/2.1"))
+    ///     .with_header(HeaderName::from_static("x-audit-user"), HeaderValue::from_static("ci"));
+    ///
+    /// let release = client.clone()
+    ///     .with_header(HeaderName::from_static("x-audit-user"), HeaderValue::from_static("release-manager"));
+    ///
+    /// assert_eq!(client.headers()["x-audit-user"], "ci");
+    /// assert_eq!(release.headers()["x-audit-user"], "release-manager");
+    /// ```
+    pub fn with_header(mut self, name: HeaderName, value: HeaderValue) -> Self {
+        Arc::make_mut(&mut self.inner).headers.insert(name, value);
+        self
+    }
+
+    /// Return headers sent with every request
+    pub fn headers(&self) -> &HeaderMap {
+        &self.inner.headers
+    }
+
+    /// Return error if `required` is newer than the API version of the daemon
+    fn check_api_version(&self, required: Option<ApiVersion>) -> Result<(), DockerError> {
+        match (required, self.inner.api_version) {
+            (Some(required), Some(current)) if current < required => Err(DockerError::BadParameters(ErrorMessage {
+                message: format!("request requires API version {}, client is set to {}", required, current)
+            })),
+            _ => Ok(()),
+        }
+    }
+
+    /// Return `X-Registry-Auth` header value, marked as sensitive so it is not shown by `Debug`.
+    ///
+    /// Without credentials an empty auth object is sent.
+    fn registry_auth_header(&self) -> HeaderValue {
+        match self.inner.auth {
+            Some(ref auth) => auth_header(auth),
+            None => auth_header(&Auth::default()),
+        }
+    }
+
+    /// Return `X-Registry-Auth` header value for image `reference`
+    ///
+    /// Credentials of the request are used if set, then those of the client, then those
+    /// of the registry of the image in the CLI configuration if its directory is set,
+    /// see `DockerConfig`.
+    async fn image_auth_header(&self, reference: &str, auth: Option<&Auth>) -> Result<Option<HeaderValue>, DockerError> {
+        if let Some(auth) = auth {
+            return Ok(Some(auth_header(auth)));
+        }
+        if self.inner.auth.is_some() {
+            return Ok(Some(self.registry_auth_header()));
+        }
+
+        let dir = match self.inner.config_dir {
+            Some(ref dir) => dir,
+            None => return Ok(None),
+        };
+
+        let config = DockerConfig::load(dir).map_err(DockerError::Credentials)?;
+        let auth = config.auth_for_image(reference).await.map_err(DockerError::Credentials)?;
+
+        Ok(auth.as_ref().map(auth_header))
+    }
+
+    pub fn registry_auth(&self) -> String {
+        self.inner.auth.as_ref().unwrap().encode()
+    }
+
+    #[cfg(feature = "unix-socket")]
+    pub fn unix<T>(host: T, auth: Option<Auth>) -> DockerClient
+        where T: Into<String>
+    {
+        DockerClient {
+            inner: Arc::new(Shared {
+                host: host.into(),
+                config: ClientConfig::UNIX {
+                    client: unix_client(MAX_IDLE_CONNECTIONS)
+                },
+                auth,
+                api_version: None,
+                deserialize_mode: DeserializeMode::default(),
+                connect_timeout: None,
+                read_timeout: None,
+                timeout: None,
+                max_idle_connections: MAX_IDLE_CONNECTIONS,
+                breaker: None,
+                retry: None,
+                proxy: None,
+                headers: default_headers(),
+                config_dir: None
+            })
+        }
+    }
+
+    /// Connect to the daemon over TCP
+    ///
+    /// `host` is `host:port`, optionally prefixed with `tcp://` or `http://`.
+    pub fn stream<T>(host: T, auth: Option<Auth>) -> DockerClient
+        where T: Into<String>
+    {
+        let proxy = Proxy::from_env().ok().flatten();
+
+        DockerClient {
+            inner: Arc::new(Shared {
+                host: authority(host.into()),
+                config: ClientConfig::TCP {
+                    client: tcp_client(None, MAX_IDLE_CONNECTIONS, proxy.clone())
+                },
+                auth,
+                api_version: None,
+                deserialize_mode: DeserializeMode::default(),
+                connect_timeout: None,
+                read_timeout: None,
+                timeout: None,
+                max_idle_connections: MAX_IDLE_CONNECTIONS,
+                breaker: None,
+                retry: None,
+                proxy,
+                headers: default_headers(),
+                config_dir: None
+            })
+        }
+    }
+
+    /// Connect to the daemon over TLS
+    ///
+    /// `host` is `host:port`, optionally prefixed with `https://`.
+    /// Return `EndpointError::InvalidCertificate` if the certificates of `tls` can not be loaded.
+    #[cfg(feature = "tls")]
+    pub fn https<T>(host: T, tls: &TlsConfig, auth: Option<Auth>) -> Result<DockerClient, EndpointError>
+        where T: Into<String>
+    {
+        let tls = tls.connector()?;
+        let proxy = Proxy::from_env().ok().flatten();
+
+        Ok(DockerClient {
+            inner: Arc::new(Shared {
+                host: authority(host.into()),
+                config: ClientConfig::HTTPS {
+                    client: https_client(&tls, None, MAX_IDLE_CONNECTIONS, proxy.clone()),
+                    tls,
+                },
+                auth,
+                api_version: None,
+                deserialize_mode: DeserializeMode::default(),
+                connect_timeout: None,
+                read_timeout: None,
+                timeout: None,
+                max_idle_connections: MAX_IDLE_CONNECTIONS,
+                breaker: None,
+                retry: None,
+                proxy,
+                headers: default_headers(),
+                config_dir: None
+            })
+        })
+    }
+
+    /// Send requests over `transport` instead of connecting to a daemon
+    ///
+    /// Connection settings (timeouts, idle connections) are left to the transport,
+    /// see `Transport`.
+    pub fn from_transport<T>(transport: T) -> DockerClient
+        where T: Transport + 'static
+    {
+        DockerClient {
+            inner: Arc::new(Shared {
+                host: "localhost".to_string(),
+                config: ClientConfig::Custom {
+                    transport: Arc::new(transport)
+                },
+                auth: None,
+                api_version: None,
+                deserialize_mode: DeserializeMode::default(),
+                connect_timeout: None,
+                read_timeout: None,
+                timeout: None,
+                max_idle_connections: 0,
+                breaker: None,
+                retry: None,
+                proxy: None,
+                headers: default_headers(),
+                config_dir: None
+            })
+        }
+    }
+
+    /// Build the URI of `path`, prefixed with the API version if it is set, e.g. `/v1.44/info`
+    pub fn make_uri<T>(&self, path: T) -> hyper::Uri
+        where T: Into<String>
+    {
+        match self.inner.api_version {
+            Some(version) => self.unversioned_uri(format!("/v{}{}", version, path.into())),
+            None => self.unversioned_uri(path),
+        }
+    }
+
+    /// Build the URI of `path` as is.
+    fn unversioned_uri<T>(&self, path: T) -> hyper::Uri
+        where T: Into<String>
+    {
+        match self.inner.config {
+            ClientConfig::TCP {..} => {
+                Uri::builder().scheme("http")
+                    .authority(self.inner.host.as_str())
+                    .path_and_query(path.into().as_str())
+                    .build()
+                    .unwrap()
+            },
+            #[cfg(feature = "tls")]
+            ClientConfig::HTTPS {..} => {
+                Uri::builder().scheme("https")
+                    .authority(self.inner.host.as_str())
+                    .path_and_query(path.into().as_str())
+                    .build()
+                    .unwrap()
+            },
+            #[cfg(feature = "unix-socket")]
+            ClientConfig::UNIX {..} => {
+                hyperlocal::Uri::new(self.inner.host.as_str(), path.into().as_str()).into()
+            },
+            ClientConfig::Custom {..} => {
+                Uri::builder().scheme("http")
+                    .authority(self.inner.host.as_str())
+                    .path_and_query(path.into().as_str())
+                    .build()
+                    .unwrap()
+            }
+        }
+    }
+
+    /// Return host name of the daemon, where published ports are reachable
+    pub(crate) fn daemon_host(&self) -> String {
+        match self.inner.config {
+            ClientConfig::TCP {..} => self.network_host(),
+            #[cfg(feature = "tls")]
+            ClientConfig::HTTPS {..} => self.network_host(),
+            #[cfg(feature = "unix-socket")]
+            ClientConfig::UNIX {..} => "localhost".to_string(),
+            ClientConfig::Custom {..} => "localhost".to_string(),
+        }
+    }
+
+    /// Return host part of `host:port`.
+    fn network_host(&self) -> String {
+        let host = self.inner.host.as_str();
+        match host.strip_prefix('[') {
+            Some(rest) => rest.split(']').next().unwrap_or(rest).to_string(),
+            None => host.rsplit_once(':').map_or(host, |(host, _)| host).to_string(),
+        }
+    }
+
+    /// Stop a container waiting `wait` seconds, negative to wait until it exits.
+    async fn stop_container_with<T>(&self, id: T, wait: Option<i64>) -> Result<(), DockerError>
+        where T: Into<ContainerRefId>
+    {
+        let path = match wait {
+            Some(seconds) => format!("/containers/{}/stop?t={}", id.into(), seconds),
+            None => format!("/containers/{}/stop", id.into()),
+        };
+
+        let uri = self.make_uri(path);
+        let request = Request::post(uri)
+            .extension(Idempotent)
+            .body(hyper::Body::empty())
+            .unwrap();
+
+        self.execute_buffered(request).await
+            .and_then(|response| {
+                match response.status {
+                    204 => Ok(()),
+                    304 => Ok(()),
+                    404 => Err(DockerError::NotFound(response.error_message())),
+                    500 => Err(DockerError::ServerError(response.error_message())),
+                    _ => Err(response.unknown_status()),
+                }
+            })
+    }
+
+    /// Execute request and buffer the body within the read timeout.
+    async fn execute_async(&self, request: hyper::Request<hyper::Body>) -> Result<DockerResponse, DockerError> {
+        let timeout = match self.inner.read_timeout {
+            Some(timeout) => timeout,
+            None => return self.execute_buffered(request).await,
+        };
+
+        match tokio::time::timeout(timeout, self.execute_buffered(request)).await {
+            Ok(result) => result,
+            Err(_) => {
+                let result = Err(DockerError::Timeout);
+                self.record(&result);
+                result
+            }
+        }
+    }
+
+    /// Cancel `future` with `DockerError::Timeout` once the timeout of the client has passed.
+    async fn bounded<F, T>(&self, future: F) -> Result<T, DockerError>
+        where F: std::future::Future<Output = Result<T, DockerError>>
+    {
+        match self.inner.timeout {
+            Some(timeout) => tokio::time::timeout(timeout, future).await.unwrap_or(Err(DockerError::Timeout)),
+            None => future.await,
+        }
+    }
+
+    /// Return error without sending the request if the circuit breaker is open.
+    fn check_breaker(&self) -> Result<(), DockerError> {
+        match self.inner.breaker {
+            Some(ref breaker) => breaker.check(),
+            None => Ok(()),
+        }
+    }
+
+    /// Count the outcome of a sent request in the circuit breaker.
+    fn record<T>(&self, result: &Result<T, DockerError>) {
+        if let Some(ref breaker) = self.inner.breaker {
+            breaker.record(result);
+        }
+    }
+
+    /// Execute request and buffer the body without the read timeout, for endpoints
+    /// which respond when an operation in the daemon is done.
+    async fn execute_buffered(&self, request: hyper::Request<hyper::Body>) -> Result<DockerResponse, DockerError> {
+        self.check_breaker()?;
+
+        let result = self.bounded(async {
+            let resp = self.send(request).await?;
+            let status = resp.status().as_u16();
+            let headers = resp.headers().clone();
+            hyper::body::to_bytes(resp.into_body()).await
+                .map(|body| DockerResponse { status, body, headers })
+                .map_err(DockerError::connection)
+        }).await;
+
+        self.record(&result);
+        result
+    }
+
+    /// Send request, retrying transient failures of idempotent requests if a retry policy is set.
+    async fn send(&self, mut request: hyper::Request<hyper::Body>) -> Result<hyper::Response<hyper::Body>, DockerError> {
+        for name in self.inner.headers.keys() {
+            if !request.headers().contains_key(name) {
+                for value in self.inner.headers.get_all(name) {
+                    request.headers_mut().append(name, value.clone());
+                }
+            }
+        }
+
+        let policy = match self.inner.retry {
+            Some(policy) if retry::is_idempotent(&request) => policy,
+            _ => return self.send_once(request).await,
+        };
+
+        // The body is sent again on every attempt.
+        let (mut parts, body) = request.into_parts();
+        let body = hyper::body::to_bytes(body).await.map_err(DockerError::connection)?;
+
+        // Extensions can not be cloned: the first attempt takes them, later ones get the marker again.
+        let idempotent = parts.extensions.get::<Idempotent>().copied();
+        let mut extensions = Some(std::mem::take(&mut parts.extensions));
+
+        let mut attempt = 0;
+        loop {
+            let mut request = Request::builder()
+                .method(parts.method.clone())
+                .uri(parts.uri.clone())
+                .version(parts.version)
+                .body(hyper::Body::from(body.clone()))
+                .unwrap();
+            *request.headers_mut() = parts.headers.clone();
+            match extensions.take() {
+                Some(extensions) => *request.extensions_mut() = extensions,
+                None => {
+                    if let Some(idempotent) = idempotent {
+                        request.extensions_mut().insert(idempotent);
+                    }
+                },
+            }
+
+            let result = self.send_once(request).await;
+            if attempt >= policy.max_retries() || !retry::is_transient(&result) {
+                return result;
+            }
+
+            drop(result);
+            tokio::time::sleep(policy.delay(attempt)).await;
+            attempt += 1;
+        }
+    }
+
+    /// Send request once over the transport of the client.
+    async fn send_once(&self, request: hyper::Request<hyper::Body>) -> Result<hyper::Response<hyper::Body>, DockerError> {
+        let response = match self.inner.config {
+            ClientConfig::TCP { ref client, ..} => client.request(request).await,
+            #[cfg(feature = "tls")]
+            ClientConfig::HTTPS { ref client, ..} => client.request(request).await,
+            #[cfg(feature = "unix-socket")]
+            ClientConfig::UNIX { ref client, ..} => client.request(request).await,
+            ClientConfig::Custom { ref transport } => return transport.execute(request).await,
+        };
+
+        response.map_err(DockerError::connection)
+    }
+
+    /// Decode response body according to the deserialization mode.
+    fn decode<T>(&self, response: &DockerResponse) -> Result<T, DockerError>
+        where T: DeserializeOwned + Serialize
+    {
+        let value: T = response.json()?;
+
+        if self.inner.deserialize_mode == DeserializeMode::Strict {
+            let raw: json::Value = response.json()?;
+            let fields = unknown_fields(&raw, &json::to_value(&value).unwrap());
+
+            if !fields.is_empty() {
+                return Err(DockerError::UnknownFields(fields));
+            }
+        }
+
+        Ok(value)
+    }
+
+    /// Decode the body like `decode` and keep the body alongside.
+    fn decode_raw<T>(&self, response: &DockerResponse) -> Result<Raw<T>, DockerError>
+        where T: DeserializeOwned + Serialize
+    {
+        self.decode(response).map(|value| Raw::new(value, response.body.clone()))
+    }
+
+    /// Execute request without buffering the body of a `200` response, for streaming endpoints.
+    ///
+    /// The stream owns the connection: dropping it (or a stream built on it) before
+    /// the end of the body closes the connection immediately, it is never pooled.
+    /// Bodies of other statuses are buffered and mapped to an error by `error`.
+    async fn execute_stream<F>(&self, request: hyper::Request<hyper::Body>, error: F) -> Result<impl Stream<Item = Result<Bytes, DockerError>> + Unpin + Send, DockerError>
+        where F: FnOnce(DockerResponse) -> DockerError
+    {
+        self.check_breaker()?;
+
+        let result = self.bounded(self.send(request)).await;
+        self.record(&result);
+
+        let response = result?;
+        match response.status().as_u16() {
+            200 => Ok(chunks(response.into_body())),
+            status => {
+                let headers = response.headers().clone();
+                let body = hyper::body::to_bytes(response.into_body()).await.map_err(DockerError::connection)?;
+
+                Err(error(DockerResponse { status, body, headers }))
+            },
+        }
+    }
+
+}
+
+/// Idle connections kept for reuse by default.
+const MAX_IDLE_CONNECTIONS: usize = 16;
+
+/// Image of containers used to reach volume contents, it is never started.
+const VOLUME_HELPER_IMAGE: &str = "busybox:latest";
+
+/// Mount point of the volume in the helper container.
+const VOLUME_MOUNT: &str = "/volume";
+
+/// Check that `name` names a volume, a path would be bind-mounted from the host instead.
+fn check_volume_name(name: &str) -> Result<(), DockerError> {
+    match name.is_empty() || name.contains('/') || name.contains('\\') {
+        true => Err(DockerError::BadParameters(ErrorMessage { message: format!("invalid volume name: {:?}", name) })),
+        false => Ok(()),
+    }
+}
+
+/// Turn an error of an archive stream into an error of the request body.
+fn archive_error(error: DockerError) -> std::io::Error {
+    match error {
+        DockerError::Io(error) => error,
+        error => std::io::Error::other(format!("{:?}", error)),
+    }
+}
+
+/// Helper container of a volume export, removed in the background if the export stream is dropped.
+struct HelperGuard {
+    client: DockerClient,
+    id: Option<ContainerId>,
+}
+
+impl HelperGuard {
+
+    /// Remove the helper container now.
+    async fn remove(mut self) -> Result<(), DockerError> {
+        match self.id.take() {
+            Some(id) => self.client.remove_container(Remover::new().id(id).with_force_delete(true).build()).await,
+            None => Ok(()),
+        }
+    }
+
+}
+
+impl Drop for HelperGuard {
+    fn drop(&mut self) {
+        if let (Some(id), Ok(runtime)) = (self.id.take(), tokio::runtime::Handle::try_current()) {
+            let client = self.client.clone();
+            runtime.spawn(async move {
+                let _ = client.remove_container(Remover::new().id(id).with_force_delete(true).build()).await;
+            });
+        }
+    }
+}
+
+/// Check that the image has repo digest `digest`.
+fn verify_digest(info: &ImageInfo, digest: &str) -> Result<(), DockerError> {
+    match info.has_digest(digest) {
+        true => Ok(()),
+        false => Err(DockerError::DigestMismatch {
+            expected: digest.to_string(),
+            found: info.repo_digests().clone(),
+        }),
+    }
+}
+
+/// Headers of every request unless set otherwise, the `User-Agent` of the crate.
+fn default_headers() -> HeaderMap {
+    let mut headers = HeaderMap::new();
+    headers.insert(USER_AGENT, HeaderValue::from_static(concat!("docker-client/", env!("CARGO_PKG_VERSION"))));
+    headers
+}
+
+/// Encode credentials as `X-Registry-Auth` header value, marked as sensitive.
+fn auth_header(auth: &Auth) -> HeaderValue {
+    let mut value = HeaderValue::from_str(auth.encode().as_str()).unwrap();
+    value.set_sensitive(true);
+    value
+}
+
+/// Strip `tcp://`, `http://` or `https://` and a trailing slash from a TCP host.
+fn authority(host: String) -> String {
+    let host = host.strip_prefix("tcp://")
+        .or_else(|| host.strip_prefix("http://"))
+        .or_else(|| host.strip_prefix("https://"))
+        .unwrap_or(&host);
+
+    host.trim_end_matches('/').to_string()
+}
+
+/// Build the HTTP client of TCP connections.
+fn tcp_client(connect_timeout: Option<Duration>, max_idle_connections: usize, proxy: Option<Proxy>) -> Client<ProxyConnector, hyper::Body> {
+    let mut connector = HttpConnector::new();
+    connector.set_connect_timeout(connect_timeout);
+
+    Client::builder()
+        .pool_max_idle_per_host(max_idle_connections)
+        .build::<_, hyper::Body>(ProxyConnector::new(connector, proxy))
+}
+
+/// Build the HTTP client of unix socket connections.
+#[cfg(feature = "unix-socket")]
+fn unix_client(max_idle_connections: usize) -> Client<UnixConnector, hyper::Body> {
+    Client::builder()
+        .pool_max_idle_per_host(max_idle_connections)
+        .build::<_, hyper::Body>(UnixConnector::default())
+}
+
+/// Build the HTTP client of TLS connections.
+#[cfg(feature = "tls")]
+fn https_client(tls: &TlsConnector, connect_timeout: Option<Duration>, max_idle_connections: usize, proxy: Option<Proxy>) -> HttpsClient {
+    let mut connector = HttpConnector::new();
+    connector.set_connect_timeout(connect_timeout);
+
+    tls.client(connector, max_idle_connections, proxy)
+}
+
+
+impl DockerClient {
+
+    pub async fn top(&self, request: ProcessesList) -> Result<TopList, DockerError> {
+
+        let uri = self.make_uri(request.get_path());
+        let request = Request::get(uri).body(hyper::Body::empty()).unwrap();
+
+        self.execute_async(request).await
+            .and_then(|response| {
+                match response.status {
+                    200 => self.decode(&response),
+                    404 => Err(DockerError::NotFound(response.error_message())),
+                    500 => Err(DockerError::ServerError(response.error_message())),
+                    _ => Err(response.unknown_status()),
+                }
+            })
+            .map_err(|e| e)
+    }
+
+    pub async fn containers_list(&self, request: crate::container::list::Request) -> Result<Vec<ShortContainerInfo>, DockerError> {
+
+        let uri = self.make_uri(request.get_path());
+        let request = Request::get(uri).body(hyper::Body::empty()).unwrap();
+
+        self.execute_async(request).await
+            .and_then(|response| {
+                match response.status {
+                    200 => self.decode(&response),
+                    400 => Err(DockerError::BadParameters(response.error_message())),
+                    500 => Err(DockerError::ServerError(response.error_message())),
+                    _ => Err(response.unknown_status()),
+                }
+            })
+            .map_err(|e| e)
+
+    }
+
+    /// Check that the daemon is alive
+    ///
+    /// `/_ping` is the cheapest request of the API and does not depend on the API
+    /// version of the client, so it can be sent before negotiating one.
+    ///
+    /// Return Ping with the version headers of the daemon or DockerError.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use docker_client::{DockerClient, DockerError};
+    /// # #[tokio::main]
+    /// async fn main() {
+    ///    let client = DockerClient::new();
+    ///
+    ///     match client.ping().await {
+    ///         Ok(ping) => { println!("API {:?} builder {:?}", ping.api_version(), ping.builder_version()); },
+    ///         Err(e) => { println!("Daemon is not available: {:?}", e); },
+    ///     }
+    ///
+    /// }
+    /// ```
+    pub async fn ping(&self) -> Result<Ping, DockerError> {
+        let request = Request::get(self.unversioned_uri("/_ping"))
+            .body(hyper::Body::empty())
+            .unwrap();
+
+        self.execute_async(request).await
+            .and_then(|response| {
+                match response.status {
+                    200 => Ok(Ping::new(&response)),
+                    500 => Err(DockerError::ServerError(response.error_message())),
+                    _ => Err(response.unknown_status()),
+                }
+            })
+    }
+
+    /// Get version of the daemon
+    ///
+    /// Return Version with the API versions accepted by the daemon and its components, or DockerError.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use docker_client::{DockerClient, DockerError};
+    /// # use docker_client::client::ApiVersion;
+    /// # #[tokio::main]
+    /// async fn main() {
+    ///    let client = DockerClient::new();
+    ///
+    ///     match client.version().await {
+    ///         Ok(version) => {
+    ///             println!("Engine {} on {}/{}", version.version(), version.os(), version.arch());
+    ///             if version.supports(ApiVersion::new(1, 44)) {
+    ///                 println!("MAC address per network is supported");
+    ///             }
+    ///         },
+    ///         Err(e) => { println!("Error: {:?}", e); },
+    ///     }
+    ///
+    /// }
+    /// ```
+    pub async fn version(&self) -> Result<Version, DockerError> {
+        let request = Request::get(self.make_uri("/version"))
+            .body(hyper::Body::empty())
+            .unwrap();
+
+        self.execute_async(request).await
+            .and_then(|response| {
+                match response.status {
+                    200 => self.decode(&response),
+                    500 => Err(DockerError::ServerError(response.error_message())),
+                    _ => Err(response.unknown_status()),
+                }
+            })
+    }
+
+    /// Get system information of the daemon
+    ///
+    /// Return SystemInfo with counts of containers and images, storage driver, resources of the host and swarm state, or DockerError.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use docker_client::{DockerClient, DockerError};
+    /// # #[tokio::main]
+    /// async fn main() {
+    ///    let client = DockerClient::new();
+    ///
+    ///     match client.info().await {
+    ///         Ok(info) => {
+    ///             println!("{} running containers on {} CPUs", info.containers_running(), info.ncpu());
+    ///             println!("Swarm: {}", info.swarm().local_node_state());
+    ///         },
+    ///         Err(e) => { println!("Error: {:?}", e); },
+    ///     }
+    ///
+    /// }
+    /// ```
+    pub async fn info(&self) -> Result<SystemInfo, DockerError> {
+        let request = Request::get(self.make_uri("/info"))
+            .body(hyper::Body::empty())
+            .unwrap();
+
+        self.execute_async(request).await
+            .and_then(|response| {
+                match response.status {
+                    200 => self.decode(&response),
+                    500 => Err(DockerError::ServerError(response.error_message())),
+                    _ => Err(response.unknown_status()),
+                }
+            })
+    }
+
+    /// Get disk usage of the daemon
+    ///
+    /// Return DiskUsage with sizes of images, containers, volumes and build cache, or DockerError.
+    /// Computing sizes of volumes can take long on hosts with large volumes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use docker_client::{DockerClient, DockerError};
+    /// # #[tokio::main]
+    /// async fn main() {
+    ///    let client = DockerClient::new();
+    ///
+    ///     match client.disk_usage().await {
+    ///         Ok(usage) => {
+    ///             println!("Images: {} bytes reclaimable", usage.reclaimable_images());
+    ///             println!("Build cache: {} bytes reclaimable", usage.reclaimable_build_cache());
+    ///         },
+    ///         Err(e) => { println!("Error: {:?}", e); },
+    ///     }
+    ///
+    /// }
+    /// ```
+    pub async fn disk_usage(&self) -> Result<DiskUsage, DockerError> {
+        let request = Request::get(self.make_uri("/system/df"))
+            .body(hyper::Body::empty())
+            .unwrap();
+
+        self.execute_async(request).await
+            .and_then(|response| {
+                match response.status {
+                    200 => self.decode(&response),
+                    500 => Err(DockerError::ServerError(response.error_message())),
+                    _ => Err(response.unknown_status()),
+                }
+            })
+    }
+
+    /// Validate registry credentials
+    ///
+    /// The daemon logs in to the registry of `server_address` of `auth`, Docker Hub without it.
+    ///
+    /// Return AuthStatus with the identity token issued by the registry, if any, or DockerError.
+    /// Wrong credentials are reported as `DockerError::Unauthorized`.
+    ///
+    /// # Arguments
+    /// * `auth` - Credentials of the registry.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use docker_client::{DockerClient, DockerError};
+    /// # use docker_client::client::Auth;
+    /// # #[tokio::main]
+    /// async fn main() {
+    ///    let client = DockerClient::new();
+    ///
+    ///     let auth = Auth::builder().username("ci").password("s3cret").server_address("registry.example.com").build();
+    ///
+    ///     match client.check_auth(auth).await {
+    ///         Ok(status) => { println!("{}", status.status()); },
+    ///         Err(e) => { println!("Error: {:?}", e); },
+    ///     }
+    ///
+    /// }
+    /// ```
+    pub async fn check_auth(&self, auth: Auth) -> Result<AuthStatus, DockerError> {
+        let request = Request::post(self.make_uri("/auth"))
+            .header("Content-Type", "application/json")
+            .body(hyper::Body::from(json::to_string(&auth).unwrap()))
+            .unwrap();
+
+        self.execute_async(request).await
+            .and_then(|response| {
+                match response.status {
+                    200 => self.decode(&response),
+                    204 => Ok(AuthStatus::default()),
+                    401 => Err(DockerError::Unauthorized(response.error_message())),
+                    500 => Err(DockerError::ServerError(response.error_message())),
+                    _ => Err(response.unknown_status()),
+                }
+            })
+    }
+
+    /// Log in to a registry
+    ///
+    /// Validate `auth` with `check_auth` and use it for later pulls and pushes of the client,
+    /// with the identity token issued by the registry instead of the password if any.
+    /// Credentials of the client replace those of the CLI configuration.
+    ///
+    /// Return DockerClient or DockerError.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use docker_client::{DockerClient, DockerError};
+    /// # use docker_client::client::Auth;
+    /// # #[tokio::main]
+    /// async fn main() {
+    ///     let auth = Auth::builder().username("ci").password("s3cret").server_address("registry.example.com").build();
+    ///
+    ///     match DockerClient::new().login(auth).await {
+    ///         Ok(client) => { let _ = client.push_image("registry.example.com/api:1.2").await; },
+    ///         Err(e) => { println!("Error: {:?}", e); },
+    ///     }
+    ///
+    /// }
+    /// ```
+    pub async fn login(mut self, auth: Auth) -> Result<DockerClient, DockerError> {
+        let status = self.check_auth(auth.clone()).await?;
+
+        Arc::make_mut(&mut self.inner).auth = Some(status.credentials(auth));
+        Ok(self)
+    }
+
+    /// Detect features of the daemon
+    ///
+    /// Experimental features and the default builder are reported by headers of `/_ping`,
+    /// experimental features also by `/version` of older daemons.
+    ///
+    /// Return Capabilities or DockerError.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use docker_client::{DockerClient, DockerError};
+    /// # #[tokio::main]
+    /// async fn main() {
+    ///    let client = DockerClient::new();
+    ///
+    ///     match client.capabilities().await {
+    ///         Ok(capabilities) => { println!("API {} experimental {}", capabilities.api_version(), capabilities.experimental()); },
+    ///         Err(e) => { println!("Error: {:?}", e); },
+    ///     }
+    ///
+    /// }
+    /// ```
+    pub async fn capabilities(&self) -> Result<Capabilities, DockerError> {
+        let ping = self.ping().await?;
+
+        Ok(Capabilities::new(self.version().await?, &ping))
+    }
+
+    /// Negotiate the API version with the daemon
+    ///
+    /// The version of the daemon is read from the `Api-Version` header of `/_ping`, or from
+    /// `/version` of daemons which don't send it. The client then uses the older of it and
+    /// `ApiVersion::LATEST`: request paths are prefixed with it and requests using newer fields
+    /// are refused, see `with_api_version`. A version already set, e.g. by `DOCKER_API_VERSION`,
+    /// is kept and nothing is sent.
+    ///
+    /// Return the client or DockerError.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use docker_client::{DockerClient, DockerError};
+    /// # #[tokio::main]
+    /// async fn main() {
+    ///     match DockerClient::new().negotiate_version().await {
+    ///         Ok(client) => { println!("API version {:?}", client.api_version()); },
+    ///         Err(e) => { println!("Error: {:?}", e); },
+    ///     }
+    /// }
+    /// ```
+    pub async fn negotiate_version(self) -> Result<DockerClient, DockerError> {
+        if self.inner.api_version.is_some() {
+            return Ok(self);
+        }
+
+        // The client has no version yet, so `/version` is not prefixed either.
+        let daemon = match self.ping().await?.api_version() {
+            Some(version) => version,
+            None => self.version().await?.api_version(),
+        };
+
+        Ok(self.with_api_version(daemon.min(ApiVersion::LATEST)))
+    }
+
+    /// Return `DockerError::FeatureUnavailable` unless experimental features of the daemon are enabled.
+    async fn require_experimental(&self, feature: &str) -> Result<(), DockerError> {
+        self.capabilities().await?.require_experimental(feature)
+    }
+
+    /// Create a container
+    ///
+    /// # Arguments
+    /// * `Config` is container to create.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// extern crate tokio;
+    /// use docker_client::DockerClient;
+    /// use docker_client::container::{Create, Config};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///
+    ///    let client = DockerClient::new();
+    ///
+    ///     let config = Create::new().config(Config::with_image("alpine").build()).name("hi").build();
+    ///     match client.create_container(config).await {
+    ///         Ok(_) => {}
+    ///         Err(_) => {}
+    ///     }
+    /// }
+    /// ```
+    pub async fn create_container(&self, request: Create) -> Result<CreatedContainer, DockerError> {
+
+        self.check_api_version(request.required_api_version())?;
+
+        let uri = self.make_uri(request.get_path());
+
+        let request = Request::post(uri)
+            .header("Content-Type", "application/json")
+            .body(hyper::Body::from(request.body()))
+            .unwrap();
+
+        self.execute_async(request).await
+            .and_then(|response| {
+                match response.status {
+                    201 => self.decode(&response),
+                    400 => Err(DockerError::BadParameters(response.error_message())),
+                    404 => Err(DockerError::NotFound(response.error_message())),
+                    409 => Err(DockerError::ContainerExists(response.error_message())),
+                    500 => Err(DockerError::ServerError(response.error_message())),
+                    _ => Err(response.unknown_status()),
+                }
+            })
+            .map_err(|e| e)
+    }
+
+    /// Make sure a container runs, creating, starting or unpausing it as needed
+    ///
+    /// The name of `request` is replaced by `name`. An existing container is not
+    /// compared with the request, it is only started.
+    ///
+    /// Return EnsuredContainer with the action taken or DockerError.
+    ///
+    /// # Arguments
+    /// * `name` - Name of the container.
+    /// * `request` - Create struct, used when the container does not exist.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use docker_client::DockerClient;
+    /// # use docker_client::container::{Config, Create};
+    /// # #[tokio::main]
+    /// async fn main() {
+    ///    let client = DockerClient::new();
+    ///
+    ///     let request = Create::new().config(Config::with_image("redis:7-alpine").build()).build();
+    ///
+    ///     match client.ensure_running("cache", request).await {
+    ///         Ok(container) => { println!("{}: {:?}", container.id(), container.action()); },
+    ///         Err(e) => { println!("Error: {:?}", e); },
+    ///     }
+    /// }
+    /// ```
+    pub async fn ensure_running<T>(&self, name: T, request: Create) -> Result<EnsuredContainer, DockerError>
+        where T: Into<String>
+    {
+        let name = name.into();
+
+        match self.inspect_container(Inspect::container(name.as_str())).await {
+            Ok(info) => {
+                let state = info.state();
+                // A paused container is also reported as running.
+                let action = if state.paused() {
+                    self.unpause_container(info.id()).await?;
+                    EnsureAction::Unpaused
+                } else if state.running() || state.restarting() {
+                    EnsureAction::AlreadyRunning
+                } else {
+                    self.start_container(info.id(), "").await?;
+                    EnsureAction::Started
+                };
+
+                return Ok(EnsuredContainer::new(info.id().clone(), action));
+            },
+            Err(DockerError::NotFound(_)) => {},
+            Err(e) => return Err(e),
+        }
+
+        match self.create_container(request.with_name(name.clone())).await {
+            Ok(created) => {
+                self.start_container(created.id(), "").await?;
+                Ok(EnsuredContainer::new(created.id().clone(), EnsureAction::Created))
+            },
+            // Created concurrently, starting a running container is a no-op.
+            Err(DockerError::ContainerExists(_)) => {
+                self.start_container(name.as_str(), "").await?;
+                let info = self.inspect_container(Inspect::container(name.as_str())).await?;
+                Ok(EnsuredContainer::new(info.id().clone(), EnsureAction::Started))
+            },
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns which files in a container's filesystem have been added, deleted, or modified.
+    ///
+    /// # Arguments
+    /// * `id` - ID or name of the container.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use docker_client::DockerClient;
+    ///
+    /// # #[tokio::main]
+    /// async fn main() {
+    ///    let client = DockerClient::new();
+    ///
+    ///     let changes = client.get_fs_changes("test").await.unwrap_or(Vec::new());
+    ///
+    ///     for change in &changes {
+    ///         println!("{:?}", change);
+    ///     }
+    /// }
+    /// ```
+    pub async fn get_fs_changes<T>(&self, id: T) -> Result<Vec<FSChanges>, DockerError>
+        where T: Into<ContainerRefId>
+    {
+
+        let uri = self.make_uri(format!("/containers/{}/changes", id.into()));
+        let request = Request::get(uri)
+            .body(hyper::Body::empty())
+            .unwrap();
+
+        self.execute_async(request).await
+            .and_then(|response| {
+                match response.status {
+                    200 => {
+                        let obj: Option<Vec<FSChanges>> = self.decode(&response)?;
+                        Ok(obj.unwrap_or(Vec::new()))
+                    },
+                    404 => Err(DockerError::NotFound(response.error_message())),
+                    500 => Err(DockerError::ServerError(response.error_message())),
+                    _ => Err(response.unknown_status()),
+                }
+            })
+            .map_err(|e| e)
+    }
+
+    /// Start a container.
+    ///
+    /// # Arguments
+    /// * `id` - ID or name of the container.
+    /// * `detach_keys` - The key sequence for detaching a container, e.g. `ctrl-p,ctrl-q`. Empty for the default.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use docker_client::{DockerClient, DockerError};
+    ///
+    /// # #[tokio::main]
+    /// async fn main() {
+    ///    let client = DockerClient::new();
+    ///
+    ///     match client.start_container("test", "ctrl-x,x").await {
+    ///         Ok(_) => {},
+    ///         Err(e) => {
+    ///             match e {
+    ///                 DockerError::NotFound(e) => println!("{}", e.message),
+    ///                 DockerError::ServerError(e) => println!("{}", e.message),
+    ///                 _ => {}
+    ///             }
+    ///         },
+    ///     }
+    ///
+    /// }
+    /// ```
+    pub async fn start_container<T, U>(&self, id: T, detach_keys: U) -> Result<(), DockerError>
+        where
+            T: Into<ContainerRefId>,
+            U: Into<String>
+    {
+        self.start_container_with(id, StartOptions::new().detach_keys(detach_keys)).await
+    }
+
+    /// Start a container with options.
+    ///
+    /// # Arguments
+    /// * `id` - ID or name of the container.
+    /// * `options` - Start options, see `StartOptions`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use docker_client::{DockerClient, DockerError};
+    /// use docker_client::container::StartOptions;
+    ///
+    /// # #[tokio::main]
+    /// async fn main() {
+    ///    let client = DockerClient::new();
+    ///
+    ///     let options = StartOptions::new().detach_keys("ctrl-x,x");
+    ///
+    ///     match client.start_container_with("test", options).await {
+    ///         Ok(_) => {},
+    ///         Err(e) => {
+    ///             match e {
+    ///                 DockerError::NotFound(e) => println!("{}", e.message),
+    ///                 DockerError::ServerError(e) => println!("{}", e.message),
+    ///                 _ => {}
+    ///             }
+    ///         },
+    ///     }
+    ///
+    /// }
+    /// ```
+    pub async fn start_container_with<T>(&self, id: T, options: StartOptions) -> Result<(), DockerError>
+        where T: Into<ContainerRefId>
+    {
+        let uri = self.make_uri(options.get_path(id.into().as_str()));
+        let request = Request::post(uri)
+            .extension(Idempotent)
+            .body(hyper::Body::empty())
+            .unwrap();
+
+        self.execute_async(request).await
+            .and_then(|response| {
+                match response.status {
+                    204 => Ok(()),
+                    304 => Ok(()),
+                    404 => Err(DockerError::NotFound(response.error_message())),
+                    500 => Err(DockerError::ServerError(response.error_message())),
+                    _ => Err(response.unknown_status()),
+                }
+            })
+    }
+
+    /// Stop a container.
+    ///
+    /// Without `wait` the daemon waits for the stop timeout of the container.
+    ///
+    /// # Arguments
+    /// * `id` - ID or name of the container.
+    /// * `wait` - Number of seconds to wait before killing the container, negative to wait until it exits.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use docker_client::{DockerClient, DockerError};
+    ///
+    /// # #[tokio::main]
+    /// async fn main() {
+    ///    let client = DockerClient::new();
+    ///
+    ///     match client.stop_container("test", Some(12)).await {
+    ///         Ok(_) => {},
+    ///         Err(e) => {
+    ///             match e {
+    ///                 DockerError::NotFound(e) => println!("{}", e.message),
+    ///                 DockerError::ServerError(e) => println!("{}", e.message),
+    ///                 _ => {}
+    ///             }
+    ///         },
+    ///     }
+    ///
+    /// }
+    /// ```
+    pub async fn stop_container<T>(&self, id: T, wait: Option<i32>) -> Result<(), DockerError>
+        where T: Into<ContainerRefId>
+    {
+        self.stop_container_with(id, wait.map(i64::from)).await
+    }
+
+    /// Stop a container with a timeout.
+    ///
+    /// The timeout is rounded up to whole seconds as the daemon expects.
+    ///
+    /// # Arguments
+    /// * `id` - ID or name of the container.
+    /// * `timeout` - Time to wait before killing the container.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use docker_client::{DockerClient, DockerError};
+    /// use std::time::Duration;
+    ///
+    /// # #[tokio::main]
+    /// async fn main() {
+    ///    let client = DockerClient::new();
+    ///
+    ///     match client.stop_container_with_timeout("test", Duration::from_secs(12)).await {
+    ///         Ok(_) => {},
+    ///         Err(e) => {
+    ///             match e {
+    ///                 DockerError::NotFound(e) => println!("{}", e.message),
+    ///                 DockerError::ServerError(e) => println!("{}", e.message),
+    ///                 _ => {}
+    ///             }
+    ///         },
+    ///     }
+    ///
+    /// }
+    /// ```
+    pub async fn stop_container_with_timeout<T>(&self, id: T, timeout: Duration) -> Result<(), DockerError>
+        where T: Into<ContainerRefId>
+    {
+        self.stop_container_with(id, Some(timeout_secs(timeout))).await
+    }
+
+    /// Restart a container.
+    ///
+    /// The timeout is rounded up to whole seconds as the daemon expects, without it
+    /// the daemon waits for the stop timeout of the container.
+    ///
+    /// # Arguments
+    /// * `id` - ID or name of the container.
+    /// * `timeout` - Time to wait before killing the container.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use docker_client::{DockerClient, DockerError};
+    /// use std::time::Duration;
+    ///
+    /// # #[tokio::main]
+    /// async fn main() {
+    ///    let client = DockerClient::new();
+    ///
+    ///     match client.restart_container("test", Some(Duration::from_secs(12))).await {
+    ///         Ok(_) => {},
+    ///         Err(e) => {
+    ///             match e {
+    ///                 DockerError::NotFound(e) => println!("{}", e.message),
+    ///                 DockerError::ServerError(e) => println!("{}", e.message),
+    ///                 _ => {}
+    ///             }
+    ///         },
+    ///     }
+    ///
+    /// }
+    /// ```
+    pub async fn restart_container<T>(&self, id: T, timeout: Option<Duration>) -> Result<(), DockerError>
+        where T: Into<ContainerRefId>
+    {
+        let path = match timeout {
+            Some(timeout) => format!("/containers/{}/restart?t={}", id.into(), timeout_secs(timeout)),
+            None => format!("/containers/{}/restart", id.into()),
+        };
+
+        let uri = self.make_uri(path);
+        let request = Request::post(uri)
+            .body(hyper::Body::empty())
+            .unwrap();
+
+        // The daemon answers once the container stopped, which may exceed the read timeout.
+        self.execute_buffered(request).await
+            .and_then(|response| {
+                match response.status {
+                    204 => Ok(()),
+                    404 => Err(DockerError::NotFound(response.error_message())),
+                    500 => Err(DockerError::ServerError(response.error_message())),
+                    _ => Err(response.unknown_status()),
+                }
+            })
+    }
+
+    /// Gracefully shut down a container.
+    ///
+    /// Stop the container giving it `grace` time to exit, kill it with `SIGKILL`
+    /// if it is still running and, if `remove` is set, remove it.
+    /// A container already being removed by the daemon (`AutoRemove`) is awaited.
+    ///
+    /// # Arguments
+    /// * `id` - ID or name of the container.
+    /// * `grace` - Time to wait before killing the container.
+    /// * `remove` - Remove the container after it stopped.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use docker_client::{DockerClient, DockerError};
+    /// use std::time::Duration;
+    ///
+    /// # #[tokio::main]
+    /// async fn main() {
+    ///    let client = DockerClient::new();
+    ///
+    ///     match client.graceful_shutdown("test", Duration::from_secs(10), true).await {
+    ///         Ok(_) => {},
+    ///         Err(e) => println!("Error: {:?}", e),
+    ///     }
+    ///
+    /// }
+    /// ```
+    pub async fn graceful_shutdown<T>(&self, id: T, grace: Duration, remove: bool) -> Result<(), DockerError>
+        where T: Into<ContainerRefId>
+    {
+        let id = id.into();
+
+        self.stop_container_with(id.as_str(), Some(timeout_secs(grace))).await?;
+
+        let running = match self.inspect_container(Inspect::container(id.to_string())).await {
+            Ok(info) => info.state().running(),
+            Err(DockerError::NotFound(_)) => return Ok(()),
+            Err(e) => return Err(e),
+        };
+
+        if running {
+            let killer = Killer::new()
+                .id(id.as_str())
+                .signal("SIGKILL")
+                .build();
+
+            match self.kill_container(killer).await {
+                Ok(_) | Err(DockerError::NotRunning(_)) | Err(DockerError::NotFound(_)) => {},
+                Err(e) => return Err(e),
+            }
+        }
+
+        if remove {
+            let remover = Remover::new()
+                .id(id.as_str())
+                .build();
+
+            match self.remove_container(remover).await {
+                Ok(_) | Err(DockerError::NotFound(_)) => {},
+                Err(DockerError::NotRunning(_)) => {
+                    // removal is already in progress
+                    match self.wait_container(id.as_str(), WaitCondition::Removed).await {
+                        Ok(_) | Err(DockerError::NotFound(_)) => {},
+                        Err(e) => return Err(e),
+                    }
+                },
+                Err(e) => return Err(e),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Pause a container.
+    ///
+    /// # Arguments
+    /// * `id` - ID or name of the container.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use docker_client::{DockerClient, DockerError};
+    ///
+    /// # #[tokio::main]
+    /// async fn main() {
+    ///    let client = DockerClient::new();
+    ///
+    ///     match client.pause_container("test").await {
+    ///         Ok(_) => {},
+    ///         Err(e) => {
+    ///             match e {
+    ///                 DockerError::NotFound(e) => println!("{}", e.message),
+    ///                 DockerError::ServerError(e) => println!("{}", e.message),
+    ///                 _ => {}
+    ///             }
+    ///         },
+    ///     }
+    ///
+    /// }
+    /// ```
+    pub async fn pause_container<T>(&self, id: T) -> Result<(), DockerError>
+        where T: Into<ContainerRefId>
+    {
+
+        let uri = self.make_uri(format!("/containers/{}/pause", id.into()));
+        let request = Request::post(uri)
+            .body(hyper::Body::empty())
+            .unwrap();
+
+        self.execute_async(request).await
+            .and_then(|response| {
+                match response.status {
+                    204 => Ok(()),
+                    404 => Err(DockerError::NotFound(response.error_message())),
+                    500 => Err(DockerError::ServerError(response.error_message())),
+                    _ => Err(response.unknown_status()),
+                }
+            })
+            .map_err(|e| e)
+    }
+
+    /// Unpause a container.
+    ///
+    /// # Arguments
+    /// * `id` - ID or name of the container.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use docker_client::{DockerClient, DockerError};
+    ///
+    /// # #[tokio::main]
+    /// async fn main() {
+    ///    let client = DockerClient::new();
+    ///
+    ///     match client.unpause_container("test").await {
+    ///         Ok(_) => {},
+    ///         Err(e) => {
+    ///             match e {
+    ///                 DockerError::NotFound(e) => println!("{}", e.message),
+    ///                 DockerError::ServerError(e) => println!("{}", e.message),
+    ///                 _ => {}
+    ///             }
+    ///         },
+    ///     }
+    ///
+    /// }
+    /// ```
+    pub async fn unpause_container<T>(&self, id: T) -> Result<(), DockerError>
+        where T: Into<ContainerRefId> {
+
+        let uri = self.make_uri(format!("/containers/{}/unpause", id.into()));
+        let request = Request::post(uri)
+            .body(hyper::Body::empty())
+            .unwrap();
+
+        self.execute_async(request).await
+            .and_then(|response| {
+                match response.status {
+                    204 => Ok(()),
+                    404 => Err(DockerError::NotFound(response.error_message())),
+                    500 => Err(DockerError::ServerError(response.error_message())),
+                    _ => Err(response.unknown_status()),
+                }
+            })
+            .map_err(|e| e)
+    }
+
+    /// Rename a container.
+    ///
+    /// # Arguments
+    /// * `id` - ID or name of the container.
+    /// * `new_name` - New name for the container.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use docker_client::{DockerClient, DockerError};
+    ///
+    /// # #[tokio::main]
+    /// async fn main() {
+    ///    let client = DockerClient::new();
+    ///
+    ///     match client.rename_container("test", "test1").await {
+    ///         Ok(_) => {},
+    ///         Err(e) => {
+    ///             match e {
+    ///                 DockerError::NotFound(e) => println!("{}", e.message),
+    ///                 DockerError::ContainerExists(e) => println!("{}", e.message),
+    ///                 DockerError::ServerError(e) => println!("{}", e.message),
+    ///                 _ => {}
+    ///             }
+    ///         },
+    ///     }
+    ///
+    /// }
+    /// ```
+    pub async fn rename_container<T, U>(&self, id: T, new_name: U) -> Result<(), DockerError>
+        where
+            T: Into<ContainerRefId>,
+            U: Into<String>
+    {
+
+        let uri = self.make_uri(format!("/containers/{}/rename?name={}", id.into(), new_name.into()));
+        let request = Request::post(uri)
+            .body(hyper::Body::empty())
+            .unwrap();
+
+        self.execute_async(request).await
+            .and_then(|response| {
+                match response.status {
+                    204 => Ok(()),
+                    404 => Err(DockerError::NotFound(response.error_message())),
+                    409 => Err(DockerError::ContainerExists(response.error_message())),
+                    500 => Err(DockerError::ServerError(response.error_message())),
+                    _ => Err(response.unknown_status()),
+                }
+            })
+            .map_err(|e| e)
+    }
+
+    /// Kill a container.
+    ///
+    /// # Arguments
+    /// * `killer` is a struct with metadata to kill a container.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use docker_client::{DockerClient, DockerError};
+    /// use docker_client::container::Killer;
+    /// # #[tokio::main]
+    /// async fn main() {
+    ///
+    ///    let client = DockerClient::new();
+    ///
+    ///     let killer = Killer::new()
+    ///         .id("test")
+    ///         .build();
+    ///
+    ///     match client.kill_container(killer).await {
+    ///         Ok(_) => {}
+    ///         Err(e) => {
+    ///             match e {
+    ///                 DockerError::NotFound(e) => println!("{}", e.message),
+    ///                 DockerError::NotRunning(e) => println!("{}", e.message),
+    ///                 DockerError::ServerError(e) => println!("{}", e.message),
+    ///                 _ => {}
+    ///             }
+    ///         }
+    ///     }
+    ///
+    /// }
+    /// ```
+    pub async fn kill_container(&self, killer: Killer) -> Result<(), DockerError> {
+
+        let uri = self.make_uri(killer.get_path());
+        let request = Request::post(uri)
+            .body(hyper::Body::empty())
+            .unwrap();
+
+        self.execute_async(request).await
+            .and_then(|response| {
+                match response.status {
+                    204 => Ok(()),
+                    400 => Err(DockerError::BadParameters(response.error_message())),
+                    404 => Err(DockerError::NotFound(response.error_message())),
+                    409 => Err(DockerError::NotRunning(response.error_message())),
+                    500 => Err(DockerError::ServerError(response.error_message())),
+                    _ => Err(response.unknown_status()),
+                }
+            })
+            .map_err(|e| e)
+    }
+
+    /// Remove a container.
+    ///
+    /// # Arguments
+    /// * `remover` is a struct with metadata to remove a container.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use docker_client::{DockerClient, DockerError};
+    /// use docker_client::container::Remover;
+    ///
+    /// # #[tokio::main]
+    /// async fn main() {
+    ///
+    ///    let client = DockerClient::new();
+    ///
+    ///     let remover = Remover::new()
+    ///         .id("test")
+    ///         .with_remove_volumes(true)
+    ///         .build();
+    ///
+    ///     match client.remove_container(remover).await {
+    ///         Ok(_) => {}
+    ///         Err(e) => {
+    ///             match e {
+    ///                 DockerError::BadParameters(e) => println!("{}", e.message),
+    ///                 DockerError::NotFound(e) => println!("{}", e.message),
+    ///                 DockerError::NotRunning(e) => println!("{}", e.message),
+    ///                 DockerError::ServerError(e) => println!("{}", e.message),
+    ///                 _ => {}
+    ///             }
+    ///         }
+    ///     }
+    ///
+    /// }
+    /// ```
+    pub async fn remove_container(&self, remover: Remover) -> Result<(), DockerError> {
+
+        let uri = self.make_uri(remover.get_path());
+        let request = Request::delete(uri)
+            .body(hyper::Body::empty())
+            .unwrap();
+
+        self.execute_async(request).await
+            .and_then(|response| {
+                match response.status {
+                    204 => Ok(()),
+                    400 => Err(DockerError::BadParameters(response.error_message())),
+                    404 => Err(DockerError::NotFound(response.error_message())),
+                    409 => Err(DockerError::NotRunning(response.error_message())),
+                    500 => Err(DockerError::ServerError(response.error_message())),
+                    _ => Err(response.unknown_status()),
+                }
+            })
+            .map_err(|e| e)
+    }
+
+    /// Delete stopped containers
+    ///
+    /// Return PrunedContainers or DockerError
+    ///
+    /// # Arguments
+    /// * `filters` - Filters, e.g. `label` or `until`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use docker_client::{DockerClient, DockerError};
+    /// # use docker_client::additionals::filters::Filters;
+    /// # #[tokio::main]
+    /// async fn main() {
+    ///    let client = DockerClient::new();
+    ///
+    ///     let filters = Filters::new().label("ci", None).until("24h").build();
+    ///
+    ///     match client.prune_containers(filters).await {
+    ///         Ok(pruned) => { println!("{:?}", pruned.containers_deleted()); },
+    ///         Err(e) => { println!("Error: {:?}", e); },
+    ///     }
+    ///
+    /// }
+    /// ```
+    pub async fn prune_containers(&self, filters: Filters) -> Result<PrunedContainers, DockerError> {
+
+        let uri = self.make_uri(match filters.query() {
+            Some(query) => format!("/containers/prune?{}", query),
+            None => "/containers/prune".to_string(),
+        });
+        let request = Request::post(uri)
+            .body(hyper::Body::empty())
+            .unwrap();
+
+        self.execute_async(request).await
+            .and_then(|response| {
+                match response.status {
+                    200 => self.decode(&response),
+                    500 => Err(DockerError::ServerError(response.error_message())),
+                    _ => Err(response.unknown_status()),
+                }
+            })
+    }
+
+    /// Inspect a container.
+    ///
+    /// Return `ContainerInfo` structure about a container.
+    ///
+    /// # Arguments
+    /// * `id` - ID or name of the container.
+    /// * `size` - Return the size of container as fields SizeRw and SizeRootFs.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use docker_client::{DockerClient, DockerError};
+    /// use docker_client::container::inspect::Inspect;
+    /// # #[tokio::main]
+    /// async fn main() {
+    ///
+    ///    let client = DockerClient::new();
+    ///
+    ///     match client.inspect_container(Inspect::container("vigilant_antonelli".to_string())).await {
+    ///         Ok(s) => { println!("{:?}", s) }
+    ///         Err(e) => {}
+    ///     }
+    ///
+    /// }
+    /// ```
+    pub async fn inspect_container(&self, request: Inspect) -> Result<ContainerInfo, DockerError> {
+        self.inspect_container_raw(request).await.map(Raw::into_value)
+    }
+
+    /// Inspect a container, keeping the untouched body
+    ///
+    /// Return `Raw` with the decoded struct and the body or DockerError.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use docker_client::{DockerClient, DockerError};
+    /// # use docker_client::container::inspect::Inspect;
+    /// # #[tokio::main]
+    /// async fn main() {
+    ///    let client = DockerClient::new();
+    ///
+    ///     match client.inspect_container_raw(Inspect::container("web")).await {
+    ///         Ok(info) => { println!("{} {:?}", info.name(), info.json().map(|raw| raw["Config"]["Labels"].clone())); },
+    ///         Err(e) => { println!("Error: {:?}", e); },
+    ///     }
+    ///
+    /// }
+    /// ```
+    pub async fn inspect_container_raw(&self, request: Inspect) -> Result<Raw<ContainerInfo>, DockerError> {
+
+        let uri = self.make_uri(request.get_path());
+        let request = Request::get(uri)
+            .body(hyper::Body::empty())
+            .unwrap();
+
+        self.execute_async(request).await
+            .and_then(|response| {
+                match response.status {
+                    200 => self.decode_raw(&response),
+                    404 => Err(DockerError::NotFound(response.error_message())),
+                    500 => Err(DockerError::ServerError(response.error_message())),
+                    _ => Err(response.unknown_status()),
+                }
+            })
+            .map_err(|e| e)
+    }
+
+    /// Check if a container exists
+    ///
+    /// Return `false` if the daemon reports the container as not found, other errors are returned.
+    ///
+    /// # Arguments
+    /// * `id` - ID or name of the container.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use docker_client::{DockerClient, DockerError};
+    /// # #[tokio::main]
+    /// async fn main() {
+    ///    let client = DockerClient::new();
+    ///
+    ///     match client.container_exists("test").await {
+    ///         Ok(exists) => { println!("Exists: {}", exists); },
+    ///         Err(e) => { println!("Error: {:?}", e); },
+    ///     }
+    ///
+    /// }
+    /// ```
+    pub async fn container_exists<T>(&self, id: T) -> Result<bool, DockerError>
+        where T: Into<ContainerRefId>
+    {
+        match self.inspect_container(Inspect::container(String::from(id.into()))).await {
+            Ok(_) => Ok(true),
+            Err(DockerError::NotFound(_)) => Ok(false),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Check if a container is running
+    ///
+    /// Return `false` if the container is not running or does not exist, other errors are returned.
+    ///
+    /// # Arguments
+    /// * `id` - ID or name of the container.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use docker_client::{DockerClient, DockerError};
+    /// # #[tokio::main]
+    /// async fn main() {
+    ///    let client = DockerClient::new();
+    ///
+    ///     match client.is_running("test").await {
+    ///         Ok(running) => { println!("Running: {}", running); },
+    ///         Err(e) => { println!("Error: {:?}", e); },
+    ///     }
+    ///
+    /// }
+    /// ```
+    pub async fn is_running<T>(&self, id: T) -> Result<bool, DockerError>
+        where T: Into<ContainerRefId>
+    {
+        match self.inspect_container(Inspect::container(String::from(id.into()))).await {
+            Ok(info) => Ok(info.state().running()),
+            Err(DockerError::NotFound(_)) => Ok(false),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Get container logs
+    ///
+    /// Get stdout and stderr logs from a container.
+    ///
+    /// # Note
+    /// This endpoint works only for containers with the json-file or journald logging driver.
+    ///
+    /// # Arguments
+    /// `id` - ID or name of the container.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use docker_client::{DockerClient, DockerError};
+    /// # #[tokio::main]
+    /// async fn main() {
+    ///    let client = DockerClient::new();
+    ///
+    ///     match client.get_container_log("test-container").await {
+    ///         Ok(log) => { println!("Log: {}", log); }
+    ///         Err(e) => { println!("Error: {:?}", e); }
+    ///     }
+    ///
+    /// }
+    /// ```
+    pub async fn get_container_log<T>(&self, id: T) -> Result<String, DockerError>
+        where T: Into<ContainerRefId>
+    {
+
+        let uri = self.make_uri(format!("/containers/{}/logs?stdout=true", id.into()));
+        let request = Request::get(uri)
+            .body(hyper::Body::empty())
+            .unwrap();
+
+        self.execute_async(request).await
+            .and_then(|response| {
+                match response.status {
+                    200 => Ok(response.body_as_string()),
+                    404 => Err(DockerError::NotFound(response.error_message())),
+                    500 => Err(DockerError::ServerError(response.error_message())),
+                    _ => Err(response.unknown_status()),
+                }
+            })
+            .map_err(|e| e)
+    }
+
+    /// Get container log lines
+    ///
+    /// Return decoded lines of stdout and stderr in the order they were received or DockerError.
+    ///
+    /// # Note
+    /// This endpoint works only for containers with the json-file or journald logging driver.
+    ///
+    /// # Arguments
+    /// * `request` - Logs request.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use docker_client::{DockerClient, DockerError};
+    /// # use docker_client::container::logs::Request;
+    /// # #[tokio::main]
+    /// async fn main() {
+    ///    let client = DockerClient::new();
+    ///
+    ///     let request = Request::container("test-container")
+    ///         .stderr(false)
+    ///         .build();
+    ///
+    ///     match client.container_logs(request).await {
+    ///         Ok(lines) => { println!("{:?}", lines); }
+    ///         Err(e) => { println!("Error: {:?}", e); }
+    ///     }
+    ///
+    /// }
+    /// ```
+    pub async fn container_logs(&self, request: crate::container::logs::Request) -> Result<Vec<String>, DockerError> {
+
+        let uri = self.make_uri(request.get_path());
+        let request = Request::get(uri)
+            .body(hyper::Body::empty())
+            .unwrap();
+
+        self.execute_async(request).await
+            .and_then(|response| {
+                match response.status {
+                    200 => Ok(
+                        crate::container::logs::lines(response.frames()).into_iter()
+                            .map(|(_, line)| line)
+                            .collect()
+                    ),
+                    404 => Err(DockerError::NotFound(response.error_message())),
+                    500 => Err(DockerError::ServerError(response.error_message())),
+                    _ => Err(response.unknown_status()),
+                }
+            })
+    }
+
+    /// Get container log lines with stream and timestamp
+    ///
+    /// Return LogLine vector or DockerError. Timestamps are parsed when the request
+    /// has `timestamps(true)`, so lines of several containers can be sorted by time.
+    ///
+    /// # Arguments
+    /// * `request` - Logs request.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use docker_client::{DockerClient, DockerError};
+    /// # use docker_client::container::logs::Request;
+    /// # #[tokio::main]
+    /// async fn main() {
+    ///    let client = DockerClient::new();
+    ///
+    ///     let request = Request::container("test-container")
+    ///         .timestamps(true)
+    ///         .build();
+    ///
+    ///     match client.container_log_lines(request).await {
+    ///         Ok(lines) => {
+    ///             for line in lines {
+    ///                 println!("{:?} {:?} {}", line.timestamp(), line.stream(), line.message());
+    ///             }
+    ///         }
+    ///         Err(e) => { println!("Error: {:?}", e); }
+    ///     }
+    ///
+    /// }
+    /// ```
+    pub async fn container_log_lines(&self, request: crate::container::logs::Request) -> Result<Vec<LogLine>, DockerError> {
+
+        let timestamps = request.timestamps();
+        let uri = self.make_uri(request.get_path());
+        let request = Request::get(uri)
+            .body(hyper::Body::empty())
+            .unwrap();
+
+        self.execute_async(request).await
+            .and_then(|response| {
+                match response.status {
+                    200 => Ok(
+                        crate::container::logs::lines(response.frames()).into_iter()
+                            .map(|(stream, line)| LogLine::parse(stream, line, timestamps))
+                            .collect()
+                    ),
+                    404 => Err(DockerError::NotFound(response.error_message())),
+                    500 => Err(DockerError::ServerError(response.error_message())),
+                    _ => Err(response.unknown_status()),
+                }
+            })
+    }
+
+    /// Stream container log lines as they are written
+    ///
+    /// Return stream of LogLine or DockerError. With `follow(true)` the stream runs until
+    /// the container stops or the stream is dropped, which closes the connection.
+    ///
+    /// # Arguments
+    /// * `request` - Logs request.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use docker_client::{DockerClient, DockerError};
+    /// # use docker_client::container::logs::Request;
+    /// use futures::StreamExt;
+    ///
+    /// # #[tokio::main]
+    /// async fn main() {
+    ///    let client = DockerClient::new();
+    ///
+    ///     let request = Request::container("test-container")
+    ///         .follow(true)
+    ///         .tail(0)
+    ///         .build();
+    ///
+    ///     match client.container_log_stream(request).await {
+    ///         Ok(mut lines) => {
+    ///             while let Some(Ok(line)) = lines.next().await {
+    ///                 println!("{}", line.message());
+    ///             }
+    ///         }
+    ///         Err(e) => { println!("Error: {:?}", e); }
+    ///     }
+    ///
+    /// }
+    /// ```
+    pub async fn container_log_stream(&self, request: crate::container::logs::Request) -> Result<impl Stream<Item = Result<LogLine, DockerError>> + Unpin + Send, DockerError> {
+
+        let timestamps = request.timestamps();
+        let uri = self.make_uri(request.get_path());
+        let request = Request::get(uri)
+            .body(hyper::Body::empty())
+            .unwrap();
+
+        let body = self.execute_stream(request, |response| {
+            match response.status {
+            404 => DockerError::NotFound(response.error_message()),
+            500 => DockerError::ServerError(response.error_message()),
+            _ => response.unknown_status(),
+            }
+        }).await?;
+
+        Ok(Box::pin(log_lines(body, timestamps)))
+    }
+
+    /// Stream logs of several containers
+    ///
+    /// Return MultiLogStream of lines tagged with the container name or DockerError if containers
+    /// can not be listed. Errors of one container, like a missing container, are emitted by the stream.
+    /// Containers selected by label are listed once, containers started later are not followed.
+    ///
+    /// # Arguments
+    /// * `request` - Containers and logs options, see `MultiLogs`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use docker_client::{DockerClient, DockerError};
+    /// # use docker_client::container::multi_logs::MultiLogs;
+    /// use futures::StreamExt;
+    ///
+    /// # #[tokio::main]
+    /// async fn main() {
+    ///    let client = DockerClient::new();
+    ///
+    ///     match client.multi_logs(MultiLogs::containers(vec!["web", "db"]).tail(5)).await {
+    ///         Ok(logs) => {
+    ///             let lines: Vec<_> = logs.collect().await;
+    ///             println!("{} lines", lines.len());
+    ///         }
+    ///         Err(e) => { println!("Error: {:?}", e); }
+    ///     }
+    ///
+    /// }
+    /// ```
+    pub async fn multi_logs(&self, request: MultiLogs) -> Result<MultiLogStream, DockerError> {
+        let (selector, request) = request.into_parts();
+
+        let containers = match selector {
+            Selector::Containers(ids) => ids,
+            Selector::Label(key, value) => {
+                let filters = Filters::new().label(key, Some(value)).build();
+                let list = self.containers_list(
+                    crate::container::list::Request::new().filters(filters).build()
+                ).await?;
+
+                list.iter()
+                    .map(|container| container.name().unwrap_or_else(|| container.id().as_str()).to_string())
+                    .collect()
+            }
+        };
+
+        // A container whose logs can not be requested, e.g. it was just removed, does not fail the others.
+        let mut logs = MultiLogStream::new();
+        for container in containers {
+            match self.container_log_stream(request.with_container(container.clone())).await {
+                Ok(stream) => logs.push(container, Box::pin(stream)),
+                Err(e) => logs.push(container, Box::pin(futures::stream::once(async move { Err(e) }))),
+            }
+        }
+
+        Ok(logs)
+    }
+
+    /// Run a watchdog of containers
+    ///
+    /// Apply the remedy of a watched container when it dies or becomes unhealthy.
+    /// Return when the events stream ends, or DockerError if the events can not be followed.
+    /// Errors of remedies are reported to the callback of the watchdog and do not stop it.
+    ///
+    /// # Arguments
+    /// * `watchdog` - Containers and their remedies, see `Watchdog`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use docker_client::{DockerClient, DockerError};
+    /// # use docker_client::container::watchdog::{Watchdog, Remedy};
+    /// # #[tokio::main]
+    /// async fn main() {
+    ///    let client = DockerClient::new();
+    ///
+    ///     let watchdog = Watchdog::new().watch("web", Remedy::Restart);
+    ///
+    ///     match client.run_watchdog(watchdog).await {
+    ///         Ok(_) => {},
+    ///         Err(e) => { println!("Error: {:?}", e); },
+    ///     }
+    ///
+    /// }
+    /// ```
+    pub async fn run_watchdog(&self, watchdog: Watchdog) -> Result<(), DockerError> {
+        watchdog.run(self).await
+    }
+
+    /// Run a watchdog of containers in a background task
+    ///
+    /// Return handle of the task, aborting it stops the watchdog.
+    /// See `run_watchdog`.
+    ///
+    /// # Arguments
+    /// * `watchdog` - Containers and their remedies, see `Watchdog`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use docker_client::DockerClient;
+    /// # use docker_client::container::watchdog::{Watchdog, Remedy};
+    /// # #[tokio::main]
+    /// async fn main() {
+    ///    let client = DockerClient::new();
+    ///
+    ///     let handle = client.spawn_watchdog(Watchdog::new().watch("web", Remedy::Restart));
+    ///     handle.abort();
+    ///
+    /// }
+    /// ```
+    pub fn spawn_watchdog(&self, watchdog: Watchdog) -> tokio::task::JoinHandle<Result<(), DockerError>> {
+        let client = self.clone();
+        tokio::spawn(async move { watchdog.run(&client).await })
+    }
+
+    /// Attach to a container and collect its output
+    ///
+    /// Return LogLine vector or DockerError. With `stream(true)` the call returns
+    /// when the container exits, with `logs(true)` the earlier output is replayed first.
+    /// Stdin is not forwarded, see `attach_container`.
+    ///
+    /// # Arguments
+    /// * `options` - Attach options.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use docker_client::{DockerClient, DockerError};
+    /// # use docker_client::container::attach::AttachOptions;
+    /// # #[tokio::main]
+    /// async fn main() {
+    ///    let client = DockerClient::new();
+    ///
+    ///     let options = AttachOptions::container("test-container")
+    ///         .logs(true)
+    ///         .stream(false)
+    ///         .build();
+    ///
+    ///     match client.attach_output(options).await {
+    ///         Ok(lines) => {
+    ///             for line in lines {
+    ///                 println!("{:?} {}", line.stream(), line.message());
+    ///             }
+    ///         }
+    ///         Err(e) => { println!("Error: {:?}", e); }
+    ///     }
+    ///
+    /// }
+    /// ```
+    pub async fn attach_output(&self, options: AttachOptions) -> Result<Vec<LogLine>, DockerError> {
+
+        let uri = self.make_uri(options.get_path());
+        let request = Request::post(uri)
+            .body(hyper::Body::empty())
+            .unwrap();
+
+        self.execute_buffered(request).await
+            .and_then(|response| {
+                match response.status {
+                    200 => Ok(
+                        crate::container::logs::lines(response.frames()).into_iter()
+                            .map(|(stream, line)| LogLine::parse(stream, line, false))
+                            .collect()
+                    ),
+                    400 => Err(DockerError::BadParameters(response.error_message())),
+                    404 => Err(DockerError::NotFound(response.error_message())),
+                    500 => Err(DockerError::ServerError(response.error_message())),
+                    _ => Err(response.unknown_status()),
+                }
+            })
+    }
+
+    /// Attach to a container with stdin
+    ///
+    /// The connection is upgraded to a raw stream, like `docker attach` or `docker run -it`:
+    /// bytes written to the returned AttachedContainer are sent to stdin of the container
+    /// (with `stdin(true)`), its output is read from it as a stream of OutputFrame.
+    /// The read timeout does not apply to the connection.
+    ///
+    /// Return AttachedContainer or DockerError.
+    ///
+    /// # Arguments
+    /// * `options` - Attach options.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use docker_client::{DockerClient, DockerError};
+    /// # use docker_client::container::attach::AttachOptions;
+    /// use futures::StreamExt;
+    /// use tokio::io::AsyncWriteExt;
+    ///
+    /// # #[tokio::main]
+    /// async fn main() {
+    ///    let client = DockerClient::new();
+    ///
+    ///     let options = AttachOptions::container("shell")
+    ///         .stdin(true)
+    ///         .build();
+    ///
+    ///     match client.attach_container(options).await {
+    ///         Ok(mut attached) => {
+    ///             attached.write_all(b"echo hello\nexit\n").await.unwrap();
+    ///             while let Some(Ok(frame)) = attached.next().await {
+    ///                 println!("{:?} {}", frame.stream(), String::from_utf8_lossy(frame.data()));
+    ///             }
+    ///         }
+    ///         Err(e) => { println!("Error: {:?}", e); }
+    ///     }
+    ///
+    /// }
+    /// ```
+    pub async fn attach_container(&self, options: AttachOptions) -> Result<AttachedContainer, DockerError> {
+
+        let uri = self.make_uri(options.get_path());
+        let request = Request::post(uri)
+            .header(hyper::header::CONNECTION, "Upgrade")
+            .header(hyper::header::UPGRADE, "tcp")
+            .body(hyper::Body::empty())
+            .unwrap();
+
+        self.check_breaker()?;
+
+        let result = self.bounded(self.send(request)).await;
+        self.record(&result);
+
+        let response = result?;
+        match response.status().as_u16() {
+            101 => {
+                let connection = hyper::upgrade::on(response).await.map_err(DockerError::connection)?;
+                Ok(AttachedContainer::new(connection))
+            },
+            status => {
+                let headers = response.headers().clone();
+                let body = hyper::body::to_bytes(response.into_body()).await.map_err(DockerError::connection)?;
+                let response = DockerResponse { status, body, headers };
+
+                match status {
+                    400 => Err(DockerError::BadParameters(response.error_message())),
+                    404 => Err(DockerError::NotFound(response.error_message())),
+                    500 => Err(DockerError::ServerError(response.error_message())),
+                    _ => Err(response.unknown_status()),
+                }
+            },
+        }
+    }
+
+    /// Get last log lines of a container
+    ///
+    /// # Arguments
+    /// * `id` - ID or name of the container.
+    /// * `n` - Number of lines.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use docker_client::{DockerClient, DockerError};
+    /// # #[tokio::main]
+    /// async fn main() {
+    ///    let client = DockerClient::new();
+    ///
+    ///     match client.tail_logs("test-container", 20).await {
+    ///         Ok(lines) => { println!("{:?}", lines); }
+    ///         Err(e) => { println!("Error: {:?}", e); }
+    ///     }
+    ///
+    /// }
+    /// ```
+    pub async fn tail_logs<T>(&self, id: T, n: usize) -> Result<Vec<String>, DockerError>
+        where T: Into<ContainerRefId>
+    {
+        self.container_logs(crate::container::logs::Request::container(id).tail(n).build()).await
+    }
+
+    /// Get log lines of a container written during the last `duration`
+    ///
+    /// # Arguments
+    /// * `id` - ID or name of the container.
+    /// * `duration` - Time window ending now.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use docker_client::{DockerClient, DockerError};
+    /// # use std::time::Duration;
+    /// # #[tokio::main]
+    /// async fn main() {
+    ///    let client = DockerClient::new();
+    ///
+    ///     match client.logs_since("test-container", Duration::from_secs(600)).await {
+    ///         Ok(lines) => { println!("{:?}", lines); }
+    ///         Err(e) => { println!("Error: {:?}", e); }
+    ///     }
+    ///
+    /// }
+    /// ```
+    pub async fn logs_since<T>(&self, id: T, duration: Duration) -> Result<Vec<String>, DockerError>
+        where T: Into<ContainerRefId>
+    {
+        let since = SystemTime::now().checked_sub(duration)
+            .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+            .map(|time| time.as_secs() as i64)
+            .unwrap_or(0);
+
+        self.container_logs(crate::container::logs::Request::container(id).since(since).build()).await
+    }
+
+
+    /// Wait for a container
+    ///
+    /// Block until a container stops, then returns the exit code.
+    ///
+    /// # Arguments
+    /// `id` - ID or name of the container.
+    /// `condition` - Wait until a container state reaches the given condition, either 'not-running' (default), 'next-exit', or 'removed'.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use docker_client::{DockerClient, DockerError};
+    /// # use docker_client::container::WaitCondition;
+    /// # #[tokio::main]
+    /// async fn main() {
+    ///    let client = DockerClient::new();
+    ///
+    ///     match client.wait_container("test-container", WaitCondition::NotRunning).await {
+    ///         Ok(status) => { println!("Status: {:?}", status); }
+    ///         Err(e) => { println!("Error: {:?}", e); }
+    ///     }
+    ///
+    /// }
+    /// ```
+    pub async fn wait_container<T>(&self, id: T, condition: WaitCondition) -> Result<WaitStatus, DockerError>
+        where T: Into<ContainerRefId>
+    {
+        let uri = self.make_uri(format!("/containers/{}/wait?condition={}", id.into(), condition.to_string()));
+        let request = Request::post(uri)
+            .body(hyper::Body::empty())
+            .unwrap();
+
+        self.execute_buffered(request).await
+            .and_then(|response| {
+                match response.status {
+                    200 => self.decode(&response),
+                    404 => Err(DockerError::NotFound(response.error_message())),
+                    500 => Err(DockerError::ServerError(response.error_message())),
+                    _ => Err(response.unknown_status()),
+                }
+            })
+    }
+
+    /// Wait for a container, giving up after `timeout`
+    ///
+    /// The request is cancelled when the timeout elapses, the container is left as is.
+    /// Return WaitStatus or DockerError, `DockerError::Timeout` if the condition was not reached in time.
+    ///
+    /// # Arguments
+    /// `id` - ID or name of the container.
+    /// `condition` - Container state to wait for, see `wait_container`.
+    /// `timeout` - Time to wait for the condition.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use docker_client::{DockerClient, DockerError};
+    /// # use docker_client::container::WaitCondition;
+    /// # use std::time::Duration;
+    /// # #[tokio::main]
+    /// async fn main() {
+    ///    let client = DockerClient::new();
+    ///
+    ///     match client.wait_container_with_timeout("job", WaitCondition::NotRunning, Duration::from_secs(30)).await {
+    ///         Ok(status) => { println!("Exit code: {}", status.status_code()); }
+    ///         Err(DockerError::Timeout) => { println!("Still running"); }
+    ///         Err(e) => { println!("Error: {:?}", e); }
+    ///     }
+    ///
+    /// }
+    /// ```
+    pub async fn wait_container_with_timeout<T>(&self, id: T, condition: WaitCondition, timeout: Duration) -> Result<WaitStatus, DockerError>
+        where T: Into<ContainerRefId>
+    {
+        match tokio::time::timeout(timeout, self.wait_container(id, condition)).await {
+            Ok(result) => result,
+            Err(_) => Err(DockerError::Timeout),
+        }
+    }
+
+
+    /// Resize the TTY of a container
+    ///
+    /// # Arguments
+    /// `id` - ID or name of the container.
+    /// `rows` - Height of the TTY in characters.
+    /// `columns` - Width of the TTY in characters.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use docker_client::{DockerClient, DockerError};
+    /// # #[tokio::main]
+    /// async fn main() {
+    ///    let client = DockerClient::new();
+    ///
+    ///     match client.resize_container_tty("shell", 40, 120).await {
+    ///         Ok(_) => {},
+    ///         Err(e) => { println!("Error: {:?}", e); },
+    ///     }
+    ///
+    /// }
+    /// ```
+    pub async fn resize_container_tty<T>(&self, id: T, rows: u16, columns: u16) -> Result<(), DockerError>
+        where T: Into<ContainerRefId>
+    {
+        let uri = self.make_uri(format!("/containers/{}/resize?h={}&w={}", id.into(), rows, columns));
+        let request = Request::post(uri)
+            .body(hyper::Body::empty())
+            .unwrap();
+
+        self.execute_async(request).await
+            .and_then(|response| {
+                match response.status {
+                    200 => Ok(()),
+                    404 => Err(DockerError::NotFound(response.error_message())),
+                    500 => Err(DockerError::ServerError(response.error_message())),
+                    _ => Err(response.unknown_status()),
+                }
+            })
+    }
+
+    /// Get checkpoints of a container
+    ///
+    /// Checkpoints are experimental, `DockerError::FeatureUnavailable` is returned if the daemon
+    /// does not enable experimental features.
+    ///
+    /// # Arguments
+    /// `id` - ID or name of the container.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use docker_client::{DockerClient, DockerError};
+    /// # #[tokio::main]
+    /// async fn main() {
+    ///    let client = DockerClient::new();
+    ///
+    ///     match client.list_checkpoints("db").await {
+    ///         Ok(checkpoints) => { println!("{:?}", checkpoints); },
+    ///         Err(DockerError::FeatureUnavailable(_)) => { println!("Checkpoints are not enabled"); },
+    ///         Err(e) => { println!("Error: {:?}", e); },
+    ///     }
+    ///
+    /// }
+    /// ```
+    pub async fn list_checkpoints<T>(&self, id: T) -> Result<Vec<Checkpoint>, DockerError>
+        where T: Into<ContainerRefId>
+    {
+        self.require_experimental("checkpoints").await?;
+
+        let uri = self.make_uri(format!("/containers/{}/checkpoints", id.into()));
+        let request = Request::get(uri)
+            .body(hyper::Body::empty())
+            .unwrap();
+
+        self.execute_async(request).await
+            .and_then(|response| {
+                match response.status {
+                    200 => self.decode(&response),
+                    404 => Err(DockerError::NotFound(response.error_message())),
+                    500 => Err(DockerError::ServerError(response.error_message())),
+                    _ => Err(response.unknown_status()),
+                }
+            })
+    }
+
+    /// Checkpoint a running container
+    ///
+    /// Checkpoints are experimental, see `list_checkpoints`.
+    ///
+    /// # Arguments
+    /// `id` - ID or name of the container.
+    /// `name` - Name of the checkpoint.
+    /// `exit` - Stop the container after the checkpoint.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use docker_client::{DockerClient, DockerError};
+    /// # #[tokio::main]
+    /// async fn main() {
+    ///    let client = DockerClient::new();
+    ///
+    ///     match client.create_checkpoint("db", "before-upgrade", false).await {
+    ///         Ok(_) => {},
+    ///         Err(e) => { println!("Error: {:?}", e); },
+    ///     }
+    ///
+    /// }
+    /// ```
+    pub async fn create_checkpoint<T, U>(&self, id: T, name: U, exit: bool) -> Result<(), DockerError>
+        where
+            T: Into<ContainerRefId>,
+            U: Into<String>
+    {
+        self.require_experimental("checkpoints").await?;
+
+        let body = json::json!({ "CheckpointID": name.into(), "Exit": exit });
+
+        let uri = self.make_uri(format!("/containers/{}/checkpoints", id.into()));
+        let request = Request::post(uri)
+            .header(hyper::header::CONTENT_TYPE, "application/json")
+            .body(hyper::Body::from(body.to_string()))
+            .unwrap();
+
+        self.execute_buffered(request).await
+            .and_then(|response| {
+                match response.status {
+                    201 => Ok(()),
+                    404 => Err(DockerError::NotFound(response.error_message())),
+                    409 => Err(DockerError::NotRunning(response.error_message())),
+                    500 => Err(DockerError::ServerError(response.error_message())),
+                    _ => Err(response.unknown_status()),
+                }
+            })
+    }
+
+    /// Remove a checkpoint of a container
+    ///
+    /// Checkpoints are experimental, see `list_checkpoints`.
+    ///
+    /// # Arguments
+    /// `id` - ID or name of the container.
+    /// `name` - Name of the checkpoint.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use docker_client::{DockerClient, DockerError};
+    /// # #[tokio::main]
+    /// async fn main() {
+    ///    let client = DockerClient::new();
+    ///
+    ///     match client.remove_checkpoint("db", "before-upgrade").await {
+    ///         Ok(_) => {},
+    ///         Err(e) => { println!("Error: {:?}", e); },
+    ///     }
+    ///
+    /// }
+    /// ```
+    pub async fn remove_checkpoint<T, U>(&self, id: T, name: U) -> Result<(), DockerError>
+        where
+            T: Into<ContainerRefId>,
+            U: Into<String>
+    {
+        self.require_experimental("checkpoints").await?;
+
+        let uri = self.make_uri(format!("/containers/{}/checkpoints/{}", id.into(), name.into()));
+        let request = Request::delete(uri)
+            .body(hyper::Body::empty())
+            .unwrap();
+
+        self.execute_async(request).await
+            .and_then(|response| {
+                match response.status {
+                    204 => Ok(()),
+                    404 => Err(DockerError::NotFound(response.error_message())),
+                    500 => Err(DockerError::ServerError(response.error_message())),
+                    _ => Err(response.unknown_status()),
+                }
+            })
+    }
+
+    /// Get container stats
+    ///
+    /// Return a single `Stats` sample of resource usage. The daemon collects two
+    /// samples so that `precpu_stats` is filled and CPU percent can be computed.
+    ///
+    /// # Arguments
+    /// `id` - ID or name of the container.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use docker_client::{DockerClient, DockerError};
+    /// # #[tokio::main]
+    /// async fn main() {
+    ///    let client = DockerClient::new();
+    ///
+    ///     match client.container_stats("test-container").await {
+    ///         Ok(stats) => { println!("CPU: {:.2}%, MEM: {:.2}%", stats.cpu_percent(), stats.memory_percent()); }
+    ///         Err(e) => { println!("Error: {:?}", e); }
+    ///     }
+    ///
+    /// }
+    /// ```
+    pub async fn container_stats<T>(&self, id: T) -> Result<Stats, DockerError>
+        where T: Into<ContainerRefId>
+    {
+        let uri = self.make_uri(format!("/containers/{}/stats?stream=false", id.into()));
+        let request = Request::get(uri)
+            .body(hyper::Body::empty())
+            .unwrap();
+
+        self.execute_async(request).await
+            .and_then(|response| {
+                match response.status {
+                    200 => self.decode(&response),
+                    404 => Err(DockerError::NotFound(response.error_message())),
+                    500 => Err(DockerError::ServerError(response.error_message())),
+                    _ => Err(response.unknown_status()),
+                }
+            })
+    }
+
+    /// Stream container stats
+    ///
+    /// Return a stream of `Stats` samples, one per second while the container runs, or DockerError.
+    /// Dropping the stream closes the connection.
+    ///
+    /// # Arguments
+    /// `id` - ID or name of the container.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use docker_client::{DockerClient, DockerError};
+    /// use futures::StreamExt;
+    ///
+    /// # #[tokio::main]
+    /// async fn main() {
+    ///    let client = DockerClient::new();
+    ///
+    ///     match client.container_stats_stream("test-container").await {
+    ///         Ok(stats) => {
+    ///             let mut samples = stats.take(3);
+    ///             while let Some(Ok(stats)) = samples.next().await {
+    ///                 println!("CPU: {:.2}%", stats.cpu_percent());
+    ///             }
+    ///         }
+    ///         Err(e) => { println!("Error: {:?}", e); }
+    ///     }
+    ///
+    /// }
+    /// ```
+    pub async fn container_stats_stream<T>(&self, id: T) -> Result<impl Stream<Item = Result<Stats, DockerError>> + Unpin + Send, DockerError>
+        where T: Into<ContainerRefId>
+    {
+        let uri = self.make_uri(format!("/containers/{}/stats?stream=true", id.into()));
+        let request = Request::get(uri)
+            .body(hyper::Body::empty())
+            .unwrap();
+
+        let body = self.execute_stream(request, |response| {
+            match response.status {
+                404 => DockerError::NotFound(response.error_message()),
+                500 => DockerError::ServerError(response.error_message()),
+                _ => response.unknown_status(),
+            }
+        }).await?;
+
+        Ok(Box::pin(json_lines::<Stats, _>(body)))
+    }
+
+
+    /// Export a container
+    ///
+    /// Return empty object or DockerError
+    ///
+    /// # Arguments
+    /// `id` - ID or name of the container.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use docker_client::{DockerClient, DockerError};
+    /// # use std::path::Path;
+    ///
+    /// # #[tokio::main]
+    /// async fn main() {
+    ///    let client = DockerClient::new();
+    ///
+    ///     let mut path = std::env::temp_dir();
+    ///     path.push("export_container");
+    ///     path.set_extension("tar");
+    ///
+    ///     match client.export_container("test-container", path.as_path()).await {
+    ///         Ok(_) => {},
+    ///         Err(e) => { println!("Error: {:?}", e); },
+    ///     }
+    ///
+    /// }
+    /// ```
+    pub async fn export_container<T>(&self, id: T, file: &Path) -> Result<(), DockerError>
+        where T: Into<ContainerRefId>
+    {
+        let mut archive = self.export_container_stream(id).await?;
+        let mut output = tokio::fs::File::create(file).await.map_err(DockerError::Io)?;
+
+        while let Some(chunk) = archive.next().await {
+            output.write_all(&chunk?).await.map_err(DockerError::Io)?;
+        }
+
+        output.flush().await.map_err(DockerError::Io)
+    }
+
+    /// Stream the export of a container
+    ///
+    /// Return the tar archive of the filesystem of the container as chunks are received, or DockerError.
+    ///
+    /// # Arguments
+    /// `id` - ID or name of the container.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use docker_client::{DockerClient, DockerError};
+    /// use futures::StreamExt;
+    ///
+    /// # #[tokio::main]
+    /// async fn main() {
+    ///    let client = DockerClient::new();
+    ///
+    ///     match client.export_container_stream("test-container").await {
+    ///         Ok(mut archive) => {
+    ///             let mut size = 0;
+    ///             while let Some(Ok(chunk)) = archive.next().await {
+    ///                 size += chunk.len();
+    ///             }
+    ///             println!("{} bytes", size);
+    ///         },
+    ///         Err(e) => { println!("Error: {:?}", e); },
+    ///     }
+    ///
+    /// }
+    /// ```
+    pub async fn export_container_stream<T>(&self, id: T) -> Result<impl Stream<Item = Result<Bytes, DockerError>> + Unpin + Send, DockerError>
+        where T: Into<ContainerRefId>
+    {
+        let uri = self.make_uri(format!("/containers/{}/export", id.into()));
+        let request = Request::get(uri)
+            .body(hyper::Body::empty())
+            .unwrap();
+
+        self.execute_stream(request, |response| {
+            match response.status {
+                404 => DockerError::NotFound(response.error_message()),
+                500 => DockerError::ServerError(response.error_message()),
+                _ => response.unknown_status(),
+            }
+        }).await
+    }
+
+    /// Download a file or directory of a container
+    ///
+    /// Return tar archive of the path or DockerError. The body is returned as received,
+    /// without copies.
+    ///
+    /// # Arguments
+    /// * `id` - ID or name of the container.
+    /// * `path` - Path of the resource in the container filesystem.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use docker_client::{DockerClient, DockerError};
+    /// # #[tokio::main]
+    /// async fn main() {
+    ///    let client = DockerClient::new();
+    ///
+    ///     match client.container_archive("test-container", "/etc/hostname").await {
+    ///         Ok(archive) => { println!("{} bytes", archive.len()); },
+    ///         Err(e) => { println!("Error: {:?}", e); },
+    ///     }
+    ///
+    /// }
+    /// ```
+    pub async fn container_archive<T, U>(&self, id: T, path: U) -> Result<Bytes, DockerError>
+        where
+            T: Into<ContainerRefId>,
+            U: AsRef<str>
+    {
+        let uri = self.make_uri(format!("/containers/{}/archive?path={}", id.into(), encode(path.as_ref())));
+        let request = Request::get(uri)
+            .body(hyper::Body::empty())
+            .unwrap();
+
+        self.execute_async(request).await
+            .and_then(|response| {
+                match response.status {
+                    200 => Ok(response.into_body()),
+                    400 => Err(DockerError::BadParameters(response.error_message())),
+                    404 => Err(DockerError::NotFound(response.error_message())),
+                    500 => Err(DockerError::ServerError(response.error_message())),
+                    _ => Err(response.unknown_status()),
+                }
+            })
+    }
+
+    /// Get stat of a file or directory of a container, without downloading it
+    ///
+    /// Return PathStat or DockerError.
+    ///
+    /// # Arguments
+    /// * `id` - ID or name of the container.
+    /// * `path` - Path of the resource in the container filesystem.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use docker_client::{DockerClient, DockerError};
+    /// # #[tokio::main]
+    /// async fn main() {
+    ///    let client = DockerClient::new();
+    ///
+    ///     match client.container_path_stat("test-container", "/etc/hostname").await {
+    ///         Ok(stat) => { println!("{} bytes, dir {}", stat.size(), stat.is_dir()); },
+    ///         Err(e) => { println!("Error: {:?}", e); },
+    ///     }
+    ///
+    /// }
+    /// ```
+    pub async fn container_path_stat<T, U>(&self, id: T, path: U) -> Result<PathStat, DockerError>
+        where
+            T: Into<ContainerRefId>,
+            U: AsRef<str>
+    {
+        let uri = self.make_uri(format!("/containers/{}/archive?path={}", id.into(), encode(path.as_ref())));
+        let request = Request::head(uri)
+            .body(hyper::Body::empty())
+            .unwrap();
+
+        // Responses to HEAD have no body, errors are only told by the status.
+        let error = |message: &str| ErrorMessage { message: format!("{}: {}", message, path.as_ref()) };
+
+        self.execute_async(request).await
+            .and_then(|response| {
+                match response.status {
+                    200 => response.path_stat().ok_or_else(|| response.unknown_status()),
+                    400 => Err(DockerError::BadParameters(error("bad parameter"))),
+                    404 => Err(DockerError::NotFound(error("no such container or path"))),
+                    500 => Err(DockerError::ServerError(error("server error"))),
+                    _ => Err(response.unknown_status()),
+                }
+            })
+    }
+
+    /// Upload a tar archive into a directory of a container
+    ///
+    /// Return empty or DockerError.
+    ///
+    /// # Arguments
+    /// * `upload` - Container, target directory and options, see `Upload`.
+    /// * `archive` - Tar archive, optionally compressed with gzip, bzip2 or xz.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use docker_client::{DockerClient, DockerError};
+    /// # use docker_client::container::Upload;
+    /// # #[tokio::main]
+    /// async fn main() {
+    ///    let client = DockerClient::new();
+    ///
+    ///     let upload = Upload::builder().id("test-container").path("/etc/app").build();
+    ///     let archive = std::fs::read("config.tar").unwrap_or_default();
+    ///
+    ///     match client.upload_archive(upload, archive).await {
+    ///         Ok(_) => {},
+    ///         Err(e) => { println!("Error: {:?}", e); },
+    ///     }
+    ///
+    /// }
+    /// ```
+    pub async fn upload_archive<A>(&self, upload: Upload, archive: A) -> Result<(), DockerError>
+        where A: Into<Bytes>
+    {
+        let uri = self.make_uri(upload.get_path());
+        let request = Request::put(uri)
+            .header("Content-Type", "application/x-tar")
+            .body(hyper::Body::from(archive.into()))
+            .unwrap();
+
+        self.execute_async(request).await
+            .and_then(|response| {
+                match response.status {
+                    200 => Ok(()),
+                    400 => Err(DockerError::BadParameters(response.error_message())),
+                    403 => Err(DockerError::Forbidden(response.error_message())),
+                    404 => Err(DockerError::NotFound(response.error_message())),
+                    500 => Err(DockerError::ServerError(response.error_message())),
+                    _ => Err(response.unknown_status()),
+                }
+            })
+    }
+
+    /// Get images list
+    ///
+    /// Return vector of ShortImageInfo or DockerError
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use docker_client::{DockerClient, DockerError};
+    /// # #[tokio::main]
+    /// async fn main() {
+    ///    let client = DockerClient::new();
+    ///
+    ///     match client.get_image_list().await {
+    ///         Ok(list) => { println!("{:?}", list); },
+    ///         Err(e) => { println!("Error: {:?}", e); },
+    ///     }
+    ///
+    /// }
+    /// ```
+    pub async fn get_image_list(&self) -> Result<Vec<ShortImageInfo>, DockerError> {
+        self.images_list(crate::image::list::Request::default()).await
+    }
+
+    /// Get images list with options
+    ///
+    /// Return vector of ShortImageInfo or DockerError
+    ///
+    /// # Arguments
+    /// * `request` - Image list request.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use docker_client::{DockerClient, DockerError};
+    /// # use docker_client::additionals::filters::Filters;
+    /// # use docker_client::image::list::Request;
+    /// # #[tokio::main]
+    /// async fn main() {
+    ///    let client = DockerClient::new();
+    ///
+    ///     let request = Request::builder()
+    ///         .filters(Filters::new().dangling(true).build())
+    ///         .build();
+    ///
+    ///     match client.images_list(request).await {
+    ///         Ok(list) => { println!("{:?}", list); },
+    ///         Err(e) => { println!("Error: {:?}", e); },
+    ///     }
+    ///
+    /// }
+    /// ```
+    pub async fn images_list(&self, request: crate::image::list::Request) -> Result<Vec<ShortImageInfo>, DockerError> {
+
+        let uri = self.make_uri(request.get_path());
+        let request = Request::get(uri)
+            .body(hyper::Body::empty())
+            .unwrap();
+
+        self.execute_async(request).await
+            .and_then(|response| {
+                match response.status {
+                    200 => self.decode(&response),
+                    500 => Err(DockerError::ServerError(response.error_message())),
+                    _ => Err(response.unknown_status()),
+                }
+            })
+    }
+
+    /// Create a volume
+    ///
+    /// Return empty object or DockerError
+    ///
+    /// # Arguments
+    /// * `volume` - VolumeCreator struct.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate tokio;
+    /// # use docker_client::{DockerClient, DockerError};
+    /// # use docker_client::volume::VolumeCreator;
+    ///
+    /// # #[tokio::main]
+    /// async fn main() {
+    ///    let client = DockerClient::new();
+    ///
+    ///     let creator = VolumeCreator::builder()
+    ///         .name("test")
+    ///         .build();
+    ///
+    ///     match client.create_volume(creator).await {
+    ///         Ok(_) => {},
+    ///         Err(e) => { println!("Error: {:?}", e); },
+    ///     }
+    ///
+    /// }
+    /// ```
+    pub async fn create_volume(&self, volume: VolumeCreator) -> Result<(), DockerError> {
+        self.create_volume_info(volume).await.map(|_| ())
+    }
+
+    /// Return a volume by name, creating it if missing
+    ///
+    /// A volume without name is always created, with a name picked by the daemon.
+    ///
+    /// Return VolumeInfo of the existing or new volume or DockerError.
+    ///
+    /// # Arguments
+    /// * `volume` - VolumeCreator struct, its name is looked up.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use docker_client::{DockerClient, DockerError};
+    /// # use docker_client::volume::VolumeCreator;
+    /// # #[tokio::main]
+    /// async fn main() {
+    ///    let client = DockerClient::new();
+    ///
+    ///     let creator = VolumeCreator::builder().name("pgdata").build();
+    ///
+    ///     match client.get_or_create_volume(creator).await {
+    ///         Ok(volume) => { println!("{}", volume.mountpoint()); },
+    ///         Err(e) => { println!("Error: {:?}", e); },
+    ///     }
+    ///
+    /// }
+    /// ```
+    pub async fn get_or_create_volume(&self, volume: VolumeCreator) -> Result<VolumeInfo, DockerError> {
+        if !volume.name().is_empty() {
+            match self.inspect_volume(volume.name()).await {
+                Ok(info) => return Ok(info),
+                Err(DockerError::NotFound(_)) => {},
+                Err(e) => return Err(e),
+            }
+        }
+
+        // Creating a volume which exists with the same driver returns it, so a
+        // volume created concurrently needs no special case.
+        self.create_volume_info(volume).await
+    }
+
+    async fn create_volume_info(&self, volume: VolumeCreator) -> Result<VolumeInfo, DockerError> {
+
+        let uri = self.make_uri("/volumes/create");
+        let request = Request::post(uri)
+            .header("Content-Type", "application/json")
+            .body(hyper::Body::from(json::to_string(&volume).unwrap()))
+            .unwrap();
+
+        self.execute_async(request).await
+            .and_then(|response| {
+                match response.status {
+                    201 => self.decode(&response),
+                    400 => Err(DockerError::BadParameters(response.error_message())),
+                    500 => Err(DockerError::ServerError(response.error_message())),
+                    _ => Err(response.unknown_status()),
+                }
+            })
+    }
+
+    /// Return handle to a volume
+    ///
+    /// # Arguments
+    /// * `name` - Name of the volume.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use docker_client::{DockerClient, DockerError};
+    /// # #[tokio::main]
+    /// async fn main() {
+    ///    let client = DockerClient::new();
+    ///
+    ///     let volume = client.volume("test");
+    ///     match volume.create().await {
+    ///         Ok(_) => { let _ = volume.remove(false).await; },
+    ///         Err(e) => { println!("Error: {:?}", e); },
+    ///     }
+    ///
+    /// }
+    /// ```
+    pub fn volume<T>(&self, name: T) -> VolumeRef<'_>
+        where T: Into<String>
+    {
+        VolumeRef::new(self, name.into())
+    }
+
+    /// Inspect volume
+    ///
+    /// Return VolumeInfo or DockerError
+    ///
+    /// # Arguments
+    /// * `name` - ID or name of the volume.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use docker_client::{DockerClient, DockerError};
+    /// # #[tokio::main]
+    /// async fn main() {
+    ///    let client = DockerClient::new();
+    ///
+    ///     match client.inspect_volume("test").await {
+    ///         Ok(info) => { println!("{:?}", info); },
+    ///         Err(e) => { println!("Error: {:?}", e); },
+    ///     }
+    ///
+    /// }
+    /// ```
+    pub async fn inspect_volume<T>(&self, name: T) -> Result<VolumeInfo, DockerError>
+        where T: Into<String>
+    {
+        self.inspect_volume_raw(name).await.map(Raw::into_value)
+    }
+
+    /// Inspect a volume, keeping the untouched body
+    ///
+    /// Return `Raw` with the decoded struct and the body or DockerError.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use docker_client::{DockerClient, DockerError};
+    /// # #[tokio::main]
+    /// async fn main() {
+    ///    let client = DockerClient::new();
+    ///
+    ///     match client.inspect_volume_raw("pgdata").await {
+    ///         Ok(volume) => { println!("{} {:?}", volume.name(), volume.json().map(|raw| raw["ClusterVolume"].clone())); },
+    ///         Err(e) => { println!("Error: {:?}", e); },
+    ///     }
+    ///
+    /// }
+    /// ```
+    pub async fn inspect_volume_raw<T>(&self, name: T) -> Result<Raw<VolumeInfo>, DockerError>
+        where T: Into<String>
+    {
+
+        let uri = self.make_uri(format!("/volumes/{}", name.into()));
+        let request = Request::get(uri)
+            .body(hyper::Body::empty())
+            .unwrap();
+
+        self.execute_async(request).await
+            .and_then(|response| {
+                match response.status {
+                    200 => self.decode_raw(&response),
+                    400 => Err(DockerError::BadParameters(response.error_message())),
+                    404 => Err(DockerError::NotFound(response.error_message())),
+                    500 => Err(DockerError::ServerError(response.error_message())),
+                    _ => Err(response.unknown_status()),
+                }
+            })
+    }
+
+    /// Remove volume
+    ///
+    /// Instruct the driver to remove the volume.
+    ///
+    /// # Arguments
+    /// * `name` - ID or name of the volume.
+    /// * `force` - Force the removal of the volume.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use docker_client::{DockerClient, DockerError};
+    /// # #[tokio::main]
+    /// async fn main() {
+    ///    let client = DockerClient::new();
+    ///
+    ///     match client.remove_volume("test", false).await {
+    ///         Ok(_) => {},
+    ///         Err(e) => { println!("Error: {:?}", e); },
+    ///     }
+    ///
+    /// }
+    /// ```
+    pub async fn remove_volume<T>(&self, name: T, force: bool) -> Result<(), DockerError>
+        where T: Into<String>
+    {
+
+        let uri = self.make_uri(format!("/volumes/{}?force={}", name.into(), force.to_string()));
+        let request = Request::delete(uri)
+            .body(hyper::Body::empty())
+            .unwrap();
+
+        self.execute_async(request).await
+            .and_then(|response| {
+                match response.status {
+                    204 => Ok(()),
+                    400 => Err(DockerError::BadParameters(response.error_message())),
+                    404 => Err(DockerError::NotFound(response.error_message())),
+                    409 => Err(DockerError::Busy(response.error_message())),
+                    500 => Err(DockerError::ServerError(response.error_message())),
+                    _ => Err(response.unknown_status()),
+                }
+            })
+    }
+
+    /// Delete unused volumes
+    ///
+    /// Return empty or DockerError
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate tokio;
+    /// # use docker_client::{DockerClient, DockerError};
+    ///
+    /// # #[tokio::main]
+    /// async fn main() {
+    ///    let client = DockerClient::new();
+    ///
+    ///     match client.delete_unused_volumes().await {
+    ///         Ok(_) => {},
+    ///         Err(e) => { println!("Error: {:?}", e); },
+    ///     }
+    ///
+    /// }
+    /// ```
+    pub async fn delete_unused_volumes(&self) -> Result<DeletedInfo, DockerError> {
+        self.prune_volumes(Filters::default()).await
+    }
+
+    /// Delete unused volumes matching filters
+    ///
+    /// Return DeletedInfo or DockerError
+    ///
+    /// # Arguments
+    /// * `filters` - Filters, e.g. `label`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use docker_client::{DockerClient, DockerError};
+    /// # use docker_client::additionals::filters::Filters;
+    /// # #[tokio::main]
+    /// async fn main() {
+    ///    let client = DockerClient::new();
+    ///
+    ///     let filters = Filters::new().label("ci", None).build();
+    ///
+    ///     match client.prune_volumes(filters).await {
+    ///         Ok(deleted) => { println!("{:?}", deleted); },
+    ///         Err(e) => { println!("Error: {:?}", e); },
+    ///     }
+    ///
+    /// }
+    /// ```
+    pub async fn prune_volumes(&self, filters: Filters) -> Result<DeletedInfo, DockerError> {
+
+        let uri = self.make_uri(match filters.query() {
+            Some(query) => format!("/volumes/prune?{}", query),
+            None => "/volumes/prune".to_string(),
+        });
+        let request = Request::post(uri)
+            .body(hyper::Body::empty())
+            .unwrap();
+
+        self.execute_async(request).await
+            .and_then(|response| {
+                match response.status {
+                    200 => self.decode(&response),
+                    400 => Err(DockerError::BadParameters(response.error_message())),
+                    500 => Err(DockerError::ServerError(response.error_message())),
+                    _ => Err(response.unknown_status()),
+                }
+            })
+    }
+
+    /// Get volumes list
+    ///
+    /// Return VolumesList or DockerError
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use docker_client::{DockerClient, DockerError};
+    /// # #[tokio::main]
+    /// async fn main() {
+    ///    let client = DockerClient::new();
+    ///
+    ///     match client.get_volumes_list().await {
+    ///         Ok(list) => { println!("{:?}", list); },
+    ///         Err(e) => { println!("Error: {:?}", e); },
+    ///     }
+    ///
+    /// }
+    /// ```
+    pub async fn get_volumes_list(&self) -> Result<VolumesList, DockerError> {
+        self.volumes_list(Filters::default()).await
+    }
+
+    /// Get volumes list matching filters
+    ///
+    /// Return VolumesList or DockerError
+    ///
+    /// # Arguments
+    /// * `filters` - Filters, e.g. `dangling`, `driver`, `label`, `name`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use docker_client::{DockerClient, DockerError};
+    /// # use docker_client::additionals::filters::Filters;
+    /// # #[tokio::main]
+    /// async fn main() {
+    ///    let client = DockerClient::new();
+    ///
+    ///     let filters = Filters::new().dangling(true).build();
+    ///
+    ///     match client.volumes_list(filters).await {
+    ///         Ok(list) => { println!("{:?}", list); },
+    ///         Err(e) => { println!("Error: {:?}", e); },
+    ///     }
+    ///
+    /// }
+    /// ```
+    pub async fn volumes_list(&self, filters: Filters) -> Result<VolumesList, DockerError> {
+
+        let uri = self.make_uri(match filters.query() {
+            Some(query) => format!("/volumes?{}", query),
+            None => "/volumes".to_string(),
+        });
+        let request = Request::get(uri)
+            .body(hyper::Body::empty())
+            .unwrap();
+
+        self.execute_async(request).await
+            .and_then(|response| {
+                match response.status {
+                    200 => self.decode(&response),
+                    400 => Err(DockerError::BadParameters(response.error_message())),
+                    500 => Err(DockerError::ServerError(response.error_message())),
+                    _ => Err(response.unknown_status()),
+                }
+            })
+    }
+
+    /// Export the contents of a volume
+    ///
+    /// The volume is mounted read-only into a container of `busybox:latest`, pulled if missing,
+    /// which is never started. The archive is streamed as it is read, the container is removed
+    /// when the stream ends or is dropped.
+    ///
+    /// Return stream of the tar archive of the volume contents, with paths relative to the volume root,
+    /// or DockerError. A missing volume is `DockerError::NotFound`, it is never created.
+    ///
+    /// # Arguments
+    /// * `name` - Name of the volume.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use docker_client::{DockerClient, DockerError};
+    /// use futures::StreamExt;
+    /// use tokio::io::AsyncWriteExt;
+    ///
+    /// # #[tokio::main]
+    /// async fn main() {
+    ///    let client = DockerClient::new();
+    ///
+    ///     match client.export_volume("pgdata").await {
+    ///         Ok(mut archive) => {
+    ///             let mut file = tokio::fs::File::create("pgdata.tar").await.unwrap();
+    ///             while let Some(Ok(chunk)) = archive.next().await {
+    ///                 file.write_all(&chunk).await.unwrap();
+    ///             }
+    ///         },
+    ///         Err(e) => { println!("Error: {:?}", e); },
+    ///     }
+    ///
+    /// }
+    /// ```
+    pub async fn export_volume<T>(&self, name: T) -> Result<Pin<Box<dyn Stream<Item = Result<Bytes, DockerError>> + Send>>, DockerError>
+        where T: Into<String>
+    {
+        let name = name.into();
+        check_volume_name(&name)?;
+        self.inspect_volume(name.clone()).await?;
+
+        let helper = self.volume_helper(name, true).await?;
+
+        let uri = self.make_uri(format!("/containers/{}/archive?path={}", helper, encode(&format!("{}/.", VOLUME_MOUNT))));
+        let request = Request::get(uri)
+            .body(hyper::Body::empty())
+            .unwrap();
+
+        let archive = self.execute_stream(request, |response| {
+            match response.status {
+                404 => DockerError::NotFound(response.error_message()),
+                500 => DockerError::ServerError(response.error_message()),
+                _ => response.unknown_status(),
+            }
+        }).await;
+
+        let archive = match archive {
+            Ok(archive) => archive,
+            Err(e) => return self.remove_helper(helper, Err(e)).await,
+        };
+
+        let helper = HelperGuard { client: self.clone(), id: Some(helper) };
+        Ok(Box::pin(futures::stream::unfold(Some((archive, helper)), |state| async move {
+            let (mut archive, helper) = state?;
+
+            match archive.next().await {
+                Some(Ok(chunk)) => Some((Ok(chunk), Some((archive, helper)))),
+                Some(Err(e)) => {
+                    let _ = helper.remove().await;
+                    Some((Err(e), None))
+                },
+                None => helper.remove().await.err().map(|e| (Err(e), None)),
+            }
+        })))
+    }
+
+    /// Restore a tar archive into a volume
+    ///
+    /// Files of the archive are written over the volume contents, other files are kept.
+    /// The volume is created if missing. See `export_volume` for the helper container.
+    /// The archive is streamed to the daemon as it is read.
+    ///
+    /// Return empty or DockerError.
+    ///
+    /// # Arguments
+    /// * `name` - Name of the volume.
+    /// * `archive` - Stream of a tar archive with paths relative to the volume root, as returned by `export_volume`.
+    ///   An error of the stream aborts the upload.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use docker_client::{DockerClient, DockerError};
+    /// # #[tokio::main]
+    /// async fn main() {
+    ///    let client = DockerClient::new();
+    ///
+    ///     let archive = std::fs::read("pgdata.tar").unwrap_or_default();
+    ///     let archive = futures::stream::once(async move { Ok::<_, DockerError>(archive) });
+    ///
+    ///     match client.import_volume("pgdata", archive).await {
+    ///         Ok(_) => {},
+    ///         Err(e) => { println!("Error: {:?}", e); },
+    ///     }
+    ///
+    /// }
+    /// ```
+    pub async fn import_volume<T, A, B>(&self, name: T, archive: A) -> Result<(), DockerError>
+        where
+            T: Into<String>,
+            A: Stream<Item = Result<B, DockerError>> + Send + 'static,
+            B: Into<Bytes> + 'static
+    {
+        let name = name.into();
+        check_volume_name(&name)?;
+
+        match self.inspect_volume(name.clone()).await {
+            Ok(_) => {},
+            Err(DockerError::NotFound(_)) => self.create_volume(VolumeCreator::builder().name(name.clone()).build()).await?,
+            Err(e) => return Err(e),
+        }
+
+        let helper = self.volume_helper(name, false).await?;
+
+        let upload = Upload::builder().id(&helper).path(VOLUME_MOUNT).build();
+        let request = Request::put(self.make_uri(upload.get_path()))
+            .header("Content-Type", "application/x-tar")
+            .body(hyper::Body::wrap_stream(archive.map(|chunk| chunk.map(Into::into).map_err(archive_error))))
+            .unwrap();
+
+        let uploaded = self.execute_async(request).await
+            .and_then(|response| {
+                match response.status {
+                    200 => Ok(()),
+                    400 => Err(DockerError::BadParameters(response.error_message())),
+                    404 => Err(DockerError::NotFound(response.error_message())),
+                    500 => Err(DockerError::ServerError(response.error_message())),
+                    _ => Err(response.unknown_status()),
+                }
+            });
+
+        self.remove_helper(helper, uploaded).await
+    }
+
+    /// Create a container mounting the existing volume `name`, which is never started.
+    async fn volume_helper(&self, name: String, read_only: bool) -> Result<ContainerId, DockerError> {
+        self.ensure_image(VOLUME_HELPER_IMAGE, None).await?;
+
+        let host_config = HostConfigBuilder::new()
+            .mount(name, VOLUME_MOUNT.to_string(), read_only)
+            .build();
+        let request = Create::new()
+            .config(Config::with_image(VOLUME_HELPER_IMAGE).host_config(host_config).build())
+            .build();
+
+        Ok(self.create_container(request).await?.id().clone())
+    }
+
+    /// Remove helper container, returning the first error.
+    async fn remove_helper<T>(&self, helper: ContainerId, result: Result<T, DockerError>) -> Result<T, DockerError> {
+        let remover = Remover::new().id(helper).with_force_delete(true).build();
+        let removed = self.remove_container(remover).await;
+
+        let value = result?;
+        removed.map(|_| value)
+    }
+
+    /// Remove old stopped containers, dangling images and unused volumes
+    ///
+    /// Containers and images are pruned with `label` and `until` filters, unused volumes
+    /// are listed and removed one by one, as volume prune has no age filter.
+    ///
+    /// Return GcReport or DockerError
+    ///
+    /// # Arguments
+    /// * `gc` - Labels, age and kinds of objects to remove, see `GarbageCollector`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use docker_client::{DockerClient, DockerError};
+    /// # use docker_client::gc::GarbageCollector;
+    /// # use std::time::Duration;
+    /// # #[tokio::main]
+    /// async fn main() {
+    ///    let client = DockerClient::new();
+    ///
+    ///     let gc = GarbageCollector::new().label("ci", None).older_than(Duration::from_secs(3600));
+    ///
+    ///     match client.collect_garbage(gc).await {
+    ///         Ok(report) => { println!("{:?}", report); },
+    ///         Err(e) => { println!("Error: {:?}", e); },
+    ///     }
+    ///
+    /// }
+    /// ```
+    pub async fn collect_garbage(&self, gc: GarbageCollector) -> Result<GcReport, DockerError> {
+        gc.run(self).await
+    }
+
+    /// Bring a named container to its spec
+    ///
+    /// The container is created if missing and recreated only when it drifted from the
+    /// spec: the config or networks changed, or the image reference now points to another
+    /// image. A matching stopped container is started. The image is pulled if missing.
+    ///
+    /// Return Reconciled with the action taken or DockerError
+    ///
+    /// # Arguments
+    /// * `spec` - Name, config and networks of the container, see `ContainerSpec`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use docker_client::{DockerClient, DockerError};
+    /// # use docker_client::container::Config;
+    /// # use docker_client::reconcile::ContainerSpec;
+    /// # #[tokio::main]
+    /// async fn main() {
+    ///    let client = DockerClient::new();
+    ///
+    ///     let spec = ContainerSpec::new("web", Config::with_image("nginx:1.25").build());
+    ///
+    ///     match client.reconcile(spec).await {
+    ///         Ok(web) => { println!("{:?}", web.action()); },
+    ///         Err(e) => { println!("Error: {:?}", e); },
+    ///     }
+    ///
+    /// }
+    /// ```
+    pub async fn reconcile(&self, spec: ContainerSpec) -> Result<Reconciled, DockerError> {
+        spec.run(self).await
+    }
+
+
+    /// Pull an image
+    ///
+    /// Pull an image from a registry. Credentials of the request, of the client or of
+    /// the registry in the CLI configuration are sent in `X-Registry-Auth` header.
+    ///
+    /// Return PullSummary derived from the progress stream or DockerError.
+    /// Errors reported in the progress stream are returned as `DockerError::ServerError`.
+    /// If the request sets a digest, the pulled image is inspected and
+    /// `DockerError::DigestMismatch` is returned unless its repo digests contain it.
+    ///
+    /// # Arguments
+    /// * `request` - Image create request.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use docker_client::{DockerClient, DockerError};
+    /// # use docker_client::image::create::RequestBuilder;
+    /// # #[tokio::main]
+    /// async fn main() {
+    ///    let client = DockerClient::new();
+    ///
+    ///     let request = RequestBuilder::new().image("alpine").tag("latest").build();
+    ///
+    ///     match client.pull_image(request).await {
+    ///         Ok(summary) => {
+    ///             println!("{:?}: {} layers downloaded, {} cached", summary.digest(), summary.layers_downloaded(), summary.layers_cached());
+    ///         },
+    ///         Err(e) => { println!("Error: {:?}", e); },
+    ///     }
+    ///
+    /// }
+    /// ```
+    pub async fn pull_image(&self, request: crate::image::create::Request) -> Result<PullSummary, DockerError> {
+        let uri = self.make_uri(request.get_path());
+        let mut request_builder = Request::post(uri);
+
+        let reference = request.reference();
+        if let Some(auth) = self.image_auth_header(reference.as_str(), request.auth()).await? {
+            request_builder = request_builder.header("X-Registry-Auth", auth);
+        }
+
+        let digest = request.digest().map(str::to_string);
+        let request = request_builder.body(hyper::Body::empty()).unwrap();
+
+        let summary = self.execute_buffered(request).await
+            .and_then(|response| {
+                match response.status {
+                    200 => {
+                        let records: Vec<Progress> = parse_lines(response.body())?;
+
+                        match records.iter().find_map(Progress::error) {
+                            Some(error) => Err(DockerError::ServerError(ErrorMessage { message: error.to_string() })),
+                            None => Ok(PullSummary::from_progress(&records)),
+                        }
+                    },
+                    404 => Err(DockerError::NotFound(response.error_message())),
+                    500 => Err(DockerError::ServerError(response.error_message())),
+                    _ => Err(response.unknown_status()),
+                }
+            })?;
+
+        if let Some(digest) = digest {
+            verify_digest(&self.inspect_image(reference).await?, &digest)?;
+        }
+
+        Ok(summary)
+    }
+
+    /// Make sure an image is present, pulling it if missing
+    ///
+    /// With `digest`, a local image without that repo digest is pulled again and the
+    /// pulled image is verified, see `pull_image`.
+    ///
+    /// Return ImageInfo of the local image or DockerError.
+    ///
+    /// # Arguments
+    /// * `reference` - Image reference, e.g. `alpine:3.19`, a missing tag means `latest`.
+    /// * `digest` - Repo digest the image must have, e.g. `sha256:...`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use docker_client::{DockerClient, DockerError};
+    /// # #[tokio::main]
+    /// async fn main() {
+    ///    let client = DockerClient::new();
+    ///
+    ///     let digest = "sha256:c5b1261d6d3e43071626931fc004f70149baeba2c8ec672bd4f27761f8e1ad6b";
+    ///
+    ///     match client.ensure_image("alpine:3.19", Some(digest)).await {
+    ///         Ok(image) => { println!("{:?}", image.id()); },
+    ///         Err(DockerError::DigestMismatch { found, .. }) => { println!("unexpected digests {:?}", found); },
+    ///         Err(e) => { println!("Error: {:?}", e); },
+    ///     }
+    ///
+    /// }
+    /// ```
+    pub async fn ensure_image(&self, reference: &str, digest: Option<&str>) -> Result<ImageInfo, DockerError> {
+        match self.inspect_image(reference).await {
+            Ok(info) if digest.is_none_or(|digest| info.has_digest(digest)) => return Ok(info),
+            Ok(_) | Err(DockerError::NotFound(_)) => {},
+            Err(e) => return Err(e),
+        }
+
+        let (image, tag) = crate::image::reference::split_reference(reference);
+        let mut request = crate::image::create::RequestBuilder::new()
+            .image(image)
+            .tag(tag.unwrap_or("latest"));
+        if let Some(digest) = digest {
+            request = request.digest(digest);
+        }
+
+        let request = request.build();
+        let pulled = request.reference();
+        self.pull_image(request).await?;
+        self.inspect_image(pulled).await
+    }
+
+    /// Pull several images concurrently
+    ///
+    /// Return MultiPullStream of progress events of all pulls, tagged with the image reference.
+    /// Credentials of the client, or of each registry in the CLI configuration,
+    /// are sent with every pull.
+    ///
+    /// # Arguments
+    /// * `references` - Image references, e.g. `alpine:3.19`, a missing tag means `latest`.
+    /// * `parallelism` - Maximum number of pulls in progress at a time, zero is treated as one.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use docker_client::{DockerClient, DockerError};
+    /// # use docker_client::image::PullEvent;
+    /// use futures::StreamExt;
+    ///
+    /// # #[tokio::main]
+    /// async fn main() {
+    ///    let client = DockerClient::new();
+    ///
+    ///     let mut pulls = client.pull_images(&["alpine:3.19", "redis:7", "postgres:16"], 2);
+    ///
+    ///     while let Some(pull) = pulls.next().await {
+    ///         match pull.event() {
+    ///             PullEvent::Progress(progress) => { println!("{}: {:?}", pull.reference(), progress.status()); },
+    ///             PullEvent::Done(summary) => { println!("{}: {}", pull.reference(), summary.status()); },
+    ///             PullEvent::Failed(e) => { println!("{}: {:?}", pull.reference(), e); },
+    ///         }
+    ///     }
+    ///
+    /// }
+    /// ```
+    pub fn pull_images<T>(&self, references: &[T], parallelism: usize) -> MultiPullStream
+        where T: AsRef<str>
+    {
+        let references = references.iter().map(|reference| reference.as_ref().to_string()).collect();
+        MultiPullStream::new(self, references, parallelism)
+    }
+
+    /// Pull an image and stream its progress records
+    pub(crate) async fn pull_progress(&self, reference: &str) -> Result<Pin<Box<dyn Stream<Item = Result<Progress, DockerError>> + Send>>, DockerError> {
+        let (image, tag) = crate::image::reference::split_reference(reference);
+        let request = crate::image::create::RequestBuilder::new()
+            .image(image)
+            .tag(tag.unwrap_or("latest"))
+            .build();
+
+        let uri = self.make_uri(request.get_path());
+        let mut request_builder = Request::post(uri);
+
+        if let Some(auth) = self.image_auth_header(reference, None).await? {
+            request_builder = request_builder.header("X-Registry-Auth", auth);
+        }
+
+        let request = request_builder.body(hyper::Body::empty()).unwrap();
+
+        let body = self.execute_stream(request, |response| {
+            match response.status {
+            404 => DockerError::NotFound(response.error_message()),
+            500 => DockerError::ServerError(response.error_message()),
+            _ => response.unknown_status(),
+            }
+        }).await?;
+
+        Ok(Box::pin(json_lines::<Progress, _>(body)))
+    }
+
+    /// Return handle to an image
+    ///
+    /// # Arguments
+    /// * `name` - Image reference, e.g. `alpine:3.19`, or ID.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use docker_client::{DockerClient, DockerError};
+    /// # #[tokio::main]
+    /// async fn main() {
+    ///    let client = DockerClient::new();
+    ///
+    ///     match client.image("alpine:3.19").inspect().await {
+    ///         Ok(info) => { println!("{:?}", info); },
+    ///         Err(e) => { println!("Error: {:?}", e); },
+    ///     }
+    ///
+    /// }
+    /// ```
+    pub fn image<T>(&self, name: T) -> ImageRef<'_>
+        where T: Into<String>
+    {
+        ImageRef::new(self, name.into())
+    }
+
+    /// Inspect an image
+    ///
+    /// Return ImageInfo or DockerError
+    ///
+    /// # Arguments
+    /// * `name` - Image name or ID.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use docker_client::{DockerClient, DockerError};
+    /// # #[tokio::main]
+    /// async fn main() {
+    ///    let client = DockerClient::new();
+    ///
+    ///     match client.inspect_image("alpine").await {
+    ///         Ok(info) => { println!("{:?}", info); },
+    ///         Err(e) => { println!("Error: {:?}", e); },
+    ///     }
+    ///
+    /// }
+    /// ```
+    pub async fn inspect_image<T>(&self, name: T) -> Result<ImageInfo, DockerError>
+        where T: Into<String>
+    {
+        self.inspect_image_raw(name).await.map(Raw::into_value)
+    }
+
+    /// Inspect an image, keeping the untouched body
+    ///
+    /// Return `Raw` with the decoded struct and the body or DockerError.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use docker_client::{DockerClient, DockerError};
+    /// # #[tokio::main]
+    /// async fn main() {
+    ///    let client = DockerClient::new();
+    ///
+    ///     match client.inspect_image_raw("alpine").await {
+    ///         Ok(image) => { println!("{:?} {:?}", image.id(), image.json().map(|raw| raw["Descriptor"].clone())); },
+    ///         Err(e) => { println!("Error: {:?}", e); },
+    ///     }
+    ///
+    /// }
+    /// ```
+    pub async fn inspect_image_raw<T>(&self, name: T) -> Result<Raw<ImageInfo>, DockerError>
+        where T: Into<String>
+    {
+        let uri = self.make_uri(format!("/images/{}/json", name.into()));
+        let request = Request::get(uri)
+            .body(hyper::Body::empty())
+            .unwrap();
+
+        self.execute_async(request).await
+            .and_then(|response| {
+                match response.status {
+                    200 => self.decode_raw(&response),
+                    404 => Err(DockerError::NotFound(response.error_message())),
+                    500 => Err(DockerError::ServerError(response.error_message())),
+                    _ => Err(response.unknown_status()),
+                }
+            })
+    }
+
+    /// Get the history of an image
+    ///
+    /// Return parent layers of an image.
+    ///
+    /// # Arguments
+    /// * `name` - Image name or ID.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use docker_client::{DockerClient, DockerError};
+    /// # #[tokio::main]
+    /// async fn main() {
+    ///    let client = DockerClient::new();
+    ///
+    ///     match client.image_history("alpine").await {
+    ///         Ok(history) => { println!("{:?}", history); },
+    ///         Err(e) => { println!("Error: {:?}", e); },
+    ///     }
+    ///
+    /// }
+    /// ```
+    pub async fn image_history<T>(&self, name: T) -> Result<Vec<ImageHistory>, DockerError>
+        where T: Into<String>
+    {
+        let uri = self.make_uri(format!("/images/{}/history", name.into()));
+        let request = Request::get(uri)
+            .body(hyper::Body::empty())
+            .unwrap();
+
+        self.execute_async(request).await
+            .and_then(|response| {
+                match response.status {
+                    200 => self.decode(&response),
+                    404 => Err(DockerError::NotFound(response.error_message())),
+                    500 => Err(DockerError::ServerError(response.error_message())),
+                    _ => Err(response.unknown_status()),
+                }
+            })
+    }
+
+    /// Export an image
+    ///
+    /// Return tar archive of the image with its layers and metadata or DockerError.
+    /// The body is returned as received, without copies.
+    ///
+    /// # Arguments
+    /// * `name` - Image name or ID.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use docker_client::{DockerClient, DockerError};
+    /// # #[tokio::main]
+    /// async fn main() {
+    ///    let client = DockerClient::new();
+    ///
+    ///     match client.save_image("alpine").await {
+    ///         Ok(archive) => { std::fs::write("alpine.tar", &archive).unwrap(); },
+    ///         Err(e) => { println!("Error: {:?}", e); },
+    ///     }
+    ///
+    /// }
+    /// ```
+    pub async fn save_image<T>(&self, name: T) -> Result<Bytes, DockerError>
+        where T: Into<String>
+    {
+        let uri = self.make_uri(format!("/images/{}/get", name.into()));
+        let request = Request::get(uri)
+            .body(hyper::Body::empty())
+            .unwrap();
+
+        self.execute_async(request).await
+            .and_then(|response| {
+                match response.status {
+                    200 => Ok(response.into_body()),
+                    500 => Err(DockerError::ServerError(response.error_message())),
+                    _ => Err(response.unknown_status()),
+                }
+            })
+    }
+
+    /// Stream the export of an image
+    ///
+    /// Like `save_image`, but the tar archive is returned as chunks are received,
+    /// so large images are never held in memory.
+    ///
+    /// # Arguments
+    /// * `name` - Image name or ID.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use docker_client::{DockerClient, DockerError};
+    /// use futures::StreamExt;
+    ///
+    /// # #[tokio::main]
+    /// async fn main() {
+    ///    let client = DockerClient::new();
+    ///
+    ///     match client.save_image_stream("alpine").await {
+    ///         Ok(mut archive) => {
+    ///             while let Some(Ok(chunk)) = archive.next().await {
+    ///                 println!("{} bytes", chunk.len());
+    ///             }
+    ///         },
+    ///         Err(e) => { println!("Error: {:?}", e); },
+    ///     }
+    ///
+    /// }
+    /// ```
+    pub async fn save_image_stream<T>(&self, name: T) -> Result<impl Stream<Item = Result<Bytes, DockerError>> + Unpin + Send, DockerError>
+        where T: Into<String>
+    {
+        let uri = self.make_uri(format!("/images/{}/get", name.into()));
+        let request = Request::get(uri)
+            .body(hyper::Body::empty())
+            .unwrap();
+
+        self.execute_stream(request, |response| {
+            match response.status {
+                500 => DockerError::ServerError(response.error_message()),
+                _ => response.unknown_status(),
+            }
+        }).await
+    }
+
+    /// Tag an image
+    ///
+    /// Create a tag that refers to a source image.
+    ///
+    /// # Arguments
+    /// * `name` - Image name or ID to tag.
+    /// * `repo` - The repository to tag in.
+    /// * `tag` - The name of the new tag.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use docker_client::{DockerClient, DockerError};
+    /// # #[tokio::main]
+    /// async fn main() {
+    ///    let client = DockerClient::new();
+    ///
+    ///     match client.tag_image("alpine", "localhost:5000/alpine", "latest").await {
+    ///         Ok(_) => {},
+    ///         Err(e) => { println!("Error: {:?}", e); },
+    ///     }
+    ///
+    /// }
+    /// ```
+    pub async fn tag_image<T, U, V>(&self, name: T, repo: U, tag: V) -> Result<(), DockerError>
+        where
+            T: Into<String>,
+            U: Into<String>,
+            V: Into<String>
+    {
+        let uri = self.make_uri(format!("/images/{}/tag?repo={}&tag={}", name.into(), repo.into(), tag.into()));
+        let request = Request::post(uri)
+            .body(hyper::Body::empty())
+            .unwrap();
+
+        self.execute_async(request).await
+            .and_then(|response| {
+                match response.status {
+                    201 => Ok(()),
+                    400 => Err(DockerError::BadParameters(response.error_message())),
+                    404 => Err(DockerError::NotFound(response.error_message())),
+                    409 => Err(DockerError::Busy(response.error_message())),
+                    500 => Err(DockerError::ServerError(response.error_message())),
+                    _ => Err(response.unknown_status()),
+                }
+            })
+    }
+
+    /// Build an image
+    ///
+    /// Build an image from a tar archive with a Dockerfile. The archive may be
+    /// compressed with gzip, bzip2 or xz.
+    ///
+    /// Return BuiltImage with id and output of the build or DockerError.
+    /// Errors reported in the build output are returned as `DockerError::ServerError`.
+    ///
+    /// # Arguments
+    /// * `options` - Build options.
+    /// * `context` - Build context as tar archive.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use docker_client::{DockerClient, DockerError};
+    /// # use docker_client::image::build::BuildOptions;
+    /// # #[tokio::main]
+    /// async fn main() {
+    ///    let client = DockerClient::new();
+    ///
+    ///     let context = std::fs::read("context.tar").unwrap_or_default();
+    ///     let options = BuildOptions::builder().tag("app:latest").build();
+    ///
+    ///     match client.build_image(options, context).await {
+    ///         Ok(image) => { println!("Built {}", image.id()); },
+    ///         Err(e) => { println!("Error: {:?}", e); },
+    ///     }
+    ///
+    /// }
+    /// ```
+    pub async fn build_image<T>(&self, options: BuildOptions, context: T) -> Result<BuiltImage, DockerError>
+        where T: Into<hyper::Body>
+    {
+        let uri = self.make_uri(options.get_path());
+        let request = Request::post(uri)
+            .header(hyper::header::CONTENT_TYPE, "application/x-tar")
+            .body(context.into())
+            .unwrap();
+
+        self.execute_buffered(request).await
+            .and_then(|response| {
+                match response.status {
+                    200 => {
+                        let records: Vec<Progress> = parse_lines(response.body())?;
+
+                        if let Some(error) = records.iter().find_map(Progress::error) {
+                            return Err(DockerError::ServerError(ErrorMessage { message: error.to_string() }));
+                        }
+
+                        let output = records.iter().filter_map(Progress::stream).collect::<String>();
+                        let id = records.iter()
+                            .filter_map(Progress::aux)
+                            .find_map(|aux| aux.get("ID").and_then(|id| id.as_str()).map(str::to_string))
+                            .or_else(|| {
+                                output.lines()
+                                    .find_map(|line| line.strip_prefix("Successfully built "))
+                                    .map(|id| id.trim().to_string())
+                            });
+
+                        match id {
+                            Some(id) => Ok(BuiltImage::new(ImageId::from(id), output)),
+                            None => Err(DockerError::ServerError(ErrorMessage {
+                                message: "build finished without an image id".to_string()
+                            })),
+                        }
+                    },
+                    400 => Err(DockerError::BadParameters(response.error_message())),
+                    500 => Err(DockerError::ServerError(response.error_message())),
+                    _ => Err(response.unknown_status()),
+                }
+            })
+    }
+
+    /// Build an image from a remote context
+    ///
+    /// Build an image from a git repository or a URL of a tar archive or Dockerfile,
+    /// without uploading a local context.
+    ///
+    /// # Arguments
+    /// * `remote` - Git URL, e.g. `https://github.com/user/repo.git#branch:dir`, or archive URL.
+    /// * `options` - Build options.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use docker_client::{DockerClient, DockerError};
+    /// # use docker_client::image::build::BuildOptions;
+    /// # #[tokio::main]
+    /// async fn main() {
+    ///    let client = DockerClient::new();
+    ///
+    ///     let options = BuildOptions::builder().tag("hello:latest").build();
+    ///     let remote = "https://github.com/docker-library/hello-world.git#master:amd64/hello-world";
+    ///
+    ///     match client.build_image_from_remote(remote, options).await {
+    ///         Ok(image) => { println!("Built {}", image.id()); },
+    ///         Err(e) => { println!("Error: {:?}", e); },
+    ///     }
+    ///
+    /// }
+    /// ```
+    pub async fn build_image_from_remote<T>(&self, remote: T, options: BuildOptions) -> Result<BuiltImage, DockerError>
+        where T: Into<String>
+    {
+        self.build_image(options.with_remote(remote.into()), hyper::Body::empty()).await
+    }
+
+    /// Get distribution info of an image from its registry
+    ///
+    /// Return manifest descriptor and available platforms of the reference or DockerError.
+    /// Credentials of the client, or of the registry in the CLI configuration,
+    /// are sent in `X-Registry-Auth` header.
+    ///
+    /// # Arguments
+    /// * `name` - Image reference, e.g. `alpine:3.13`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use docker_client::{DockerClient, DockerError};
+    /// # #[tokio::main]
+    /// async fn main() {
+    ///    let client = DockerClient::new();
+    ///
+    ///     match client.inspect_distribution("alpine:latest").await {
+    ///         Ok(info) => {
+    ///             for platform in info.platforms() {
+    ///                 println!("{}", platform);
+    ///             }
+    ///             println!("arm64: {:?}", info.digest_for("linux/arm64"));
+    ///         },
+    ///         Err(e) => { println!("Error: {:?}", e); },
+    ///     }
+    ///
+    /// }
+    /// ```
+    pub async fn inspect_distribution<T>(&self, name: T) -> Result<DistributionInfo, DockerError>
+        where T: Into<String>
+    {
+        let name = name.into();
+        let uri = self.make_uri(format!("/distribution/{}/json", name));
+        let mut request_builder = Request::get(uri);
+
+        if let Some(auth) = self.image_auth_header(name.as_str(), None).await? {
+            request_builder = request_builder.header("X-Registry-Auth", auth);
+        }
+
+        let request = request_builder.body(hyper::Body::empty()).unwrap();
+
+        self.execute_async(request).await
+            .and_then(|response| {
+                match response.status {
+                    200 => self.decode(&response),
+                    401 => Err(DockerError::Unauthorized(response.error_message())),
+                    404 => Err(DockerError::NotFound(response.error_message())),
+                    500 => Err(DockerError::ServerError(response.error_message())),
+                    _ => Err(response.unknown_status()),
+                }
+            })
+    }
+
+    /// Resolve digest to pin an image reference for a platform
+    ///
+    /// Return `name@digest` if `platform` (`os/arch[/variant]`) is available, `None` otherwise.
+    ///
+    /// # Arguments
+    /// * `name` - Image reference, e.g. `alpine:3.13`.
+    /// * `platform` - Platform, e.g. `linux/arm64` or `linux/arm/v7`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use docker_client::{DockerClient, DockerError};
+    /// # #[tokio::main]
+    /// async fn main() {
+    ///    let client = DockerClient::new();
+    ///
+    ///     match client.resolve_platform("alpine:latest", "linux/arm/v7").await {
+    ///         Ok(Some(pinned)) => { println!("{}", pinned); },
+    ///         Ok(None) => { println!("Platform not available"); },
+    ///         Err(e) => { println!("Error: {:?}", e); },
+    ///     }
+    ///
+    /// }
+    /// ```
+    pub async fn resolve_platform<T, U>(&self, name: T, platform: U) -> Result<Option<String>, DockerError>
+        where
+            T: Into<String>,
+            U: Into<String>
+    {
+        let name = name.into();
+        let info = self.inspect_distribution(name.as_str()).await?;
+        let (image, _) = crate::image::reference::split_reference(name.as_str());
+
+        Ok(info.digest_for(platform.into().as_str()).map(|digest| format!("{}@{}", image, digest)))
+    }
+
+    /// Push an image
+    ///
+    /// Push an image to a registry. Credentials of the client, or of the registry
+    /// in the CLI configuration, are sent in `X-Registry-Auth` header.
+    ///
+    /// Return PushedImage with the digest of the pushed manifest or DockerError.
+    /// Errors reported in the progress stream are returned as `DockerError::ServerError`.
+    ///
+    /// # Arguments
+    /// * `name` - Image reference, e.g. `registry:5000/alpine:latest`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use docker_client::{DockerClient, DockerError};
+    /// # #[tokio::main]
+    /// async fn main() {
+    ///    let client = DockerClient::new();
+    ///
+    ///     match client.push_image("localhost:5000/alpine:latest").await {
+    ///         Ok(pushed) => { println!("Digest: {}", pushed.digest()); },
+    ///         Err(e) => { println!("Error: {:?}", e); },
+    ///     }
+    ///
+    /// }
+    /// ```
+    pub async fn push_image<T>(&self, name: T) -> Result<PushedImage, DockerError>
+        where T: Into<String>
+    {
+        self.push_image_as(name.into(), None).await
+    }
+
+    /// Push an image with its own credentials
+    ///
+    /// Like `push_image` with credentials `auth` instead of those of the client, so
+    /// one client can push to several registries.
+    ///
+    /// # Arguments
+    /// * `name` - Image reference, e.g. `registry:5000/alpine:latest`.
+    /// * `auth` - Credentials of the registry.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use docker_client::{DockerClient, DockerError};
+    /// # use docker_client::client::Auth;
+    /// # #[tokio::main]
+    /// async fn main() {
+    ///    let client = DockerClient::new();
+    ///
+    ///     let auth = Auth::builder().username("ci").password("s3cret").server_address("registry.example.com").build();
+    ///
+    ///     match client.push_image_with_auth("registry.example.com/api:1.2", auth).await {
+    ///         Ok(pushed) => { println!("Digest: {}", pushed.digest()); },
+    ///         Err(e) => { println!("Error: {:?}", e); },
+    ///     }
+    ///
+    /// }
+    /// ```
+    pub async fn push_image_with_auth<T>(&self, name: T, auth: Auth) -> Result<PushedImage, DockerError>
+        where T: Into<String>
+    {
+        self.push_image_as(name.into(), Some(&auth)).await
+    }
+
+    /// Push an image with credentials `auth` if set.
+    async fn push_image_as(&self, name: String, auth: Option<&Auth>) -> Result<PushedImage, DockerError> {
+        let (image, tag) = crate::image::reference::split_reference(name.as_str());
+
+        let path = match tag {
+            Some(tag) => format!("/images/{}/push?tag={}", image, tag),
+            None => format!("/images/{}/push", image),
+        };
+
+        let auth = self.image_auth_header(name.as_str(), auth).await?
+            .unwrap_or_else(|| self.registry_auth_header());
+
+        let uri = self.make_uri(path);
+        let request = Request::post(uri)
+            .header("X-Registry-Auth", auth)
+            .body(hyper::Body::empty())
+            .unwrap();
+
+        self.execute_buffered(request).await
+            .and_then(|response| {
+                match response.status {
+                    200 => {
+                        let records: Vec<Progress> = parse_lines(response.body())?;
+
+                        if let Some(error) = records.iter().find_map(Progress::error) {
+                            return Err(DockerError::ServerError(ErrorMessage { message: error.to_string() }));
+                        }
+
+                        records.iter()
+                            .filter_map(Progress::aux)
+                            .find_map(|aux| json::from_value::<PushedImage>(aux.clone()).ok())
+                            .ok_or_else(|| DockerError::ServerError(ErrorMessage {
+                                message: "push finished without a digest".to_string()
+                            }))
+                    },
+                    404 => Err(DockerError::NotFound(response.error_message())),
+                    500 => Err(DockerError::ServerError(response.error_message())),
+                    _ => Err(response.unknown_status()),
+                }
+            })
+    }
+
+    /// Remove an image
+    ///
+    /// Remove an image, along with any untagged parent images that were referenced by that image.
+    ///
+    /// # Arguments
+    /// * `name` - Image name or ID.
+    /// * `force` - Remove the image even if it is being used by stopped containers or has other tags.
+    /// * `no_prune` - Do not delete untagged parent images.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use docker_client::{DockerClient, DockerError};
+    /// # #[tokio::main]
+    /// async fn main() {
+    ///    let client = DockerClient::new();
+    ///
+    ///     match client.remove_image("alpine", false, false).await {
+    ///         Ok(removed) => { println!("{:?}", removed); },
+    ///         Err(e) => { println!("Error: {:?}", e); },
+    ///     }
+    ///
+    /// }
+    /// ```
+    pub async fn remove_image<T>(&self, name: T, force: bool, no_prune: bool) -> Result<Vec<RemovedImage>, DockerError>
+        where T: Into<String>
+    {
+        let uri = self.make_uri(format!("/images/{}?force={}&noprune={}", name.into(), force, no_prune));
+        let request = Request::delete(uri)
+            .body(hyper::Body::empty())
+            .unwrap();
+
+        self.execute_async(request).await
+            .and_then(|response| {
+                match response.status {
+                    200 => self.decode(&response),
+                    404 => Err(DockerError::NotFound(response.error_message())),
+                    409 => Err(DockerError::Busy(response.error_message())),
+                    500 => Err(DockerError::ServerError(response.error_message())),
+                    _ => Err(response.unknown_status()),
+                }
+            })
+    }
+
+    /// Delete unused images
+    ///
+    /// Without a `dangling` filter only dangling images are deleted.
+    ///
+    /// Return PrunedImages or DockerError
+    ///
+    /// # Arguments
+    /// * `filters` - Filters, e.g. `dangling`, `label` or `until`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use docker_client::{DockerClient, DockerError};
+    /// # use docker_client::additionals::filters::Filters;
+    /// # #[tokio::main]
+    /// async fn main() {
+    ///    let client = DockerClient::new();
+    ///
+    ///     let filters = Filters::new().dangling(true).until("24h").build();
+    ///
+    ///     match client.prune_images(filters).await {
+    ///         Ok(pruned) => { println!("{} bytes", pruned.space_reclaimed()); },
+    ///         Err(e) => { println!("Error: {:?}", e); },
+    ///     }
+    ///
+    /// }
+    /// ```
+    pub async fn prune_images(&self, filters: Filters) -> Result<PrunedImages, DockerError> {
+
+        let uri = self.make_uri(match filters.query() {
+            Some(query) => format!("/images/prune?{}", query),
+            None => "/images/prune".to_string(),
+        });
+        let request = Request::post(uri)
+            .body(hyper::Body::empty())
+            .unwrap();
+
+        self.execute_async(request).await
+            .and_then(|response| {
+                match response.status {
+                    200 => self.decode(&response),
+                    500 => Err(DockerError::ServerError(response.error_message())),
+                    _ => Err(response.unknown_status()),
+                }
+            })
+    }
+
+    /// Create a network
+    ///
+    /// Return id of the new network and a warning reported by the daemon, if any, or DockerError.
+    ///
+    /// # Arguments
+    /// * `request` - Network create request.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use docker_client::{DockerClient, DockerError};
+    /// # use docker_client::networks::create::RequestBuilder;
+    /// # #[tokio::main]
+    /// async fn main() {
+    ///    let client = DockerClient::new();
+    ///
+    ///     let request = RequestBuilder::with_name("test-network").build();
+    ///
+    ///     match client.create_network(request).await {
+    ///         Ok(created) => {
+    ///             println!("{}", created.id());
+    ///             if let Some(warning) = created.warning() {
+    ///                 println!("Warning: {}", warning);
+    ///             }
+    ///         },
+    ///         Err(e) => { println!("Error: {:?}", e); },
+    ///     }
+    ///
+    /// }
+    /// ```
+    pub async fn create_network(&self, request: crate::networks::create::Request) -> Result<crate::networks::create::CreatedNetwork, DockerError> {
+        let uri = self.make_uri(request.get_path());
+        let req = Request::post(uri)
+            .header(hyper::header::CONTENT_TYPE, "application/json")
+            .body(hyper::Body::from(json::to_string(&request).unwrap()))
+            .unwrap();
+
+        self.execute_async(req).await
+            .and_then(|response| {
+                match response.status {
+                    201 => self.decode(&response),
+                    404 => Err(DockerError::NotFound(response.error_message())),
+                    409 => Err(DockerError::NetworkExists(response.error_message())),
+                    500 => Err(DockerError::ServerError(response.error_message())),
+                    _ => Err(response.unknown_status()),
+                }
+            })
+    }
+
+    /// Return a network by name, creating it if missing
+    ///
+    /// A network created concurrently between the lookup and the create request is
+    /// looked up again instead of failing with `DockerError::NetworkExists`.
+    ///
+    /// Return ID of the existing or new network or DockerError.
+    ///
+    /// # Arguments
+    /// * `request` - Network create request, its name is looked up.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use docker_client::{DockerClient, DockerError};
+    /// # use docker_client::networks::create::RequestBuilder;
+    /// # #[tokio::main]
+    /// async fn main() {
+    ///    let client = DockerClient::new();
+    ///
+    ///     let request = RequestBuilder::with_name("backend").build();
+    ///
+    ///     match client.get_or_create_network(request).await {
+    ///         Ok(id) => { println!("{}", id); },
+    ///         Err(e) => { println!("Error: {:?}", e); },
+    ///     }
+    ///
+    /// }
+    /// ```
+    pub async fn get_or_create_network(&self, request: crate::networks::create::Request) -> Result<String, DockerError> {
+        let lookup = crate::networks::inspect::RequestBuilder::with_name(request.name()).build();
+
+        match self.inspect_network(lookup.clone()).await {
+            Ok(info) => return Ok(info.id().to_string()),
+            Err(DockerError::NotFound(_)) => {},
+            Err(e) => return Err(e),
+        }
+
+        match self.create_network(request).await {
+            Ok(created) => Ok(created.id().to_string()),
+            Err(DockerError::NetworkExists(_)) => self.inspect_network(lookup).await.map(|info| info.id().to_string()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub async fn inspect_network(&self, request: crate::networks::inspect::Request) -> Result<crate::networks::inspect::NetworkInfo, DockerError> {
+        self.inspect_network_raw(request).await.map(Raw::into_value)
+    }
+
+    /// Inspect a network, keeping the untouched body
+    ///
+    /// Return `Raw` with the decoded struct and the body or DockerError.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use docker_client::{DockerClient, DockerError};
+    /// # use docker_client::networks::inspect::RequestBuilder;
+    /// # #[tokio::main]
+    /// async fn main() {
+    ///    let client = DockerClient::new();
+    ///
+    ///     match client.inspect_network_raw(RequestBuilder::with_name("bridge").build()).await {
+    ///         Ok(network) => { println!("{} {:?}", network.id(), network.json().map(|raw| raw["ConfigFrom"].clone())); },
+    ///         Err(e) => { println!("Error: {:?}", e); },
+    ///     }
+    ///
+    /// }
+    /// ```
+    pub async fn inspect_network_raw(&self, request: crate::networks::inspect::Request) -> Result<Raw<crate::networks::inspect::NetworkInfo>, DockerError> {
+        let uri = self.make_uri(request.get_path());
+        let req = Request::get(uri)
+            .body(hyper::Body::empty())
+            .unwrap();
+
+        self.execute_async(req).await
+            .and_then(|response| {
+                match response.status {
+                    200 => self.decode_raw(&response),
+                    404 => Err(DockerError::NotFound(response.error_message())),
+                    500 => Err(DockerError::ServerError(response.error_message())),
+                    _ => Err(response.unknown_status()),
+                }
+            })
+    }
+
+    /// Get networks list matching filters
+    ///
+    /// Return vector of NetworkInfo or DockerError
+    ///
+    /// # Arguments
+    /// * `filters` - Filters, e.g. `driver`, `id`, `label`, `name`, `scope`, `type`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use docker_client::{DockerClient, DockerError};
+    /// # use docker_client::additionals::filters::Filters;
+    /// # #[tokio::main]
+    /// async fn main() {
+    ///    let client = DockerClient::new();
+    ///
+    ///     let filters = Filters::new().filter("driver", "bridge").build();
+    ///
+    ///     match client.networks_list(filters).await {
+    ///         Ok(list) => { println!("{:?}", list); },
+    ///         Err(e) => { println!("Error: {:?}", e); },
+    ///     }
+    ///
+    /// }
+    /// ```
+    pub async fn networks_list(&self, filters: Filters) -> Result<Vec<crate::networks::inspect::NetworkInfo>, DockerError> {
+        let uri = self.make_uri(match filters.query() {
+            Some(query) => format!("/networks?{}", query),
+            None => "/networks".to_string(),
+        });
+        let req = Request::get(uri)
+            .body(hyper::Body::empty())
+            .unwrap();
+
+        self.execute_async(req).await
+            .and_then(|response| {
+                match response.status {
+                    200 => self.decode(&response),
+                    500 => Err(DockerError::ServerError(response.error_message())),
+                    _ => Err(response.unknown_status()),
+                }
+            })
+    }
+
+    /// Remove a network
+    ///
+    /// # Arguments
+    /// * `id` - Network ID or name.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use docker_client::{DockerClient, DockerError};
+    /// # #[tokio::main]
+    /// async fn main() {
+    ///    let client = DockerClient::new();
+    ///
+    ///     match client.remove_network("test-network").await {
+    ///         Ok(_) => {},
+    ///         Err(e) => { println!("Error: {:?}", e); },
+    ///     }
+    ///
+    /// }
+    /// ```
+    pub async fn remove_network<T>(&self, id: T) -> Result<(), DockerError>
+        where T: Into<String>
+    {
+        let uri = self.make_uri(format!("/networks/{}", id.into()));
+        let req = Request::delete(uri)
+            .body(hyper::Body::empty())
+            .unwrap();
+
+        self.execute_async(req).await
+            .and_then(|response| {
+                match response.status {
+                    204 => Ok(()),
+                    403 => Err(DockerError::Forbidden(response.error_message())),
+                    404 => Err(DockerError::NotFound(response.error_message())),
+                    500 => Err(DockerError::ServerError(response.error_message())),
+                    _ => Err(response.unknown_status()),
+                }
+            })
+    }
+
+    /// Return handle to a network
+    ///
+    /// # Arguments
+    /// * `name` - Network ID or name.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use docker_client::{DockerClient, DockerError};
+    /// # #[tokio::main]
+    /// async fn main() {
+    ///    let client = DockerClient::new();
+    ///
+    ///     let network = client.network("test-network");
+    ///     match network.create().await {
+    ///         Ok(_) => { let _ = network.connect("test-container").await; },
+    ///         Err(e) => { println!("Error: {:?}", e); },
+    ///     }
+    ///
+    /// }
+    /// ```
+    pub fn network<T>(&self, name: T) -> NetworkRef<'_>
+        where T: Into<String>
+    {
+        NetworkRef::new(self, name.into())
+    }
+
+    pub async fn connect_container_to_network(&self, request: crate::networks::connect::Request) -> Result<(), DockerError> {
+        let uri = self.make_uri(request.get_path());
+        let req = Request::post(uri)
+            .header(hyper::header::CONTENT_TYPE, "application/json")
+            .body(hyper::Body::from(json::to_string(&request).unwrap()))
+            .unwrap();
+
+        self.execute_async(req).await
+            .and_then(|response| {
+                match response.status {
+                    200 => Ok(()),
+                    404 => Err(DockerError::NotFound(response.error_message())),
+                    500 => Err(DockerError::ServerError(response.error_message())),
+                    _ => Err(response.unknown_status()),
+                }
+            })
+    }
+
+    pub async fn create_exec_instance(&self, request: crate::exec::create::Request) -> Result<String, DockerError> {
+        let uri = self.make_uri(request.get_path());
+        let req = Request::post(uri)
+            .header(hyper::header::CONTENT_TYPE, "application/json")
+            .body(hyper::Body::from(json::to_string(&request).unwrap()))
+            .unwrap();
+
+        self.execute_async(req).await
+            .and_then(|response| {
+                match response.status {
+                    201 => self.decode::<crate::exec::create::Exec>(&response).map(|exec| exec.id),
+                    404 => Err(DockerError::NotFound(response.error_message())),
+                    409 => Err(DockerError::ContainerPaused(response.error_message())),
+                    500 => Err(DockerError::ServerError(response.error_message())),
+                    _ => Err(response.unknown_status()),
+                }
+            })
+    }
+
+    pub async fn start_exec(&self, id: String) -> Result<(), DockerError> {
+        let uri = self.make_uri(format!("/exec/{}/start", &id));
+        let req = Request::post(uri)
+            .header(hyper::header::CONTENT_TYPE, "application/json")
+            .body(hyper::body::Body::from("{}"))
+            .unwrap();
+
+        self.execute_buffered(req).await
+            .and_then(|response| {
+                match response.status {
+                    200 => Ok(()),
+                    400 => Err(DockerError::BadParameters(response.error_message())),
+                    404 => Err(DockerError::NotFound(response.error_message())),
+                    409 => Err(DockerError::ContainerPaused(response.error_message())),
+                    500 => Err(DockerError::ServerError(response.error_message())),
+                    _ => Err(response.unknown_status()),
+                }
+            })
+    }
+
+    /// Resize the TTY of an exec instance
+    ///
+    /// # Arguments
+    /// * `id` - ID of the exec instance.
+    /// * `rows` - Height of the TTY in characters.
+    /// * `columns` - Width of the TTY in characters.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use docker_client::{DockerClient, DockerError};
+    /// # #[tokio::main]
+    /// async fn main() {
+    ///    let client = DockerClient::new();
+    ///
+    ///     match client.resize_exec("b3c9f3b1c1e0", 40, 120).await {
+    ///         Ok(_) => {},
+    ///         Err(e) => { println!("Error: {:?}", e); },
+    ///     }
+    ///
+    /// }
+    /// ```
+    pub async fn resize_exec<T>(&self, id: T, rows: u16, columns: u16) -> Result<(), DockerError>
+        where T: Into<String>
+    {
+        let uri = self.make_uri(format!("/exec/{}/resize?h={}&w={}", id.into(), rows, columns));
+        let req = Request::post(uri)
+            .body(hyper::Body::empty())
+            .unwrap();
+
+        self.execute_async(req).await
+            .and_then(|response| {
+                match response.status {
+                    200 | 201 => Ok(()),
+                    400 => Err(DockerError::BadParameters(response.error_message())),
+                    404 => Err(DockerError::NotFound(response.error_message())),
+                    500 => Err(DockerError::ServerError(response.error_message())),
+                    _ => Err(response.unknown_status()),
+                }
+            })
+    }
 
-        let uri = self.make_uri(format!("/containers/{}/changes", id.into()));
-        let request = Request::get(uri)
+    pub async fn inspect_exec(&self, id: String) -> Result<crate::exec::inspect::ExecStatus, DockerError> {
+        let uri = self.make_uri(format!("/exec/{}/json", &id));
+        let req = Request::get(uri)
             .body(hyper::Body::empty())
             .unwrap();
 
-        self.execute_async(request).await
+        self.execute_async(req).await
             .and_then(|response| {
                 match response.status {
-                    200 => {
-                        let obj: Option<Vec<FSChanges>> = json::from_str(response.body_as_string().as_str()).unwrap();
-                        Ok(obj.unwrap_or(Vec::new()))
-                    },
-                    404 => Err(DockerError::NotFound(json::from_str(response.body_as_string().as_str()).unwrap())),
-                    500 => Err(DockerError::ServerError(json::from_str(response.body_as_string().as_str()).unwrap())),
-                    _ => Err(DockerError::UnknownStatus),
+                    200 => self.decode(&response),
+                    404 => Err(DockerError::NotFound(response.error_message())),
+                    500 => Err(DockerError::ServerError(response.error_message())),
+                    _ => Err(response.unknown_status()),
                 }
             })
-            .map_err(|e| e)
     }
 
-    /// Start a container.
+    /// Run a shell command in a container
+    ///
+    /// The command is wrapped in `/bin/sh -c`, executed attached and
+    /// its output is captured together with the exit code.
     ///
     /// # Arguments
     /// * `id` - ID or name of the container.
-    /// * `detach_keys` - The key sequence for detaching a container.
+    /// * `command` - Shell command line.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use docker_client::{DockerClient, DockerError};
-    ///
+    /// # use docker_client::{DockerClient, DockerError};
     /// # #[tokio::main]
     /// async fn main() {
     ///    let client = DockerClient::new();
     ///
-    ///     match client.start_container("test", "-d").await {
-    ///         Ok(_) => {},
-    ///         Err(e) => {
-    ///             match e {
-    ///                 DockerError::NotFound(e) => println!("{}", e.message),
-    ///                 DockerError::ServerError(e) => println!("{}", e.message),
-    ///                 _ => {}
-    ///             }
-    ///         },
+    ///     match client.exec_shell("test", "echo $HOME && ls").await {
+    ///         Ok(output) => { println!("{} exited with {}", output.stdout(), output.exit_code()); },
+    ///         Err(e) => { println!("Error: {:?}", e); },
     ///     }
     ///
     /// }
     /// ```
-    pub async fn start_container<T, U>(&self, id: T, _detach_keys: U) -> Result<(), DockerError>
+    pub async fn exec_shell<T, U>(&self, id: T, command: U) -> Result<ExecOutput, DockerError>
         where
-            T: Into<String>,
+            T: Into<ContainerRefId>,
             U: Into<String>
     {
+        let request = crate::exec::create::Request::with_container(id.into())
+            .add_commands(vec!["/bin/sh".to_string(), "-c".to_string(), command.into()])
+            .attach_stdout(true)
+            .attach_stderr(true)
+            .build();
 
-        let uri = self.make_uri(format!("/containers/{}/start", id.into()));
-        let request = Request::post(uri)
-            .body(hyper::Body::empty())
+        let exec_id = self.create_exec_instance(request).await?;
+
+        let uri = self.make_uri(format!("/exec/{}/start", &exec_id));
+        let req = Request::post(uri)
+            .header(hyper::header::CONTENT_TYPE, "application/json")
+            .body(hyper::body::Body::from(r#"{"Detach":false,"Tty":false}"#))
             .unwrap();
 
-        self.execute_async(request).await
+        let (stdout, stderr) = self.execute_buffered(req).await
             .and_then(|response| {
                 match response.status {
-                    204 => Ok(()),
-                    304 => Ok(()),
-                    404 => Err(DockerError::NotFound(json::from_str(response.body_as_string().as_str()).unwrap())),
-                    500 => Err(DockerError::ServerError(json::from_str(response.body_as_string().as_str()).unwrap())),
-                    _ => Err(DockerError::UnknownStatus),
+                    200 => Ok(response.demultiplex()),
+                    404 => Err(DockerError::NotFound(response.error_message())),
+                    409 => Err(DockerError::ContainerPaused(response.error_message())),
+                    500 => Err(DockerError::ServerError(response.error_message())),
+                    _ => Err(response.unknown_status()),
                 }
-            })
-            .map_err(|e| e)
+            })?;
+
+        let status = self.inspect_exec(exec_id).await?;
 
+        Ok(ExecOutput::new(
+            String::from_utf8_lossy(&stdout).into_owned(),
+            String::from_utf8_lossy(&stderr).into_owned(),
+            status.exit_code
+        ))
     }
 
-    /// Stop a container.
+
+    /// Subscribe to daemon events
+    ///
+    /// Return stream of Event or DockerError. The stream ends when the connection is closed,
+    /// or once `until` of the request is reached.
+    /// Dropping the stream aborts the request and closes the connection to the daemon.
     ///
     /// # Arguments
-    /// * `id` - ID or name of the container.
-    /// * `wait` - Number of seconds to wait before killing the container.
+    /// * `request` - EventsRequest, or only Filters, see `EventsFilter`.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use docker_client::{DockerClient, DockerError};
+    /// # use docker_client::{DockerClient, DockerError};
+    /// # use docker_client::events::{EventsFilter, EventsRequest};
+    /// use std::time::{Duration, SystemTime};
+    /// use futures::StreamExt;
     ///
     /// # #[tokio::main]
     /// async fn main() {
     ///    let client = DockerClient::new();
     ///
-    ///     match client.stop_container("test", Some(12)).await {
-    ///         Ok(_) => {},
-    ///         Err(e) => {
-    ///             match e {
-    ///                 DockerError::NotFound(e) => println!("{}", e.message),
-    ///                 DockerError::ServerError(e) => println!("{}", e.message),
-    ///                 _ => {}
+    ///     let filters = EventsFilter::new()
+    ///         .event_type("container")
+    ///         .event("die")
+    ///         .label("com.example.app", Some("web"))
+    ///         .build();
+    ///
+    ///     let request = EventsRequest::new()
+    ///         .since(SystemTime::now() - Duration::from_secs(3600))
+    ///         .until(SystemTime::now())
+    ///         .filters(filters);
+    ///
+    ///     match client.events(request).await {
+    ///         Ok(mut events) => {
+    ///             while let Some(Ok(event)) = events.next().await {
+    ///                 println!("{} died", event.actor().id());
     ///             }
     ///         },
+    ///         Err(e) => { println!("Error: {:?}", e); },
     ///     }
     ///
     /// }
     /// ```
-    pub async fn stop_container<T>(&self, id: T, _wait: Option<i32>) -> Result<(), DockerError>
-        where T: Into<String>
+    pub async fn events<T>(&self, request: T) -> Result<impl Stream<Item = Result<Event, DockerError>> + Unpin, DockerError>
+        where T: Into<EventsRequest>
     {
-        let path = format!("/containers/{}/stop", id.into());
-
-        let uri = self.make_uri(path);
-        let request = Request::post(uri)
+        let uri = self.make_uri(request.into().get_path());
+        let req = Request::get(uri)
             .body(hyper::Body::empty())
             .unwrap();
 
-        self.execute_async(request).await
-            .and_then(|response| {
-                match response.status {
-                    204 => Ok(()),
-                    304 => Ok(()),
-                    404 => Err(DockerError::NotFound(json::from_str(response.body_as_string().as_str()).unwrap())),
-                    500 => Err(DockerError::ServerError(json::from_str(response.body_as_string().as_str()).unwrap())),
-                    _ => Err(DockerError::UnknownStatus),
-                }
-            })
-            .map_err(|e| e)
+        let body = self.execute_stream(req, |response| {
+            match response.status {
+            400 => DockerError::BadParameters(response.error_message()),
+            500 => DockerError::ServerError(response.error_message()),
+            _ => response.unknown_status(),
+            }
+        }).await?;
+
+        Ok(Box::pin(json_lines::<Event, _>(body)))
     }
 
-    /// Pause a container.
+    /// Get events of the last `since` and the following ones
+    ///
+    /// Return stream of Event or DockerError. Like `docker events --since 10m`,
+    /// the duration is relative to the clock of the client.
     ///
     /// # Arguments
-    /// * `id` - ID or name of the container.
+    /// * `filters` - Filters, see `EventsFilter`.
+    /// * `since` - How far back to replay events, e.g. `"10m".parse()`.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use docker_client::{DockerClient, DockerError};
+    /// # use docker_client::DockerClient;
+    /// # use docker_client::events::EventsFilter;
+    /// use futures::StreamExt;
     ///
     /// # #[tokio::main]
     /// async fn main() {
-    ///    let client = DockerClient::new();
+    ///     let client = DockerClient::new();
     ///
-    ///     match client.pause_container("test").await {
-    ///         Ok(_) => {},
-    ///         Err(e) => {
-    ///             match e {
-    ///                 DockerError::NotFound(e) => println!("{}", e.message),
-    ///                 DockerError::ServerError(e) => println!("{}", e.message),
-    ///                 _ => {}
-    ///             }
-    ///         },
-    ///     }
+    ///     let since = "10m".parse().unwrap();
     ///
+    ///     if let Ok(mut events) = client.events_since(EventsFilter::new().event("die").build(), since).await {
+    ///         if let Some(Ok(event)) = events.next().await {
+    ///             println!("{} died", event.actor().id());
+    ///         }
+    ///     }
     /// }
     /// ```
-    pub async fn pause_container<T>(&self, id: T) -> Result<(), DockerError>
-        where T: Into<String>
-    {
+    pub async fn events_since(&self, filters: Filters, since: GoDuration) -> Result<impl Stream<Item = Result<Event, DockerError>> + Unpin, DockerError> {
 
-        let uri = self.make_uri(format!("/containers/{}/pause", id.into()));
-        let request = Request::post(uri)
-            .body(hyper::Body::empty())
-            .unwrap();
+        let since = SystemTime::now()
+            .checked_sub(since.as_duration())
+            .unwrap_or(UNIX_EPOCH);
 
-        self.execute_async(request).await
-            .and_then(|response| {
-                match response.status {
-                    204 => Ok(()),
-                    404 => Err(DockerError::NotFound(json::from_str(response.body_as_string().as_str()).unwrap())),
-                    500 => Err(DockerError::ServerError(json::from_str(response.body_as_string().as_str()).unwrap())),
-                    _ => Err(DockerError::UnknownStatus),
-                }
-            })
-            .map_err(|e| e)
+        self.events(EventsRequest::new().since(since).filters(filters)).await
     }
 
-    /// Unpause a container.
+    /// Watch lifecycle events of a container
+    ///
+    /// Return stream of ContainerEvent or DockerError. Events are filtered by the daemon,
+    /// only `create`, `start`, `die`, `oom` and `destroy` are reported.
+    /// The stream ends when the connection is closed, dropping it closes the connection.
     ///
     /// # Arguments
     /// * `id` - ID or name of the container.
@@ -462,260 +5628,286 @@ impl DockerClient {
     /// # Examples
     ///
     /// ```rust
-    /// use docker_client::{DockerClient, DockerError};
+    /// # use docker_client::{DockerClient, DockerError};
+    /// # use docker_client::events::ContainerEvent;
+    /// use futures::StreamExt;
     ///
     /// # #[tokio::main]
     /// async fn main() {
     ///    let client = DockerClient::new();
     ///
-    ///     match client.unpause_container("test").await {
-    ///         Ok(_) => {},
-    ///         Err(e) => {
-    ///             match e {
-    ///                 DockerError::NotFound(e) => println!("{}", e.message),
-    ///                 DockerError::ServerError(e) => println!("{}", e.message),
-    ///                 _ => {}
+    ///     match client.watch_container("test").await {
+    ///         Ok(mut events) => {
+    ///             while let Some(Ok(event)) = events.next().await {
+    ///                 if let ContainerEvent::Die { exit_code, .. } = event {
+    ///                     println!("exited with {:?}", exit_code);
+    ///                     break;
+    ///                 }
     ///             }
     ///         },
+    ///         Err(e) => { println!("Error: {:?}", e); },
     ///     }
     ///
     /// }
     /// ```
-    pub async fn unpause_container<T>(&self, id: T) -> Result<(), DockerError>
-        where T: Into<String> {
+    pub async fn watch_container<T>(&self, id: T) -> Result<impl Stream<Item = Result<ContainerEvent, DockerError>> + Unpin, DockerError>
+        where T: Into<ContainerRefId>
+    {
+        let mut filters = EventsFilter::new()
+            .event_type("container")
+            .container(String::from(id.into()));
+        for action in ContainerEvent::ACTIONS.iter() {
+            filters = filters.event(*action);
+        }
 
-        let uri = self.make_uri(format!("/containers/{}/unpause", id.into()));
-        let request = Request::post(uri)
-            .body(hyper::Body::empty())
-            .unwrap();
+        let events = self.events(filters.build()).await?;
 
-        self.execute_async(request).await
-            .and_then(|response| {
-                match response.status {
-                    204 => Ok(()),
-                    404 => Err(DockerError::NotFound(json::from_str(response.body_as_string().as_str()).unwrap())),
-                    500 => Err(DockerError::ServerError(json::from_str(response.body_as_string().as_str()).unwrap())),
-                    _ => Err(DockerError::UnknownStatus),
-                }
-            })
-            .map_err(|e| e)
+        Ok(events.filter_map(|event| async move {
+            match event {
+                Ok(event) => ContainerEvent::from_event(event).map(Ok),
+                Err(e) => Some(Err(e)),
+            }
+        }).boxed())
     }
 
-    /// Rename a container.
+    /// Watch health status transitions of a container
+    ///
+    /// Return stream of HealthStatus or DockerError. The current status is emitted first,
+    /// then every change: a restart goes back to `Starting`, probes move it to `Healthy`
+    /// or `Unhealthy`. Repeated reports of the same status are not emitted.
+    /// Containers without a health check only report `HealthStatus::None`.
     ///
     /// # Arguments
     /// * `id` - ID or name of the container.
-    /// * `new_name` - New name for the container.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use docker_client::{DockerClient, DockerError};
+    /// # use docker_client::{DockerClient, DockerError};
+    /// # use docker_client::container::inspect::HealthStatus;
+    /// use futures::StreamExt;
     ///
     /// # #[tokio::main]
     /// async fn main() {
     ///    let client = DockerClient::new();
     ///
-    ///     match client.rename_container("test", "test1").await {
-    ///         Ok(_) => {},
-    ///         Err(e) => {
-    ///             match e {
-    ///                 DockerError::NotFound(e) => println!("{}", e.message),
-    ///                 DockerError::ContainerExists(e) => println!("{}", e.message),
-    ///                 DockerError::ServerError(e) => println!("{}", e.message),
-    ///                 _ => {}
+    ///     match client.health_events("test").await {
+    ///         Ok(mut statuses) => {
+    ///             while let Some(Ok(status)) = statuses.next().await {
+    ///                 if status == HealthStatus::Unhealthy {
+    ///                     println!("restarting test");
+    ///                 }
     ///             }
     ///         },
+    ///         Err(e) => { println!("Error: {:?}", e); },
     ///     }
     ///
     /// }
     /// ```
-    pub async fn rename_container<T>(&self, id: T, new_name: T) -> Result<(), DockerError>
-        where T: Into<String>
+    pub async fn health_events<T>(&self, id: T) -> Result<impl Stream<Item = Result<HealthStatus, DockerError>> + Unpin, DockerError>
+        where T: Into<ContainerRefId>
     {
+        let id = String::from(id.into());
+        let filters = EventsFilter::new()
+            .event_type("container")
+            .container(id.clone())
+            .event("health_status")
+            .event("start")
+            .build();
 
-        let uri = self.make_uri(format!("/containers/{}/rename?name={}", id.into(), new_name.into()));
-        let request = Request::post(uri)
-            .body(hyper::Body::empty())
-            .unwrap();
+        // Subscribe before inspecting, so no transition is lost in between.
+        let events = self.events(filters).await?;
+        let current = self.inspect_container(Inspect::container(id)).await?.state().health_status();
 
-        self.execute_async(request).await
-            .and_then(|response| {
-                match response.status {
-                    204 => Ok(()),
-                    404 => Err(DockerError::NotFound(json::from_str(response.body_as_string().as_str()).unwrap())),
-                    409 => Err(DockerError::ContainerExists(json::from_str(response.body_as_string().as_str()).unwrap())),
-                    500 => Err(DockerError::ServerError(json::from_str(response.body_as_string().as_str()).unwrap())),
-                    _ => Err(DockerError::UnknownStatus),
-                }
+        let checked = current != HealthStatus::None;
+        let changes = events.filter_map(move |event| async move {
+            match event {
+                Ok(event) if event.action() == "start" && checked => Some(Ok(HealthStatus::Starting)),
+                Ok(event) => event.health_status().map(Ok),
+                Err(e) => Some(Err(e)),
+            }
+        });
+
+        let mut last = None;
+        Ok(futures::stream::once(async move { Ok(current) })
+            .chain(changes)
+            .filter(move |status| {
+                let changed = match status {
+                    Ok(status) => last.replace(status.clone()).as_ref() != Some(status),
+                    Err(_) => true,
+                };
+                async move { changed }
             })
-            .map_err(|e| e)
+            .boxed())
     }
 
-    /// Kill a container.
+    /// Inspect a swarm service
+    ///
+    /// Return Service or DockerError.
     ///
     /// # Arguments
-    /// * `killer` is a struct with metadata to kill a container.
+    /// * `id` - ID or name of the service.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use docker_client::{DockerClient, DockerError};
-    /// use docker_client::container::Killer;
+    /// # use docker_client::{DockerClient, DockerError};
     /// # #[tokio::main]
     /// async fn main() {
-    ///
     ///    let client = DockerClient::new();
     ///
-    ///     let killer = Killer::new()
-    ///         .id("test")
-    ///         .build();
-    ///
-    ///     match client.kill_container(killer).await {
-    ///         Ok(_) => {}
-    ///         Err(e) => {
-    ///             match e {
-    ///                 DockerError::NotFound(e) => println!("{}", e.message),
-    ///                 DockerError::NotRunning(e) => println!("{}", e.message),
-    ///                 DockerError::ServerError(e) => println!("{}", e.message),
-    ///                 _ => {}
-    ///             }
-    ///         }
+    ///     match client.inspect_service("web").await {
+    ///         Ok(service) => { println!("{} at version {}", service.name(), service.version().index()); },
+    ///         Err(e) => { println!("Error: {:?}", e); },
     ///     }
     ///
     /// }
     /// ```
-    pub async fn kill_container(&self, killer: Killer) -> Result<(), DockerError> {
-
-        let uri = self.make_uri(killer.get_path());
-        let request = Request::post(uri)
+    pub async fn inspect_service<T>(&self, id: T) -> Result<Service, DockerError>
+        where T: Into<String>
+    {
+        let uri = self.make_uri(format!("/services/{}", id.into()));
+        let req = Request::get(uri)
             .body(hyper::Body::empty())
             .unwrap();
 
-        self.execute_async(request).await
+        self.execute_async(req).await
             .and_then(|response| {
                 match response.status {
-                    204 => Ok(()),
-                    400 => Err(DockerError::BadParameters(json::from_str(response.body_as_string().as_str()).unwrap())),
-                    404 => Err(DockerError::NotFound(json::from_str(response.body_as_string().as_str()).unwrap())),
-                    409 => Err(DockerError::NotRunning(json::from_str(response.body_as_string().as_str()).unwrap())),
-                    500 => Err(DockerError::ServerError(json::from_str(response.body_as_string().as_str()).unwrap())),
-                    _ => Err(DockerError::UnknownStatus),
+                    200 => self.decode(&response),
+                    404 => Err(DockerError::NotFound(response.error_message())),
+                    500 => Err(DockerError::ServerError(response.error_message())),
+                    503 => Err(DockerError::NotInSwarm(response.error_message())),
+                    _ => Err(response.unknown_status()),
                 }
             })
-            .map_err(|e| e)
     }
 
-    /// Remove a container.
+    /// Create a swarm service
+    ///
+    /// Return CreatedService with the ID and warnings of the daemon or DockerError.
     ///
     /// # Arguments
-    /// * `remover` is a struct with metadata to remove a container.
+    /// * `spec` - Spec of the service, as in the docker API.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use docker_client::{DockerClient, DockerError};
-    /// use docker_client::container::Remover;
-    ///
+    /// # use docker_client::{DockerClient, DockerError};
     /// # #[tokio::main]
     /// async fn main() {
-    ///
     ///    let client = DockerClient::new();
     ///
-    ///     let remover = Remover::new()
-    ///         .id("test")
-    ///         .with_remove_volumes(true)
-    ///         .build();
+    ///     let spec = serde_json::json!({
+    ///         "Name": "web",
+    ///         "TaskTemplate": { "ContainerSpec": { "Image": "nginx:1.25" } },
+    ///     });
     ///
-    ///     match client.remove_container(remover).await {
-    ///         Ok(_) => {}
-    ///         Err(e) => {
-    ///             match e {
-    ///                 DockerError::BadParameters(e) => println!("{}", e.message),
-    ///                 DockerError::NotFound(e) => println!("{}", e.message),
-    ///                 DockerError::NotRunning(e) => println!("{}", e.message),
-    ///                 DockerError::ServerError(e) => println!("{}", e.message),
-    ///                 _ => {}
+    ///     match client.create_service(&spec).await {
+    ///         Ok(created) => {
+    ///             for warning in created.warnings() {
+    ///                 println!("warning: {}", warning);
     ///             }
-    ///         }
+    ///         },
+    ///         Err(e) => { println!("Error: {:?}", e); },
     ///     }
     ///
     /// }
     /// ```
-    pub async fn remove_container(&self, remover: Remover) -> Result<(), DockerError> {
+    pub async fn create_service(&self, spec: &json::Value) -> Result<CreatedService, DockerError> {
+        let uri = self.make_uri("/services/create");
+        let mut request_builder = Request::post(uri)
+            .header(hyper::header::CONTENT_TYPE, "application/json");
 
-        let uri = self.make_uri(remover.get_path());
-        let request = Request::delete(uri)
-            .body(hyper::Body::empty())
+        if self.inner.auth.is_some() {
+            request_builder = request_builder.header("X-Registry-Auth", self.registry_auth_header());
+        }
+
+        let req = request_builder
+            .body(hyper::Body::from(json::to_string(spec).unwrap()))
             .unwrap();
 
-        self.execute_async(request).await
+        self.execute_async(req).await
             .and_then(|response| {
                 match response.status {
-                    204 => Ok(()),
-                    400 => Err(DockerError::BadParameters(json::from_str(response.body_as_string().as_str()).unwrap())),
-                    404 => Err(DockerError::NotFound(json::from_str(response.body_as_string().as_str()).unwrap())),
-                    409 => Err(DockerError::NotRunning(json::from_str(response.body_as_string().as_str()).unwrap())),
-                    500 => Err(DockerError::ServerError(json::from_str(response.body_as_string().as_str()).unwrap())),
-                    _ => Err(DockerError::UnknownStatus),
+                    201 => self.decode(&response),
+                    400 => Err(DockerError::BadParameters(response.error_message())),
+                    403 => Err(DockerError::Forbidden(response.error_message())),
+                    409 => Err(DockerError::ServiceExists(response.error_message())),
+                    500 => Err(DockerError::ServerError(response.error_message())),
+                    503 => Err(DockerError::NotInSwarm(response.error_message())),
+                    _ => Err(response.unknown_status()),
                 }
             })
-            .map_err(|e| e)
     }
 
-    /// Inspect a container.
+    /// Update a swarm service
     ///
-    /// Return `ContainerInfo` structure about a container.
+    /// Return UpdatedService or DockerError. The spec replaces the current one,
+    /// `version` must be the current version of the service.
     ///
     /// # Arguments
-    /// * `id` - ID or name of the container.
-    /// * `size` - Return the size of container as fields SizeRw and SizeRootFs.
+    /// * `id` - ID or name of the service.
+    /// * `version` - Current version of the service.
+    /// * `spec` - New spec of the service.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use docker_client::{DockerClient, DockerError};
-    /// use docker_client::container::inspect::Inspect;
+    /// # use docker_client::{DockerClient, DockerError};
     /// # #[tokio::main]
     /// async fn main() {
-    ///
     ///    let client = DockerClient::new();
     ///
-    ///     match client.inspect_container(Inspect::container("vigilant_antonelli".to_string())).await {
-    ///         Ok(s) => { println!("{:?}", s) }
-    ///         Err(e) => {}
+    ///     if let Ok(service) = client.inspect_service("web").await {
+    ///         let mut spec = service.spec().clone();
+    ///         spec["Mode"]["Replicated"]["Replicas"] = 3.into();
+    ///
+    ///         match client.update_service("web", service.version(), &spec).await {
+    ///             Ok(updated) => { println!("{:?}", updated.warnings()); },
+    ///             Err(e) => { println!("Error: {:?}", e); },
+    ///         }
     ///     }
     ///
     /// }
     /// ```
-    pub async fn inspect_container(&self, request: Inspect) -> Result<ContainerInfo, DockerError> {
+    pub async fn update_service<T, V>(&self, id: T, version: V, spec: &json::Value) -> Result<UpdatedService, DockerError>
+        where
+            T: Into<String>,
+            V: Into<ObjectVersion>
+    {
+        let uri = self.make_uri(format!("/services/{}/update?version={}", id.into(), version.into().index()));
+        let mut request_builder = Request::post(uri)
+            .header(hyper::header::CONTENT_TYPE, "application/json");
 
-        let uri = self.make_uri(request.get_path());
-        let request = Request::get(uri)
-            .body(hyper::Body::empty())
+        if self.inner.auth.is_some() {
+            request_builder = request_builder.header("X-Registry-Auth", self.registry_auth_header());
+        }
+
+        let req = request_builder
+            .body(hyper::Body::from(json::to_string(spec).unwrap()))
             .unwrap();
 
-        self.execute_async(request).await
+        self.execute_async(req).await
             .and_then(|response| {
                 match response.status {
-                    200 => Ok(json::from_str(response.body_as_string().as_str()).unwrap()),
-                    404 => Err(DockerError::NotFound(json::from_str(response.body_as_string().as_str()).unwrap())),
-                    500 => Err(DockerError::ServerError(json::from_str(response.body_as_string().as_str()).unwrap())),
-                    _ => Err(DockerError::UnknownStatus),
+                    200 => self.decode(&response),
+                    400 => Err(DockerError::BadParameters(response.error_message())),
+                    404 => Err(DockerError::NotFound(response.error_message())),
+                    500 => Err(DockerError::ServerError(response.error_message())),
+                    503 => Err(DockerError::NotInSwarm(response.error_message())),
+                    _ => Err(response.unknown_status()),
                 }
             })
-            .map_err(|e| e)
     }
 
-    /// Get container logs
-    ///
-    /// Get stdout and stderr logs from a container.
-    ///
-    /// # Note
-    /// This endpoint works only for containers with the json-file or journald logging driver.
+    /// Restart all tasks of a swarm service without changing its definition
+    ///
+    /// Increment `TaskTemplate.ForceUpdate` and submit the spec again, like `docker service update --force`.
+    /// Return UpdatedService or DockerError.
     ///
     /// # Arguments
-    /// `id` - ID or name of the container.
+    /// * `id` - ID or name of the service.
     ///
     /// # Examples
     ///
@@ -725,133 +5917,101 @@ impl DockerClient {
     /// async fn main() {
     ///    let client = DockerClient::new();
     ///
-    ///     match client.get_container_log("test-container").await {
-    ///         Ok(log) => { println!("Log: {}", log); }
-    ///         Err(e) => { println!("Error: {:?}", e); }
+    ///     match client.force_update_service("web").await {
+    ///         Ok(updated) => { println!("{:?}", updated.warnings()); },
+    ///         Err(e) => { println!("Error: {:?}", e); },
     ///     }
     ///
     /// }
     /// ```
-    pub async fn get_container_log<T>(&self, id: T) -> Result<String, DockerError>
+    pub async fn force_update_service<T>(&self, id: T) -> Result<UpdatedService, DockerError>
         where T: Into<String>
     {
+        let service = self.inspect_service(id).await?;
 
-        let uri = self.make_uri(format!("/containers/{}/logs?stdout=true", id.into()));
-        let request = Request::get(uri)
-            .body(hyper::Body::empty())
-            .unwrap();
-
-        self.execute_async(request).await
-            .and_then(|response| {
-                match response.status {
-                    200 => Ok(response.body_as_string()),
-                    404 => Err(DockerError::NotFound(json::from_str(response.body_as_string().as_str()).unwrap())),
-                    500 => Err(DockerError::ServerError(json::from_str(response.body_as_string().as_str()).unwrap())),
-                    _ => Err(DockerError::UnknownStatus),
-                }
-            })
-            .map_err(|e| e)
+        self.update_service(service.id(), service.version(), &service.force_updated_spec()).await
     }
 
-
-    /// Wait for a container
+    /// Get list of swarm tasks
     ///
-    /// Block until a container stops, then returns the exit code.
+    /// Return list of Task or DockerError.
     ///
     /// # Arguments
-    /// `id` - ID or name of the container.
-    /// `condition` - Wait until a container state reaches the given condition, either 'not-running' (default), 'next-exit', or 'removed'.
+    /// * `filters` - Filters, e.g. `service`, `node`, `desired-state`.
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use docker_client::{DockerClient, DockerError};
-    /// # use docker_client::container::WaitCondition;
+    /// # use docker_client::additionals::filters::Filters;
     /// # #[tokio::main]
     /// async fn main() {
     ///    let client = DockerClient::new();
     ///
-    ///     match client.wait_container("test-container", WaitCondition::NotRunning).await {
-    ///         Ok(status) => { println!("Status: {:?}", status); }
-    ///         Err(e) => { println!("Error: {:?}", e); }
+    ///     let filters = Filters::new().filter("service", "web").build();
+    ///
+    ///     match client.tasks_list(filters).await {
+    ///         Ok(tasks) => { println!("{:?}", tasks); },
+    ///         Err(e) => { println!("Error: {:?}", e); },
     ///     }
     ///
     /// }
     /// ```
-    pub async fn wait_container<T>(&self, id: T, condition: WaitCondition) -> Result<WaitStatus, DockerError>
-        where T: Into<String>
-    {
-        let uri = self.make_uri(format!("/containers/{}/wait?condition={}", id.into(), condition.to_string()));
-        let request = Request::post(uri)
+    pub async fn tasks_list(&self, filters: Filters) -> Result<Vec<Task>, DockerError> {
+        let uri = self.make_uri(match filters.query() {
+            Some(query) => format!("/tasks?{}", query),
+            None => "/tasks".to_string(),
+        });
+        let req = Request::get(uri)
             .body(hyper::Body::empty())
             .unwrap();
 
-        self.execute_async(request).await
+        self.execute_async(req).await
             .and_then(|response| {
                 match response.status {
-                    200 => Ok(json::from_str(response.body_as_string().as_str()).unwrap()),
-                    404 => Err(DockerError::NotFound(json::from_str(response.body_as_string().as_str()).unwrap())),
-                    500 => Err(DockerError::ServerError(json::from_str(response.body_as_string().as_str()).unwrap())),
-                    _ => Err(DockerError::UnknownStatus),
+                    200 => self.decode(&response),
+                    500 => Err(DockerError::ServerError(response.error_message())),
+                    503 => Err(DockerError::NotInSwarm(response.error_message())),
+                    _ => Err(response.unknown_status()),
                 }
             })
     }
 
-
-    /// Export a container
+    /// Get list of tasks running on a swarm node, like `docker node ps`
     ///
-    /// Return empty object or DockerError
+    /// Return list of Task with desired and current state or DockerError.
     ///
     /// # Arguments
-    /// `id` - ID or name of the container.
+    /// * `node_id` - ID or name of the node, `self` for the current node.
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use docker_client::{DockerClient, DockerError};
-    /// # use std::path::Path;
-    ///
     /// # #[tokio::main]
     /// async fn main() {
     ///    let client = DockerClient::new();
     ///
-    ///     let mut path = std::env::temp_dir();
-    ///     path.push("export_container");
-    ///     path.set_extension("tar");
-    ///
-    ///     match client.export_container("test-container", path.as_path()).await {
-    ///         Ok(_) => {},
+    ///     match client.node_ps("self").await {
+    ///         Ok(tasks) => {
+    ///             for task in tasks {
+    ///                 println!("{} {} {}", task.name(), task.desired_state(), task.current_state());
+    ///             }
+    ///         },
     ///         Err(e) => { println!("Error: {:?}", e); },
     ///     }
     ///
     /// }
     /// ```
-    pub async fn export_container<T>(&self, id: T, file: &Path) -> Result<(), DockerError>
+    pub async fn node_ps<T>(&self, node_id: T) -> Result<Vec<Task>, DockerError>
         where T: Into<String>
     {
-
-        let uri = self.make_uri(format!("/containers/{}/export", id.into()));
-        let request = Request::get(uri)
-            .body(hyper::Body::empty())
-            .unwrap();
-
-        self.execute_async(request).await
-            .and_then(|response| {
-                match response.status {
-                    200 => {
-                        response.save_to_file(file)
-                            .map_err(|_| DockerError::UnknownStatus)
-                    },
-                    404 => Err(DockerError::NotFound(json::from_str(response.body_as_string().as_str()).unwrap())),
-                    500 => Err(DockerError::ServerError(json::from_str(response.body_as_string().as_str()).unwrap())),
-                    _ => Err(DockerError::UnknownStatus),
-                }
-            })
+        self.tasks_list(Filters::new().filter("node", node_id).build()).await
     }
 
-    /// Get images list
+    /// Get unlock key of an autolock-enabled swarm
     ///
-    /// Return vector of ShortImageInfo or DockerError
+    /// Return unlock key, e.g. `SWMKEY-1-...`, or DockerError. The key is empty if autolock is disabled.
     ///
     /// # Examples
     ///
@@ -861,84 +6021,76 @@ impl DockerClient {
     /// async fn main() {
     ///    let client = DockerClient::new();
     ///
-    ///     match client.get_image_list().await {
-    ///         Ok(list) => { println!("{:?}", list); },
+    ///     match client.swarm_unlock_key().await {
+    ///         Ok(key) => { println!("{}", key); },
     ///         Err(e) => { println!("Error: {:?}", e); },
     ///     }
     ///
     /// }
     /// ```
-    pub async fn get_image_list(&self) -> Result<Vec<ShortImageInfo>, DockerError> {
-
-        let uri = self.make_uri("/images/json");
-        let request = Request::get(uri)
+    pub async fn swarm_unlock_key(&self) -> Result<String, DockerError> {
+        let uri = self.make_uri("/swarm/unlockkey");
+        let req = Request::get(uri)
             .body(hyper::Body::empty())
             .unwrap();
 
-        self.execute_async(request).await
+        self.execute_async(req).await
             .and_then(|response| {
                 match response.status {
-                    200 => Ok(json::from_str(response.body_as_string().as_str()).unwrap()),
-                    500 => Err(DockerError::ServerError(json::from_str(response.body_as_string().as_str()).unwrap())),
-                    _ => Err(DockerError::UnknownStatus),
+                    200 => self.decode::<UnlockKey>(&response).map(UnlockKey::into_key),
+                    500 => Err(DockerError::ServerError(response.error_message())),
+                    503 => Err(DockerError::NotInSwarm(response.error_message())),
+                    _ => Err(response.unknown_status()),
                 }
             })
     }
 
-    /// Create a volume
-    ///
-    /// Return empty object or DockerError
+    /// Unlock a locked swarm manager
     ///
     /// # Arguments
-    /// * `volume` - VolumeCreator struct.
+    /// * `key` - Unlock key returned by `swarm_unlock_key`.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// # extern crate tokio;
     /// # use docker_client::{DockerClient, DockerError};
-    /// # use docker_client::volume::VolumeCreator;
-    ///
     /// # #[tokio::main]
     /// async fn main() {
     ///    let client = DockerClient::new();
     ///
-    ///     let creator = VolumeCreator::builder()
-    ///         .name("test")
-    ///         .build();
-    ///
-    ///     match client.create_volume(creator).await {
+    ///     match client.unlock_swarm("SWMKEY-1-7c37Cc8654o6p38HnroywCi19pllOnGtbdZEgtKxZu8").await {
     ///         Ok(_) => {},
     ///         Err(e) => { println!("Error: {:?}", e); },
     ///     }
     ///
     /// }
     /// ```
-    pub async fn create_volume(&self, volume: VolumeCreator) -> Result<(), DockerError> {
-
-        let uri = self.make_uri("/volumes/create");
-        let request = Request::post(uri)
-            .header("Content-Type", "application/json")
-            .body(hyper::Body::from(json::to_string(&volume).unwrap()))
+    pub async fn unlock_swarm<T>(&self, key: T) -> Result<(), DockerError>
+        where T: Into<String>
+    {
+        let uri = self.make_uri("/swarm/unlock");
+        let req = Request::post(uri)
+            .header(hyper::header::CONTENT_TYPE, "application/json")
+            .body(hyper::Body::from(json::to_string(&UnlockKey::new(key.into())).unwrap()))
             .unwrap();
 
-        self.execute_async(request).await
+        self.execute_async(req).await
             .and_then(|response| {
                 match response.status {
-                    201 => Ok(()),
-                    400 => Err(DockerError::BadParameters(json::from_str(response.body_as_string().as_str()).unwrap())),
-                    500 => Err(DockerError::ServerError(json::from_str(response.body_as_string().as_str()).unwrap())),
-                    _ => Err(DockerError::UnknownStatus),
+                    200 => Ok(()),
+                    500 => Err(DockerError::ServerError(response.error_message())),
+                    503 => Err(DockerError::NotInSwarm(response.error_message())),
+                    _ => Err(response.unknown_status()),
                 }
             })
     }
 
-    /// Inspect volume
+    /// Inspect a swarm secret
     ///
-    /// Return VolumeInfo or DockerError
+    /// Return Secret or DockerError.
     ///
     /// # Arguments
-    /// * `name` - ID or name of the volume.
+    /// * `id` - ID or name of the secret.
     ///
     /// # Examples
     ///
@@ -948,285 +6100,187 @@ impl DockerClient {
     /// async fn main() {
     ///    let client = DockerClient::new();
     ///
-    ///     match client.inspect_volume("test").await {
-    ///         Ok(info) => { println!("{:?}", info); },
+    ///     match client.inspect_secret("app-secret").await {
+    ///         Ok(secret) => { println!("{} {:?}", secret.name(), secret.labels()); },
     ///         Err(e) => { println!("Error: {:?}", e); },
     ///     }
     ///
     /// }
     /// ```
-    pub async fn inspect_volume<T>(&self, name: T) -> Result<VolumeInfo, DockerError>
+    pub async fn inspect_secret<T>(&self, id: T) -> Result<Secret, DockerError>
         where T: Into<String>
     {
-
-        let uri = self.make_uri(format!("/volumes/{}", name.into()));
-        let request = Request::get(uri)
+        let uri = self.make_uri(format!("/secrets/{}", id.into()));
+        let req = Request::get(uri)
             .body(hyper::Body::empty())
             .unwrap();
 
-        self.execute_async(request).await
+        self.execute_async(req).await
             .and_then(|response| {
                 match response.status {
-                    200 => Ok(json::from_str(response.body_as_string().as_str()).unwrap()),
-                    400 => Err(DockerError::BadParameters(json::from_str(response.body_as_string().as_str()).unwrap())),
-                    404 => Err(DockerError::NotFound(json::from_str(response.body_as_string().as_str()).unwrap())),
-                    500 => Err(DockerError::ServerError(json::from_str(response.body_as_string().as_str()).unwrap())),
-                    _ => Err(DockerError::UnknownStatus),
+                    200 => self.decode(&response),
+                    404 => Err(DockerError::NotFound(response.error_message())),
+                    500 => Err(DockerError::ServerError(response.error_message())),
+                    503 => Err(DockerError::NotInSwarm(response.error_message())),
+                    _ => Err(response.unknown_status()),
                 }
             })
     }
 
-    /// Remove volume
+    /// Replace labels of a swarm secret
     ///
-    /// Instruct the driver to remove the volume.
+    /// Only labels of a secret can be updated. The current version of the secret
+    /// is fetched before the update. Return DockerError on failure.
     ///
     /// # Arguments
-    /// * `name` - ID or name of the volume.
-    /// * `force` - Force the removal of the volume.
+    /// * `id` - ID or name of the secret.
+    /// * `labels` - New labels, replace all current labels.
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use docker_client::{DockerClient, DockerError};
+    /// # use std::collections::HashMap;
     /// # #[tokio::main]
     /// async fn main() {
     ///    let client = DockerClient::new();
     ///
-    ///     match client.remove_volume("test", false).await {
+    ///     let mut labels = HashMap::new();
+    ///     labels.insert("env".to_string(), "prod".to_string());
+    ///
+    ///     match client.update_secret("app-secret", labels).await {
     ///         Ok(_) => {},
     ///         Err(e) => { println!("Error: {:?}", e); },
     ///     }
     ///
     /// }
     /// ```
-    pub async fn remove_volume<T>(&self, name: T, force: bool) -> Result<(), DockerError>
+    pub async fn update_secret<T>(&self, id: T, labels: HashMap<String, String>) -> Result<(), DockerError>
         where T: Into<String>
     {
+        let secret = self.inspect_secret(id).await?;
 
-        let uri = self.make_uri(format!("/volumes/{}?force={}", name.into(), force.to_string()));
-        let request = Request::delete(uri)
-            .body(hyper::Body::empty())
+        let uri = self.make_uri(format!("/secrets/{}/update?version={}", secret.id(), secret.version().index()));
+        let req = Request::post(uri)
+            .header(hyper::header::CONTENT_TYPE, "application/json")
+            .body(hyper::Body::from(json::to_string(&secret.spec_with_labels(labels)).unwrap()))
             .unwrap();
 
-        self.execute_async(request).await
+        self.execute_async(req).await
             .and_then(|response| {
                 match response.status {
-                    204 => Ok(()),
-                    400 => Err(DockerError::BadParameters(json::from_str(response.body_as_string().as_str()).unwrap())),
-                    404 => Err(DockerError::NotFound(json::from_str(response.body_as_string().as_str()).unwrap())),
-                    409 => Err(DockerError::Busy(json::from_str(response.body_as_string().as_str()).unwrap())),
-                    500 => Err(DockerError::ServerError(json::from_str(response.body_as_string().as_str()).unwrap())),
-                    _ => Err(DockerError::UnknownStatus),
+                    200 => Ok(()),
+                    400 => Err(DockerError::BadParameters(response.error_message())),
+                    404 => Err(DockerError::NotFound(response.error_message())),
+                    500 => Err(DockerError::ServerError(response.error_message())),
+                    503 => Err(DockerError::NotInSwarm(response.error_message())),
+                    _ => Err(response.unknown_status()),
                 }
             })
     }
 
-    /// Delete unused volumes
+    /// Inspect a swarm config
     ///
-    /// Return empty or DockerError
+    /// Return SwarmConfig or DockerError.
+    ///
+    /// # Arguments
+    /// * `id` - ID or name of the config.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// # extern crate tokio;
     /// # use docker_client::{DockerClient, DockerError};
-    ///
     /// # #[tokio::main]
     /// async fn main() {
     ///    let client = DockerClient::new();
     ///
-    ///     match client.delete_unused_volumes().await {
-    ///         Ok(_) => {},
+    ///     match client.inspect_config("app-config").await {
+    ///         Ok(config) => { println!("{} {:?}", config.name(), config.labels()); },
     ///         Err(e) => { println!("Error: {:?}", e); },
     ///     }
     ///
     /// }
     /// ```
-    pub async fn delete_unused_volumes(&self) -> Result<DeletedInfo, DockerError> {
-
-        let uri = self.make_uri("/volumes/prune");
-        let request = Request::post(uri)
+    pub async fn inspect_config<T>(&self, id: T) -> Result<SwarmConfig, DockerError>
+        where T: Into<String>
+    {
+        let uri = self.make_uri(format!("/configs/{}", id.into()));
+        let req = Request::get(uri)
             .body(hyper::Body::empty())
             .unwrap();
 
-        self.execute_async(request).await
+        self.execute_async(req).await
             .and_then(|response| {
                 match response.status {
-                    200 => Ok(json::from_str(response.body_as_string().as_str()).unwrap()),
-                    400 => Err(DockerError::BadParameters(json::from_str(response.body_as_string().as_str()).unwrap())),
-                    500 => Err(DockerError::ServerError(json::from_str(response.body_as_string().as_str()).unwrap())),
-                    _ => Err(DockerError::UnknownStatus),
+                    200 => self.decode(&response),
+                    404 => Err(DockerError::NotFound(response.error_message())),
+                    500 => Err(DockerError::ServerError(response.error_message())),
+                    503 => Err(DockerError::NotInSwarm(response.error_message())),
+                    _ => Err(response.unknown_status()),
                 }
             })
     }
 
-    /// Get volumes list
+    /// Replace labels of a swarm config
     ///
-    /// Return VolumesList or DockerError
+    /// Only labels of a config can be updated. The current version of the config
+    /// is fetched before the update. Return DockerError on failure.
+    ///
+    /// # Arguments
+    /// * `id` - ID or name of the config.
+    /// * `labels` - New labels, replace all current labels.
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use docker_client::{DockerClient, DockerError};
+    /// # use std::collections::HashMap;
     /// # #[tokio::main]
     /// async fn main() {
     ///    let client = DockerClient::new();
     ///
-    ///     match client.get_volumes_list().await {
-    ///         Ok(list) => { println!("{:?}", list); },
+    ///     let mut labels = HashMap::new();
+    ///     labels.insert("env".to_string(), "prod".to_string());
+    ///
+    ///     match client.update_config("app-config", labels).await {
+    ///         Ok(_) => {},
     ///         Err(e) => { println!("Error: {:?}", e); },
     ///     }
     ///
     /// }
     /// ```
-    pub async fn get_volumes_list(&self) -> Result<VolumesList, DockerError> {
-
-        let uri = self.make_uri("/volumes");
-        let request = Request::get(uri)
-            .body(hyper::Body::empty())
-            .unwrap();
-
-        self.execute_async(request).await
-            .and_then(|response| {
-                match response.status {
-                    200 => Ok(json::from_str(response.body_as_string().as_str()).unwrap()),
-                    400 => Err(DockerError::BadParameters(json::from_str(response.body_as_string().as_str()).unwrap())),
-                    500 => Err(DockerError::ServerError(json::from_str(response.body_as_string().as_str()).unwrap())),
-                    _ => Err(DockerError::UnknownStatus),
-                }
-            })
-    }
-
-
-    pub async fn pull_image(&self, request: crate::image::create::Request) -> Result<(), DockerError> {
-        let uri = self.make_uri(request.get_path());
-        let mut request_builder = Request::post(uri);
-
-        if self.auth.is_some() {
-            request_builder = request_builder.header("X-Registry-Auth", self.registry_auth());
-        }
-
-        let request = request_builder.body(hyper::Body::empty()).unwrap();
-
-        self.execute_async(request).await
-            .and_then(|response| {
-                match response.status {
-                    200 => Ok(()),
-                    404 => Err(DockerError::NotFound(json::from_str(response.body_as_string().as_str()).unwrap())),
-                    500 => Err(DockerError::ServerError(json::from_str(response.body_as_string().as_str()).unwrap())),
-                    _ => Err(DockerError::UnknownStatus),
-                }
-            })
-    }
-
-    pub async fn create_network(&self, request: crate::networks::create::Request) -> Result<crate::networks::create::CreatedNetwork, DockerError> {
-        let uri = self.make_uri(request.get_path());
-        let req = Request::post(uri)
-            .header(hyper::header::CONTENT_TYPE, "application/json")
-            .body(hyper::Body::from(json::to_string(&request).unwrap()))
-            .unwrap();
-
-        self.execute_async(req).await
-            .and_then(|response| {
-                match response.status {
-                    201 => Ok(json::from_str(response.body_as_string().as_str()).unwrap()),
-                    404 => Err(DockerError::NotFound(json::from_str(response.body_as_string().as_str()).unwrap())),
-                    409 => Err(DockerError::NetworkExists(json::from_str(response.body_as_string().as_str()).unwrap())),
-                    500 => Err(DockerError::ServerError(json::from_str(response.body_as_string().as_str()).unwrap())),
-                    _ => Err(DockerError::UnknownStatus),
-                }
-            })
-    }
-
-    pub async fn inspect_network(&self, request: crate::networks::inspect::Request) -> Result<(), DockerError> {
-        let uri = self.make_uri(request.get_path());
-        let req = Request::get(uri)
-            .body(hyper::Body::empty())
-            .unwrap();
-
-        self.execute_async(req).await
-            .and_then(|response| {
-                match response.status {
-                    200 => Ok(()),
-                    404 => Err(DockerError::NotFound(json::from_str(response.body_as_string().as_str()).unwrap())),
-                    500 => Err(DockerError::ServerError(json::from_str(response.body_as_string().as_str()).unwrap())),
-                    _ => Err(DockerError::UnknownStatus),
-                }
-            })
-    }
-
-    pub async fn connect_container_to_network(&self, request: crate::networks::connect::Request) -> Result<(), DockerError> {
-        let uri = self.make_uri(request.get_path());
-        let req = Request::post(uri)
-            .header(hyper::header::CONTENT_TYPE, "application/json")
-            .body(hyper::Body::from(json::to_string(&request).unwrap()))
-            .unwrap();
-
-        self.execute_async(req).await
-            .and_then(|response| {
-                match response.status {
-                    200 => Ok(()),
-                    404 => Err(DockerError::NotFound(json::from_str(response.body_as_string().as_str()).unwrap())),
-                    500 => Err(DockerError::ServerError(json::from_str(response.body_as_string().as_str()).unwrap())),
-                    _ => Err(DockerError::UnknownStatus),
-                }
-            })
-    }
-
-    pub async fn create_exec_instance(&self, request: crate::exec::create::Request) -> Result<String, DockerError> {
-        let uri = self.make_uri(request.get_path());
-        let req = Request::post(uri)
-            .header(hyper::header::CONTENT_TYPE, "application/json")
-            .body(hyper::Body::from(json::to_string(&request).unwrap()))
-            .unwrap();
-
-        self.execute_async(req).await
-            .and_then(|response| {
-                match response.status {
-                    201 => Ok(json::from_str::<crate::exec::create::Exec>(response.body_as_string().as_str()).unwrap().id),
-                    404 => Err(DockerError::NotFound(json::from_str(response.body_as_string().as_str()).unwrap())),
-                    409 => Err(DockerError::ContainerPaused(json::from_str(response.body_as_string().as_str()).unwrap())),
-                    500 => Err(DockerError::ServerError(json::from_str(response.body_as_string().as_str()).unwrap())),
-                    _ => Err(DockerError::UnknownStatus),
-                }
-            })
-    }
+    pub async fn update_config<T>(&self, id: T, labels: HashMap<String, String>) -> Result<(), DockerError>
+        where T: Into<String>
+    {
+        let config = self.inspect_config(id).await?;
 
-    pub async fn start_exec(&self, id: String) -> Result<(), DockerError> {
-        let uri = self.make_uri(format!("/exec/{}/start", &id));
+        let uri = self.make_uri(format!("/configs/{}/update?version={}", config.id(), config.version().index()));
         let req = Request::post(uri)
             .header(hyper::header::CONTENT_TYPE, "application/json")
-            .body(hyper::body::Body::from("{}"))
+            .body(hyper::Body::from(json::to_string(&config.spec_with_labels(labels)).unwrap()))
             .unwrap();
 
         self.execute_async(req).await
             .and_then(|response| {
                 match response.status {
                     200 => Ok(()),
-                    400 => Err(DockerError::BadParameters(json::from_str(response.body_as_string().as_str()).unwrap())),
-                    404 => Err(DockerError::NotFound(json::from_str(response.body_as_string().as_str()).unwrap())),
-                    409 => Err(DockerError::ContainerPaused(json::from_str(response.body_as_string().as_str()).unwrap())),
-                    500 => Err(DockerError::ServerError(json::from_str(response.body_as_string().as_str()).unwrap())),
-                    _ => Err(DockerError::UnknownStatus),
+                    400 => Err(DockerError::BadParameters(response.error_message())),
+                    404 => Err(DockerError::NotFound(response.error_message())),
+                    500 => Err(DockerError::ServerError(response.error_message())),
+                    503 => Err(DockerError::NotInSwarm(response.error_message())),
+                    _ => Err(response.unknown_status()),
                 }
             })
     }
 
-    pub async fn inspect_exec(&self, id: String) -> Result<crate::exec::inspect::ExecStatus, DockerError> {
-        let uri = self.make_uri(format!("/exec/{}/json", &id));
-        let req = Request::get(uri)
-            .body(hyper::Body::empty())
-            .unwrap();
+}
 
-        self.execute_async(req).await
-            .and_then(|response| {
-                match response.status {
-                    200 => Ok(json::from_str(response.body_as_string().as_str()).unwrap()),
-                    404 => Err(DockerError::NotFound(json::from_str(response.body_as_string().as_str()).unwrap())),
-                    500 => Err(DockerError::ServerError(json::from_str(response.body_as_string().as_str()).unwrap())),
-                    _ => Err(DockerError::UnknownStatus),
-                }
-            })
-    }
+/// Convert timeout into whole seconds for `t` query parameters, rounding up.
+fn timeout_secs(timeout: Duration) -> i64 {
+    let seconds = match timeout.subsec_nanos() {
+        0 => timeout.as_secs(),
+        _ => timeout.as_secs() + 1,
+    };
 
-}
\ No newline at end of file
+    seconds.min(i64::MAX as u64) as i64
+}
@@ -0,0 +1,267 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::process::Stdio;
+
+use serde::Deserialize;
+use tokio::io::AsyncWriteExt;
+use tokio::process::Command;
+
+use crate::client::Auth;
+
+/// Key of Docker Hub credentials in `config.json`.
+const DOCKER_HUB: &str = "https://index.docker.io/v1/";
+
+/// Registry credentials of the docker CLI configuration, `config.json`
+///
+/// Like `docker pull`, credentials of a registry come from its entry in `credHelpers`,
+/// then from the `credsStore`, then from `auths`. Stores and helpers are programs
+/// named `docker-credential-<name>`, looked up in `PATH`.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use docker_client::client::DockerConfig;
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let config = DockerConfig::load("/home/ci/.docker").unwrap();
+///
+/// match config.auth_for_image("ghcr.io/acme/api:1.2").await {
+///     Ok(Some(auth)) => { println!("pulling as {}", auth.username); },
+///     Ok(None) => { println!("pulling anonymously"); },
+///     Err(e) => { println!("Error: {}", e); },
+/// }
+/// # }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+pub struct DockerConfig {
+
+    #[serde(default)]
+    auths: HashMap<String, AuthEntry>,
+
+    #[serde(rename = "credsStore", default)]
+    creds_store: Option<String>,
+
+    #[serde(rename = "credHelpers", default)]
+    cred_helpers: HashMap<String, String>,
+
+}
+
+/// Entry of `auths`, `auth` holds base64 encoded `username:password`.
+#[derive(Clone, Default, PartialEq, Eq, Deserialize)]
+struct AuthEntry {
+
+    #[serde(default)]
+    auth: Option<String>,
+
+    #[serde(default)]
+    username: Option<String>,
+
+    #[serde(default)]
+    password: Option<String>,
+
+    #[serde(default)]
+    identitytoken: Option<String>,
+
+    #[serde(default)]
+    registrytoken: Option<String>,
+
+}
+
+impl fmt::Debug for AuthEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AuthEntry")
+            .field("username", &self.username)
+            .finish()
+    }
+}
+
+/// Response of `docker-credential-<name> get`.
+#[derive(Deserialize)]
+struct HelperCredentials {
+
+    #[serde(rename = "Username", default)]
+    username: String,
+
+    #[serde(rename = "Secret", default)]
+    secret: String,
+
+}
+
+impl DockerConfig {
+
+    /// Read `config.json` of directory `dir`, a missing file gives an empty configuration
+    pub fn load<T>(dir: T) -> Result<Self, CredentialsError>
+        where T: AsRef<Path>
+    {
+        let path = dir.as_ref().join("config.json");
+
+        match fs::read(&path) {
+            Ok(content) => serde_json::from_slice(&content)
+                .map_err(|e| CredentialsError::InvalidConfig(format!("{}: {}", path.display(), e))),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(DockerConfig::default()),
+            Err(e) => Err(CredentialsError::InvalidConfig(format!("{}: {}", path.display(), e))),
+        }
+    }
+
+    /// Return credentials of the registry of image `reference`, e.g. `ghcr.io` for
+    /// `ghcr.io/acme/api:1.2` and Docker Hub for `alpine`
+    pub async fn auth_for_image(&self, reference: &str) -> Result<Option<Auth>, CredentialsError> {
+        self.auth(crate::image::reference::registry(reference)).await
+    }
+
+    /// Return credentials of `registry`, e.g. `ghcr.io` or `docker.io`
+    pub async fn auth(&self, registry: &str) -> Result<Option<Auth>, CredentialsError> {
+        let hostname = hostname(registry);
+        let server = match hostname {
+            "docker.io" | "index.docker.io" | "registry-1.docker.io" => DOCKER_HUB,
+            _ => hostname,
+        };
+
+        let helper = self.cred_helpers.get(server)
+            .or_else(|| self.cred_helpers.get(hostname))
+            .or(self.creds_store.as_ref());
+
+        if let Some(helper) = helper {
+            if let Some(auth) = run_helper(helper, server).await? {
+                return Ok(Some(auth));
+            }
+        }
+
+        let entry = self.auths.iter()
+            .find(|(key, _)| key.as_str() == server || hostname_key(key) == hostname_key(server))
+            .map(|(_, entry)| entry);
+
+        match entry {
+            Some(entry) => entry.to_auth(server).map(Some),
+            None => Ok(None),
+        }
+    }
+
+}
+
+impl AuthEntry {
+
+    /// Decode the entry of registry `server`.
+    fn to_auth(&self, server: &str) -> Result<Auth, CredentialsError> {
+        let mut auth = Auth::builder().server_address(server).build();
+
+        if let Some(ref encoded) = self.auth {
+            let decoded = base64::decode(encoded.trim())
+                .ok()
+                .and_then(|decoded| String::from_utf8(decoded).ok())
+                .ok_or_else(|| CredentialsError::InvalidConfig(format!("invalid auth of `{}`", server)))?;
+
+            let (username, password) = decoded.split_once(':')
+                .ok_or_else(|| CredentialsError::InvalidConfig(format!("invalid auth of `{}`", server)))?;
+
+            auth.username = username.to_string();
+            auth.password = password.to_string();
+        }
+
+        if let Some(ref username) = self.username {
+            auth.username = username.clone();
+        }
+        if let Some(ref password) = self.password {
+            auth.password = password.clone();
+        }
+        auth.identity_token = self.identitytoken.clone().filter(|token| !token.is_empty());
+        auth.registry_token = self.registrytoken.clone().filter(|token| !token.is_empty());
+
+        Ok(auth)
+    }
+
+}
+
+/// Ask `docker-credential-<helper>` for credentials of `server`, `None` if it has none.
+async fn run_helper(helper: &str, server: &str) -> Result<Option<Auth>, CredentialsError> {
+    let program = format!("docker-credential-{}", helper);
+    let failed = |message: String| CredentialsError::Helper(program.clone(), message);
+
+    let mut child = Command::new(&program)
+        .arg("get")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| failed(e.to_string()))?;
+
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(server.as_bytes()).await.map_err(|e| failed(e.to_string()))?;
+    }
+
+    let output = child.wait_with_output().await.map_err(|e| failed(e.to_string()))?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    if !output.status.success() {
+        // Helpers answer with this message when they have no entry for the server.
+        if stdout.contains("credentials not found") {
+            return Ok(None);
+        }
+
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let message = match stderr.trim() {
+            "" => stdout.trim(),
+            stderr => stderr,
+        };
+        return Err(failed(message.to_string()));
+    }
+
+    let credentials: HelperCredentials = serde_json::from_str(&stdout)
+        .map_err(|e| failed(e.to_string()))?;
+
+    let mut auth = Auth::builder().server_address(server).build();
+    match credentials.username.as_str() {
+        // Identity tokens are stored with this placeholder user name.
+        "<token>" => auth.identity_token = Some(credentials.secret),
+        _ => {
+            auth.username = credentials.username;
+            auth.password = credentials.secret;
+        },
+    }
+
+    Ok(Some(auth))
+}
+
+/// Strip the scheme and path of a registry address, as the docker CLI does.
+fn hostname(address: &str) -> &str {
+    let address = address.strip_prefix("https://")
+        .or_else(|| address.strip_prefix("http://"))
+        .unwrap_or(address);
+
+    address.split('/').next().unwrap_or(address)
+}
+
+/// Hostname of an `auths` key, Docker Hub keys are written in several ways.
+fn hostname_key(key: &str) -> &str {
+    match hostname(key) {
+        "docker.io" | "index.docker.io" | "registry-1.docker.io" => "index.docker.io",
+        hostname => hostname,
+    }
+}
+
+/// Error of reading registry credentials
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CredentialsError {
+
+    /// `config.json` can not be read or parsed
+    InvalidConfig(String),
+
+    /// Credential helper `(program, message)` failed
+    Helper(String, String),
+
+}
+
+impl fmt::Display for CredentialsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CredentialsError::InvalidConfig(reason) => write!(f, "invalid docker configuration: {}", reason),
+            CredentialsError::Helper(program, message) => write!(f, "credential helper {} failed: {}", program, message),
+        }
+    }
+}
+
+impl std::error::Error for CredentialsError {}
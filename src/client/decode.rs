@@ -0,0 +1,65 @@
+use serde_json::Value;
+
+/// Deserialization mode of responses
+///
+/// Models ignore fields they don't know, so newer daemons keep working.
+/// `Strict` reports such fields as `DockerError::UnknownFields`, which is
+/// useful in tests to catch drift between the models and the daemon.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum DeserializeMode {
+
+    /// Ignore unknown fields
+    #[default]
+    Lenient,
+
+    /// Fail on unknown fields
+    Strict,
+
+}
+
+/// Return paths of fields present in `raw` but lost by the model, compared with the re-serialized `known` value.
+///
+/// Null and empty values are ignored, models skip them when serializing. Keys are matched
+/// ignoring case as a fallback, so fields read through an alias like `SizeRW` are not reported.
+pub(crate) fn unknown_fields(raw: &Value, known: &Value) -> Vec<String> {
+    let mut fields = Vec::new();
+    collect(raw, known, "", &mut fields);
+    fields
+}
+
+fn collect(raw: &Value, known: &Value, path: &str, fields: &mut Vec<String>) {
+    match (raw, known) {
+        (Value::Object(raw), Value::Object(known)) => {
+            for (key, value) in raw {
+                let field = match path {
+                    "" => key.clone(),
+                    _ => format!("{}.{}", path, key),
+                };
+
+                let known = known.get(key)
+                    .or_else(|| known.iter().find(|(name, _)| name.eq_ignore_ascii_case(key)).map(|(_, value)| value));
+
+                match known {
+                    Some(known) => collect(value, known, &field, fields),
+                    None if is_empty(value) => {},
+                    None => fields.push(field),
+                }
+            }
+        },
+        (Value::Array(raw), Value::Array(known)) => {
+            for (index, (value, known)) in raw.iter().zip(known).enumerate() {
+                collect(value, known, &format!("{}[{}]", path, index), fields);
+            }
+        },
+        _ => {},
+    }
+}
+
+fn is_empty(value: &Value) -> bool {
+    match value {
+        Value::Null => true,
+        Value::Array(array) => array.is_empty(),
+        Value::Object(object) => object.is_empty(),
+        _ => false,
+    }
+}
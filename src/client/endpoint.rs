@@ -0,0 +1,217 @@
+use std::fmt;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+/// Port of plain HTTP daemons
+const HTTP_PORT: u16 = 2375;
+
+/// Port of TLS daemons
+const HTTPS_PORT: u16 = 2376;
+
+/// Port of SSH servers
+const SSH_PORT: u16 = 22;
+
+/// Address of a docker daemon, as in `DOCKER_HOST`
+///
+/// # Examples
+///
+/// ```rust
+/// use docker_client::client::Endpoint;
+///
+/// let endpoint: Endpoint = "tcp://10.0.0.2".parse().unwrap();
+/// assert_eq!(endpoint, Endpoint::Tcp { host: "10.0.0.2".to_string(), port: 2375 });
+///
+/// let endpoint: Endpoint = "unix:///run/user/1000/docker.sock".parse().unwrap();
+/// assert_eq!(endpoint.to_string(), "unix:///run/user/1000/docker.sock");
+///
+/// assert!("ftp://example.com".parse::<Endpoint>().is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Endpoint {
+
+    /// Plain HTTP, `tcp://host[:port]` or `http://host[:port]`, port defaults to 2375
+    Tcp { host: String, port: u16 },
+
+    /// HTTP over TLS, `https://host[:port]`, port defaults to 2376
+    Https { host: String, port: u16 },
+
+    /// Unix socket, `unix:///path`
+    Unix(PathBuf),
+
+    /// Windows named pipe, `npipe:////./pipe/name`
+    NamedPipe(String),
+
+    /// Daemon reached over SSH, `ssh://[user@]host[:port]`, port defaults to 22
+    Ssh { user: Option<String>, host: String, port: u16 },
+
+    /// Systemd socket activation, `fd://` or `fd://name`
+    Fd(String),
+
+}
+
+impl Endpoint {
+
+    /// Return URL scheme of the endpoint
+    pub fn scheme(&self) -> &'static str {
+        match self {
+            Endpoint::Tcp { .. } => "tcp",
+            Endpoint::Https { .. } => "https",
+            Endpoint::Unix(_) => "unix",
+            Endpoint::NamedPipe(_) => "npipe",
+            Endpoint::Ssh { .. } => "ssh",
+            Endpoint::Fd(_) => "fd",
+        }
+    }
+
+    /// Return `host:port` of network endpoints
+    pub fn authority(&self) -> Option<String> {
+        match self {
+            Endpoint::Tcp { host, port } | Endpoint::Https { host, port } | Endpoint::Ssh { host, port, .. } => {
+                Some(format!("{}:{}", bracket(host), port))
+            },
+            _ => None,
+        }
+    }
+
+}
+
+impl Default for Endpoint {
+
+    /// Local daemon: the unix socket with the `unix-socket` feature, `tcp://localhost:2375` otherwise
+    fn default() -> Self {
+        #[cfg(feature = "unix-socket")]
+        return Endpoint::Unix(PathBuf::from("/var/run/docker.sock"));
+
+        #[cfg(not(feature = "unix-socket"))]
+        return Endpoint::Tcp { host: "localhost".to_string(), port: HTTP_PORT };
+    }
+
+}
+
+impl FromStr for Endpoint {
+    type Err = EndpointError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let (scheme, rest) = s.split_once("://")
+            .ok_or_else(|| EndpointError::InvalidAddress(s.to_string()))?;
+
+        match scheme.to_ascii_lowercase().as_str() {
+            "tcp" | "http" => {
+                let (host, port) = host_port(rest, HTTP_PORT).ok_or_else(|| EndpointError::InvalidAddress(s.to_string()))?;
+                Ok(Endpoint::Tcp { host, port })
+            },
+            "https" => {
+                let (host, port) = host_port(rest, HTTPS_PORT).ok_or_else(|| EndpointError::InvalidAddress(s.to_string()))?;
+                Ok(Endpoint::Https { host, port })
+            },
+            "ssh" => {
+                let (user, address) = match rest.split_once('@') {
+                    Some((user, address)) if !user.is_empty() => (Some(user.to_string()), address),
+                    Some(_) => return Err(EndpointError::InvalidAddress(s.to_string())),
+                    None => (None, rest),
+                };
+                let (host, port) = host_port(address, SSH_PORT).ok_or_else(|| EndpointError::InvalidAddress(s.to_string()))?;
+                Ok(Endpoint::Ssh { user, host, port })
+            },
+            "unix" if !rest.is_empty() => Ok(Endpoint::Unix(PathBuf::from(rest))),
+            "npipe" if !rest.is_empty() => Ok(Endpoint::NamedPipe(rest.to_string())),
+            "fd" => Ok(Endpoint::Fd(rest.to_string())),
+            "unix" | "npipe" => Err(EndpointError::InvalidAddress(s.to_string())),
+            _ => Err(EndpointError::UnsupportedScheme(scheme.to_string())),
+        }
+    }
+}
+
+impl fmt::Display for Endpoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Endpoint::Unix(path) => write!(f, "unix://{}", path.display()),
+            Endpoint::NamedPipe(path) => write!(f, "npipe://{}", path),
+            Endpoint::Fd(name) => write!(f, "fd://{}", name),
+            Endpoint::Ssh { user: Some(user), .. } => write!(f, "ssh://{}@{}", user, self.authority().unwrap_or_default()),
+            _ => write!(f, "{}://{}", self.scheme(), self.authority().unwrap_or_default()),
+        }
+    }
+}
+
+/// Split `host[:port]`, with optional brackets around IPv6 hosts and an optional trailing slash.
+fn host_port(address: &str, default_port: u16) -> Option<(String, u16)> {
+    let address = address.strip_suffix('/').unwrap_or(address);
+
+    let (host, port) = match address.strip_prefix('[') {
+        Some(rest) => {
+            let (host, rest) = rest.split_once(']')?;
+            match rest {
+                "" => (host, None),
+                _ => (host, Some(rest.strip_prefix(':')?)),
+            }
+        },
+        None => match address.rsplit_once(':') {
+            Some((host, port)) => (host, Some(port)),
+            None => (address, None),
+        },
+    };
+
+    if host.is_empty() || host.contains('/') {
+        return None;
+    }
+
+    let port = match port {
+        Some(port) => port.parse().ok()?,
+        None => default_port,
+    };
+
+    Some((host.to_string(), port))
+}
+
+/// Put brackets around IPv6 hosts.
+fn bracket(host: &str) -> String {
+    match host.contains(':') {
+        true => format!("[{}]", host),
+        false => host.to_string(),
+    }
+}
+
+/// Error of parsing or connecting to an `Endpoint`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EndpointError {
+
+    /// Scheme is not a docker endpoint scheme
+    UnsupportedScheme(String),
+
+    /// Address is malformed
+    InvalidAddress(String),
+
+    /// Endpoint is valid but the client can not connect over its transport
+    UnsupportedTransport(Endpoint),
+
+    /// Environment variable `(name, value)` has an invalid value
+    InvalidVariable(String, String),
+
+    /// TLS certificate or key can not be loaded
+    InvalidCertificate(String),
+
+    /// Proxy URL is malformed or not `http`
+    InvalidProxy(String),
+
+}
+
+impl fmt::Display for EndpointError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EndpointError::UnsupportedScheme(scheme) => write!(
+                f, "unsupported scheme `{}`, expected one of tcp, http, https, unix, npipe, ssh or fd", scheme
+            ),
+            EndpointError::InvalidAddress(address) => write!(f, "invalid daemon address `{}`", address),
+            EndpointError::UnsupportedTransport(endpoint) => write!(
+                f, "connecting over {} is not supported, endpoint `{}`", endpoint.scheme(), endpoint
+            ),
+            EndpointError::InvalidVariable(name, value) => write!(f, "invalid value `{}` of {}", value, name),
+            EndpointError::InvalidCertificate(reason) => write!(f, "invalid TLS configuration: {}", reason),
+            EndpointError::InvalidProxy(url) => write!(f, "invalid proxy `{}`, expected http://[user:password@]host[:port]", url),
+        }
+    }
+}
+
+impl std::error::Error for EndpointError {}
@@ -0,0 +1,128 @@
+use std::env;
+use std::path::PathBuf;
+
+use crate::client::{ApiVersion, Endpoint, EndpointError};
+
+/// Daemon settings from the standard docker environment variables
+///
+/// * `DOCKER_HOST` - daemon endpoint, `Endpoint::default()` if unset.
+/// * `DOCKER_API_VERSION` - API version to use.
+/// * `DOCKER_TLS_VERIFY` - use TLS and verify the daemon certificate if not empty.
+/// * `DOCKER_CERT_PATH` - directory with `ca.pem`, `cert.pem` and `key.pem`, setting it enables TLS.
+/// * `DOCKER_CONFIG` - directory of the CLI configuration, `~/.docker` if unset.
+///
+/// Like the docker CLI, a `tcp://` host is reached over TLS when TLS is enabled.
+///
+/// # Examples
+///
+/// ```rust
+/// use docker_client::client::{DockerEnv, Endpoint, ApiVersion};
+///
+/// let env = DockerEnv::from_vars(vec![
+///     ("DOCKER_HOST", "tcp://10.0.0.2:2376"),
+///     ("DOCKER_TLS_VERIFY", "1"),
+///     ("DOCKER_API_VERSION", "1.41"),
+/// ]).unwrap();
+///
+/// assert_eq!(env.endpoint(), &Endpoint::Https { host: "10.0.0.2".to_string(), port: 2376 });
+/// assert_eq!(env.api_version(), Some(ApiVersion::new(1, 41)));
+/// assert!(env.tls_verify());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DockerEnv {
+    endpoint: Endpoint,
+    api_version: Option<ApiVersion>,
+    tls_verify: bool,
+    cert_path: Option<PathBuf>,
+    config_dir: PathBuf,
+}
+
+impl DockerEnv {
+
+    /// Read settings from the environment of the process
+    pub fn read() -> Result<Self, EndpointError> {
+        DockerEnv::from_vars(env::vars())
+    }
+
+    /// Read settings from `(name, value)` pairs, other variables are ignored
+    pub fn from_vars<I, K, V>(vars: I) -> Result<Self, EndpointError>
+        where
+            I: IntoIterator<Item = (K, V)>,
+            K: AsRef<str>,
+            V: AsRef<str>
+    {
+        let mut host = None;
+        let mut api_version = None;
+        let mut tls_verify = false;
+        let mut cert_path = None;
+        let mut config_dir = None;
+
+        for (name, value) in vars {
+            let value = value.as_ref();
+            if value.is_empty() {
+                continue;
+            }
+
+            match name.as_ref() {
+                "DOCKER_HOST" => host = Some(value.parse::<Endpoint>()?),
+                "DOCKER_API_VERSION" => api_version = Some(value.parse::<ApiVersion>()
+                    .map_err(|_| EndpointError::InvalidVariable("DOCKER_API_VERSION".to_string(), value.to_string()))?),
+                "DOCKER_TLS_VERIFY" => tls_verify = true,
+                "DOCKER_CERT_PATH" => cert_path = Some(PathBuf::from(value)),
+                "DOCKER_CONFIG" => config_dir = Some(PathBuf::from(value)),
+                _ => {},
+            }
+        }
+
+        let config_dir = config_dir.unwrap_or_else(default_config_dir);
+        let tls = tls_verify || cert_path.is_some();
+
+        let endpoint = match host.unwrap_or_default() {
+            Endpoint::Tcp { host, port } if tls => Endpoint::Https { host, port },
+            endpoint => endpoint,
+        };
+
+        let cert_path = match endpoint {
+            Endpoint::Https { .. } => Some(cert_path.unwrap_or_else(|| config_dir.clone())),
+            _ => cert_path,
+        };
+
+        Ok(DockerEnv { endpoint, api_version, tls_verify, cert_path, config_dir })
+    }
+
+    /// Return endpoint of the daemon
+    pub fn endpoint(&self) -> &Endpoint {
+        &self.endpoint
+    }
+
+    /// Return API version if set
+    pub fn api_version(&self) -> Option<ApiVersion> {
+        self.api_version
+    }
+
+    /// Return `true` if the daemon certificate must be verified
+    pub fn tls_verify(&self) -> bool {
+        self.tls_verify
+    }
+
+    /// Return directory of TLS certificates, set for `https` endpoints
+    pub fn cert_path(&self) -> Option<&PathBuf> {
+        self.cert_path.as_ref()
+    }
+
+    /// Return directory of the CLI configuration, holding `config.json`
+    pub fn config_dir(&self) -> &PathBuf {
+        &self.config_dir
+    }
+
+}
+
+/// `~/.docker`, or `.docker` if the home directory is unknown.
+pub(crate) fn default_config_dir() -> PathBuf {
+    let home = env::var_os("HOME").or_else(|| env::var_os("USERPROFILE"));
+
+    match home {
+        Some(home) => PathBuf::from(home).join(".docker"),
+        None => PathBuf::from(".docker"),
+    }
+}
@@ -1,4 +1,5 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use crate::client::CredentialsError;
 
 /// `DockerError` enum.
 #[derive(Debug)]
@@ -24,21 +25,63 @@ pub enum DockerError {
     /// Server error (HTTP status is 409)
     NetworkExists(ErrorMessage), // 409
 
+    /// Service with the same name exists (HTTP status is 409)
+    ServiceExists(ErrorMessage), // 409
+
     /// Busy by container (HTTP status is 409)
     Busy(ErrorMessage), // 409
 
     /// Container paused
     ContainerPaused(ErrorMessage),
 
-    /// Unknown status
-    UnknownStatus,
+    /// Operation not permitted (HTTP status is 403)
+    Forbidden(ErrorMessage), // 403
+
+    /// Credentials were rejected (HTTP status is 401)
+    Unauthorized(ErrorMessage), // 401
+
+    /// Response has fields unknown to the model, only in `DeserializeMode::Strict`
+    UnknownFields(Vec<String>),
+
+    /// Node is not part of a swarm (HTTP status is 503)
+    NotInSwarm(ErrorMessage), // 503
+
+    /// Status not expected from the endpoint, with the body of the response
+    UnknownStatus { status: u16, body: String },
+
+    /// Daemon refused the connection, e.g. it is not running
+    ConnectionRefused,
+
+    /// Unix socket of the daemon does not exist
+    SocketNotFound,
+
+    /// Not allowed to connect to the daemon, e.g. no access to `/var/run/docker.sock`
+    PermissionDenied,
+
+    /// Connecting to the daemon or reading the response timed out
+    Timeout,
+
+    /// Connection failed or was closed before the response was read
+    Io(std::io::Error),
+
+    /// Request was not sent, the circuit breaker is open after repeated transport failures
+    Unavailable,
 
-    /// Closed connection
-    ClosedConnection,
+    /// Feature is not enabled in the daemon, e.g. an experimental endpoint
+    FeatureUnavailable(String),
+
+    /// Image does not have the expected repo digest
+    DigestMismatch { expected: String, found: Vec<String> },
+
+    /// Registry credentials can not be read from the CLI configuration
+    Credentials(CredentialsError),
+
+    /// Response body is not what the endpoint returns, e.g. truncated
+    Deserialize { body: String, source: serde_json::Error },
 }
 
 /// `ErrorMessage` struct.
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 pub struct ErrorMessage {
     /// Error message get from response.
     pub message: String,
@@ -46,6 +89,36 @@ pub struct ErrorMessage {
 
 impl DockerError {
 
+    /// Tell failures to reach the daemon apart by the io error causing `error`.
+    pub(crate) fn connection<E>(error: E) -> DockerError
+        where E: std::error::Error + Send + Sync + 'static
+    {
+        let mut kind = None;
+        let mut source: Option<&(dyn std::error::Error + 'static)> = Some(&error);
+        while let Some(cause) = source {
+            if let Some(io) = cause.downcast_ref::<std::io::Error>() {
+                kind = Some(io.kind());
+                break;
+            }
+            source = cause.source();
+        }
+
+        match kind {
+            Some(std::io::ErrorKind::ConnectionRefused) => DockerError::ConnectionRefused,
+            Some(std::io::ErrorKind::NotFound) => DockerError::SocketNotFound,
+            Some(std::io::ErrorKind::PermissionDenied) => DockerError::PermissionDenied,
+            Some(std::io::ErrorKind::TimedOut) => DockerError::Timeout,
+            kind => DockerError::Io(std::io::Error::new(kind.unwrap_or(std::io::ErrorKind::Other), error)),
+        }
+    }
+
+    /// Return `true` if the daemon could not be reached or the connection failed.
+    pub(crate) fn is_connection(&self) -> bool {
+        matches!(self,
+            DockerError::ConnectionRefused | DockerError::SocketNotFound | DockerError::PermissionDenied |
+            DockerError::Timeout | DockerError::Io(_))
+    }
+
     pub fn get_error_message(&self) -> Option<String> {
         match self {
             DockerError::BadParameters(ref msg) => { Some(msg.message.clone()) }
@@ -54,11 +127,31 @@ impl DockerError {
             DockerError::NotRunning(ref msg) => { Some(msg.message.clone()) }
             DockerError::AlreadyStarted(ref msg) => { Some(msg.message.clone()) }
             DockerError::ContainerExists(ref msg) => { Some(msg.message.clone()) }
+            DockerError::ServiceExists(ref msg) => { Some(msg.message.clone()) }
             DockerError::Busy(ref msg) => { Some(msg.message.clone()) }
             DockerError::NetworkExists(ref msg) => { Some(msg.message.clone()) }
             DockerError::ContainerPaused(ref msg) => { Some(msg.message.clone()) }
-            DockerError::UnknownStatus => { None }
-            DockerError::ClosedConnection => { None }
+            DockerError::Forbidden(ref msg) => { Some(msg.message.clone()) }
+            DockerError::Unauthorized(ref msg) => { Some(msg.message.clone()) }
+            DockerError::NotInSwarm(ref msg) => { Some(msg.message.clone()) }
+            DockerError::UnknownFields(ref fields) => { Some(format!("unknown fields: {}", fields.join(", "))) }
+            DockerError::UnknownStatus { status, ref body } => {
+                Some(format!("unexpected status {}: {}", status, body.trim()))
+            }
+            DockerError::ConnectionRefused => { None }
+            DockerError::SocketNotFound => { None }
+            DockerError::PermissionDenied => { None }
+            DockerError::Timeout => { None }
+            DockerError::Io(ref e) => { Some(e.to_string()) }
+            DockerError::Unavailable => { None }
+            DockerError::FeatureUnavailable(ref feature) => {
+                Some(format!("{} requires experimental features of the daemon", feature))
+            }
+            DockerError::DigestMismatch { ref expected, ref found } => {
+                Some(format!("expected digest {}, image has [{}]", expected, found.join(", ")))
+            }
+            DockerError::Credentials(ref e) => { Some(e.to_string()) }
+            DockerError::Deserialize { ref source, .. } => { Some(format!("invalid response body: {}", source)) }
         }
     }
 
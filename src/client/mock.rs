@@ -0,0 +1,172 @@
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+
+use hyper::body::Bytes;
+use hyper::{Body, HeaderMap, Method, Request, Response};
+
+use crate::client::{DockerError, Transport, TransportFuture};
+
+/// Canned responses of each route, by method and path.
+type Routes = HashMap<(Method, String), VecDeque<(u16, Bytes)>>;
+
+/// Transport returning canned responses, to unit test code using `DockerClient`
+///
+/// Responses are registered per method and path, the API version prefix of the
+/// path is ignored. Several responses of a route are returned in order, the last
+/// one repeats. Requests without a response get a `404`.
+///
+/// Clones share responses and recorded requests.
+///
+/// # Examples
+///
+/// ```rust
+/// use docker_client::DockerClient;
+/// use docker_client::client::MockTransport;
+/// use hyper::Method;
+///
+/// # #[tokio::main]
+/// async fn main() {
+///     let mock = MockTransport::new()
+///         .with_response(Method::GET, "/images/json", 200, "[]")
+///         .with_response(Method::POST, "/containers/web/start", 204, "");
+///
+///     let client = DockerClient::from_transport(mock.clone());
+///
+///     assert!(client.get_image_list().await.unwrap().is_empty());
+///     client.start_container("web", "").await.unwrap();
+///
+///     assert_eq!(mock.requests().len(), 2);
+///     assert_eq!(mock.requests()[1].path(), "/containers/web/start");
+/// }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MockTransport {
+
+    responses: Arc<Mutex<Routes>>,
+
+    requests: Arc<Mutex<Vec<MockRequest>>>,
+
+}
+
+/// Request received by a `MockTransport`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MockRequest {
+
+    method: Method,
+
+    path: String,
+
+    query: Option<String>,
+
+    headers: HeaderMap,
+
+    body: Bytes,
+
+}
+
+impl MockRequest {
+
+    /// Return method of the request
+    pub fn method(&self) -> &Method {
+        &self.method
+    }
+
+    /// Return path of the request, without the API version prefix
+    pub fn path(&self) -> &str {
+        self.path.as_str()
+    }
+
+    /// Return query string of the request if any
+    pub fn query(&self) -> Option<&str> {
+        self.query.as_deref()
+    }
+
+    /// Return headers of the request
+    pub fn headers(&self) -> &HeaderMap {
+        &self.headers
+    }
+
+    /// Return body of the request
+    pub fn body(&self) -> &Bytes {
+        &self.body
+    }
+
+}
+
+impl MockTransport {
+
+    /// Creates a new `MockTransport` without responses
+    pub fn new() -> Self {
+        MockTransport::default()
+    }
+
+    /// Respond to `method` requests of `path` with `status` and `body`
+    pub fn with_response<T, U>(self, method: Method, path: T, status: u16, body: U) -> Self
+        where
+            T: Into<String>,
+            U: Into<Bytes>
+    {
+        self.responses.lock().unwrap()
+            .entry((method, path.into()))
+            .or_default()
+            .push_back((status, body.into()));
+
+        self
+    }
+
+    /// Return requests received so far, in order
+    pub fn requests(&self) -> Vec<MockRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+
+    /// Return the next response of a route.
+    fn respond(&self, method: &Method, path: &str) -> (u16, Bytes) {
+        let mut responses = self.responses.lock().unwrap();
+
+        match responses.get_mut(&(method.clone(), path.to_string())) {
+            Some(queue) if queue.len() > 1 => queue.pop_front().unwrap(),
+            Some(queue) if !queue.is_empty() => queue[0].clone(),
+            _ => (404, Bytes::from(format!(r#"{{"message":"no response for {} {}"}}"#, method, path))),
+        }
+    }
+
+}
+
+impl Transport for MockTransport {
+    fn execute(&self, request: Request<Body>) -> TransportFuture<'_> {
+        Box::pin(async move {
+            let (parts, body) = request.into_parts();
+            let body = hyper::body::to_bytes(body).await.map_err(DockerError::connection)?;
+
+            let path = strip_version(parts.uri.path()).to_string();
+            let (status, response) = self.respond(&parts.method, &path);
+
+            self.requests.lock().unwrap().push(MockRequest {
+                method: parts.method,
+                path,
+                query: parts.uri.query().map(str::to_string),
+                headers: parts.headers,
+                body,
+            });
+
+            let status_code = hyper::StatusCode::from_u16(status)
+                .map_err(|_| DockerError::UnknownStatus { status, body: String::from_utf8_lossy(&response).into_owned() })?;
+
+            let mut response = Response::new(Body::from(response));
+            *response.status_mut() = status_code;
+
+            Ok(response)
+        })
+    }
+}
+
+/// Strip an API version prefix like `/v1.44` from a path.
+fn strip_version(path: &str) -> &str {
+    let versioned = path.strip_prefix("/v")
+        .and_then(|rest| rest.find('/').map(|end| (&rest[..end], &rest[end..])));
+
+    match versioned {
+        Some((version, rest)) if version.parse::<f64>().is_ok() => rest,
+        _ => path,
+    }
+}
@@ -13,9 +13,42 @@
 
 
 mod client;
+mod auth;
+mod credentials;
 mod error;
 mod response;
+mod stream;
+mod version;
+mod decode;
+mod breaker;
+mod retry;
+mod endpoint;
+mod env;
+mod raw;
+mod proxy;
+mod capabilities;
+mod ping;
+mod transport;
+mod mock;
+#[cfg(feature = "tls")]
+mod tls;
 
-pub use client::{DockerClient, Auth};
+pub use client::{DockerClient, DockerClientBuilder};
+pub use auth::{Auth, AuthBuilder, AuthStatus};
+pub use credentials::{DockerConfig, CredentialsError};
 pub use error::{DockerError, ErrorMessage};
+pub use version::ApiVersion;
+pub use decode::DeserializeMode;
+pub use breaker::CircuitBreaker;
+pub use retry::RetryPolicy;
+pub use endpoint::{Endpoint, EndpointError};
+pub use env::DockerEnv;
+pub use raw::Raw;
+pub use proxy::Proxy;
+pub use capabilities::Capabilities;
+pub use ping::Ping;
+pub use transport::{Transport, TransportFuture};
+pub use mock::{MockTransport, MockRequest};
+#[cfg(feature = "tls")]
+pub use tls::{TlsConfig, TlsBackend};
 
@@ -0,0 +1,57 @@
+use crate::client::ApiVersion;
+use crate::client::response::DockerResponse;
+
+/// Headers of `/_ping`, see `DockerClient::ping`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Ping {
+
+    api_version: Option<ApiVersion>,
+
+    builder_version: Option<String>,
+
+    experimental: bool,
+
+    os_type: Option<String>,
+
+    swarm: Option<String>,
+
+}
+
+impl Ping {
+
+    pub(crate) fn new(response: &DockerResponse) -> Self {
+        Ping {
+            api_version: response.api_version(),
+            builder_version: response.builder_version().map(String::from),
+            experimental: response.experimental() == Some(true),
+            os_type: response.header("OSType").map(String::from),
+            swarm: response.header("Swarm").map(String::from),
+        }
+    }
+
+    /// Return newest API version of the daemon, unknown to API versions before 1.25
+    pub fn api_version(&self) -> Option<ApiVersion> {
+        self.api_version
+    }
+
+    /// Return default builder of the daemon, `1` or `2` for BuildKit, unknown to API versions before 1.31
+    pub fn builder_version(&self) -> Option<&str> {
+        self.builder_version.as_deref()
+    }
+
+    /// Return `true` if experimental features of the daemon are enabled
+    pub fn experimental(&self) -> bool {
+        self.experimental
+    }
+
+    /// Return operating system of the daemon, `linux` or `windows`
+    pub fn os_type(&self) -> Option<&str> {
+        self.os_type.as_deref()
+    }
+
+    /// Return swarm status of the node, e.g. `inactive` or `active/manager`, unknown to API versions before 1.40
+    pub fn swarm(&self) -> Option<&str> {
+        self.swarm.as_deref()
+    }
+
+}
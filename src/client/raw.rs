@@ -0,0 +1,71 @@
+use std::ops::Deref;
+use hyper::body::Bytes;
+
+/// Decoded response together with its untouched body
+///
+/// Gives access to fields of newer daemons which the crate does not model yet,
+/// while the rest of the response stays typed.
+///
+/// # Examples
+///
+/// ```rust
+/// # use docker_client::DockerClient;
+/// # #[tokio::main]
+/// async fn main() {
+///     let client = DockerClient::new();
+///
+///     if let Ok(image) = client.inspect_image_raw("alpine").await {
+///         println!("{:?}", image.id());
+///         println!("{:?}", image.json().ok().and_then(|raw| raw.get("Descriptor").cloned()));
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Raw<T> {
+
+    value: T,
+
+    body: Bytes,
+
+}
+
+impl<T> Raw<T> {
+
+    pub(crate) fn new(value: T, body: Bytes) -> Self {
+        Raw { value, body }
+    }
+
+    /// Return decoded response
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// Return decoded response, dropping the body
+    pub fn into_value(self) -> T {
+        self.value
+    }
+
+    /// Return body as sent by the daemon
+    pub fn bytes(&self) -> &Bytes {
+        &self.body
+    }
+
+    /// Parse body as untyped JSON, on every call
+    pub fn json(&self) -> serde_json::Result<serde_json::Value> {
+        serde_json::from_slice(&self.body)
+    }
+
+    /// Return decoded response and body
+    pub fn into_parts(self) -> (T, Bytes) {
+        (self.value, self.body)
+    }
+
+}
+
+impl<T> Deref for Raw<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
@@ -1,22 +1,155 @@
 use hyper::body::Bytes;
-use std::path::Path;
+use hyper::HeaderMap;
+use serde::de::DeserializeOwned;
+
+use crate::client::{ApiVersion, DockerError, ErrorMessage};
+use crate::container::PathStat;
 
 #[derive(Clone)]
 pub struct DockerResponse {
     pub status: u16,
     pub body: Bytes,
+    pub(crate) headers: HeaderMap,
 }
 
 impl DockerResponse {
 
+    /// Return the body without copying it.
+    pub fn body(&self) -> &Bytes {
+        &self.body
+    }
+
+    /// Take the body without copying it, for binary endpoints.
+    pub fn into_body(self) -> Bytes {
+        self.body
+    }
+
+    /// Return value of header `name`, `None` if missing or not visible ASCII.
+    pub(crate) fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(name).and_then(|value| value.to_str().ok())
+    }
+
+    /// Return API version of the daemon, from header `Api-Version`.
+    pub fn api_version(&self) -> Option<ApiVersion> {
+        self.header("Api-Version").and_then(|version| version.parse().ok())
+    }
+
+    /// Return whether experimental features are enabled, from header `Docker-Experimental`.
+    pub fn experimental(&self) -> Option<bool> {
+        self.header("Docker-Experimental").and_then(|experimental| experimental.parse().ok())
+    }
+
+    /// Return default builder of the daemon, `1` or `2` (BuildKit), from header `Builder-Version`.
+    pub fn builder_version(&self) -> Option<&str> {
+        self.header("Builder-Version").filter(|version| !version.is_empty())
+    }
+
+    /// Return stat of an archive path, from base64 JSON header `X-Docker-Container-Path-Stat`.
+    pub fn path_stat(&self) -> Option<PathStat> {
+        let stat = base64::decode(self.header("X-Docker-Container-Path-Stat")?).ok()?;
+        serde_json::from_slice(&stat).ok()
+    }
+
+    /// Copy the body into a `String`. Only for text bodies, binary endpoints
+    /// should use `body` or `into_body`.
+    /// Invalid UTF-8 is replaced, see `String::from_utf8_lossy`.
     pub fn body_as_string(&self) -> String {
-        String::from_utf8(
-        self.body.to_vec()
-        ).unwrap()
+        String::from_utf8_lossy(&self.body).into_owned()
+    }
+
+    /// Return error message of an error response
+    ///
+    /// Bodies which are not a JSON `{"message": ...}`, like the HTML page of a
+    /// proxy, are taken as the message.
+    pub fn error_message(&self) -> ErrorMessage {
+        match self.json::<ErrorMessage>() {
+            Ok(message) => message,
+            Err(_) => ErrorMessage { message: self.body_as_string().trim().to_string() },
+        }
     }
 
-    pub fn save_to_file(&self, path: &Path) -> std::io::Result<()> {
-        std::fs::write(path, self.body.to_vec())
+    /// Return `DockerError::UnknownStatus` of a response the endpoint does not expect
+    pub fn unknown_status(&self) -> DockerError {
+        DockerError::UnknownStatus { status: self.status, body: self.body_as_string() }
     }
 
-}
\ No newline at end of file
+    /// Decode a JSON body straight from the bytes.
+    ///
+    /// With the `simd-json` feature the body is parsed by simd-json, which needs
+    /// a mutable copy of the body but is faster on large lists.
+    ///
+    /// Return `DockerError::Deserialize` if the body is not a valid `T`.
+    #[cfg(not(feature = "simd-json"))]
+    pub fn json<T>(&self) -> Result<T, DockerError>
+        where T: DeserializeOwned
+    {
+        serde_json::from_slice(&self.body).map_err(|source| self.deserialize_error(source))
+    }
+
+    /// Decode a JSON body straight from the bytes.
+    ///
+    /// With the `simd-json` feature the body is parsed by simd-json, which needs
+    /// a mutable copy of the body but is faster on large lists.
+    ///
+    /// Return `DockerError::Deserialize` if the body is not a valid `T`.
+    #[cfg(feature = "simd-json")]
+    pub fn json<T>(&self) -> Result<T, DockerError>
+        where T: DeserializeOwned
+    {
+        let mut body = self.body.to_vec();
+        simd_json::serde::from_slice(&mut body)
+            .map_err(serde::de::Error::custom)
+            .map_err(|source| self.deserialize_error(source))
+    }
+
+    /// Wrap a decoding error with the body.
+    fn deserialize_error(&self, source: serde_json::Error) -> DockerError {
+        DockerError::Deserialize { body: self.body_as_string(), source }
+    }
+
+    /// Split a multiplexed stream body into stdout and stderr.
+    ///
+    /// Every frame starts with an 8 bytes header: stream type, three zero bytes
+    /// and big endian payload size. A body without valid headers (TTY enabled)
+    /// is returned as stdout.
+    pub fn demultiplex(&self) -> (Vec<u8>, Vec<u8>) {
+        let mut stdout = Vec::new();
+        let mut stderr = Vec::new();
+
+        for (stream, payload) in self.frames() {
+            match stream {
+                2 => stderr.extend_from_slice(payload),
+                _ => stdout.extend_from_slice(payload),
+            }
+        }
+
+        (stdout, stderr)
+    }
+
+    /// Return frames of a multiplexed stream body in order as `(stream type, payload)`.
+    ///
+    /// A body without valid headers (TTY enabled) is returned as a single stdout frame.
+    pub(crate) fn frames(&self) -> Vec<(u8, &[u8])> {
+        let body = self.body.as_ref();
+        let mut frames = Vec::new();
+        let mut offset = 0;
+
+        while offset < body.len() {
+            let header = match body.get(offset..offset + 8) {
+                Some(header) if header[0] <= 2 && header[1..4] == [0, 0, 0] => header,
+                _ => return vec![(1, body)],
+            };
+
+            let size = u32::from_be_bytes([header[4], header[5], header[6], header[7]]) as usize;
+            match body.get(offset + 8..offset + 8 + size) {
+                Some(payload) => frames.push((header[0], payload)),
+                None => return vec![(1, body)],
+            }
+
+            offset += 8 + size;
+        }
+
+        frames
+    }
+
+}
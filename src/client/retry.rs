@@ -0,0 +1,98 @@
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::time::Duration;
+
+use hyper::Method;
+
+use crate::client::DockerError;
+
+/// Retry policy for transient failures
+///
+/// Refused and failed connections and `500`, `502`, `503` and `504` responses are retried up to
+/// `max_retries` times. The delay before retry `n` is `initial_backoff * 2^n`, capped at
+/// `max_backoff`, with a random jitter taking up to half of it.
+///
+/// Only idempotent requests are retried: `GET` and `HEAD`, and calls which can safely be
+/// sent twice, like starting or stopping a container.
+///
+/// # Examples
+///
+/// ```rust
+/// use docker_client::DockerClient;
+/// use docker_client::client::RetryPolicy;
+/// use std::time::Duration;
+///
+/// let client = DockerClient::new()
+///     .with_retry_policy(RetryPolicy::new(3).backoff(Duration::from_millis(50), Duration::from_secs(2)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    max_retries: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+}
+
+impl RetryPolicy {
+
+    /// Creates a new `RetryPolicy` with backoff from 100ms up to 5s
+    pub fn new(max_retries: u32) -> Self {
+        RetryPolicy {
+            max_retries,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(5),
+        }
+    }
+
+    /// Set delay before the first retry and the maximum delay
+    pub fn backoff(mut self, initial: Duration, max: Duration) -> Self {
+        self.initial_backoff = initial;
+        self.max_backoff = max.max(initial);
+
+        self
+    }
+
+    /// Return how many times a request is retried
+    pub fn max_retries(&self) -> u32 {
+        self.max_retries
+    }
+
+    /// Return delay before the first retry
+    pub fn initial_backoff(&self) -> Duration {
+        self.initial_backoff
+    }
+
+    /// Return maximum delay between retries
+    pub fn max_backoff(&self) -> Duration {
+        self.max_backoff
+    }
+
+    /// Return delay before retry `attempt`, counted from zero
+    pub(crate) fn delay(&self, attempt: u32) -> Duration {
+        let delay = self.initial_backoff
+            .checked_mul(1u32.checked_shl(attempt).unwrap_or(u32::MAX))
+            .unwrap_or(self.max_backoff)
+            .min(self.max_backoff);
+
+        let jitter = delay / 2;
+        let random = RandomState::new().build_hasher().finish();
+        delay - jitter.mul_f64((random % 1000) as f64 / 1000.0)
+    }
+
+}
+
+/// Marker of requests which may be sent more than once.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Idempotent;
+
+/// Return `true` if the outcome of an attempt is worth another one.
+pub(crate) fn is_transient(result: &Result<hyper::Response<hyper::Body>, DockerError>) -> bool {
+    match result {
+        Ok(response) => matches!(response.status().as_u16(), 500 | 502 | 503 | 504),
+        Err(e) => matches!(e, DockerError::ConnectionRefused | DockerError::SocketNotFound | DockerError::Io(_)),
+    }
+}
+
+/// Return `true` if `request` may be retried.
+pub(crate) fn is_idempotent<T>(request: &hyper::Request<T>) -> bool {
+    matches!(*request.method(), Method::GET | Method::HEAD) || request.extensions().get::<Idempotent>().is_some()
+}
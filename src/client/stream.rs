@@ -0,0 +1,163 @@
+use futures::stream::{self, Stream, StreamExt};
+use hyper::body::Bytes;
+use serde::de::DeserializeOwned;
+use std::collections::VecDeque;
+
+use crate::client::DockerError;
+use crate::container::logs::{LogDecoder, LogLine};
+
+/// Incremental decoder of newline delimited JSON.
+///
+/// Chunks are fed as they arrive, records split across chunk boundaries are
+/// kept until the rest of the line is received. Lines which can not be decoded
+/// as `T` are kept as `DockerError::Deserialize` in place of the record.
+pub(crate) struct JsonLinesDecoder<T> {
+    buffer: Vec<u8>,
+    records: VecDeque<Result<T, DockerError>>,
+}
+
+impl<T> JsonLinesDecoder<T>
+    where T: DeserializeOwned
+{
+    pub(crate) fn new() -> Self {
+        JsonLinesDecoder {
+            buffer: Vec::new(),
+            records: VecDeque::new(),
+        }
+    }
+
+    /// Feed a chunk of the body.
+    pub(crate) fn push(&mut self, chunk: &[u8]) {
+        let mut rest = chunk;
+
+        while let Some(position) = rest.iter().position(|b| *b == b'\n') {
+            let (line, tail) = rest.split_at(position + 1);
+            rest = tail;
+
+            let record = if self.buffer.is_empty() {
+                decode(line)
+            } else {
+                self.buffer.extend_from_slice(line);
+                let record = decode(&self.buffer);
+                self.buffer.clear();
+                record
+            };
+
+            self.records.extend(record);
+        }
+
+        self.buffer.extend_from_slice(rest);
+    }
+
+    /// Take the next complete record.
+    pub(crate) fn next_record(&mut self) -> Option<Result<T, DockerError>> {
+        self.records.pop_front()
+    }
+
+    /// Decode the last line of a body which does not end with a newline.
+    pub(crate) fn finish(&mut self) -> Option<Result<T, DockerError>> {
+        let record = decode(&self.buffer);
+        self.buffer.clear();
+        record
+    }
+}
+
+/// Chunks of a body as they are received, a transport error ends the stream.
+///
+/// Like `json_lines`, dropping the stream aborts the request.
+pub(crate) fn chunks<S, E>(body: S) -> impl Stream<Item = Result<Bytes, DockerError>> + Unpin
+    where
+        S: Stream<Item = Result<Bytes, E>> + Unpin,
+        E: std::error::Error + Send + Sync + 'static
+{
+    body.map(|chunk| chunk.map_err(DockerError::connection))
+}
+
+/// Decode a newline delimited JSON body into records.
+///
+/// Records may be split across chunks. A line which can not be decoded as `T`
+/// is yielded as `DockerError::Deserialize` and the stream goes on, a transport
+/// error ends the stream.
+///
+/// The stream holds nothing but the body, so dropping it at any point aborts
+/// the request. Nothing is spawned which could keep the connection alive.
+pub(crate) fn json_lines<T, S>(body: S) -> impl Stream<Item = Result<T, DockerError>>
+    where
+        T: DeserializeOwned,
+        S: Stream<Item = Result<Bytes, DockerError>> + Unpin
+{
+    stream::unfold(Some((body, JsonLinesDecoder::new())), |state| async move {
+        let (mut body, mut decoder) = state?;
+
+        loop {
+            if let Some(record) = decoder.next_record() {
+                return Some((record, Some((body, decoder))));
+            }
+
+            match body.next().await {
+                Some(Ok(chunk)) => decoder.push(&chunk),
+                Some(Err(e)) => return Some((Err(e), None)),
+                None => {
+                    return decoder.finish().map(|record| (record, None));
+                },
+            }
+        }
+    })
+}
+
+/// Decode a logs body into lines as they are written.
+///
+/// Like `json_lines`, dropping the stream aborts the request.
+pub(crate) fn log_lines<S>(body: S, timestamps: bool) -> impl Stream<Item = Result<LogLine, DockerError>>
+    where S: Stream<Item = Result<Bytes, DockerError>> + Unpin
+{
+    stream::unfold(Some((Some(body), LogDecoder::new())), move |state| async move {
+        let (mut body, mut decoder) = state?;
+
+        loop {
+            if let Some((stream, line)) = decoder.next_line() {
+                return Some((Ok(LogLine::parse(stream, line, timestamps)), Some((body, decoder))));
+            }
+
+            let chunk = match body.as_mut() {
+                Some(body) => body.next().await,
+                None => return None,
+            };
+
+            match chunk {
+                Some(Ok(chunk)) => decoder.push(&chunk),
+                Some(Err(e)) => return Some((Err(e), None)),
+                None => {
+                    decoder.finish();
+                    body = None;
+                },
+            }
+        }
+    })
+}
+
+/// Decode a line, `None` if it is blank.
+fn decode<T>(line: &[u8]) -> Option<Result<T, DockerError>>
+    where T: DeserializeOwned
+{
+    if line.iter().all(u8::is_ascii_whitespace) {
+        return None;
+    }
+
+    Some(serde_json::from_slice(line).map_err(|source| DockerError::Deserialize {
+        body: String::from_utf8_lossy(line).trim().to_string(),
+        source,
+    }))
+}
+
+/// Decode a buffered newline delimited JSON body, failing on the first line which can not be decoded.
+pub(crate) fn parse_lines<T>(body: &[u8]) -> Result<Vec<T>, DockerError>
+    where T: DeserializeOwned
+{
+    let mut decoder = JsonLinesDecoder::new();
+    decoder.push(body);
+
+    let mut records: Vec<Result<T, DockerError>> = std::iter::from_fn(|| decoder.next_record()).collect();
+    records.extend(decoder.finish());
+    records.into_iter().collect()
+}
@@ -0,0 +1,55 @@
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+
+use hyper::{Body, Request, Response};
+
+use crate::client::DockerError;
+
+/// Future of a response returned by `Transport::execute`
+pub type TransportFuture<'a> = Pin<Box<dyn Future<Output = Result<Response<Body>, DockerError>> + Send + 'a>>;
+
+/// Transport of requests to a daemon
+///
+/// `DockerClient` builds requests and decodes responses, a transport only sends
+/// them. Timeouts, retries and the circuit breaker of the client apply on top
+/// of it. Use `DockerClient::from_transport` to plug in your own, e.g.
+/// `MockTransport` in unit tests.
+///
+/// Request URIs have scheme `http` and host `localhost`, their path is prefixed
+/// with the API version if one is set on the client.
+///
+/// # Examples
+///
+/// ```rust
+/// use docker_client::DockerClient;
+/// use docker_client::client::{Transport, TransportFuture};
+/// use hyper::{Body, Request, Response};
+///
+/// /// Daemon which has no images.
+/// #[derive(Debug)]
+/// struct NoImages;
+///
+/// impl Transport for NoImages {
+///     fn execute(&self, _request: Request<Body>) -> TransportFuture<'_> {
+///         Box::pin(async { Ok(Response::new(Body::from("[]"))) })
+///     }
+/// }
+///
+/// # #[tokio::main]
+/// async fn main() {
+///     let client = DockerClient::from_transport(NoImages);
+///
+///     assert!(client.get_image_list().await.unwrap().is_empty());
+/// }
+/// ```
+pub trait Transport: fmt::Debug + Send + Sync {
+
+    /// Send `request` and return the response of the daemon
+    ///
+    /// Failures to reach the daemon are reported as `DockerError::ConnectionRefused`,
+    /// `SocketNotFound`, `PermissionDenied`, `Timeout` or `Io`, so the retry policy
+    /// and the circuit breaker can tell them apart from errors of the daemon.
+    fn execute(&self, request: Request<Body>) -> TransportFuture<'_>;
+
+}
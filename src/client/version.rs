@@ -0,0 +1,82 @@
+use std::fmt;
+use std::str::FromStr;
+use std::convert::TryFrom;
+use serde::{Deserialize, Serialize};
+
+/// Docker engine API version, e.g. `1.41`
+///
+/// # Examples
+///
+/// ```rust
+/// use docker_client::client::ApiVersion;
+///
+/// let version: ApiVersion = "1.43".parse().unwrap();
+///
+/// assert!(version >= ApiVersion::new(1, 41));
+/// assert_eq!(version.to_string(), "1.43");
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[serde(try_from = "String", into = "String")]
+pub struct ApiVersion {
+    major: u16,
+    minor: u16,
+}
+
+impl ApiVersion {
+
+    /// Version this crate was written against
+    pub const V1_40: ApiVersion = ApiVersion::new(1, 40);
+
+    /// Newest version the models of this crate support, the upper bound of negotiation
+    pub const LATEST: ApiVersion = ApiVersion::new(1, 44);
+
+    /// Creates a new `ApiVersion`
+    pub const fn new(major: u16, minor: u16) -> Self {
+        ApiVersion { major, minor }
+    }
+
+    /// Return major version
+    pub fn major(&self) -> u16 {
+        self.major
+    }
+
+    /// Return minor version
+    pub fn minor(&self) -> u16 {
+        self.minor
+    }
+
+}
+
+impl FromStr for ApiVersion {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim().trim_start_matches('v');
+        let (major, minor) = s.split_once('.').ok_or_else(|| format!("invalid API version `{}`", s))?;
+
+        Ok(ApiVersion {
+            major: major.parse().map_err(|_| format!("invalid API version `{}`", s))?,
+            minor: minor.parse().map_err(|_| format!("invalid API version `{}`", s))?,
+        })
+    }
+}
+
+impl TryFrom<String> for ApiVersion {
+    type Error = String;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl From<ApiVersion> for String {
+    fn from(version: ApiVersion) -> Self {
+        version.to_string()
+    }
+}
+
+impl fmt::Display for ApiVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}", self.major, self.minor)
+    }
+}
@@ -0,0 +1,332 @@
+//!
+//! Container attach options.
+//!
+//! The module provides [AttachOptionsBuilder](struct.AttachOptionsBuilder.html) and [AttachOptions](struct.AttachOptions.html) types
+//! used to attach to a container.
+//!
+//! By default the live output of stdout and stderr is streamed, like `docker attach`.
+//! Enable `logs` to replay the output written before attaching.
+//!
+//! [AttachedContainer](struct.AttachedContainer.html) is the connection returned by
+//! `DockerClient::attach_container`: stdin is written to it and output is read from it
+//! as a stream of [OutputFrame](struct.OutputFrame.html).
+//!
+//! # Examples
+//!
+//! ```rust
+//! use docker_client::container::attach::AttachOptions;
+//!
+//! let options = AttachOptions::container("test")
+//!     .logs(true)
+//!     .stdin(true)
+//!     .detach_keys("ctrl-p,ctrl-q")
+//!     .build();
+//!
+//! assert_eq!(
+//!     options.get_path(),
+//!     "/containers/test/attach?logs=true&stream=true&stdin=true&stdout=true&stderr=true&detachKeys=ctrl-p%2Cctrl-q"
+//! );
+//! ```
+
+use std::io;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures::Stream;
+use hyper::body::Bytes;
+use hyper::upgrade::Upgraded;
+use tokio::io::{AsyncRead, AsyncWrite, ReadBuf, ReadHalf, WriteHalf};
+
+use crate::client::DockerError;
+use crate::container::ContainerRefId;
+use crate::container::logs::{FrameDecoder, LogStream};
+use crate::additionals::query::encode;
+
+/// Attach options builder
+#[derive(Debug)]
+pub struct AttachOptionsBuilder {
+
+    id: String,
+
+    logs: bool,
+
+    stream: bool,
+
+    stdin: bool,
+
+    stdout: bool,
+
+    stderr: bool,
+
+    detach_keys: Option<String>,
+
+}
+
+impl AttachOptionsBuilder {
+
+    /// Creates a new instance of `AttachOptionsBuilder` for container `id`, streaming stdout and stderr.
+    pub fn container<T>(id: T) -> Self
+        where T: Into<ContainerRefId>
+    {
+        AttachOptionsBuilder {
+            id: String::from(id.into()),
+            logs: false,
+            stream: true,
+            stdin: false,
+            stdout: true,
+            stderr: true,
+            detach_keys: None,
+        }
+    }
+
+    /// Replay output written before attaching
+    pub fn logs(mut self, v: bool) -> Self {
+        self.logs = v;
+
+        self
+    }
+
+    /// Stream output written after attaching
+    pub fn stream(mut self, v: bool) -> Self {
+        self.stream = v;
+
+        self
+    }
+
+    /// Attach to stdin
+    pub fn stdin(mut self, v: bool) -> Self {
+        self.stdin = v;
+
+        self
+    }
+
+    /// Attach to stdout
+    pub fn stdout(mut self, v: bool) -> Self {
+        self.stdout = v;
+
+        self
+    }
+
+    /// Attach to stderr
+    pub fn stderr(mut self, v: bool) -> Self {
+        self.stderr = v;
+
+        self
+    }
+
+    /// Override the key sequence for detaching, e.g. `ctrl-p,ctrl-q`
+    pub fn detach_keys<T>(mut self, keys: T) -> Self
+        where T: Into<String>
+    {
+        self.detach_keys = Some(keys.into());
+
+        self
+    }
+
+    /// Build `AttachOptions` from `AttachOptionsBuilder`
+    pub fn build(self) -> AttachOptions {
+        AttachOptions {
+            id: self.id,
+            logs: self.logs,
+            stream: self.stream,
+            stdin: self.stdin,
+            stdout: self.stdout,
+            stderr: self.stderr,
+            detach_keys: self.detach_keys,
+        }
+    }
+
+}
+
+/// Attach options
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AttachOptions {
+
+    id: String,
+
+    logs: bool,
+
+    stream: bool,
+
+    stdin: bool,
+
+    stdout: bool,
+
+    stderr: bool,
+
+    detach_keys: Option<String>,
+
+}
+
+impl AttachOptions {
+
+    /// Creates a new instance of `AttachOptionsBuilder` for container `id`.
+    pub fn container<T>(id: T) -> AttachOptionsBuilder
+        where T: Into<ContainerRefId>
+    {
+        AttachOptionsBuilder::container(id)
+    }
+
+    /// Return id or name of the container
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Return `true` if stdin is attached
+    pub fn stdin(&self) -> bool {
+        self.stdin
+    }
+
+    /// Return `true` if output written after attaching is streamed
+    pub fn stream(&self) -> bool {
+        self.stream
+    }
+
+    /// Return path for request
+    pub fn get_path(&self) -> String {
+        let mut path = format!(
+            "/containers/{}/attach?logs={}&stream={}&stdin={}&stdout={}&stderr={}",
+            self.id, self.logs, self.stream, self.stdin, self.stdout, self.stderr
+        );
+
+        if let Some(keys) = self.detach_keys.as_ref() {
+            path.push_str(format!("&detachKeys={}", encode(keys)).as_str());
+        }
+
+        path
+    }
+
+}
+
+/// Output written by an attached container
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutputFrame {
+
+    stream: LogStream,
+
+    data: Bytes,
+
+}
+
+impl OutputFrame {
+
+    /// Return stream the output was written to, always stdout for TTY containers
+    pub fn stream(&self) -> LogStream {
+        self.stream
+    }
+
+    /// Return output as written, not split into lines
+    pub fn data(&self) -> &Bytes {
+        &self.data
+    }
+
+}
+
+/// Output of an attached container, see `AttachedContainer::into_split`
+///
+/// The stream ends when the container closes its output, e.g. when it exits.
+#[derive(Debug)]
+pub struct AttachOutput {
+
+    reader: ReadHalf<Upgraded>,
+
+    decoder: FrameDecoder,
+
+    done: bool,
+
+}
+
+impl Stream for AttachOutput {
+    type Item = Result<OutputFrame, DockerError>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        let mut buffer = [0u8; 8192];
+
+        loop {
+            if let Some((stream, data)) = this.decoder.next_frame() {
+                let frame = OutputFrame { stream: LogStream::from_frame(stream), data: Bytes::from(data) };
+                return Poll::Ready(Some(Ok(frame)));
+            }
+
+            if this.done {
+                return Poll::Ready(None);
+            }
+
+            let mut read = ReadBuf::new(&mut buffer);
+            match Pin::new(&mut this.reader).poll_read(cx, &mut read) {
+                Poll::Pending => return Poll::Pending,
+                Poll::Ready(Err(e)) => {
+                    this.done = true;
+                    return Poll::Ready(Some(Err(DockerError::connection(e))));
+                },
+                Poll::Ready(Ok(())) if read.filled().is_empty() => {
+                    this.done = true;
+                    this.decoder.finish();
+                },
+                Poll::Ready(Ok(())) => this.decoder.push(read.filled()),
+            }
+        }
+    }
+}
+
+/// Connection to an attached container, see `DockerClient::attach_container`
+///
+/// Bytes written are sent to stdin of the container, shutting it down closes stdin.
+/// Output is read as a stream of `OutputFrame`, multiplexed frames of non-TTY
+/// containers are split by stream. Dropping it detaches from the container.
+#[derive(Debug)]
+pub struct AttachedContainer {
+
+    stdin: WriteHalf<Upgraded>,
+
+    output: AttachOutput,
+
+}
+
+impl AttachedContainer {
+
+    pub(crate) fn new(connection: Upgraded) -> Self {
+        let (reader, stdin) = tokio::io::split(connection);
+
+        AttachedContainer {
+            stdin,
+            output: AttachOutput { reader, decoder: FrameDecoder::new(), done: false },
+        }
+    }
+
+    /// Split into stdin and output, to write and read from separate tasks
+    pub fn into_split(self) -> (WriteHalf<Upgraded>, AttachOutput) {
+        (self.stdin, self.output)
+    }
+
+    /// Return the raw connection, e.g. for `terminal::run`
+    ///
+    /// Output already read from the connection but not yet returned by the stream is lost.
+    pub fn into_inner(self) -> Upgraded {
+        self.output.reader.unsplit(self.stdin)
+    }
+
+}
+
+impl Stream for AttachedContainer {
+    type Item = Result<OutputFrame, DockerError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Pin::new(&mut self.output).poll_next(cx)
+    }
+}
+
+impl AsyncWrite for AttachedContainer {
+    fn poll_write(mut self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &[u8]) -> Poll<io::Result<usize>> {
+        Pin::new(&mut self.stdin).poll_write(cx, buf)
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.stdin).poll_flush(cx)
+    }
+
+    fn poll_shutdown(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.stdin).poll_shutdown(cx)
+    }
+}
@@ -0,0 +1,21 @@
+//! Checkpoint module
+
+use serde::{Deserialize, Serialize};
+
+/// Checkpoint of a container, an experimental feature of the daemon
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct Checkpoint {
+
+    #[serde(rename = "Name")]
+    name: String,
+
+}
+
+impl Checkpoint {
+
+    /// Return name of the checkpoint
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+}
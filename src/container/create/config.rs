@@ -5,6 +5,7 @@ use crate::container::HealthCheck;
 
 use crate::additionals::serde_helpers::*;
 use crate::additionals::host::host_config::HostConfig;
+use crate::client::ApiVersion;
 
 #[derive(Debug, Default)]
 pub struct ConfigBuilder {
@@ -536,7 +537,7 @@ impl ConfigBuilder {
 }
 
 /// A struct of metadata to create a container.
-#[derive(Debug, Serialize, Deserialize, Default, Clone)]
+#[derive(Debug, Serialize, Deserialize, Default, Clone, PartialEq, Eq)]
 pub struct Config {
 
     #[serde(skip_serializing_if = "Option::is_none", rename = "Hostname")]
@@ -557,7 +558,7 @@ pub struct Config {
     #[serde(skip_serializing_if = "Option::is_none", rename = "AttachStderr")]
     attach_stderr: Option<bool>,
 
-    #[serde(skip_serializing_if = "HashMap::is_empty", rename = "ExposedPorts", default)]
+    #[serde(skip_serializing_if = "HashMap::is_empty", rename = "ExposedPorts", deserialize_with = "nullable_priority_hash", default)]
     exposed_ports: HashMap<String, EmptyObject>,
 
     #[serde(skip_serializing_if = "Option::is_none", rename = "Tty")]
@@ -569,22 +570,22 @@ pub struct Config {
     #[serde(skip_serializing_if = "Option::is_none", rename = "StdinOnce")]
     stdin_once: Option<bool>,
 
-    #[serde(skip_serializing_if = "Vec::is_empty", rename = "Env", deserialize_with = "nullable_priority_vec")]
+    #[serde(skip_serializing_if = "Vec::is_empty", rename = "Env", deserialize_with = "nullable_priority_vec", default)]
     env: Vec<String>,
 
-    #[serde(skip_serializing_if = "HashMap::is_empty", rename = "Labels")]
+    #[serde(skip_serializing_if = "HashMap::is_empty", rename = "Labels", deserialize_with = "nullable_default", default)]
     labels: HashMap<String, String>,
 
-    #[serde(skip_serializing_if = "Vec::is_empty", rename = "Cmd", deserialize_with = "nullable_priority_vec")]
+    #[serde(skip_serializing_if = "Vec::is_empty", rename = "Cmd", deserialize_with = "nullable_priority_vec", default)]
     cmd: Vec<String>,
 
-    #[serde(skip_serializing_if = "Vec::is_empty", rename = "Entrypoint", deserialize_with = "nullable_priority_vec")]
+    #[serde(skip_serializing_if = "Vec::is_empty", rename = "Entrypoint", deserialize_with = "nullable_priority_vec", default)]
     entry_point: Vec<String>,
 
     #[serde(skip_serializing_if = "Option::is_none", rename = "Image")]
     image: Option<String>,
 
-    #[serde(skip_serializing_if = "HashMap::is_empty", rename = "Volumes", deserialize_with = "nullable_priority_hash")]
+    #[serde(skip_serializing_if = "HashMap::is_empty", rename = "Volumes", deserialize_with = "nullable_priority_hash", default)]
     volumes: HashMap<String, EmptyObject>,
 
     #[serde(skip_serializing_if = "Option::is_none", rename = "Healthcheck")]
@@ -621,4 +622,29 @@ impl Config {
         builder
     }
 
+    /// Return image of the container
+    pub fn image(&self) -> Option<&str> {
+        self.image.as_deref()
+    }
+
+    /// Return labels of the container
+    pub fn labels(&self) -> &HashMap<String, String> {
+        &self.labels
+    }
+
+    /// Return config with label `key=value` added
+    pub(crate) fn with_label(mut self, key: String, value: String) -> Self {
+        self.labels.insert(key, value);
+
+        self
+    }
+
+    /// Return minimum API version required by the set fields
+    pub(crate) fn required_api_version(&self) -> Option<ApiVersion> {
+        let health_check = self.health_check.as_ref().and_then(HealthCheck::required_api_version);
+        let host_config = self.host_config.as_ref().and_then(HostConfig::required_api_version);
+
+        health_check.max(host_config)
+    }
+
 }
\ No newline at end of file
@@ -41,6 +41,7 @@ impl CreateBuilder {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Create {
 
     name: String,
@@ -66,6 +67,16 @@ impl Create {
         path
     }
 
+    /// Return the request creating a container named `name`
+    pub(crate) fn with_name(self, name: String) -> Self {
+        Create { name, ..self }
+    }
+
+    /// Return minimum API version required by the request
+    pub(crate) fn required_api_version(&self) -> Option<crate::client::ApiVersion> {
+        self.config.required_api_version()
+    }
+
     pub fn body(&self) -> String {
         serde_json::to_string(&self.config).unwrap()
     }
@@ -1,19 +1,27 @@
-use serde::{Deserialize};
+use serde::{Deserialize, Serialize};
+use crate::additionals::serde_helpers::nullable_priority_vec;
+use crate::container::ContainerId;
 
-#[derive(Deserialize, Debug)]
+/// Response of container creation
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 pub struct CreatedContainer {
 
-    #[serde(rename(deserialize = "Id"))]
-    id: String,
+    #[serde(rename = "Id")]
+    id: ContainerId,
 
-    #[serde(rename(deserialize = "Warnings"))]
+    #[serde(rename = "Warnings", deserialize_with = "nullable_priority_vec", default)]
     warnings: Vec<String>,
 }
 
 impl CreatedContainer {
 
-    pub fn id(&self) -> &String {
+    pub fn id(&self) -> &ContainerId {
         &self.id
     }
 
+    /// Return warnings reported by the daemon, e.g. about ignored resource limits
+    pub fn warnings(&self) -> &Vec<String> {
+        &self.warnings
+    }
+
 }
\ No newline at end of file
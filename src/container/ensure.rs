@@ -0,0 +1,49 @@
+//! Ensure module
+
+use crate::container::ContainerId;
+
+/// Action taken by `DockerClient::ensure_running`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EnsureAction {
+
+    /// Container did not exist, it was created and started
+    Created,
+
+    /// Container existed but was stopped, it was started
+    Started,
+
+    /// Container was paused, it was unpaused
+    Unpaused,
+
+    /// Container was already running, nothing was done
+    AlreadyRunning,
+
+}
+
+/// Container ensured to run
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EnsuredContainer {
+
+    id: ContainerId,
+
+    action: EnsureAction,
+
+}
+
+impl EnsuredContainer {
+
+    pub(crate) fn new(id: ContainerId, action: EnsureAction) -> Self {
+        EnsuredContainer { id, action }
+    }
+
+    /// Return ID of the container
+    pub fn id(&self) -> &ContainerId {
+        &self.id
+    }
+
+    /// Return action taken to make the container run
+    pub fn action(&self) -> EnsureAction {
+        self.action
+    }
+
+}
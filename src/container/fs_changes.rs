@@ -1,12 +1,12 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 /// `FSChanges` struct.
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 pub struct FSChanges {
-    #[serde(rename(deserialize = "Path"))]
+    #[serde(rename = "Path")]
     path: String,
 
-    #[serde(rename(deserialize = "Kind"))]
+    #[serde(rename = "Kind")]
     kind: i32,
 }
 
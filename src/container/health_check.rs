@@ -38,6 +38,9 @@
 //! }
 //! ```
 use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use crate::additionals::duration::GoDuration;
+use crate::client::ApiVersion;
 
 /// `HealthCheckBuilder` struct
 #[derive(Debug, Default)]
@@ -46,14 +49,15 @@ pub struct HealthCheckBuilder {
     interval: Option<u64>,
     timeout: Option<u64>,
     retries: Option<u64>,
-    start_period: Option<u64>
+    start_period: Option<u64>,
+    start_interval: Option<u64>
 }
 
 /// `HealthCheck` struct.
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 pub struct HealthCheck {
 
-    #[serde(skip_serializing_if = "Vec::is_empty", rename = "Test")]
+    #[serde(skip_serializing_if = "Vec::is_empty", rename = "Test", default)]
     test: Vec<String>,
 
     #[serde(skip_serializing_if = "Option::is_none", rename = "Interval")]
@@ -65,8 +69,11 @@ pub struct HealthCheck {
     #[serde(skip_serializing_if = "Option::is_none", rename = "Retries")]
     retries: Option<u64>,
 
-    #[serde(skip_serializing_if = "Option::is_none", rename = "StartPeriod")]
-    start_period: Option<u64>
+    #[serde(skip_serializing_if = "Option::is_none", rename = "StartPeriod", default)]
+    start_period: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none", rename = "StartInterval", default)]
+    start_interval: Option<u64>
 }
 
 impl HealthCheckBuilder {
@@ -166,13 +173,21 @@ impl HealthCheckBuilder {
     /// # use docker_client::container::HealthCheck;
     /// let builder = HealthCheck::new().build();
     /// ```
+    /// Set interval between probes during the start period in nanoseconds, requires API 1.44
+    pub fn start_interval(mut self, interval: Option<u64>) -> Self {
+        self.start_interval = interval;
+
+        self
+    }
+
     pub fn build(self) -> HealthCheck {
         HealthCheck {
             test: self.test,
             interval: self.interval,
             timeout: self.timeout,
             retries: self.retries,
-            start_period: self.start_period
+            start_period: self.start_period,
+            start_interval: self.start_interval
         }
     }
 }
@@ -184,4 +199,38 @@ impl HealthCheck {
         HealthCheckBuilder::default()
     }
 
-}
\ No newline at end of file
+    /// Return interval between probes, e.g. `30s` as shown by `docker inspect`
+    pub fn interval(&self) -> Option<GoDuration> {
+        self.interval.map(nanos_to_duration)
+    }
+
+    /// Return time after which a probe is considered hung
+    pub fn timeout(&self) -> Option<GoDuration> {
+        self.timeout.map(nanos_to_duration)
+    }
+
+    /// Return number of consecutive failures needed to report unhealthy
+    pub fn retries(&self) -> Option<u64> {
+        self.retries
+    }
+
+    /// Return time for the container to start before failures count
+    pub fn start_period(&self) -> Option<GoDuration> {
+        self.start_period.map(nanos_to_duration)
+    }
+
+    /// Return interval between probes during the start period in nanoseconds
+    pub fn start_interval(&self) -> Option<u64> {
+        self.start_interval
+    }
+
+    /// Return minimum API version required by the set fields
+    pub(crate) fn required_api_version(&self) -> Option<ApiVersion> {
+        self.start_interval.map(|_| ApiVersion::new(1, 44))
+    }
+
+}
+
+fn nanos_to_duration(nanos: u64) -> GoDuration {
+    GoDuration::from(Duration::from_nanos(nanos))
+}
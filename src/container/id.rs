@@ -0,0 +1,139 @@
+//! Container identifiers
+
+use std::fmt;
+use std::ops::Deref;
+use serde::{Deserialize, Serialize};
+
+/// Full container ID as returned by the daemon
+///
+/// # Examples
+///
+/// ```rust
+/// use docker_client::container::ContainerId;
+///
+/// let id = ContainerId::from("4fa6e0f0c6786287e131c3852c58a2e01cc697a68231826813597e4994f1d6e2".to_string());
+///
+/// assert_eq!(id.short(), "4fa6e0f0c678");
+/// assert_eq!(id.len(), 64);
+/// ```
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[serde(transparent)]
+pub struct ContainerId(String);
+
+impl ContainerId {
+
+    /// Return ID as string slice
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Return first 12 characters of the ID, like `docker ps` shows
+    pub fn short(&self) -> &str {
+        short(&self.0)
+    }
+
+    /// Return inner string
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+
+}
+
+impl From<String> for ContainerId {
+    fn from(id: String) -> Self {
+        ContainerId(id)
+    }
+}
+
+impl Deref for ContainerId {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for ContainerId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for ContainerId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// Container ID or name accepted by container endpoints
+///
+/// Built from `&str`, `String` or `ContainerId`, but not from `ImageId`,
+/// so an image cannot be passed where a container is expected.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ContainerRefId(String);
+
+impl ContainerRefId {
+
+    /// Return ID or name as string slice
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+}
+
+impl From<&str> for ContainerRefId {
+    fn from(id: &str) -> Self {
+        ContainerRefId(id.to_string())
+    }
+}
+
+impl From<String> for ContainerRefId {
+    fn from(id: String) -> Self {
+        ContainerRefId(id)
+    }
+}
+
+impl From<&String> for ContainerRefId {
+    fn from(id: &String) -> Self {
+        ContainerRefId(id.clone())
+    }
+}
+
+impl From<ContainerId> for ContainerRefId {
+    fn from(id: ContainerId) -> Self {
+        ContainerRefId(id.0)
+    }
+}
+
+impl From<&ContainerId> for ContainerRefId {
+    fn from(id: &ContainerId) -> Self {
+        ContainerRefId(id.0.clone())
+    }
+}
+
+impl From<ContainerRefId> for String {
+    fn from(id: ContainerRefId) -> Self {
+        id.0
+    }
+}
+
+impl Deref for ContainerRefId {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for ContainerRefId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+pub(crate) fn short(id: &str) -> &str {
+    match id.char_indices().nth(12) {
+        Some((index, _)) => &id[..index],
+        None => id,
+    }
+}
@@ -1,4 +1,6 @@
+use crate::container::ContainerRefId;
 
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Inspect {
 
     id: String,
@@ -9,13 +11,24 @@ pub struct Inspect {
 
 impl Inspect {
 
-    pub fn container(_id: String) -> Self {
+    pub fn container<T>(id: T) -> Self
+        where T: Into<ContainerRefId>
+    {
         Inspect {
-            id: _id,
+            id: String::from(id.into()),
             size: None
         }
     }
 
+    /// Return size of the container fields `SizeRw` and `SizeRootFs`
+    pub fn with_size(mut self, v: bool) -> Self {
+        self.size = Some(v);
+
+        self
+    }
+
+    /// Set `size` in place
+    #[deprecated(note = "use `with_size`, which consumes and returns the request")]
     pub fn size(&mut self, v: bool) {
         self.size = Some(v);
     }
@@ -26,4 +39,4 @@ impl Inspect {
 
     }
 
-}
\ No newline at end of file
+}
@@ -1,10 +1,16 @@
 use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
 use crate::Config;
 use crate::additionals::network::NetworkSettings;
-use serde::Deserialize;
+use crate::additionals::host::host_config::HostConfig;
+use serde::{Deserialize, Serialize};
+use crate::additionals::mount::MountType;
 use crate::additionals::serde_helpers::*;
+use crate::container::{ContainerId, ContainerState};
+use crate::image::ImageId;
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
 pub struct HealthCheckResult {
 
     #[serde(rename = "Start")]
@@ -21,11 +27,110 @@ pub struct HealthCheckResult {
 
 }
 
-#[derive(Debug, Deserialize)]
+impl HealthCheckResult {
+
+    /// Return start time of the probe as RFC 3339 string
+    pub fn start(&self) -> &str {
+        &self.start
+    }
+
+    /// Return end time of the probe as RFC 3339 string
+    pub fn end(&self) -> &str {
+        &self.end
+    }
+
+    /// Return exit code of the probe, `0` is healthy
+    pub fn exit_code(&self) -> i32 {
+        self.exit_code
+    }
+
+    /// Return output of the probe
+    pub fn output(&self) -> &str {
+        &self.output
+    }
+
+}
+
+/// Health status of a container
+///
+/// Unknown statuses reported by newer daemons are kept in `Other`.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[serde(from = "String", into = "String")]
+pub enum HealthStatus {
+
+    /// Container is still within its start period
+    Starting,
+
+    /// Last probes succeeded
+    Healthy,
+
+    /// Failing streak reached the retries threshold
+    Unhealthy,
+
+    /// No health check is configured
+    #[default]
+    None,
+
+    /// Status unknown to this crate
+    Other(String),
+
+}
+
+impl HealthStatus {
+
+    /// Return status as string slice
+    pub fn as_str(&self) -> &str {
+        match self {
+            HealthStatus::Starting => "starting",
+            HealthStatus::Healthy => "healthy",
+            HealthStatus::Unhealthy => "unhealthy",
+            HealthStatus::None => "none",
+            HealthStatus::Other(status) => status.as_str(),
+        }
+    }
+
+}
+
+impl From<String> for HealthStatus {
+    fn from(status: String) -> Self {
+        match status.as_str() {
+            "starting" => HealthStatus::Starting,
+            "healthy" => HealthStatus::Healthy,
+            "unhealthy" => HealthStatus::Unhealthy,
+            "none" | "" => HealthStatus::None,
+            _ => HealthStatus::Other(status),
+        }
+    }
+}
+
+impl From<HealthStatus> for String {
+    fn from(status: HealthStatus) -> Self {
+        match status {
+            HealthStatus::Other(status) => status,
+            status => status.as_str().to_string(),
+        }
+    }
+}
+
+impl FromStr for HealthStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(HealthStatus::from(s.to_string()))
+    }
+}
+
+impl fmt::Display for HealthStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
 pub struct Health {
 
     #[serde(rename = "Status")]
-    status: String,
+    status: HealthStatus,
 
     #[serde(rename = "FailingStreak")]
     failing_streak: i32,
@@ -35,11 +140,30 @@ pub struct Health {
 
 }
 
-#[derive(Debug, Deserialize)]
+impl Health {
+
+    /// Return health status
+    pub fn status(&self) -> HealthStatus {
+        self.status.clone()
+    }
+
+    /// Return count of consecutive failed probes
+    pub fn failing_streak(&self) -> i32 {
+        self.failing_streak
+    }
+
+    /// Return recent probe results, oldest first
+    pub fn log(&self) -> &[HealthCheckResult] {
+        &self.log
+    }
+
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
 pub struct State {
 
     #[serde(rename = "Status")]
-    status: String,
+    status: ContainerState,
 
     #[serde(rename = "Running")]
     running: bool,
@@ -73,50 +197,168 @@ pub struct State {
 
 }
 
-#[derive(Debug, Deserialize)]
+impl State {
+
+    /// Return status, e.g. `running` or `exited`
+    pub fn status(&self) -> &ContainerState {
+        &self.status
+    }
+
+    /// Return `true` if the container is running
+    pub fn running(&self) -> bool {
+        self.running
+    }
+
+    /// Return `true` if the container is paused
+    pub fn paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Return `true` if the container is restarting
+    pub fn restarting(&self) -> bool {
+        self.restarting
+    }
+
+    /// Return `true` if the container was killed by the OOM killer
+    pub fn oom_killed(&self) -> bool {
+        self.oom_killed
+    }
+
+    /// Return `true` if the container is dead
+    pub fn dead(&self) -> bool {
+        self.dead
+    }
+
+    /// Return pid of the main process
+    pub fn pid(&self) -> i32 {
+        self.pid
+    }
+
+    /// Return exit code of the last run
+    pub fn exit_code(&self) -> i32 {
+        self.exit_code
+    }
+
+    /// Return start time as RFC 3339 string
+    pub fn started_at(&self) -> &str {
+        &self.started_at
+    }
+
+    /// Return finish time as RFC 3339 string
+    pub fn finished_at(&self) -> &str {
+        &self.finished_at
+    }
+
+    /// Return start time as `DateTime<Utc>`, `None` if never started
+    #[cfg(feature = "chrono")]
+    pub fn started_at_datetime(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        crate::additionals::timestamp::parse_rfc3339(&self.started_at)
+    }
+
+    /// Return finish time as `DateTime<Utc>`, `None` if never finished
+    #[cfg(feature = "chrono")]
+    pub fn finished_at_datetime(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        crate::additionals::timestamp::parse_rfc3339(&self.finished_at)
+    }
+
+    /// Return health check state, `None` if no health check is configured
+    pub fn health(&self) -> Option<&Health> {
+        self.health.as_ref()
+    }
+
+    /// Return health status, `HealthStatus::None` if no health check is configured
+    pub fn health_status(&self) -> HealthStatus {
+        self.health.as_ref().map(Health::status).unwrap_or_default()
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
 pub struct GraphDriverData {
 
     #[serde(rename = "Name")]
     name: String,
 
-    #[serde(rename = "Data")]
+    #[serde(rename = "Data", deserialize_with = "nullable_default", default)]
     data: HashMap<String, String>
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
 pub struct MountPoint {
 
-    #[serde(rename = "Type")]
-    mount_type: String,
+    #[serde(rename = "Type", default)]
+    mount_type: MountType,
 
-    #[serde(rename = "Name")]
+    #[serde(rename = "Name", default)]
     name: String,
 
-    #[serde(rename = "Source")]
+    #[serde(rename = "Source", default)]
     source: String,
 
     #[serde(rename = "Destination")]
     destination: String,
 
-    #[serde(rename = "Driver")]
+    #[serde(rename = "Driver", default)]
     driver: String,
 
-    #[serde(rename = "Mode")]
+    #[serde(rename = "Mode", default)]
     mode: String,
 
-    #[serde(rename = "RW")]
+    #[serde(rename = "RW", default)]
     rw: bool,
 
-    #[serde(rename = "Propagation")]
+    #[serde(rename = "Propagation", default)]
     propagation: String
 
 }
 
-#[derive(Debug, Deserialize)]
+impl MountPoint {
+
+    /// Return mount type
+    pub fn mount_type(&self) -> &MountType {
+        &self.mount_type
+    }
+
+    /// Return name of the volume, empty for bind mounts
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Return source path on the host
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// Return destination path in the container
+    pub fn destination(&self) -> &str {
+        &self.destination
+    }
+
+    /// Return volume driver, empty for bind mounts
+    pub fn driver(&self) -> &str {
+        &self.driver
+    }
+
+    /// Return mount mode, e.g. `z` or `ro`
+    pub fn mode(&self) -> &str {
+        &self.mode
+    }
+
+    /// Return `true` if the mount is writable
+    pub fn rw(&self) -> bool {
+        self.rw
+    }
+
+    /// Return propagation mode of a bind mount
+    pub fn propagation(&self) -> &str {
+        &self.propagation
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
 pub struct ContainerInfo {
 
     #[serde(rename = "Id")]
-    id: String,
+    id: ContainerId,
 
     #[serde(rename = "Created")]
     created: String,
@@ -131,7 +373,7 @@ pub struct ContainerInfo {
     state: State,
 
     #[serde(rename = "Image")]
-    image: String,
+    image: ImageId,
 
     #[serde(rename = "ResolvConfPath")]
     resolv_conf_path: String,
@@ -154,7 +396,7 @@ pub struct ContainerInfo {
     #[serde(rename = "Driver")]
     driver: String,
 
-    #[serde(rename = "Platform")]
+    #[serde(rename = "Platform", default)]
     platform: String,
 
     #[serde(rename = "MountLabel")]
@@ -184,6 +426,71 @@ pub struct ContainerInfo {
     #[serde(rename = "Config")]
     config: Config,
 
+    #[serde(rename = "HostConfig", default)]
+    host_config: Option<HostConfig>,
+
     #[serde(rename = "NetworkSettings")]
-    network_settings: NetworkSettings
+    network_settings: NetworkSettings,
+
+    #[serde(flatten, skip_serializing)]
+    extra: HashMap<String, serde_json::Value>,
+}
+
+impl ContainerInfo {
+
+    /// Return id of the container
+    pub fn id(&self) -> &ContainerId {
+        &self.id
+    }
+
+    /// Return id of the image the container was created from
+    pub fn image(&self) -> &ImageId {
+        &self.image
+    }
+
+    /// Return name of the container
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Return configuration the container was created with, merged with the image defaults
+    pub fn config(&self) -> &Config {
+        &self.config
+    }
+
+    /// Return host configuration
+    pub fn host_config(&self) -> Option<&HostConfig> {
+        self.host_config.as_ref()
+    }
+
+    /// Return creation time as RFC 3339 string
+    pub fn created(&self) -> &str {
+        &self.created
+    }
+
+    /// Return creation time as `DateTime<Utc>`
+    #[cfg(feature = "chrono")]
+    pub fn created_datetime(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        crate::additionals::timestamp::parse_rfc3339(&self.created)
+    }
+
+    /// Return state of the container
+    pub fn state(&self) -> &State {
+        &self.state
+    }
+
+    /// Return mounts of the container
+    pub fn mounts(&self) -> &Vec<MountPoint> {
+        &self.mounts
+    }
+
+    /// Return networks and published ports of the container
+    pub fn network_settings(&self) -> &NetworkSettings {
+        &self.network_settings
+    }
+
+    /// Return fields unknown to this crate, e.g. added by newer daemons
+    pub fn extra(&self) -> &HashMap<String, serde_json::Value> {
+        &self.extra
+    }
 }
@@ -37,6 +37,8 @@
 //! }
 //! ```
 
+use crate::container::ContainerRefId;
+
 
 /// A Killer builder.
 ///
@@ -48,7 +50,7 @@ pub struct KillerBuilder {
 }
 
 /// Represents a Killer.
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Killer {
     id: String,
     signal: Option<String>,
@@ -103,9 +105,9 @@ impl KillerBuilder {
     ///     .build();
     /// ```
     pub fn id<T>(mut self, id: T) -> Self
-        where T: Into<String>
+        where T: Into<ContainerRefId>
     {
-        self.id = id.into();
+        self.id = String::from(id.into());
 
         self
     }
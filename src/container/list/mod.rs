@@ -1,7 +1,6 @@
 mod response;
 mod request;
-mod filters;
 
 pub use response::*;
 pub use request::*;
-pub use filters::*;
\ No newline at end of file
+pub use crate::additionals::filters::{Filters, FiltersBuilder};
\ No newline at end of file
@@ -53,7 +53,7 @@ impl RequestBuilder {
 
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Request {
 
     all: Option<bool>,
@@ -71,19 +71,6 @@ impl Request {
         RequestBuilder::default()
     }
 
-    fn percent_encoded(value: String) -> String {
-        let mut result = String::new();
-
-        for char in value.chars() {
-            match char {
-                '"' => { result.push_str("%22"); },
-                _ => { result.push(char); }
-            };
-        }
-
-        result
-    }
-
     pub fn get_path(&self) -> String {
         let mut path = "/containers/json?".to_string();
 
@@ -97,15 +84,8 @@ impl Request {
             path.push_str(format!("size={}&", self.size.unwrap()).as_str());
         }
 
-        if !self.filters.label().is_empty() {
-            path.push_str(
-                format!(
-                    "filters={}&",
-                    Request::percent_encoded(
-                        serde_json::to_string(&self.filters.clone()).unwrap()
-                    )
-                ).as_str()
-            );
+        if let Some(filters) = self.filters.query() {
+            path.push_str(format!("{}&", filters).as_str());
         }
 
         path.pop();
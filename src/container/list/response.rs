@@ -2,82 +2,91 @@
 use std::collections::HashMap;
 use serde::{Deserialize, Serialize};
 use crate::additionals::network::NetworkSettings;
-use crate::additionals::mount::Mount;
+use crate::additionals::host::host_config::PortBinding;
+use crate::additionals::port::{ExposedPort, PortMap, host_port_for};
+use crate::container::inspect::MountPoint;
+use crate::additionals::serde_helpers::nullable_default;
+use crate::container::{ContainerId, ContainerState};
+use crate::image::ImageId;
 
 
 // response
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
 struct PortInfo {
 
-    #[serde(rename(deserialize = "IP"))]
+    #[serde(rename = "IP", default)]
     ip: String,
 
-    #[serde(rename(deserialize = "PrivatePort"))]
+    #[serde(rename = "PrivatePort")]
     private_port: u16,
 
-    #[serde(rename(deserialize = "PublicPort"))]
+    #[serde(rename = "PublicPort", default)]
     public_port: u16,
 
-    #[serde(rename(deserialize = "Type"))]
+    #[serde(rename = "Type")]
     port_type: String
 
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
 struct HostConfig {
 
-    #[serde(rename(deserialize = "NetworkMode"))]
+    #[serde(rename = "NetworkMode")]
     network_mode: String
 
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
 pub struct ShortContainerInfo {
 
-    #[serde(rename(deserialize = "Id"))]
-    id: String,
+    #[serde(rename = "Id")]
+    id: ContainerId,
 
-    #[serde(rename(deserialize = "Names"))]
+    #[serde(rename = "Names")]
     names: Vec<String>,
 
-    #[serde(rename(deserialize = "Image"))]
+    #[serde(rename = "Image")]
     image: String,
 
-    #[serde(rename(deserialize = "ImageID"))]
-    image_id: String,
+    #[serde(rename = "ImageID")]
+    image_id: ImageId,
 
-    #[serde(rename(deserialize = "Command"))]
+    #[serde(rename = "Command")]
     command: String,
 
-    #[serde(rename(deserialize = "Created"))]
+    #[serde(rename = "Created")]
     created: u64,
 
-    #[serde(rename(deserialize = "Ports"))]
+    #[serde(rename = "Ports")]
     ports: Vec<PortInfo>,
 
-    #[serde(rename(deserialize = "SizeRW"), skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "SizeRw", alias = "SizeRW", default, skip_serializing_if = "Option::is_none")]
     size_rw: Option<i64>,
 
-    #[serde(rename(deserialize = "SizeRootFS"), skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "SizeRootFs", alias = "SizeRootFS", default, skip_serializing_if = "Option::is_none")]
     size_root_fs: Option<i64>,
 
-    #[serde(rename(deserialize = "Labels"))]
+    #[serde(rename = "Labels")]
     labels: HashMap<String, String>,
 
-    #[serde(rename(deserialize = "State"))]
-    state: String,
+    #[serde(rename = "State")]
+    state: ContainerState,
 
-    #[serde(rename(deserialize = "Status"))]
+    #[serde(rename = "Status")]
     status: String,
 
-    #[serde(rename(deserialize = "HostConfig"))]
+    #[serde(rename = "HostConfig")]
     host_config: HostConfig,
 
-    #[serde(rename(deserialize = "NetworkSettings"))]
+    #[serde(rename = "NetworkSettings")]
     network_settings: NetworkSettings,
 
-    #[serde(rename(deserialize = "Mounts"))]
-    mounts: Vec<Mount>,
+    #[serde(rename = "Mounts", deserialize_with = "nullable_default", default)]
+    mounts: Vec<MountPoint>,
+
+    #[serde(flatten, skip_serializing)]
+    extra: HashMap<String, serde_json::Value>,
+
 }
 
 impl ShortContainerInfo {
@@ -90,8 +99,88 @@ impl ShortContainerInfo {
         &self.labels
     }
 
-    pub fn id(&self) -> &str {
+    pub fn id(&self) -> &ContainerId {
         &self.id
     }
 
+    /// Return names of the container, with a leading `/`
+    pub fn names(&self) -> &[String] {
+        &self.names
+    }
+
+    /// Return first name of the container without the leading `/`
+    pub fn name(&self) -> Option<&str> {
+        self.names.first().map(|name| name.trim_start_matches('/'))
+    }
+
+    /// Return state of the container
+    pub fn state(&self) -> &ContainerState {
+        &self.state
+    }
+
+    /// Return human readable status, e.g. `Up 2 hours`
+    pub fn status(&self) -> &str {
+        &self.status
+    }
+
+    pub fn image_id(&self) -> &ImageId {
+        &self.image_id
+    }
+
+    /// Return created as seconds since epoch
+    pub fn created(&self) -> u64 {
+        self.created
+    }
+
+    /// Return created as `DateTime<Utc>`
+    #[cfg(feature = "chrono")]
+    pub fn created_datetime(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        crate::additionals::timestamp::from_unix(self.created as i64)
+    }
+
+    /// Return mounts of the container
+    pub fn mounts(&self) -> &Vec<MountPoint> {
+        &self.mounts
+    }
+
+    /// Return size of files written by the container in bytes, reported when sizes are requested
+    pub fn size_rw(&self) -> Option<i64> {
+        self.size_rw
+    }
+
+    /// Return size of all files of the container in bytes, reported when sizes are requested
+    pub fn size_root_fs(&self) -> Option<i64> {
+        self.size_root_fs
+    }
+
+    /// Return published ports by container port, empty bindings for exposed but unpublished ports
+    pub fn ports(&self) -> PortMap {
+        let mut ports = PortMap::new();
+
+        for info in &self.ports {
+            let bindings = ports.entry(ExposedPort::new(info.private_port, info.port_type.as_str())).or_default();
+            if info.public_port != 0 {
+                let host_ip = Some(info.ip.clone()).filter(|ip| !ip.is_empty());
+                bindings.push(PortBinding::new(host_ip, info.public_port.to_string()));
+            }
+        }
+
+        ports
+    }
+
+    /// Return first host port published for container `port`
+    pub fn host_port_for(&self, port: &ExposedPort) -> Option<u16> {
+        host_port_for(&self.ports(), port)
+    }
+
+    /// Return networks the container is connected to
+    pub fn network_settings(&self) -> &NetworkSettings {
+        &self.network_settings
+    }
+
+    /// Return fields unknown to this crate, e.g. added by newer daemons
+    pub fn extra(&self) -> &HashMap<String, serde_json::Value> {
+        &self.extra
+    }
+
 }
\ No newline at end of file
@@ -0,0 +1,446 @@
+//!
+//! Container logs request.
+//!
+//! The module provides [RequestBuilder](struct.RequestBuilder.html) and [Request](struct.Request.html) types
+//! used to read logs of a container.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use docker_client::container::logs::Request;
+//!
+//! let request = Request::container("test")
+//!     .stderr(false)
+//!     .tail(10)
+//!     .build();
+//!
+//! assert_eq!(request.get_path(), "/containers/test/logs?stdout=true&stderr=false&tail=10");
+//! ```
+
+use std::collections::VecDeque;
+use std::time::SystemTime;
+use serde::Serialize;
+use crate::container::ContainerRefId;
+
+/// Logs request builder
+#[derive(Debug)]
+pub struct RequestBuilder {
+
+    id: String,
+
+    stdout: bool,
+
+    stderr: bool,
+
+    since: Option<i64>,
+
+    until: Option<i64>,
+
+    timestamps: bool,
+
+    tail: Option<usize>,
+
+    follow: bool,
+
+}
+
+impl RequestBuilder {
+
+    /// Creates a new instance of `RequestBuilder` for container `id`, reading stdout and stderr.
+    pub fn container<T>(id: T) -> Self
+        where T: Into<ContainerRefId>
+    {
+        RequestBuilder {
+            id: String::from(id.into()),
+            stdout: true,
+            stderr: true,
+            since: None,
+            until: None,
+            timestamps: false,
+            tail: None,
+            follow: false,
+        }
+    }
+
+    /// Return logs from stdout
+    pub fn stdout(mut self, v: bool) -> Self {
+        self.stdout = v;
+
+        self
+    }
+
+    /// Return logs from stderr
+    pub fn stderr(mut self, v: bool) -> Self {
+        self.stderr = v;
+
+        self
+    }
+
+    /// Only return logs since this time, as seconds since epoch
+    pub fn since(mut self, v: i64) -> Self {
+        self.since = Some(v);
+
+        self
+    }
+
+    /// Only return logs before this time, as seconds since epoch
+    pub fn until(mut self, v: i64) -> Self {
+        self.until = Some(v);
+
+        self
+    }
+
+    /// Prefix every log line with its timestamp
+    pub fn timestamps(mut self, v: bool) -> Self {
+        self.timestamps = v;
+
+        self
+    }
+
+    /// Only return this number of lines from the end of the logs
+    pub fn tail(mut self, v: usize) -> Self {
+        self.tail = Some(v);
+
+        self
+    }
+
+    /// Keep the connection open and return new lines as they are written,
+    /// only useful with `DockerClient::container_log_stream`
+    pub fn follow(mut self, v: bool) -> Self {
+        self.follow = v;
+
+        self
+    }
+
+    /// Build `Request` from `RequestBuilder`
+    pub fn build(self) -> Request {
+        Request {
+            id: self.id,
+            stdout: self.stdout,
+            stderr: self.stderr,
+            since: self.since,
+            until: self.until,
+            timestamps: self.timestamps,
+            tail: self.tail,
+            follow: self.follow,
+        }
+    }
+
+}
+
+/// Logs request
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Request {
+
+    id: String,
+
+    stdout: bool,
+
+    stderr: bool,
+
+    since: Option<i64>,
+
+    until: Option<i64>,
+
+    timestamps: bool,
+
+    tail: Option<usize>,
+
+    follow: bool,
+
+}
+
+impl Request {
+
+    /// Creates a new instance of `RequestBuilder` for container `id`.
+    pub fn container<T>(id: T) -> RequestBuilder
+        where T: Into<ContainerRefId>
+    {
+        RequestBuilder::container(id)
+    }
+
+    /// Return `true` if lines are prefixed with timestamps
+    pub fn timestamps(&self) -> bool {
+        self.timestamps
+    }
+
+    /// Return `true` if new lines are followed
+    pub fn follow(&self) -> bool {
+        self.follow
+    }
+
+    /// Return copy of the request for container `id`
+    pub(crate) fn with_container(&self, id: String) -> Request {
+        Request { id, ..self.clone() }
+    }
+
+    /// Return path for request
+    pub fn get_path(&self) -> String {
+        let mut path = format!("/containers/{}/logs?stdout={}&stderr={}", self.id, self.stdout, self.stderr);
+
+        if let Some(since) = self.since {
+            path.push_str(format!("&since={}", since).as_str());
+        }
+        if let Some(until) = self.until {
+            path.push_str(format!("&until={}", until).as_str());
+        }
+        if self.timestamps {
+            path.push_str("&timestamps=true");
+        }
+        if let Some(tail) = self.tail {
+            path.push_str(format!("&tail={}", tail).as_str());
+        }
+        if self.follow {
+            path.push_str("&follow=true");
+        }
+
+        path
+    }
+
+}
+
+/// Stream a log line was written to
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LogStream {
+
+    /// Standard input, written back by TTY containers
+    Stdin,
+
+    /// Standard output
+    Stdout,
+
+    /// Standard error
+    Stderr,
+
+}
+
+impl LogStream {
+
+    pub(crate) fn from_frame(stream: u8) -> Self {
+        match stream {
+            0 => LogStream::Stdin,
+            2 => LogStream::Stderr,
+            _ => LogStream::Stdout,
+        }
+    }
+
+}
+
+/// Log line of a container
+///
+/// `timestamp` is set when logs were requested with `timestamps(true)`.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct LogLine {
+
+    timestamp: Option<SystemTime>,
+
+    stream: LogStream,
+
+    message: String,
+
+}
+
+impl LogLine {
+
+    /// Parse a line, splitting the RFC 3339 timestamp prefix if `timestamps` is set
+    pub(crate) fn parse(stream: u8, line: String, timestamps: bool) -> Self {
+        let stream = LogStream::from_frame(stream);
+
+        if timestamps {
+            if let Some((prefix, message)) = line.split_once(' ') {
+                if let Some(timestamp) = crate::additionals::timestamp::parse_system_time(prefix) {
+                    return LogLine { timestamp: Some(timestamp), stream, message: message.to_string() };
+                }
+            }
+        }
+
+        LogLine { timestamp: None, stream, message: line }
+    }
+
+    /// Return time the line was written
+    pub fn timestamp(&self) -> Option<SystemTime> {
+        self.timestamp
+    }
+
+    /// Return time the line was written as `DateTime<Utc>`
+    #[cfg(feature = "chrono")]
+    pub fn datetime(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        self.timestamp.map(chrono::DateTime::from)
+    }
+
+    /// Return stream the line was written to
+    pub fn stream(&self) -> LogStream {
+        self.stream
+    }
+
+    /// Return line without the timestamp prefix
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+}
+
+/// Split frames into lines, keeping a separate buffer per stream.
+pub(crate) fn lines(frames: Vec<(u8, &[u8])>) -> Vec<(u8, String)> {
+    let mut buffers: [Vec<u8>; 3] = Default::default();
+    let mut lines = Vec::new();
+
+    for (stream, payload) in frames {
+        let buffer = &mut buffers[stream as usize];
+        buffer.extend_from_slice(payload);
+
+        while let Some(position) = buffer.iter().position(|b| *b == b'\n') {
+            let line: Vec<u8> = buffer.drain(..=position).collect();
+            lines.push((stream, decode(&line[..line.len() - 1])));
+        }
+    }
+
+    for (stream, buffer) in buffers.iter().enumerate() {
+        if !buffer.is_empty() {
+            lines.push((stream as u8, decode(buffer)));
+        }
+    }
+
+    lines
+}
+
+fn decode(line: &[u8]) -> String {
+    let line = String::from_utf8_lossy(line);
+
+    line.strip_suffix('\r').unwrap_or(&line).to_string()
+}
+
+
+/// Incremental decoder of a multiplexed body into frames.
+///
+/// Chunks are fed as they arrive. Like `DockerResponse::frames`, a body starting
+/// without a valid frame header (TTY enabled) is read as stdout.
+#[derive(Debug)]
+pub(crate) struct FrameDecoder {
+    multiplexed: Option<bool>,
+    buffer: Vec<u8>,
+    frames: VecDeque<(u8, Vec<u8>)>,
+}
+
+impl FrameDecoder {
+
+    pub(crate) fn new() -> Self {
+        FrameDecoder {
+            multiplexed: None,
+            buffer: Vec::new(),
+            frames: VecDeque::new(),
+        }
+    }
+
+    /// Feed a chunk of the body.
+    pub(crate) fn push(&mut self, chunk: &[u8]) {
+        self.buffer.extend_from_slice(chunk);
+
+        if self.multiplexed.is_none() {
+            let header = &self.buffer[..self.buffer.len().min(8)];
+            let valid = header.first().is_some_and(|stream| *stream <= 2)
+                && header.iter().skip(1).take(3).all(|b| *b == 0);
+
+            match (valid, header.len()) {
+                (_, 0) => return,
+                (false, _) => self.multiplexed = Some(false),
+                (true, 8) => self.multiplexed = Some(true),
+                _ => return,
+            }
+        }
+
+        while self.multiplexed == Some(true) && self.buffer.len() >= 8 {
+            if self.buffer[0] > 2 {
+                // Not a frame header, read the rest as raw output.
+                self.multiplexed = Some(false);
+                break;
+            }
+
+            let size = u32::from_be_bytes([self.buffer[4], self.buffer[5], self.buffer[6], self.buffer[7]]) as usize;
+            if self.buffer.len() < 8 + size {
+                break;
+            }
+
+            let frame: Vec<u8> = self.buffer.drain(..8 + size).collect();
+            self.frames.push_back((frame[0], frame[8..].to_vec()));
+        }
+
+        if self.multiplexed == Some(false) && !self.buffer.is_empty() {
+            let data = std::mem::take(&mut self.buffer);
+            self.frames.push_back((1, data));
+        }
+    }
+
+    /// Take the next complete frame as `(stream type, payload)`.
+    pub(crate) fn next_frame(&mut self) -> Option<(u8, Vec<u8>)> {
+        self.frames.pop_front()
+    }
+
+    /// Flush the output at the end of the body.
+    pub(crate) fn finish(&mut self) {
+        // A short body without a complete header is raw output.
+        if self.multiplexed.is_none() && !self.buffer.is_empty() {
+            self.multiplexed = Some(false);
+            self.push(&[]);
+        }
+    }
+
+}
+
+/// Incremental decoder of a logs body into lines.
+///
+/// Chunks are fed as they arrive and split into frames by `FrameDecoder`.
+pub(crate) struct LogDecoder {
+    frames: FrameDecoder,
+    partial: [Vec<u8>; 3],
+    lines: VecDeque<(u8, String)>,
+}
+
+impl LogDecoder {
+
+    pub(crate) fn new() -> Self {
+        LogDecoder {
+            frames: FrameDecoder::new(),
+            partial: Default::default(),
+            lines: VecDeque::new(),
+        }
+    }
+
+    /// Feed a chunk of the body.
+    pub(crate) fn push(&mut self, chunk: &[u8]) {
+        self.frames.push(chunk);
+        self.split_frames();
+    }
+
+    /// Split the decoded frames into lines, keeping a separate buffer per stream.
+    fn split_frames(&mut self) {
+        while let Some((stream, payload)) = self.frames.next_frame() {
+            let partial = &mut self.partial[stream as usize];
+            partial.extend_from_slice(&payload);
+
+            while let Some(position) = partial.iter().position(|b| *b == b'\n') {
+                let line: Vec<u8> = partial.drain(..=position).collect();
+                self.lines.push_back((stream, decode(&line[..line.len() - 1])));
+            }
+        }
+    }
+
+    /// Take the next complete line as `(stream type, line)`.
+    pub(crate) fn next_line(&mut self) -> Option<(u8, String)> {
+        self.lines.pop_front()
+    }
+
+    /// Flush lines which do not end with a newline at the end of the body.
+    pub(crate) fn finish(&mut self) {
+        self.frames.finish();
+        self.split_frames();
+
+        for stream in 0..self.partial.len() {
+            if !self.partial[stream].is_empty() {
+                let line = std::mem::take(&mut self.partial[stream]);
+                self.lines.push_back((stream as u8, decode(&line)));
+            }
+        }
+    }
+
+}
@@ -6,10 +6,22 @@ mod create;
 pub mod inspect;
 pub mod remove;
 pub mod kill;
+pub mod upload;
 pub mod health_check;
 pub mod list;
 mod fs_changes;
 mod wait;
+mod ensure;
+mod checkpoint;
+mod start;
+mod path_stat;
+mod state;
+pub mod stats;
+pub mod logs;
+pub mod multi_logs;
+pub mod watchdog;
+pub mod attach;
+pub(crate) mod id;
 
 
 pub mod processes_list;
@@ -18,12 +30,28 @@ pub use health_check::HealthCheck;
 
 pub use create::*;
 
-pub use remove::{RemoverBuilder, Remover};
+pub use remove::{RemoverBuilder, Remover, PrunedContainers};
 
 pub use kill::{KillerBuilder, Killer};
 
+pub use upload::{UploadBuilder, Upload};
+
 pub use fs_changes::FSChanges;
 
 pub use wait::{WaitCondition, WaitStatus};
 
+pub use ensure::{EnsureAction, EnsuredContainer};
+
+pub use checkpoint::Checkpoint;
+
+pub use start::StartOptions;
+
+pub use path_stat::PathStat;
+
+pub use stats::Stats;
+
+pub use state::ContainerState;
+
+pub use id::{ContainerId, ContainerRefId};
+
 pub use list::{ShortContainerInfo};
\ No newline at end of file
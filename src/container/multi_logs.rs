@@ -0,0 +1,274 @@
+//!
+//! Logs of several containers in one stream.
+//!
+//! The module provides [MultiLogs](struct.MultiLogs.html) to select containers, by name or
+//! by label, and [MultiLogStream](struct.MultiLogStream.html) which interleaves their lines
+//! as they arrive, like `docker compose logs`.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # use docker_client::DockerClient;
+//! use docker_client::container::multi_logs::MultiLogs;
+//! use futures::StreamExt;
+//!
+//! # #[tokio::main]
+//! async fn main() {
+//!     let client = DockerClient::new();
+//!
+//!     let request = MultiLogs::label("com.docker.compose.project", "shop")
+//!         .tail(10)
+//!         .follow(true);
+//!
+//!     match client.multi_logs(request).await {
+//!         Ok(mut logs) => {
+//!             while let Some(Ok(line)) = logs.next().await {
+//!                 println!("{} | {}", line.container(), line.line().message());
+//!             }
+//!         },
+//!         Err(e) => { println!("Error: {:?}", e); },
+//!     }
+//! }
+//! ```
+
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use futures::stream::{Stream, StreamExt};
+
+use crate::client::DockerError;
+use crate::container::ContainerRefId;
+use crate::container::logs::{LogLine, Request, RequestBuilder};
+
+/// Containers selected by `MultiLogs`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Selector {
+
+    /// Names or IDs of containers
+    Containers(Vec<String>),
+
+    /// Running containers with label `key=value`
+    Label(String, String),
+
+}
+
+/// Request of logs of several containers
+#[derive(Debug)]
+pub struct MultiLogs {
+
+    selector: Selector,
+
+    request: RequestBuilder,
+
+}
+
+impl MultiLogs {
+
+    /// Select containers by name or ID
+    pub fn containers<I, T>(ids: I) -> Self
+        where
+            I: IntoIterator<Item = T>,
+            T: Into<ContainerRefId>
+    {
+        let ids = ids.into_iter().map(|id| String::from(id.into())).collect();
+        MultiLogs::new(Selector::Containers(ids))
+    }
+
+    /// Select running containers with label `key=value`
+    pub fn label<K, V>(key: K, value: V) -> Self
+        where
+            K: Into<String>,
+            V: Into<String>
+    {
+        MultiLogs::new(Selector::Label(key.into(), value.into()))
+    }
+
+    fn new(selector: Selector) -> Self {
+        // Timestamps are needed to interleave lines, they are removed from messages.
+        MultiLogs { selector, request: RequestBuilder::container("").timestamps(true) }
+    }
+
+    /// Return logs from stdout
+    pub fn stdout(mut self, v: bool) -> Self {
+        self.request = self.request.stdout(v);
+
+        self
+    }
+
+    /// Return logs from stderr
+    pub fn stderr(mut self, v: bool) -> Self {
+        self.request = self.request.stderr(v);
+
+        self
+    }
+
+    /// Only return logs since this time, as seconds since epoch
+    pub fn since(mut self, v: i64) -> Self {
+        self.request = self.request.since(v);
+
+        self
+    }
+
+    /// Only return this number of lines from the end of the logs of every container
+    pub fn tail(mut self, v: usize) -> Self {
+        self.request = self.request.tail(v);
+
+        self
+    }
+
+    /// Keep following new lines
+    pub fn follow(mut self, v: bool) -> Self {
+        self.request = self.request.follow(v);
+
+        self
+    }
+
+    /// Return selected containers and the logs request for all of them
+    pub(crate) fn into_parts(self) -> (Selector, Request) {
+        (self.selector, self.request.build())
+    }
+
+}
+
+/// Log line tagged with the name of its container
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TaggedLogLine {
+
+    container: String,
+
+    line: LogLine,
+
+}
+
+impl TaggedLogLine {
+
+    /// Return name of the container, or ID if the container was selected by ID
+    pub fn container(&self) -> &str {
+        &self.container
+    }
+
+    /// Return log line
+    pub fn line(&self) -> &LogLine {
+        &self.line
+    }
+
+    /// Return log line, dropping the container name
+    pub fn into_line(self) -> LogLine {
+        self.line
+    }
+
+}
+
+/// Error of the logs of one container, tagged with the name of the container
+#[derive(Debug)]
+pub struct TaggedLogError {
+
+    container: String,
+
+    error: DockerError,
+
+}
+
+impl TaggedLogError {
+
+    /// Return name of the container, or ID if the container was selected by ID
+    pub fn container(&self) -> &str {
+        &self.container
+    }
+
+    /// Return error
+    pub fn error(&self) -> &DockerError {
+        &self.error
+    }
+
+    /// Return error, dropping the container name
+    pub fn into_error(self) -> DockerError {
+        self.error
+    }
+
+}
+
+type LineStream = Pin<Box<dyn Stream<Item = Result<LogLine, DockerError>> + Send>>;
+
+struct Source {
+    container: String,
+    stream: LineStream,
+    head: Option<LogLine>,
+    done: bool,
+}
+
+/// Interleaved logs of several containers
+///
+/// Ordering by timestamp is best-effort: a line is emitted as soon as any container
+/// has one ready, so a quiet container never delays the others, and only lines
+/// available at the same time are emitted oldest first. Lines without a timestamp
+/// come after those with one. Lines of one container always keep their order.
+/// An error of one container, including a failed request of its logs, is emitted
+/// once with the container name and its logs end, the others go on.
+/// Dropping the stream closes all connections.
+pub struct MultiLogStream {
+    sources: Vec<Source>,
+}
+
+impl MultiLogStream {
+
+    pub(crate) fn new() -> Self {
+        MultiLogStream { sources: Vec::new() }
+    }
+
+    pub(crate) fn push(&mut self, container: String, stream: LineStream) {
+        self.sources.push(Source { container, stream, head: None, done: false });
+    }
+
+    /// Return names of the followed containers
+    pub fn containers(&self) -> Vec<&str> {
+        self.sources.iter().map(|source| source.container.as_str()).collect()
+    }
+
+}
+
+impl Stream for MultiLogStream {
+    type Item = Result<TaggedLogLine, TaggedLogError>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        for source in this.sources.iter_mut().filter(|source| source.head.is_none() && !source.done) {
+            match source.stream.poll_next_unpin(cx) {
+                Poll::Ready(Some(Ok(line))) => source.head = Some(line),
+                Poll::Ready(Some(Err(e))) => {
+                    source.done = true;
+                    return Poll::Ready(Some(Err(TaggedLogError { container: source.container.clone(), error: e })));
+                },
+                Poll::Ready(None) => source.done = true,
+                Poll::Pending => {},
+            }
+        }
+
+        if this.sources.iter().all(|source| source.done && source.head.is_none()) {
+            return Poll::Ready(None);
+        }
+
+        let oldest = this.sources.iter_mut()
+            .filter(|source| source.head.is_some())
+            .min_by_key(|source| {
+                let timestamp = source.head.as_ref().and_then(LogLine::timestamp);
+                (timestamp.is_none(), timestamp)
+            });
+
+        match oldest {
+            Some(source) => Poll::Ready(Some(Ok(TaggedLogLine {
+                container: source.container.clone(),
+                line: source.head.take().unwrap(),
+            }))),
+            None => Poll::Pending,
+        }
+    }
+}
+
+impl std::fmt::Debug for MultiLogStream {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MultiLogStream")
+            .field("containers", &self.containers())
+            .finish()
+    }
+}
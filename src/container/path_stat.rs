@@ -0,0 +1,74 @@
+//! Path stat module
+
+use serde::{Deserialize, Serialize};
+
+/// Go `os.ModeDir` bit of a file mode.
+const MODE_DIR: u32 = 1 << 31;
+
+/// Go `os.ModeSymlink` bit of a file mode.
+const MODE_SYMLINK: u32 = 1 << 27;
+
+/// Stat of a path in a container, from header `X-Docker-Container-Path-Stat`
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct PathStat {
+
+    #[serde(rename = "name")]
+    name: String,
+
+    #[serde(rename = "size", default)]
+    size: i64,
+
+    #[serde(rename = "mode", default)]
+    mode: u32,
+
+    #[serde(rename = "mtime", default)]
+    mtime: String,
+
+    #[serde(rename = "linkTarget", default)]
+    link_target: String,
+
+}
+
+impl PathStat {
+
+    /// Return base name of the path
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Return size in bytes
+    pub fn size(&self) -> i64 {
+        self.size
+    }
+
+    /// Return mode as Go `os.FileMode`, permission bits and type bits
+    pub fn mode(&self) -> u32 {
+        self.mode
+    }
+
+    /// Return permission bits, e.g. `0o644`
+    pub fn permissions(&self) -> u32 {
+        self.mode & 0o777
+    }
+
+    /// Return `true` if the path is a directory
+    pub fn is_dir(&self) -> bool {
+        self.mode & MODE_DIR != 0
+    }
+
+    /// Return `true` if the path is a symbolic link
+    pub fn is_symlink(&self) -> bool {
+        self.mode & MODE_SYMLINK != 0
+    }
+
+    /// Return modification time as RFC 3339 string
+    pub fn mtime(&self) -> &str {
+        &self.mtime
+    }
+
+    /// Return target of a symbolic link, empty otherwise
+    pub fn link_target(&self) -> &str {
+        &self.link_target
+    }
+
+}
@@ -1,5 +1,6 @@
+use crate::container::ContainerRefId;
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ProcessesList {
 
     id: String,
@@ -10,13 +11,26 @@ pub struct ProcessesList {
 
 impl ProcessesList {
 
-    pub fn container(name: String) -> Self {
+    pub fn container<T>(id: T) -> Self
+        where T: Into<ContainerRefId>
+    {
         ProcessesList {
-            id: name,
+            id: String::from(id.into()),
             ps_args: String::new()
         }
     }
 
+    /// Arguments to pass to `ps`, e.g. `aux`
+    pub fn with_ps_args<T>(mut self, args: T) -> Self
+        where T: Into<String>
+    {
+        self.ps_args = args.into();
+
+        self
+    }
+
+    /// Set `ps_args` in place
+    #[deprecated(note = "use `with_ps_args`, which consumes and returns the request")]
     pub fn ps_args(&mut self, args: String) {
         self.ps_args = args;
     }
@@ -31,4 +45,4 @@ impl ProcessesList {
         path
     }
 
-}
\ No newline at end of file
+}
@@ -1,6 +1,7 @@
-use serde::Deserialize;
+use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
 pub struct TopList {
 
     #[serde(rename = "Titles")]
@@ -9,4 +10,98 @@ pub struct TopList {
     #[serde(rename = "Processes")]
     processes: Vec<Vec<String>>
 
-}
\ No newline at end of file
+}
+
+impl TopList {
+
+    /// Return column titles, they depend on `ps_args`
+    pub fn titles(&self) -> &Vec<String> {
+        &self.titles
+    }
+
+    /// Return raw process rows
+    pub fn processes(&self) -> &Vec<Vec<String>> {
+        &self.processes
+    }
+
+    /// Return process rows mapped by column titles
+    pub fn entries(&self) -> Vec<Process> {
+        self.processes.iter()
+            .map(|row| Process::from_row(&self.titles, row))
+            .collect()
+    }
+
+}
+
+/// Process entry of `TopList`
+///
+/// Typed fields are `None` when the column is not present for the used `ps_args`.
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct Process {
+
+    pid: Option<u32>,
+
+    ppid: Option<u32>,
+
+    user: Option<String>,
+
+    cpu: Option<f64>,
+
+    command: Option<String>,
+
+    columns: HashMap<String, String>,
+
+}
+
+impl Process {
+
+    fn from_row(titles: &[String], row: &[String]) -> Self {
+        let columns: HashMap<String, String> = titles.iter().cloned()
+            .zip(row.iter().cloned())
+            .collect();
+
+        let column = |names: &[&str]| {
+            names.iter().find_map(|name| columns.get(*name)).cloned()
+        };
+
+        Process {
+            pid: column(&["PID"]).and_then(|v| v.parse().ok()),
+            ppid: column(&["PPID"]).and_then(|v| v.parse().ok()),
+            user: column(&["USER", "UID"]),
+            cpu: column(&["%CPU", "C"]).and_then(|v| v.parse().ok()),
+            command: column(&["CMD", "COMMAND"]),
+            columns,
+        }
+    }
+
+    /// Return process id
+    pub fn pid(&self) -> Option<u32> {
+        self.pid
+    }
+
+    /// Return parent process id
+    pub fn ppid(&self) -> Option<u32> {
+        self.ppid
+    }
+
+    /// Return user name or id
+    pub fn user(&self) -> Option<&str> {
+        self.user.as_deref()
+    }
+
+    /// Return CPU utilization in percent
+    pub fn cpu(&self) -> Option<f64> {
+        self.cpu
+    }
+
+    /// Return command line
+    pub fn command(&self) -> Option<&str> {
+        self.command.as_deref()
+    }
+
+    /// Return value of column `title`
+    pub fn column(&self, title: &str) -> Option<&str> {
+        self.columns.get(title).map(String::as_str)
+    }
+
+}
@@ -36,6 +36,11 @@
 //! }
 //! ```
 
+use serde::{Deserialize, Serialize};
+
+use crate::additionals::serde_helpers::nullable_priority_vec;
+use crate::container::ContainerRefId;
+
 /// Remover builder struct.
 #[derive(Debug, Default)]
 pub struct RemoverBuilder {
@@ -46,7 +51,7 @@ pub struct RemoverBuilder {
 }
 
 /// Remover struct.
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Remover {
     id: String,
     v: Option<bool>,
@@ -98,9 +103,9 @@ impl RemoverBuilder {
     ///     .build();
     /// ```
     pub fn id<T>(mut self, id: T) -> Self
-        where T: Into<String>
+        where T: Into<ContainerRefId>
     {
-        self.id = id.into();
+        self.id = String::from(id.into());
 
         self
     }
@@ -170,4 +175,27 @@ impl RemoverBuilder {
         }
     }
 
-}
\ No newline at end of file
+}
+/// Pruned containers structure
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct PrunedContainers {
+
+    #[serde(rename = "ContainersDeleted", deserialize_with = "nullable_priority_vec", default)]
+    containers_deleted: Vec<String>,
+
+    #[serde(rename = "SpaceReclaimed")]
+    space_reclaimed: i64,
+}
+
+impl PrunedContainers {
+
+    /// Return IDs of the deleted containers
+    pub fn containers_deleted(&self) -> &Vec<String> {
+        &self.containers_deleted
+    }
+
+    /// Return disk space reclaimed in bytes
+    pub fn space_reclaimed(&self) -> i64 {
+        self.space_reclaimed
+    }
+}
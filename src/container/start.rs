@@ -0,0 +1,56 @@
+//!
+//! Container start options.
+//!
+//! The module provides [StartOptions](struct.StartOptions.html) used by
+//! `DockerClient::start_container_with`.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use docker_client::container::StartOptions;
+//!
+//! let options = StartOptions::new().detach_keys("ctrl-p,ctrl-q");
+//!
+//! assert_eq!(options.get_path("test"), "/containers/test/start?detachKeys=ctrl-p%2Cctrl-q");
+//! assert_eq!(StartOptions::new().get_path("test"), "/containers/test/start");
+//! ```
+
+use crate::additionals::query::encode;
+
+/// Options of starting a container
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct StartOptions {
+
+    detach_keys: Option<String>,
+
+}
+
+impl StartOptions {
+
+    /// Creates a new instance of `StartOptions` with the defaults of the daemon
+    pub fn new() -> Self {
+        StartOptions::default()
+    }
+
+    /// Override the key sequence for detaching, e.g. `ctrl-p,ctrl-q`. Empty for the default.
+    pub fn detach_keys<T>(mut self, keys: T) -> Self
+        where T: Into<String>
+    {
+        let keys = keys.into();
+        self.detach_keys = match keys.is_empty() {
+            true => None,
+            false => Some(keys),
+        };
+
+        self
+    }
+
+    /// Return path for request of container `id`
+    pub fn get_path(&self, id: &str) -> String {
+        match self.detach_keys.as_ref() {
+            Some(keys) => format!("/containers/{}/start?detachKeys={}", id, encode(keys)),
+            None => format!("/containers/{}/start", id),
+        }
+    }
+
+}
@@ -0,0 +1,105 @@
+//! Container state
+
+use std::fmt;
+use std::str::FromStr;
+use serde::{Deserialize, Serialize};
+
+/// State of a container
+///
+/// Unknown states reported by newer daemons are kept in `Other`.
+///
+/// # Examples
+///
+/// ```rust
+/// use docker_client::container::ContainerState;
+///
+/// let state: ContainerState = "running".parse().unwrap();
+///
+/// assert_eq!(state, ContainerState::Running);
+/// assert_eq!(ContainerState::from("frozen".to_string()), ContainerState::Other("frozen".to_string()));
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[serde(from = "String", into = "String")]
+pub enum ContainerState {
+
+    /// Container was created but never started
+    Created,
+
+    /// Container is running
+    Running,
+
+    /// Container is paused
+    Paused,
+
+    /// Container is restarting
+    Restarting,
+
+    /// Container is being removed
+    Removing,
+
+    /// Container has exited
+    Exited,
+
+    /// Container is dead
+    Dead,
+
+    /// State unknown to this crate
+    Other(String),
+
+}
+
+impl ContainerState {
+
+    /// Return state as string slice
+    pub fn as_str(&self) -> &str {
+        match self {
+            ContainerState::Created => "created",
+            ContainerState::Running => "running",
+            ContainerState::Paused => "paused",
+            ContainerState::Restarting => "restarting",
+            ContainerState::Removing => "removing",
+            ContainerState::Exited => "exited",
+            ContainerState::Dead => "dead",
+            ContainerState::Other(state) => state.as_str(),
+        }
+    }
+
+}
+
+impl From<String> for ContainerState {
+    fn from(state: String) -> Self {
+        match state.as_str() {
+            "created" => ContainerState::Created,
+            "running" => ContainerState::Running,
+            "paused" => ContainerState::Paused,
+            "restarting" => ContainerState::Restarting,
+            "removing" => ContainerState::Removing,
+            "exited" => ContainerState::Exited,
+            "dead" => ContainerState::Dead,
+            _ => ContainerState::Other(state),
+        }
+    }
+}
+
+impl From<ContainerState> for String {
+    fn from(state: ContainerState) -> Self {
+        match state {
+            ContainerState::Other(state) => state,
+            state => state.as_str().to_string(),
+        }
+    }
+}
+
+impl FromStr for ContainerState {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(ContainerState::from(s.to_string()))
+    }
+}
+
+impl fmt::Display for ContainerState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
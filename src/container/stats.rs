@@ -0,0 +1,355 @@
+//! Stats module
+
+use std::collections::HashMap;
+use std::time::Duration;
+use serde::{Deserialize, Serialize};
+use crate::container::ContainerId;
+
+/// CPU usage structure
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq, Eq)]
+pub struct CpuUsage {
+    #[serde(default)]
+    total_usage: u64,
+
+    #[serde(default)]
+    percpu_usage: Option<Vec<u64>>,
+
+    #[serde(default)]
+    usage_in_kernelmode: u64,
+
+    #[serde(default)]
+    usage_in_usermode: u64,
+}
+
+impl CpuUsage {
+
+    /// Return total CPU time consumed in nanoseconds
+    pub fn total_usage(&self) -> u64 {
+        self.total_usage
+    }
+
+    /// Return per CPU time consumed in nanoseconds
+    pub fn percpu_usage(&self) -> &[u64] {
+        self.percpu_usage.as_deref().unwrap_or(&[])
+    }
+
+    /// Return time spent in kernel mode in nanoseconds
+    pub fn usage_in_kernelmode(&self) -> u64 {
+        self.usage_in_kernelmode
+    }
+
+    /// Return time spent in user mode in nanoseconds
+    pub fn usage_in_usermode(&self) -> u64 {
+        self.usage_in_usermode
+    }
+}
+
+/// CPU stats structure
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq, Eq)]
+pub struct CpuStats {
+    #[serde(default)]
+    cpu_usage: CpuUsage,
+
+    #[serde(default)]
+    system_cpu_usage: Option<u64>,
+
+    #[serde(default)]
+    online_cpus: Option<u32>,
+}
+
+impl CpuStats {
+
+    /// Return CPU usage
+    pub fn cpu_usage(&self) -> &CpuUsage {
+        &self.cpu_usage
+    }
+
+    /// Return system CPU usage in nanoseconds
+    pub fn system_cpu_usage(&self) -> Option<u64> {
+        self.system_cpu_usage
+    }
+
+    /// Return count of online CPUs
+    ///
+    /// Falls back to the length of `percpu_usage` for older daemons.
+    pub fn online_cpus(&self) -> u32 {
+        match self.online_cpus {
+            Some(count) if count > 0 => count,
+            _ => self.cpu_usage.percpu_usage().len() as u32,
+        }
+    }
+}
+
+/// Memory stats structure
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq, Eq)]
+pub struct MemoryStats {
+    #[serde(default)]
+    usage: Option<u64>,
+
+    #[serde(default)]
+    max_usage: Option<u64>,
+
+    #[serde(default)]
+    limit: Option<u64>,
+
+    #[serde(default)]
+    stats: HashMap<String, u64>,
+}
+
+impl MemoryStats {
+
+    /// Return memory usage in bytes including cache
+    pub fn usage(&self) -> Option<u64> {
+        self.usage
+    }
+
+    /// Return maximum memory usage in bytes
+    pub fn max_usage(&self) -> Option<u64> {
+        self.max_usage
+    }
+
+    /// Return memory limit in bytes
+    pub fn limit(&self) -> Option<u64> {
+        self.limit
+    }
+
+    /// Return raw cgroup memory statistics
+    pub fn stats(&self) -> &HashMap<String, u64> {
+        &self.stats
+    }
+
+    /// Return size of the page cache counted in `usage`
+    ///
+    /// Uses `total_inactive_file` (cgroup v1) or `inactive_file` (cgroup v2)
+    /// like the docker CLI does, and falls back to `cache`.
+    pub fn cache(&self) -> u64 {
+        ["total_inactive_file", "inactive_file", "cache"].iter()
+            .find_map(|key| self.stats.get(*key))
+            .cloned()
+            .unwrap_or(0)
+    }
+}
+
+/// Network interface stats structure
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq, Eq)]
+pub struct NetworkStats {
+    #[serde(default)]
+    rx_bytes: u64,
+
+    #[serde(default)]
+    rx_packets: u64,
+
+    #[serde(default)]
+    rx_errors: u64,
+
+    #[serde(default)]
+    rx_dropped: u64,
+
+    #[serde(default)]
+    tx_bytes: u64,
+
+    #[serde(default)]
+    tx_packets: u64,
+
+    #[serde(default)]
+    tx_errors: u64,
+
+    #[serde(default)]
+    tx_dropped: u64,
+}
+
+impl NetworkStats {
+
+    /// Return received bytes
+    pub fn rx_bytes(&self) -> u64 {
+        self.rx_bytes
+    }
+
+    /// Return received packets
+    pub fn rx_packets(&self) -> u64 {
+        self.rx_packets
+    }
+
+    /// Return receive errors
+    pub fn rx_errors(&self) -> u64 {
+        self.rx_errors
+    }
+
+    /// Return dropped incoming packets
+    pub fn rx_dropped(&self) -> u64 {
+        self.rx_dropped
+    }
+
+    /// Return transmitted bytes
+    pub fn tx_bytes(&self) -> u64 {
+        self.tx_bytes
+    }
+
+    /// Return transmitted packets
+    pub fn tx_packets(&self) -> u64 {
+        self.tx_packets
+    }
+
+    /// Return transmit errors
+    pub fn tx_errors(&self) -> u64 {
+        self.tx_errors
+    }
+
+    /// Return dropped outgoing packets
+    pub fn tx_dropped(&self) -> u64 {
+        self.tx_dropped
+    }
+}
+
+/// Network rate of an interface between two stats samples
+#[derive(Serialize, Debug, Clone, Copy, Default, PartialEq)]
+pub struct NetworkRate {
+    /// Received bytes per second
+    pub rx_bytes_per_sec: f64,
+
+    /// Transmitted bytes per second
+    pub tx_bytes_per_sec: f64,
+}
+
+/// Container stats structure
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct Stats {
+    #[serde(default)]
+    id: ContainerId,
+
+    #[serde(default)]
+    name: String,
+
+    read: String,
+
+    #[serde(default)]
+    preread: String,
+
+    #[serde(default)]
+    cpu_stats: CpuStats,
+
+    #[serde(default)]
+    precpu_stats: CpuStats,
+
+    #[serde(default)]
+    memory_stats: MemoryStats,
+
+    #[serde(default)]
+    networks: Option<HashMap<String, NetworkStats>>,
+}
+
+impl Stats {
+
+    /// Return id of the container
+    pub fn id(&self) -> &ContainerId {
+        &self.id
+    }
+
+    /// Return name of the container
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Return time of the sample
+    pub fn read(&self) -> &str {
+        &self.read
+    }
+
+    /// Return time of the previous sample
+    pub fn preread(&self) -> &str {
+        &self.preread
+    }
+
+    /// Return time of the sample as `DateTime<Utc>`
+    #[cfg(feature = "chrono")]
+    pub fn read_datetime(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        crate::additionals::timestamp::parse_rfc3339(&self.read)
+    }
+
+    /// Return time of the previous sample as `DateTime<Utc>`
+    #[cfg(feature = "chrono")]
+    pub fn preread_datetime(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        crate::additionals::timestamp::parse_rfc3339(&self.preread)
+    }
+
+    /// Return CPU stats
+    pub fn cpu_stats(&self) -> &CpuStats {
+        &self.cpu_stats
+    }
+
+    /// Return CPU stats of the previous sample
+    pub fn precpu_stats(&self) -> &CpuStats {
+        &self.precpu_stats
+    }
+
+    /// Return memory stats
+    pub fn memory_stats(&self) -> &MemoryStats {
+        &self.memory_stats
+    }
+
+    /// Return network stats by interface
+    pub fn networks(&self) -> Option<&HashMap<String, NetworkStats>> {
+        self.networks.as_ref()
+    }
+
+    /// Return CPU usage in percent, computed as the docker CLI does
+    ///
+    /// The value is relative to a single CPU, so a container using two cores
+    /// fully reports 200%.
+    pub fn cpu_percent(&self) -> f64 {
+        let cpu_delta = self.cpu_stats.cpu_usage.total_usage
+            .saturating_sub(self.precpu_stats.cpu_usage.total_usage);
+        let system_delta = self.cpu_stats.system_cpu_usage.unwrap_or(0)
+            .saturating_sub(self.precpu_stats.system_cpu_usage.unwrap_or(0));
+
+        if cpu_delta == 0 || system_delta == 0 {
+            return 0.0;
+        }
+
+        (cpu_delta as f64 / system_delta as f64) * self.cpu_stats.online_cpus() as f64 * 100.0
+    }
+
+    /// Return memory usage in bytes excluding cache
+    pub fn memory_usage(&self) -> u64 {
+        self.memory_stats.usage.unwrap_or(0)
+            .saturating_sub(self.memory_stats.cache())
+    }
+
+    /// Return memory usage excluding cache in percent of the limit
+    pub fn memory_percent(&self) -> f64 {
+        match self.memory_stats.limit {
+            Some(limit) if limit > 0 => self.memory_usage() as f64 / limit as f64 * 100.0,
+            _ => 0.0,
+        }
+    }
+
+    /// Return per interface network rates since the `previous` sample
+    ///
+    /// # Arguments
+    /// * `previous` - Earlier sample of the same container.
+    /// * `elapsed` - Time between the two samples.
+    pub fn network_rates(&self, previous: &Stats, elapsed: Duration) -> HashMap<String, NetworkRate> {
+        let seconds = elapsed.as_secs_f64();
+        let mut rates = HashMap::new();
+
+        let (current, previous) = match (self.networks.as_ref(), previous.networks.as_ref()) {
+            (Some(current), Some(previous)) => (current, previous),
+            _ => return rates,
+        };
+
+        if seconds <= 0.0 {
+            return rates;
+        }
+
+        for (interface, stats) in current {
+            if let Some(before) = previous.get(interface) {
+                rates.insert(interface.clone(), NetworkRate {
+                    rx_bytes_per_sec: stats.rx_bytes.saturating_sub(before.rx_bytes) as f64 / seconds,
+                    tx_bytes_per_sec: stats.tx_bytes.saturating_sub(before.tx_bytes) as f64 / seconds,
+                });
+            }
+        }
+
+        rates
+    }
+}
@@ -0,0 +1,131 @@
+//!
+//! Archive upload types.
+//!
+//! The module provides [UploadBuilder](struct.UploadBuilder.html) and [Upload](struct.Upload.html) types
+//! used to extract a tar archive into a directory of a container.
+//!
+//! # API Documentaion
+//!
+//! API documentaion available at [link](https://docs.docker.com/engine/api/v1.44/#tag/Container/operation/PutContainerArchive)
+//!
+//! # Examples
+//!
+//! Upload an archive keeping the ownership of its files.
+//! ```rust
+//! use docker_client::DockerClient;
+//! use docker_client::container::Upload;
+//!
+//! #[tokio::main]
+//! async fn main() {
+//!     let client = DockerClient::new();
+//!
+//!     let upload = Upload::builder()
+//!         .id("example-upload")
+//!         .path("/srv/app")
+//!         .copy_uid_gid(true)
+//!         .build();
+//!
+//!     let archive = std::fs::read("app.tar").unwrap_or_default();
+//!
+//!     match client.upload_archive(upload, archive).await {
+//!         Ok(_) => {},
+//!         Err(_) => {},
+//!     }
+//! }
+//! ```
+
+use crate::additionals::query::encode;
+use crate::container::ContainerRefId;
+
+/// An Upload builder.
+///
+/// This type can be used to construct an instance of `Upload` through a builder-like pattern.
+#[derive(Debug, Default)]
+pub struct UploadBuilder {
+    id: String,
+    path: String,
+    copy_uid_gid: Option<bool>,
+    no_overwrite_dir_non_dir: Option<bool>,
+}
+
+/// Represents an upload of a tar archive into a container.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Upload {
+    id: String,
+    path: String,
+    copy_uid_gid: Option<bool>,
+    no_overwrite_dir_non_dir: Option<bool>,
+}
+
+impl Upload {
+    /// Creates a new default instance of `UploadBuilder` to construct an `Upload`.
+    pub fn builder() -> UploadBuilder {
+        UploadBuilder::default()
+    }
+
+    /// Return path for request
+    pub fn get_path(&self) -> String {
+        let mut path = format!("/containers/{}/archive?path={}&", self.id, encode(&self.path));
+
+        if let Some(copy_uid_gid) = self.copy_uid_gid {
+            path.push_str(format!("copyUIDGID={}&", copy_uid_gid).as_str());
+        }
+
+        if let Some(no_overwrite) = self.no_overwrite_dir_non_dir {
+            path.push_str(format!("noOverwriteDirNonDir={}&", no_overwrite).as_str());
+        }
+
+        path.pop();
+        path
+    }
+}
+
+impl UploadBuilder {
+
+    /// Creates a new default instance of `UploadBuilder` to construct an `Upload`.
+    pub fn new() -> Self {
+        UploadBuilder::default()
+    }
+
+    /// Set ID or name of the container.
+    pub fn id<T>(mut self, id: T) -> Self
+        where T: Into<ContainerRefId>
+    {
+        self.id = String::from(id.into());
+
+        self
+    }
+
+    /// Set existing directory in the container filesystem to extract the archive into.
+    pub fn path<T>(mut self, path: T) -> Self
+        where T: Into<String>
+    {
+        self.path = path.into();
+
+        self
+    }
+
+    /// Give extracted files the UID and GID of the container user instead of the ones in the archive.
+    pub fn copy_uid_gid(mut self, v: bool) -> Self {
+        self.copy_uid_gid = Some(v);
+
+        self
+    }
+
+    /// Fail if a directory would be replaced by a non-directory or the other way round.
+    pub fn no_overwrite_dir_non_dir(mut self, v: bool) -> Self {
+        self.no_overwrite_dir_non_dir = Some(v);
+
+        self
+    }
+
+    /// Build `Upload` from `UploadBuilder`
+    pub fn build(self) -> Upload {
+        Upload {
+            id: self.id,
+            path: self.path,
+            copy_uid_gid: self.copy_uid_gid,
+            no_overwrite_dir_non_dir: self.no_overwrite_dir_non_dir,
+        }
+    }
+}
@@ -1,10 +1,10 @@
 //! Wait module
 
 use crate::client::ErrorMessage;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 /// Wait condition enum
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum WaitCondition {
     /// Not running
     NotRunning,
@@ -34,7 +34,7 @@ impl Default for WaitCondition {
 
 
 /// Wait status structure
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 pub struct WaitStatus {
     #[serde(rename = "StatusCode")]
     status_code: i32,
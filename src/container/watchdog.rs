@@ -0,0 +1,264 @@
+//!
+//! Self-healing of unhealthy containers.
+//!
+//! The module provides [Watchdog](struct.Watchdog.html), which follows `die` and
+//! `health_status` events of selected containers and applies a [Remedy](enum.Remedy.html)
+//! to them, like a restart policy of swarm services on a single daemon.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # use docker_client::DockerClient;
+//! use docker_client::container::{Config, Create};
+//! use docker_client::container::watchdog::{Watchdog, Remedy};
+//!
+//! # #[tokio::main]
+//! async fn main() {
+//!     let client = DockerClient::new();
+//!
+//!     let worker = Create::new()
+//!         .name("worker")
+//!         .config(Config::with_image("alpine").build())
+//!         .build();
+//!
+//!     let watchdog = Watchdog::new()
+//!         .watch("web", Remedy::Restart)
+//!         .watch("worker", Remedy::Recreate(Box::new(worker)))
+//!         .on_incident(|incident| println!("{} {:?}", incident.container(), incident.trigger()));
+//!
+//!     let handle = client.spawn_watchdog(watchdog);
+//!     // ...
+//!     handle.abort();
+//! }
+//! ```
+
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+use futures::StreamExt;
+
+use crate::client::{DockerClient, DockerError};
+use crate::container::{ContainerRefId, Create, Remover};
+use crate::container::inspect::HealthStatus;
+use crate::events::{Event, EventsFilter};
+
+/// What the watchdog does with a failed container
+#[derive(Debug, Clone)]
+pub enum Remedy {
+
+    /// Stop the container, if it still runs, and start it again
+    Restart,
+
+    /// Remove the container and create it again from the request, then start it.
+    /// The container is created with the watched name, whatever the request sets.
+    Recreate(Box<Create>),
+
+    /// Only report the incident to the callback
+    Notify,
+
+}
+
+/// Why the watchdog stepped in
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Trigger {
+
+    /// Health check of the container failed
+    Unhealthy,
+
+    /// Main process of the container exited
+    Died { exit_code: Option<i64> },
+
+}
+
+/// Failure of a watched container and the outcome of its remedy
+#[derive(Debug)]
+pub struct Incident {
+
+    container: String,
+
+    trigger: Trigger,
+
+    error: Option<DockerError>,
+
+}
+
+impl Incident {
+
+    /// Return name or ID of the container, as passed to `Watchdog::watch`
+    pub fn container(&self) -> &str {
+        &self.container
+    }
+
+    /// Return why the watchdog stepped in
+    pub fn trigger(&self) -> &Trigger {
+        &self.trigger
+    }
+
+    /// Return error of the remedy, `None` if it succeeded
+    pub fn error(&self) -> Option<&DockerError> {
+        self.error.as_ref()
+    }
+
+}
+
+type Callback = Arc<dyn Fn(&Incident) + Send + Sync>;
+
+/// Watchdog of several containers
+///
+/// Nothing is watched until the watchdog is passed to `DockerClient::run_watchdog`
+/// or `DockerClient::spawn_watchdog`. Events caused by a remedy itself, like `die`
+/// of a restarted container, are ignored.
+#[derive(Clone)]
+pub struct Watchdog {
+
+    containers: HashMap<String, Remedy>,
+
+    callback: Option<Callback>,
+
+    stop_timeout: Duration,
+
+}
+
+impl Watchdog {
+
+    /// Creates a new `Watchdog` watching no container
+    pub fn new() -> Self {
+        Watchdog {
+            containers: HashMap::new(),
+            callback: None,
+            stop_timeout: Duration::from_secs(10),
+        }
+    }
+
+    /// Watch container by name or ID, a name survives `Remedy::Recreate`
+    pub fn watch<T>(mut self, id: T, remedy: Remedy) -> Self
+        where T: Into<ContainerRefId>
+    {
+        self.containers.insert(String::from(id.into()), remedy);
+
+        self
+    }
+
+    /// Call `callback` after every remedy
+    pub fn on_incident<F>(mut self, callback: F) -> Self
+        where F: Fn(&Incident) + Send + Sync + 'static
+    {
+        self.callback = Some(Arc::new(callback));
+
+        self
+    }
+
+    /// Time to wait for a container to stop before killing it, 10 seconds by default
+    pub fn stop_timeout(mut self, timeout: Duration) -> Self {
+        self.stop_timeout = timeout;
+
+        self
+    }
+
+    /// Return names or IDs of the watched containers
+    pub fn containers(&self) -> Vec<&str> {
+        self.containers.keys().map(String::as_str).collect()
+    }
+
+    pub(crate) async fn run(self, client: &DockerClient) -> Result<(), DockerError> {
+        if self.containers.is_empty() {
+            return Ok(());
+        }
+
+        let filters = self.containers.keys()
+            .fold(EventsFilter::new().event_type("container"), |filters, id| filters.container(id.clone()))
+            .event("die")
+            .event("health_status")
+            .event("start")
+            .build();
+
+        let mut events = client.events(filters).await?;
+
+        // Events of a remedied container are caused by the remedy until it is started again.
+        // The `start` event is used rather than a time, the clock of the daemon may differ.
+        let mut remedied: HashSet<String> = HashSet::new();
+
+        while let Some(event) = events.next().await {
+            let event = event?;
+
+            let (container, remedy) = match self.find(&event) {
+                Some(found) => found,
+                None => continue,
+            };
+
+            if event.action() == "start" {
+                remedied.remove(container);
+                continue;
+            }
+
+            if remedied.contains(container) {
+                continue;
+            }
+
+            let trigger = match event.action() {
+                "die" => Trigger::Died {
+                    exit_code: event.actor().attribute("exitCode").and_then(|code| code.parse().ok()),
+                },
+                _ if event.health_status() == Some(HealthStatus::Unhealthy) => Trigger::Unhealthy,
+                _ => continue,
+            };
+
+            let error = self.apply(client, container, remedy).await.err();
+            if error.is_none() && !matches!(remedy, Remedy::Notify) {
+                remedied.insert(container.clone());
+            }
+
+            if let Some(callback) = &self.callback {
+                callback(&Incident { container: container.clone(), trigger, error });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Return watched container of the event, matched by name or ID prefix
+    fn find(&self, event: &Event) -> Option<(&String, &Remedy)> {
+        let actor = event.actor();
+        let name = actor.attribute("name").unwrap_or_default();
+
+        self.containers.iter()
+            .find(|(id, _)| id.as_str() == name || (!id.is_empty() && actor.id().starts_with(id.as_str())))
+    }
+
+    async fn apply(&self, client: &DockerClient, container: &str, remedy: &Remedy) -> Result<(), DockerError> {
+        match remedy {
+            Remedy::Restart => {
+                client.stop_container_with_timeout(container, self.stop_timeout).await?;
+                client.start_container(container, "").await
+            },
+            Remedy::Recreate(request) => {
+                let remover = Remover::new().id(container).with_force_delete(true).build();
+                match client.remove_container(remover).await {
+                    Ok(_) | Err(DockerError::NotFound(_)) => {},
+                    Err(e) => return Err(e),
+                }
+
+                let created = client.create_container(Create::clone(request).with_name(container.to_string())).await?;
+                client.start_container(created.id(), "").await
+            },
+            Remedy::Notify => Ok(()),
+        }
+    }
+
+}
+
+impl Default for Watchdog {
+    fn default() -> Self {
+        Watchdog::new()
+    }
+}
+
+impl fmt::Debug for Watchdog {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Watchdog")
+            .field("containers", &self.containers)
+            .field("stop_timeout", &self.stop_timeout)
+            .finish()
+    }
+}
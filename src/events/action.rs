@@ -0,0 +1,191 @@
+use std::fmt;
+use crate::container::inspect::HealthStatus;
+
+/// Action of an event
+///
+/// Unknown actions reported by newer daemons are kept in `Other`.
+///
+/// # Examples
+///
+/// ```rust
+/// use docker_client::container::inspect::HealthStatus;
+/// use docker_client::events::EventAction;
+///
+/// assert_eq!(EventAction::from("die"), EventAction::Die);
+/// assert_eq!(EventAction::from("exec_start: sh -c ls"), EventAction::ExecStart("sh -c ls".to_string()));
+/// assert_eq!(EventAction::from("health_status: healthy"), EventAction::HealthStatus(HealthStatus::Healthy));
+/// assert_eq!(EventAction::from("prune").to_string(), "prune");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum EventAction {
+
+    /// Object was created
+    Create,
+
+    /// Container was started
+    Start,
+
+    /// Container was asked to stop
+    Stop,
+
+    /// Container was restarted
+    Restart,
+
+    /// Container was sent a signal
+    Kill,
+
+    /// Container process exited
+    Die,
+
+    /// Container ran out of memory
+    Oom,
+
+    /// Container was paused
+    Pause,
+
+    /// Container was unpaused
+    Unpause,
+
+    /// Container was renamed
+    Rename,
+
+    /// Container was updated
+    Update,
+
+    /// Container was removed
+    Destroy,
+
+    /// Client attached to a container
+    Attach,
+
+    /// Client detached from a container
+    Detach,
+
+    /// Exec instance was created, with its command
+    ExecCreate(String),
+
+    /// Exec instance was started, with its command
+    ExecStart(String),
+
+    /// Exec process exited
+    ExecDie,
+
+    /// Health status of a container changed
+    HealthStatus(HealthStatus),
+
+    /// Image was pulled
+    Pull,
+
+    /// Image was pushed
+    Push,
+
+    /// Image was tagged
+    Tag,
+
+    /// Tag was removed from an image
+    Untag,
+
+    /// Image, network or other object was deleted
+    Delete,
+
+    /// Network or volume was removed
+    Remove,
+
+    /// Container was connected to a network
+    Connect,
+
+    /// Container was disconnected from a network
+    Disconnect,
+
+    /// Volume was mounted
+    Mount,
+
+    /// Volume was unmounted
+    Unmount,
+
+    /// Action unknown to this crate
+    Other(String),
+
+}
+
+impl From<&str> for EventAction {
+    fn from(action: &str) -> Self {
+        let (name, argument) = match action.split_once(':') {
+            Some((name, argument)) => (name, argument.trim()),
+            None => (action, ""),
+        };
+
+        match name {
+            "create" => EventAction::Create,
+            "start" => EventAction::Start,
+            "stop" => EventAction::Stop,
+            "restart" => EventAction::Restart,
+            "kill" => EventAction::Kill,
+            "die" => EventAction::Die,
+            "oom" => EventAction::Oom,
+            "pause" => EventAction::Pause,
+            "unpause" => EventAction::Unpause,
+            "rename" => EventAction::Rename,
+            "update" => EventAction::Update,
+            "destroy" => EventAction::Destroy,
+            "attach" => EventAction::Attach,
+            "detach" => EventAction::Detach,
+            "exec_create" => EventAction::ExecCreate(argument.to_string()),
+            "exec_start" => EventAction::ExecStart(argument.to_string()),
+            "exec_die" => EventAction::ExecDie,
+            "health_status" => EventAction::HealthStatus(HealthStatus::from(argument.to_string())),
+            "pull" => EventAction::Pull,
+            "push" => EventAction::Push,
+            "tag" => EventAction::Tag,
+            "untag" => EventAction::Untag,
+            "delete" => EventAction::Delete,
+            "remove" => EventAction::Remove,
+            "connect" => EventAction::Connect,
+            "disconnect" => EventAction::Disconnect,
+            "mount" => EventAction::Mount,
+            "unmount" => EventAction::Unmount,
+            _ => EventAction::Other(action.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for EventAction {
+
+    /// Format as reported by the daemon
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            EventAction::Create => "create",
+            EventAction::Start => "start",
+            EventAction::Stop => "stop",
+            EventAction::Restart => "restart",
+            EventAction::Kill => "kill",
+            EventAction::Die => "die",
+            EventAction::Oom => "oom",
+            EventAction::Pause => "pause",
+            EventAction::Unpause => "unpause",
+            EventAction::Rename => "rename",
+            EventAction::Update => "update",
+            EventAction::Destroy => "destroy",
+            EventAction::Attach => "attach",
+            EventAction::Detach => "detach",
+            EventAction::ExecCreate(command) => return write!(f, "exec_create: {}", command),
+            EventAction::ExecStart(command) => return write!(f, "exec_start: {}", command),
+            EventAction::ExecDie => "exec_die",
+            EventAction::HealthStatus(status) => return write!(f, "health_status: {}", status),
+            EventAction::Pull => "pull",
+            EventAction::Push => "push",
+            EventAction::Tag => "tag",
+            EventAction::Untag => "untag",
+            EventAction::Delete => "delete",
+            EventAction::Remove => "remove",
+            EventAction::Connect => "connect",
+            EventAction::Disconnect => "disconnect",
+            EventAction::Mount => "mount",
+            EventAction::Unmount => "unmount",
+            EventAction::Other(action) => action,
+        };
+
+        f.write_str(name)
+    }
+
+}
@@ -0,0 +1,66 @@
+use serde::Serialize;
+use super::Event;
+
+/// Lifecycle transition of a container
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub enum ContainerEvent {
+
+    /// Container was created
+    Create(Event),
+
+    /// Container was started
+    Start(Event),
+
+    /// Container process exited
+    Die {
+        /// Exit code of the main process, if reported
+        exit_code: Option<i64>,
+
+        /// Raw event
+        event: Event,
+    },
+
+    /// Container ran out of memory
+    Oom(Event),
+
+    /// Container was removed
+    Destroy(Event),
+
+}
+
+impl ContainerEvent {
+
+    /// Actions requested from the daemon for lifecycle subscriptions
+    pub(crate) const ACTIONS: [&'static str; 5] = ["create", "start", "die", "oom", "destroy"];
+
+    /// Convert a container event, `None` for other actions
+    pub fn from_event(event: Event) -> Option<Self> {
+        if event.event_type() != "container" {
+            return None;
+        }
+
+        match event.action() {
+            "create" => Some(ContainerEvent::Create(event)),
+            "start" => Some(ContainerEvent::Start(event)),
+            "die" => Some(ContainerEvent::Die {
+                exit_code: event.actor().attribute("exitCode").and_then(|code| code.parse().ok()),
+                event,
+            }),
+            "oom" => Some(ContainerEvent::Oom(event)),
+            "destroy" => Some(ContainerEvent::Destroy(event)),
+            _ => None,
+        }
+    }
+
+    /// Return raw event
+    pub fn event(&self) -> &Event {
+        match self {
+            ContainerEvent::Create(event) => event,
+            ContainerEvent::Start(event) => event,
+            ContainerEvent::Die { event, .. } => event,
+            ContainerEvent::Oom(event) => event,
+            ContainerEvent::Destroy(event) => event,
+        }
+    }
+
+}
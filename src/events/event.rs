@@ -0,0 +1,109 @@
+use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+use crate::container::inspect::HealthStatus;
+use super::EventAction;
+
+/// Object which emitted the event
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq, Eq)]
+pub struct Actor {
+
+    #[serde(rename = "ID", default)]
+    id: String,
+
+    #[serde(rename = "Attributes", default)]
+    attributes: HashMap<String, String>,
+
+}
+
+impl Actor {
+
+    /// Return id of the object
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Return attributes of the object, e.g. `name`, `image` or `exitCode`
+    pub fn attributes(&self) -> &HashMap<String, String> {
+        &self.attributes
+    }
+
+    /// Return value of attribute `key`
+    pub fn attribute(&self, key: &str) -> Option<&str> {
+        self.attributes.get(key).map(String::as_str)
+    }
+
+}
+
+/// Event reported by the daemon
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq, Eq)]
+pub struct Event {
+
+    #[serde(rename = "Type", default)]
+    type_: String,
+
+    #[serde(rename = "Action", default)]
+    action: String,
+
+    #[serde(rename = "Actor", default)]
+    actor: Actor,
+
+    #[serde(default)]
+    scope: String,
+
+    #[serde(default)]
+    time: i64,
+
+    #[serde(rename = "timeNano", default)]
+    time_nano: i64,
+
+}
+
+impl Event {
+
+    /// Return type of the object, e.g. `container`, `image` or `network`
+    pub fn event_type(&self) -> &str {
+        &self.type_
+    }
+
+    /// Return action, e.g. `start` or `die`
+    pub fn action(&self) -> &str {
+        &self.action
+    }
+
+    /// Return action as `EventAction`
+    pub fn typed_action(&self) -> EventAction {
+        EventAction::from(self.action.as_str())
+    }
+
+    /// Return object which emitted the event
+    pub fn actor(&self) -> &Actor {
+        &self.actor
+    }
+
+    /// Return new status of `health_status` events, e.g. `health_status: healthy`
+    pub fn health_status(&self) -> Option<HealthStatus> {
+        self.action.strip_prefix("health_status:").map(|status| HealthStatus::from(status.trim().to_string()))
+    }
+
+    /// Return scope, `local` or `swarm`
+    pub fn scope(&self) -> &str {
+        &self.scope
+    }
+
+    /// Return time as seconds since epoch
+    pub fn time(&self) -> i64 {
+        self.time
+    }
+
+    /// Return time as nanoseconds since epoch
+    pub fn time_nano(&self) -> i64 {
+        self.time_nano
+    }
+
+    /// Return time as `DateTime<Utc>`
+    #[cfg(feature = "chrono")]
+    pub fn datetime(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        crate::additionals::timestamp::from_unix(self.time)
+    }
+
+}
@@ -0,0 +1,87 @@
+//!
+//! Events filter builder.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use docker_client::events::EventsFilter;
+//!
+//! let filters = EventsFilter::new()
+//!     .event_type("container")
+//!     .event("die")
+//!     .label("com.example.app", Some("web"))
+//!     .build();
+//!
+//! assert_eq!(filters.get("event"), Some(&vec!["die".to_string()]));
+//! ```
+
+use crate::additionals::filters::{Filters, FiltersBuilder};
+
+/// Events filter builder
+#[derive(Debug, Default, Clone)]
+pub struct EventsFilter {
+
+    filters: FiltersBuilder
+
+}
+
+impl EventsFilter {
+
+    /// Creates a new default instance of `EventsFilter`.
+    pub fn new() -> Self {
+        EventsFilter::default()
+    }
+
+    /// Only events of container `id` or name
+    pub fn container<T>(mut self, id: T) -> Self
+        where T: Into<String>
+    {
+        self.filters = self.filters.filter("container", id);
+
+        self
+    }
+
+    /// Only events of image `name` or id
+    pub fn image<T>(mut self, name: T) -> Self
+        where T: Into<String>
+    {
+        self.filters = self.filters.filter("image", name);
+
+        self
+    }
+
+    /// Only events of objects with label `key` or `key=value`
+    pub fn label<T, U>(mut self, key: T, value: Option<U>) -> Self
+        where
+            T: Into<String>,
+            U: Into<String>
+    {
+        self.filters = self.filters.label(key, value.map(Into::into));
+
+        self
+    }
+
+    /// Only events of object type, e.g. `container`, `image`, `volume` or `network`
+    pub fn event_type<T>(mut self, event_type: T) -> Self
+        where T: Into<String>
+    {
+        self.filters = self.filters.filter("type", event_type);
+
+        self
+    }
+
+    /// Only events with action, e.g. `start` or `die`
+    pub fn event<T>(mut self, action: T) -> Self
+        where T: Into<String>
+    {
+        self.filters = self.filters.filter("event", action);
+
+        self
+    }
+
+    /// Build `Filters` from `EventsFilter`
+    pub fn build(self) -> Filters {
+        self.filters.build()
+    }
+
+}
@@ -0,0 +1,13 @@
+//! Events module
+
+mod action;
+mod event;
+mod container;
+mod filter;
+mod request;
+
+pub use action::EventAction;
+pub use event::{Event, Actor};
+pub use container::ContainerEvent;
+pub use filter::EventsFilter;
+pub use request::EventsRequest;
@@ -0,0 +1,99 @@
+//!
+//! Events request.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use std::time::{Duration, UNIX_EPOCH};
+//! use docker_client::events::{EventsFilter, EventsRequest};
+//!
+//! let request = EventsRequest::new()
+//!     .since(UNIX_EPOCH + Duration::from_secs(1700000000))
+//!     .until(UNIX_EPOCH + Duration::from_millis(1700000060500))
+//!     .filters(EventsFilter::new().event_type("container").build());
+//!
+//! assert_eq!(
+//!     request.get_path(),
+//!     "/events?since=1700000000.000000000&until=1700000060.500000000&filters=%7B%22type%22%3A%5B%22container%22%5D%7D"
+//! );
+//! ```
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::additionals::filters::Filters;
+
+/// Request of daemon events, see `DockerClient::events`
+///
+/// Without `since` only new events are reported. Without `until` the stream
+/// never ends, the daemon closes it once `until` is reached otherwise.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct EventsRequest {
+
+    since: Option<SystemTime>,
+
+    until: Option<SystemTime>,
+
+    filters: Filters,
+
+}
+
+impl EventsRequest {
+
+    /// Creates a new instance of `EventsRequest` reporting every new event
+    pub fn new() -> Self {
+        EventsRequest::default()
+    }
+
+    /// Replay events since this time
+    pub fn since(mut self, v: SystemTime) -> Self {
+        self.since = Some(v);
+
+        self
+    }
+
+    /// End the stream at this time
+    pub fn until(mut self, v: SystemTime) -> Self {
+        self.until = Some(v);
+
+        self
+    }
+
+    /// Set filters, see `EventsFilter`
+    pub fn filters(mut self, f: Filters) -> Self {
+        self.filters = f;
+
+        self
+    }
+
+    /// Return path for request
+    pub fn get_path(&self) -> String {
+        let mut path = "/events?".to_string();
+
+        if let Some(since) = self.since {
+            path.push_str(format!("since={}&", timestamp(since)).as_str());
+        }
+        if let Some(until) = self.until {
+            path.push_str(format!("until={}&", timestamp(until)).as_str());
+        }
+        if let Some(filters) = self.filters.query() {
+            path.push_str(format!("{}&", filters).as_str());
+        }
+
+        path.pop();
+        path
+    }
+
+}
+
+impl From<Filters> for EventsRequest {
+    fn from(filters: Filters) -> Self {
+        EventsRequest::new().filters(filters)
+    }
+}
+
+/// Format `time` as seconds since epoch with nanoseconds, as the daemon expects.
+fn timestamp(time: SystemTime) -> String {
+    let time = time.duration_since(UNIX_EPOCH).unwrap_or_default();
+
+    format!("{}.{:09}", time.as_secs(), time.subsec_nanos())
+}
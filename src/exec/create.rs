@@ -1,5 +1,6 @@
 
 use serde::{Deserialize, Serialize};
+use crate::container::ContainerRefId;
 
 pub struct RequestBuilder {
 
@@ -33,10 +34,10 @@ impl Default for RequestBuilder {
 impl RequestBuilder {
 
     pub fn with_container<T>(container: T) -> Self
-        where T: Into<String>
+        where T: Into<ContainerRefId>
     {
         let mut builder = RequestBuilder::default();
-        builder.id = container.into();
+        builder.id = String::from(container.into());
         builder
     }
 
@@ -93,7 +94,7 @@ impl RequestBuilder {
 
 }
 
-#[derive(Deserialize, Serialize)]
+#[derive(Deserialize, Serialize, Clone, PartialEq, Eq)]
 pub struct Request {
 
     #[serde(skip_serializing)]
@@ -111,7 +112,7 @@ pub struct Request {
     #[serde(rename = "AttachStdin")]
     attach_stdin: bool,
 
-    #[serde(rename = "AttachOut")]
+    #[serde(rename = "AttachStdout")]
     attach_stdout: bool,
 
 }
@@ -119,9 +120,9 @@ pub struct Request {
 impl Request {
 
     pub fn with_container<T>(container: T) -> RequestBuilder
-        where T: Into<String>
+        where T: Into<ContainerRefId>
     {
-        RequestBuilder::with_container(container.into())
+        RequestBuilder::with_container(container)
     }
 
     pub fn get_path(&self) -> String {
@@ -130,7 +131,7 @@ impl Request {
 
 }
 
-#[derive(Deserialize, Serialize)]
+#[derive(Deserialize, Serialize, Clone, PartialEq, Eq)]
 pub struct Exec {
 
     #[serde(rename = "Id")]
@@ -1,2 +1,5 @@
 pub mod create;
-pub mod inspect;
\ No newline at end of file
+pub mod inspect;
+mod output;
+
+pub use output::ExecOutput;
\ No newline at end of file
@@ -0,0 +1,45 @@
+use serde::Serialize;
+
+/// Captured result of a command executed in a container.
+#[derive(Debug, Clone, Default, Serialize, PartialEq, Eq)]
+pub struct ExecOutput {
+
+    stdout: String,
+
+    stderr: String,
+
+    exit_code: i32
+
+}
+
+impl ExecOutput {
+
+    pub(crate) fn new(stdout: String, stderr: String, exit_code: i32) -> Self {
+        ExecOutput {
+            stdout,
+            stderr,
+            exit_code
+        }
+    }
+
+    /// Return captured stdout
+    pub fn stdout(&self) -> &str {
+        &self.stdout
+    }
+
+    /// Return captured stderr
+    pub fn stderr(&self) -> &str {
+        &self.stderr
+    }
+
+    /// Return exit code of the command
+    pub fn exit_code(&self) -> i32 {
+        self.exit_code
+    }
+
+    /// Return `true` if the command exited with code 0
+    pub fn success(&self) -> bool {
+        self.exit_code == 0
+    }
+
+}
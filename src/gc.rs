@@ -0,0 +1,214 @@
+//!
+//! Garbage collection of docker objects.
+//!
+//! The module provides [GarbageCollector](struct.GarbageCollector.html), which removes stopped
+//! containers, dangling images and unused volumes matching labels and older than an age,
+//! like the cleanup job of a CI runner.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # use docker_client::DockerClient;
+//! use docker_client::gc::GarbageCollector;
+//! use std::time::Duration;
+//!
+//! # #[tokio::main]
+//! async fn main() {
+//!     let client = DockerClient::new();
+//!
+//!     let gc = GarbageCollector::new()
+//!         .label("com.example.ci", None)
+//!         .older_than(Duration::from_secs(24 * 3600));
+//!
+//!     match client.collect_garbage(gc).await {
+//!         Ok(report) => { println!("{} bytes reclaimed", report.space_reclaimed()); },
+//!         Err(e) => { println!("Error: {:?}", e); },
+//!     }
+//! }
+//! ```
+
+use std::time::{Duration, SystemTime};
+
+use crate::additionals::filters::{Filters, FiltersBuilder};
+use crate::additionals::timestamp::parse_system_time;
+use crate::client::{DockerClient, DockerError};
+use crate::image::RemovedImage;
+
+/// Selection of containers, images and volumes to remove
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GarbageCollector {
+
+    labels: Vec<(String, Option<String>)>,
+
+    older_than: Option<Duration>,
+
+    containers: bool,
+
+    images: bool,
+
+    volumes: bool,
+
+}
+
+impl GarbageCollector {
+
+    /// Creates a new `GarbageCollector` removing all stopped containers,
+    /// dangling images and unused volumes
+    pub fn new() -> Self {
+        GarbageCollector {
+            labels: Vec::new(),
+            older_than: None,
+            containers: true,
+            images: true,
+            volumes: true,
+        }
+    }
+
+    /// Only remove objects with label `key` or `key=value`, may be called several times
+    pub fn label<T>(mut self, key: T, value: Option<String>) -> Self
+        where T: Into<String>
+    {
+        self.labels.push((key.into(), value));
+
+        self
+    }
+
+    /// Only remove objects created more than `age` ago
+    pub fn older_than(mut self, age: Duration) -> Self {
+        self.older_than = Some(age);
+
+        self
+    }
+
+    /// Remove stopped containers, `true` by default
+    pub fn containers(mut self, v: bool) -> Self {
+        self.containers = v;
+
+        self
+    }
+
+    /// Remove dangling images, `true` by default
+    pub fn images(mut self, v: bool) -> Self {
+        self.images = v;
+
+        self
+    }
+
+    /// Remove volumes not used by any container, `true` by default
+    pub fn volumes(mut self, v: bool) -> Self {
+        self.volumes = v;
+
+        self
+    }
+
+    fn filters(&self) -> FiltersBuilder {
+        self.labels.iter()
+            .fold(Filters::new(), |filters, (key, value)| filters.label(key.clone(), value.clone()))
+    }
+
+    /// Return `true` if the volume was created before the age limit.
+    /// Volumes of unknown age are kept.
+    fn is_old(&self, created: &str) -> bool {
+        let age = match self.older_than {
+            Some(age) => age,
+            None => return true,
+        };
+
+        match (parse_system_time(created), SystemTime::now().checked_sub(age)) {
+            (Some(created), Some(limit)) => created <= limit,
+            _ => false,
+        }
+    }
+
+    pub(crate) async fn run(self, client: &DockerClient) -> Result<GcReport, DockerError> {
+        let mut report = GcReport::default();
+
+        // The daemon accepts Go durations as `until`, relative to its own clock.
+        let until = self.older_than.map(|age| format!("{}s", age.as_secs()));
+
+        // Containers go first, they keep images and volumes in use.
+        if self.containers {
+            let mut filters = self.filters();
+            if let Some(until) = &until {
+                filters = filters.until(until.clone());
+            }
+
+            let pruned = client.prune_containers(filters.build()).await?;
+            report.space_reclaimed += pruned.space_reclaimed();
+            report.containers_deleted.extend(pruned.containers_deleted().iter().cloned());
+        }
+
+        if self.images {
+            let mut filters = self.filters().dangling(true);
+            if let Some(until) = &until {
+                filters = filters.until(until.clone());
+            }
+
+            let pruned = client.prune_images(filters.build()).await?;
+            report.space_reclaimed += pruned.space_reclaimed();
+            report.images_deleted.extend(pruned.images_deleted().iter().cloned());
+        }
+
+        // Volume prune has no `until` filter, so unused volumes are listed and removed one by one.
+        if self.volumes {
+            let list = client.volumes_list(self.filters().dangling(true).build()).await?;
+
+            for volume in list.volumes().iter().filter(|volume| self.is_old(volume.created())) {
+                match client.remove_volume(volume.name(), false).await {
+                    Ok(_) => report.volumes_deleted.push(volume.name().to_string()),
+                    // Taken in use or removed since listed.
+                    Err(DockerError::Busy(_)) | Err(DockerError::NotFound(_)) => {},
+                    Err(e) => return Err(e),
+                }
+            }
+        }
+
+        Ok(report)
+    }
+
+}
+
+impl Default for GarbageCollector {
+    fn default() -> Self {
+        GarbageCollector::new()
+    }
+}
+
+/// Objects removed by a `GarbageCollector`
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct GcReport {
+
+    containers_deleted: Vec<String>,
+
+    images_deleted: Vec<RemovedImage>,
+
+    volumes_deleted: Vec<String>,
+
+    space_reclaimed: i64,
+
+}
+
+impl GcReport {
+
+    /// Return IDs of the deleted containers
+    pub fn containers_deleted(&self) -> &Vec<String> {
+        &self.containers_deleted
+    }
+
+    /// Return untagged references and deleted images
+    pub fn images_deleted(&self) -> &Vec<RemovedImage> {
+        &self.images_deleted
+    }
+
+    /// Return names of the deleted volumes
+    pub fn volumes_deleted(&self) -> &Vec<String> {
+        &self.volumes_deleted
+    }
+
+    /// Return disk space reclaimed by containers and images in bytes,
+    /// the daemon does not report the size of removed volumes
+    pub fn space_reclaimed(&self) -> i64 {
+        self.space_reclaimed
+    }
+
+}
@@ -0,0 +1,296 @@
+//!
+//! Image build options.
+//!
+//! The module provides [BuildOptionsBuilder](struct.BuildOptionsBuilder.html) and [BuildOptions](struct.BuildOptions.html) types
+//! used to build an image from a tar archive context.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use docker_client::image::build::BuildOptions;
+//!
+//! let options = BuildOptions::builder()
+//!     .tag("app:latest")
+//!     .dockerfile("docker/Dockerfile")
+//!     .build_arg("VERSION", "1.0")
+//!     .target("release")
+//!     .no_cache(true)
+//!     .build();
+//!
+//! assert_eq!(
+//!     options.get_path(),
+//!     "/build?dockerfile=docker%2FDockerfile&t=app%3Alatest&buildargs=%7B%22VERSION%22%3A%221.0%22%7D&target=release&nocache=true"
+//! );
+//! ```
+//!
+//! Remote context example.
+//! ```rust
+//! use docker_client::image::build::BuildOptions;
+//!
+//! let options = BuildOptions::builder()
+//!     .remote("https://github.com/docker-library/hello-world.git#master:amd64/hello-world")
+//!     .build();
+//!
+//! assert_eq!(
+//!     options.get_path(),
+//!     "/build?remote=https%3A%2F%2Fgithub.com%2Fdocker-library%2Fhello-world.git%23master%3Aamd64%2Fhello-world"
+//! );
+//! ```
+
+use std::collections::BTreeMap;
+use serde::{Deserialize, Serialize};
+use crate::additionals::query::encode;
+use crate::image::ImageId;
+
+/// Build options builder
+#[derive(Debug, Default)]
+pub struct BuildOptionsBuilder {
+    options: BuildOptions,
+}
+
+impl BuildOptionsBuilder {
+
+    /// Creates a new default instance of `BuildOptionsBuilder` to construct a `BuildOptions`.
+    pub fn new() -> Self {
+        BuildOptionsBuilder::default()
+    }
+
+    /// Set path of the Dockerfile within the build context, `Dockerfile` by default
+    pub fn dockerfile<T>(mut self, path: T) -> Self
+        where T: Into<String>
+    {
+        self.options.dockerfile = Some(path.into());
+
+        self
+    }
+
+    /// Add `name:tag` to apply to the image
+    pub fn tag<T>(mut self, tag: T) -> Self
+        where T: Into<String>
+    {
+        self.options.tags.push(tag.into());
+
+        self
+    }
+
+    /// Add build-time variable
+    pub fn build_arg<T, U>(mut self, key: T, value: U) -> Self
+        where
+            T: Into<String>,
+            U: Into<String>
+    {
+        self.options.build_args.insert(key.into(), value.into());
+
+        self
+    }
+
+    /// Set target stage of a multi-stage build
+    pub fn target<T>(mut self, target: T) -> Self
+        where T: Into<String>
+    {
+        self.options.target = Some(target.into());
+
+        self
+    }
+
+    /// Do not use the cache
+    pub fn no_cache(mut self, v: bool) -> Self {
+        self.options.no_cache = Some(v);
+
+        self
+    }
+
+    /// Always attempt to pull a newer version of base images
+    pub fn pull(mut self, v: bool) -> Self {
+        self.options.pull = Some(v);
+
+        self
+    }
+
+    /// Add image used as cache source
+    pub fn cache_from<T>(mut self, image: T) -> Self
+        where T: Into<String>
+    {
+        self.options.cache_from.push(image.into());
+
+        self
+    }
+
+    /// Add label to the image
+    pub fn label<T, U>(mut self, key: T, value: U) -> Self
+        where
+            T: Into<String>,
+            U: Into<String>
+    {
+        self.options.labels.insert(key.into(), value.into());
+
+        self
+    }
+
+    /// Set network mode for `RUN` instructions, e.g. `host` or `none`
+    pub fn network_mode<T>(mut self, mode: T) -> Self
+        where T: Into<String>
+    {
+        self.options.network_mode = Some(mode.into());
+
+        self
+    }
+
+    /// Add `host:ip` entry to `/etc/hosts` of build containers
+    pub fn extra_host<T>(mut self, host: T) -> Self
+        where T: Into<String>
+    {
+        self.options.extra_hosts.push(host.into());
+
+        self
+    }
+
+    /// Set target platform, e.g. `linux/arm64`
+    pub fn platform<T>(mut self, platform: T) -> Self
+        where T: Into<String>
+    {
+        self.options.platform = Some(platform.into());
+
+        self
+    }
+
+    /// Squash the resulting layers into a single layer, experimental daemons only
+    pub fn squash(mut self, v: bool) -> Self {
+        self.options.squash = Some(v);
+
+        self
+    }
+
+    /// Set remote build context, a git repository (`https://github.com/user/repo.git#branch:dir`)
+    /// or a URL of a tar archive or a plain Dockerfile
+    pub fn remote<T>(mut self, url: T) -> Self
+        where T: Into<String>
+    {
+        self.options.remote = Some(url.into());
+
+        self
+    }
+
+    /// Build `BuildOptions` from `BuildOptionsBuilder`
+    pub fn build(self) -> BuildOptions {
+        self.options
+    }
+
+}
+
+/// Build options
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct BuildOptions {
+    dockerfile: Option<String>,
+    tags: Vec<String>,
+    build_args: BTreeMap<String, String>,
+    target: Option<String>,
+    no_cache: Option<bool>,
+    pull: Option<bool>,
+    cache_from: Vec<String>,
+    labels: BTreeMap<String, String>,
+    network_mode: Option<String>,
+    extra_hosts: Vec<String>,
+    platform: Option<String>,
+    squash: Option<bool>,
+    remote: Option<String>,
+}
+
+impl BuildOptions {
+
+    /// Creates a new default instance of `BuildOptionsBuilder` to construct a `BuildOptions`.
+    pub fn builder() -> BuildOptionsBuilder {
+        BuildOptionsBuilder::default()
+    }
+
+    /// Return remote build context
+    pub fn remote(&self) -> Option<&str> {
+        self.remote.as_deref()
+    }
+
+    pub(crate) fn with_remote(mut self, url: String) -> Self {
+        self.remote = Some(url);
+        self
+    }
+
+    /// Return path for request
+    pub fn get_path(&self) -> String {
+        let mut path = "/build?".to_string();
+
+        let mut push = |key: &str, value: &str| {
+            path.push_str(format!("{}={}&", key, encode(value)).as_str());
+        };
+
+        if let Some(dockerfile) = &self.dockerfile {
+            push("dockerfile", dockerfile);
+        }
+        for tag in &self.tags {
+            push("t", tag);
+        }
+        if !self.build_args.is_empty() {
+            push("buildargs", serde_json::to_string(&self.build_args).unwrap().as_str());
+        }
+        if let Some(target) = &self.target {
+            push("target", target);
+        }
+        if let Some(no_cache) = self.no_cache {
+            push("nocache", no_cache.to_string().as_str());
+        }
+        if let Some(pull) = self.pull {
+            push("pull", pull.to_string().as_str());
+        }
+        if !self.cache_from.is_empty() {
+            push("cachefrom", serde_json::to_string(&self.cache_from).unwrap().as_str());
+        }
+        if !self.labels.is_empty() {
+            push("labels", serde_json::to_string(&self.labels).unwrap().as_str());
+        }
+        if let Some(network_mode) = &self.network_mode {
+            push("networkmode", network_mode);
+        }
+        if !self.extra_hosts.is_empty() {
+            push("extrahosts", self.extra_hosts.join(",").as_str());
+        }
+        if let Some(platform) = &self.platform {
+            push("platform", platform);
+        }
+        if let Some(squash) = self.squash {
+            push("squash", squash.to_string().as_str());
+        }
+        if let Some(remote) = &self.remote {
+            push("remote", remote);
+        }
+
+        path.pop();
+        path
+    }
+
+}
+
+/// Built image structure
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct BuiltImage {
+
+    id: ImageId,
+
+    output: String,
+
+}
+
+impl BuiltImage {
+
+    pub(crate) fn new(id: ImageId, output: String) -> Self {
+        BuiltImage { id, output }
+    }
+
+    /// Return id of the built image
+    pub fn id(&self) -> &ImageId {
+        &self.id
+    }
+
+    /// Return build output
+    pub fn output(&self) -> &str {
+        &self.output
+    }
+
+}
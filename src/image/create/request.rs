@@ -1,4 +1,4 @@
-
+use crate::client::Auth;
 
 #[derive(Default)]
 pub struct RequestBuilder {
@@ -13,7 +13,11 @@ pub struct RequestBuilder {
 
     message: String,
 
-    platform: String
+    platform: String,
+
+    digest: Option<String>,
+
+    auth: Option<Auth>
 
 }
 
@@ -71,6 +75,22 @@ impl RequestBuilder {
         self
     }
 
+    /// Require the pulled image to have repo digest `digest`, e.g. `sha256:...`
+    pub fn digest<T>(mut self, digest: T) -> Self
+        where T: Into<String>
+    {
+        self.digest = Some(digest.into());
+
+        self
+    }
+
+    /// Pull with credentials `auth` instead of those of the client
+    pub fn auth(mut self, auth: Auth) -> Self {
+        self.auth = Some(auth);
+
+        self
+    }
+
     pub fn build(self) -> Request {
         Request {
             from_image: self.from_image,
@@ -81,12 +101,15 @@ impl RequestBuilder {
                 _=> self.tag.clone()
             },
             message: self.message,
-            platform: self.platform
+            platform: self.platform,
+            digest: self.digest,
+            auth: self.auth
         }
     }
 
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Request {
 
     from_image: String,
@@ -99,7 +122,11 @@ pub struct Request {
 
     message: String,
 
-    platform: String
+    platform: String,
+
+    digest: Option<String>,
+
+    auth: Option<Auth>
 
 }
 
@@ -129,6 +156,24 @@ impl Request {
         &self.platform
     }
 
+    /// Return digest the pulled image must have
+    pub fn digest(&self) -> Option<&str> {
+        self.digest.as_deref()
+    }
+
+    /// Return credentials of the pull if set
+    pub fn auth(&self) -> Option<&Auth> {
+        self.auth.as_ref()
+    }
+
+    /// Return reference of the pulled image, `image:tag` or `image@digest`
+    pub(crate) fn reference(&self) -> String {
+        match self.tag.contains(':') {
+            true => format!("{}@{}", self.from_image, self.tag),
+            false => format!("{}:{}", self.from_image, self.tag),
+        }
+    }
+
     pub fn get_path(&self) -> String {
 
         let mut path = String::from("/images/create?");
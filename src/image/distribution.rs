@@ -0,0 +1,148 @@
+use serde::{Deserialize, Serialize};
+use crate::additionals::serde_helpers::nullable_default;
+
+/// Content descriptor of a manifest
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq, Eq)]
+pub struct Descriptor {
+
+    #[serde(rename = "mediaType", default)]
+    media_type: String,
+
+    #[serde(default)]
+    digest: String,
+
+    #[serde(default)]
+    size: i64,
+
+}
+
+impl Descriptor {
+
+    /// Return media type, e.g. `application/vnd.docker.distribution.manifest.list.v2+json`
+    pub fn media_type(&self) -> &str {
+        &self.media_type
+    }
+
+    /// Return digest, e.g. `sha256:...`
+    pub fn digest(&self) -> &str {
+        &self.digest
+    }
+
+    /// Return size in bytes
+    pub fn size(&self) -> i64 {
+        self.size
+    }
+
+    /// Return `true` if the manifest is a multi-platform list or OCI index
+    pub fn is_manifest_list(&self) -> bool {
+        self.media_type.contains("manifest.list") || self.media_type.contains("image.index")
+    }
+
+}
+
+/// Platform of an image
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct Platform {
+
+    #[serde(default)]
+    architecture: String,
+
+    #[serde(default)]
+    os: String,
+
+    #[serde(rename = "os.version", default, skip_serializing_if = "String::is_empty")]
+    os_version: String,
+
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    variant: String,
+
+}
+
+impl Platform {
+
+    /// Return CPU architecture, e.g. `amd64` or `arm64`
+    pub fn architecture(&self) -> &str {
+        &self.architecture
+    }
+
+    /// Return operating system, e.g. `linux`
+    pub fn os(&self) -> &str {
+        &self.os
+    }
+
+    /// Return operating system version, set for windows images
+    pub fn os_version(&self) -> Option<&str> {
+        match self.os_version.as_str() {
+            "" => None,
+            version => Some(version),
+        }
+    }
+
+    /// Return CPU variant, e.g. `v7` for `arm`
+    pub fn variant(&self) -> Option<&str> {
+        match self.variant.as_str() {
+            "" => None,
+            variant => Some(variant),
+        }
+    }
+
+    /// Return `true` if the platform matches `os/arch[/variant]`, a missing variant matches any
+    pub fn matches(&self, platform: &str) -> bool {
+        let mut parts = platform.split('/');
+
+        match (parts.next(), parts.next(), parts.next()) {
+            (Some(os), Some(arch), variant) => {
+                self.os == os && self.architecture == arch
+                    && variant.is_none_or(|variant| self.variant == variant)
+            },
+            _ => false,
+        }
+    }
+
+}
+
+impl std::fmt::Display for Platform {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.variant() {
+            Some(variant) => write!(f, "{}/{}/{}", self.os, self.architecture, variant),
+            None => write!(f, "{}/{}", self.os, self.architecture),
+        }
+    }
+}
+
+/// Distribution info of an image reference, as reported by the registry
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq, Eq)]
+pub struct DistributionInfo {
+
+    #[serde(rename = "Descriptor", default)]
+    descriptor: Descriptor,
+
+    #[serde(rename = "Platforms", deserialize_with = "nullable_default", default)]
+    platforms: Vec<Platform>,
+
+}
+
+impl DistributionInfo {
+
+    /// Return descriptor of the manifest or manifest list
+    pub fn descriptor(&self) -> &Descriptor {
+        &self.descriptor
+    }
+
+    /// Return available platforms
+    pub fn platforms(&self) -> &Vec<Platform> {
+        &self.platforms
+    }
+
+    /// Return digest to pin for `platform` (`os/arch[/variant]`), `None` if the platform is not available
+    ///
+    /// The daemon only reports the digest of the manifest list. Pulling `name@digest`
+    /// with the requested platform resolves the platform manifest, so this is the digest to pin.
+    pub fn digest_for(&self, platform: &str) -> Option<&str> {
+        match self.platforms.iter().any(|p| p.matches(platform)) {
+            true => Some(self.descriptor.digest()),
+            false => None,
+        }
+    }
+
+}
@@ -0,0 +1,91 @@
+use crate::{DockerClient, DockerError};
+use crate::image::{ImageInfo, ImageHistory, RemovedImage, PushedImage, PullSummary};
+use crate::image::reference::split_reference;
+
+/// Handle to an image bound to a client.
+///
+/// Returned by [DockerClient::image](../struct.DockerClient.html#method.image).
+///
+/// # Examples
+///
+/// ```rust
+/// # use docker_client::DockerClient;
+/// # #[tokio::main]
+/// async fn main() {
+///     let client = DockerClient::new();
+///
+///     let image = client.image("alpine:3.19");
+///     if image.pull().await.is_ok() {
+///         match image.tag("localhost:5000/alpine", "3.19").await {
+///             Ok(tagged) => { let _ = tagged.push().await; },
+///             Err(e) => println!("Error: {:?}", e),
+///         }
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ImageRef<'a> {
+    client: &'a DockerClient,
+    name: String,
+}
+
+impl<'a> ImageRef<'a> {
+
+    pub(crate) fn new(client: &'a DockerClient, name: String) -> Self {
+        ImageRef {
+            client,
+            name
+        }
+    }
+
+    /// Return image reference
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Pull the image from registry
+    pub async fn pull(&self) -> Result<PullSummary, DockerError> {
+        let (image, tag) = split_reference(&self.name);
+
+        let request = crate::image::create::RequestBuilder::new()
+            .image(image)
+            .tag(tag.unwrap_or("latest"))
+            .build();
+
+        self.client.pull_image(request).await
+    }
+
+    /// Return low-level information about the image
+    pub async fn inspect(&self) -> Result<ImageInfo, DockerError> {
+        self.client.inspect_image(self.name.as_str()).await
+    }
+
+    /// Return parent layers of the image
+    pub async fn history(&self) -> Result<Vec<ImageHistory>, DockerError> {
+        self.client.image_history(self.name.as_str()).await
+    }
+
+    /// Tag the image and return handle to the new reference
+    pub async fn tag<T, U>(&self, repo: T, tag: U) -> Result<ImageRef<'a>, DockerError>
+        where
+            T: Into<String>,
+            U: Into<String>
+    {
+        let repo = repo.into();
+        let tag = tag.into();
+
+        self.client.tag_image(self.name.as_str(), repo.as_str(), tag.as_str()).await?;
+
+        Ok(ImageRef::new(self.client, format!("{}:{}", repo, tag)))
+    }
+
+    /// Push the image to registry, returning the pushed digest
+    pub async fn push(&self) -> Result<PushedImage, DockerError> {
+        self.client.push_image(self.name.as_str()).await
+    }
+
+    /// Remove the image
+    pub async fn remove(&self, force: bool) -> Result<Vec<RemovedImage>, DockerError> {
+        self.client.remove_image(self.name.as_str(), force, false).await
+    }
+}
@@ -0,0 +1,65 @@
+use serde::{Deserialize, Serialize};
+use crate::additionals::serde_helpers::nullable_priority_vec;
+
+/// Image history item structure
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct ImageHistory {
+
+    #[serde(rename = "Id")]
+    id: String,
+
+    #[serde(rename = "Created")]
+    created: i64,
+
+    #[serde(rename = "CreatedBy")]
+    created_by: String,
+
+    #[serde(rename = "Tags", deserialize_with = "nullable_priority_vec", default)]
+    tags: Vec<String>,
+
+    #[serde(rename = "Size")]
+    size: i64,
+
+    #[serde(rename = "Comment")]
+    comment: String,
+
+}
+
+impl ImageHistory {
+
+    /// Return id of layer, `<missing>` for layers built elsewhere
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Return created as seconds since epoch
+    pub fn created(&self) -> i64 {
+        self.created
+    }
+
+    /// Return created as `DateTime<Utc>`
+    #[cfg(feature = "chrono")]
+    pub fn created_datetime(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        crate::additionals::timestamp::from_unix(self.created)
+    }
+
+    /// Return command which created the layer
+    pub fn created_by(&self) -> &str {
+        &self.created_by
+    }
+
+    /// Return tags
+    pub fn tags(&self) -> &Vec<String> {
+        &self.tags
+    }
+
+    /// Return size of layer
+    pub fn size(&self) -> i64 {
+        self.size
+    }
+
+    /// Return comment
+    pub fn comment(&self) -> &str {
+        &self.comment
+    }
+}
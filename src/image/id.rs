@@ -0,0 +1,83 @@
+//! Image identifiers
+
+use std::fmt;
+use std::ops::Deref;
+use serde::{Deserialize, Serialize};
+
+/// Image ID as returned by the daemon, e.g. `sha256:...`
+///
+/// # Examples
+///
+/// ```rust
+/// use docker_client::image::ImageId;
+///
+/// let id = ImageId::from("sha256:a24bb4013296f61e89ba57005a7b3e52274d8edd3ae2077d04395f806b63d83e".to_string());
+///
+/// assert_eq!(id.short(), "a24bb4013296");
+/// assert!(id.starts_with("sha256:"));
+/// ```
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[serde(transparent)]
+pub struct ImageId(String);
+
+impl ImageId {
+
+    /// Return ID as string slice
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Return first 12 characters of the ID without the digest algorithm, like `docker images` shows
+    pub fn short(&self) -> &str {
+        let id = match self.0.find(':') {
+            Some(index) => &self.0[index + 1..],
+            None => &self.0,
+        };
+
+        crate::container::id::short(id)
+    }
+
+    /// Return inner string
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+
+}
+
+impl From<String> for ImageId {
+    fn from(id: String) -> Self {
+        ImageId(id)
+    }
+}
+
+impl From<ImageId> for String {
+    fn from(id: ImageId) -> Self {
+        id.0
+    }
+}
+
+impl From<&ImageId> for String {
+    fn from(id: &ImageId) -> Self {
+        id.0.clone()
+    }
+}
+
+impl Deref for ImageId {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for ImageId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for ImageId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
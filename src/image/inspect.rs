@@ -0,0 +1,290 @@
+use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+use crate::additionals::serde_helpers::{nullable_default, nullable_priority_vec};
+use crate::container::HealthCheck;
+use super::ImageId;
+
+/// Root filesystem of an image
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq, Eq)]
+pub struct RootFS {
+
+    #[serde(rename = "Type", default)]
+    fs_type: String,
+
+    #[serde(rename = "Layers", deserialize_with = "nullable_priority_vec", default)]
+    layers: Vec<String>,
+
+}
+
+impl RootFS {
+
+    /// Return type of the root filesystem, usually `layers`
+    pub fn fs_type(&self) -> &str {
+        &self.fs_type
+    }
+
+    /// Return digests of the layers from bottom to top
+    pub fn layers(&self) -> &Vec<String> {
+        &self.layers
+    }
+
+}
+
+/// Configuration baked into an image
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+pub struct ImageConfig {
+
+    #[serde(rename = "User", default)]
+    user: String,
+
+    #[serde(rename = "ExposedPorts", deserialize_with = "nullable_default", default)]
+    exposed_ports: HashMap<String, serde_json::Value>,
+
+    #[serde(rename = "Env", deserialize_with = "nullable_priority_vec", default)]
+    env: Vec<String>,
+
+    #[serde(rename = "Cmd", deserialize_with = "nullable_priority_vec", default)]
+    cmd: Vec<String>,
+
+    #[serde(rename = "Entrypoint", deserialize_with = "nullable_priority_vec", default)]
+    entry_point: Vec<String>,
+
+    #[serde(rename = "Healthcheck", default)]
+    health_check: Option<HealthCheck>,
+
+    #[serde(rename = "Volumes", deserialize_with = "nullable_default", default)]
+    volumes: HashMap<String, serde_json::Value>,
+
+    #[serde(rename = "WorkingDir", default)]
+    work_dir: String,
+
+    #[serde(rename = "Labels", deserialize_with = "nullable_default", default)]
+    labels: HashMap<String, String>,
+
+    #[serde(rename = "StopSignal", default)]
+    stop_signal: String,
+
+}
+
+impl ImageConfig {
+
+    /// Return default user
+    pub fn user(&self) -> &str {
+        &self.user
+    }
+
+    /// Return exposed ports, e.g. `80/tcp`
+    pub fn exposed_ports(&self) -> Vec<&str> {
+        let mut ports: Vec<&str> = self.exposed_ports.keys().map(String::as_str).collect();
+        ports.sort_unstable();
+        ports
+    }
+
+    /// Return environment variables in `KEY=value` form
+    pub fn env(&self) -> &Vec<String> {
+        &self.env
+    }
+
+    /// Return default command
+    pub fn cmd(&self) -> &Vec<String> {
+        &self.cmd
+    }
+
+    /// Return entrypoint
+    pub fn entry_point(&self) -> &Vec<String> {
+        &self.entry_point
+    }
+
+    /// Return health check
+    pub fn health_check(&self) -> Option<&HealthCheck> {
+        self.health_check.as_ref()
+    }
+
+    /// Return volume mount points
+    pub fn volumes(&self) -> Vec<&str> {
+        let mut volumes: Vec<&str> = self.volumes.keys().map(String::as_str).collect();
+        volumes.sort_unstable();
+        volumes
+    }
+
+    /// Return working directory
+    pub fn work_dir(&self) -> &str {
+        &self.work_dir
+    }
+
+    /// Return labels
+    pub fn labels(&self) -> &HashMap<String, String> {
+        &self.labels
+    }
+
+    /// Return signal to stop containers, empty if not set
+    pub fn stop_signal(&self) -> &str {
+        &self.stop_signal
+    }
+
+}
+
+/// Image info structure
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ImageInfo {
+
+    #[serde(rename = "Id")]
+    id: ImageId,
+
+    #[serde(rename = "RepoTags", deserialize_with = "nullable_priority_vec", default)]
+    repo_tags: Vec<String>,
+
+    #[serde(rename = "RepoDigests", deserialize_with = "nullable_priority_vec", default)]
+    repo_digests: Vec<String>,
+
+    #[serde(rename = "Parent", default)]
+    parent: String,
+
+    #[serde(rename = "Comment", default)]
+    comment: String,
+
+    #[serde(rename = "Created")]
+    created: String,
+
+    #[serde(rename = "DockerVersion", default)]
+    docker_version: String,
+
+    #[serde(rename = "Author", default)]
+    author: String,
+
+    #[serde(rename = "Size")]
+    size: i64,
+
+    #[serde(rename = "VirtualSize", default)]
+    virtual_size: i64,
+
+    #[serde(rename = "Os", default)]
+    os: String,
+
+    #[serde(rename = "Architecture", default)]
+    architecture: String,
+
+    #[serde(rename = "Variant", default, skip_serializing_if = "String::is_empty")]
+    variant: String,
+
+    #[serde(rename = "Config", deserialize_with = "nullable_default", default)]
+    config: ImageConfig,
+
+    #[serde(rename = "RootFS", default)]
+    root_fs: RootFS,
+
+    #[serde(flatten, skip_serializing)]
+    extra: HashMap<String, serde_json::Value>,
+
+}
+
+impl ImageInfo {
+
+    /// Return id of image
+    pub fn id(&self) -> &ImageId {
+        &self.id
+    }
+
+    /// Return repo tags
+    pub fn repo_tags(&self) -> &Vec<String> {
+        &self.repo_tags
+    }
+
+    /// Return repo digests
+    pub fn repo_digests(&self) -> &Vec<String> {
+        &self.repo_digests
+    }
+
+    /// Return `true` if a repo digest, `repository@digest`, has digest `digest`
+    pub fn has_digest(&self, digest: &str) -> bool {
+        self.repo_digests.iter()
+            .any(|repo_digest| repo_digest.rsplit_once('@').is_some_and(|(_, d)| d == digest))
+    }
+
+    /// Return parent id
+    pub fn parent(&self) -> &str {
+        &self.parent
+    }
+
+    /// Return comment
+    pub fn comment(&self) -> &str {
+        &self.comment
+    }
+
+    /// Return creation time as RFC 3339 string
+    pub fn created(&self) -> &str {
+        &self.created
+    }
+
+    /// Return creation time as `DateTime<Utc>`
+    #[cfg(feature = "chrono")]
+    pub fn created_datetime(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        crate::additionals::timestamp::parse_rfc3339(&self.created)
+    }
+
+    /// Return version of docker used to build the image
+    pub fn docker_version(&self) -> &str {
+        &self.docker_version
+    }
+
+    /// Return author
+    pub fn author(&self) -> &str {
+        &self.author
+    }
+
+    /// Return size of image
+    pub fn size(&self) -> i64 {
+        self.size
+    }
+
+    /// Return virtual size
+    pub fn virtual_size(&self) -> i64 {
+        self.virtual_size
+    }
+
+    /// Return operating system, e.g. `linux`
+    pub fn os(&self) -> &str {
+        &self.os
+    }
+
+    /// Return CPU architecture, e.g. `amd64`
+    pub fn architecture(&self) -> &str {
+        &self.architecture
+    }
+
+    /// Return CPU variant, e.g. `v8` for `arm64`
+    pub fn variant(&self) -> Option<&str> {
+        match self.variant.as_str() {
+            "" => None,
+            variant => Some(variant),
+        }
+    }
+
+    /// Return platform as `os/arch[/variant]`
+    pub fn platform(&self) -> String {
+        match self.variant() {
+            Some(variant) => format!("{}/{}/{}", self.os, self.architecture, variant),
+            None => format!("{}/{}", self.os, self.architecture),
+        }
+    }
+
+    /// Return configuration of the image
+    pub fn config(&self) -> &ImageConfig {
+        &self.config
+    }
+
+    /// Return root filesystem
+    pub fn root_fs(&self) -> &RootFS {
+        &self.root_fs
+    }
+
+    /// Return digests of the layers from bottom to top
+    pub fn layers(&self) -> &Vec<String> {
+        self.root_fs.layers()
+    }
+
+    /// Return fields unknown to this crate, e.g. added by newer daemons
+    pub fn extra(&self) -> &HashMap<String, serde_json::Value> {
+        &self.extra
+    }
+}
@@ -0,0 +1,93 @@
+//! Image list request
+
+use crate::additionals::filters::Filters;
+
+/// Image list request builder
+#[derive(Debug, Default)]
+pub struct RequestBuilder {
+
+    all: Option<bool>,
+
+    digests: Option<bool>,
+
+    filters: Filters
+
+}
+
+impl RequestBuilder {
+
+    /// Creates a new default instance of `RequestBuilder` to construct a `Request`.
+    pub fn new() -> Self {
+        RequestBuilder::default()
+    }
+
+    /// Show all images, by default intermediate image layers are hidden.
+    pub fn all(mut self, v: bool) -> Self {
+        self.all = Some(v);
+
+        self
+    }
+
+    /// Show digest information as a `RepoDigests` field on each image.
+    pub fn digests(mut self, v: bool) -> Self {
+        self.digests = Some(v);
+
+        self
+    }
+
+    /// Set filters, e.g. `dangling`, `label`, `reference`, `before`, `since`.
+    pub fn filters(mut self, f: Filters) -> Self {
+        self.filters = f;
+
+        self
+    }
+
+    /// Build `Request` from `RequestBuilder`
+    pub fn build(self) -> Request {
+        Request {
+            all: self.all,
+            digests: self.digests,
+            filters: self.filters
+        }
+    }
+
+}
+
+/// Image list request
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Request {
+
+    all: Option<bool>,
+
+    digests: Option<bool>,
+
+    filters: Filters
+
+}
+
+impl Request {
+
+    /// Creates a new default instance of `RequestBuilder` to construct a `Request`.
+    pub fn builder() -> RequestBuilder {
+        RequestBuilder::default()
+    }
+
+    /// Return path for request
+    pub fn get_path(&self) -> String {
+        let mut path = "/images/json?".to_string();
+
+        if let Some(all) = self.all {
+            path.push_str(format!("all={}&", all).as_str());
+        }
+        if let Some(digests) = self.digests {
+            path.push_str(format!("digests={}&", digests).as_str());
+        }
+        if let Some(filters) = self.filters.query() {
+            path.push_str(format!("{}&", filters).as_str());
+        }
+
+        path.pop();
+        path
+    }
+
+}
@@ -1,7 +1,28 @@
 //! Image module
 
 mod short_info;
+mod inspect;
+mod history;
+mod remove;
+mod handle;
+mod id;
+mod progress;
+mod push;
+mod pull;
+mod distribution;
+pub(crate) mod reference;
 
 pub mod create;
+pub mod list;
+pub mod build;
 
-pub use short_info::ShortImageInfo;
\ No newline at end of file
+pub use short_info::ShortImageInfo;
+pub use inspect::{ImageInfo, ImageConfig, RootFS};
+pub use history::ImageHistory;
+pub use remove::{RemovedImage, PrunedImages};
+pub use handle::ImageRef;
+pub use id::ImageId;
+pub use progress::{Progress, ProgressDetail, ErrorDetail};
+pub use push::PushedImage;
+pub use pull::{PullSummary, PullEvent, ImagePullEvent, MultiPullStream};
+pub use distribution::{DistributionInfo, Descriptor, Platform};
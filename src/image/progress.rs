@@ -0,0 +1,115 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Progress details of a layer
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq, Eq)]
+pub struct ProgressDetail {
+
+    #[serde(default)]
+    current: Option<u64>,
+
+    #[serde(default)]
+    total: Option<u64>,
+
+}
+
+impl ProgressDetail {
+
+    /// Return transferred bytes
+    pub fn current(&self) -> Option<u64> {
+        self.current
+    }
+
+    /// Return size in bytes
+    pub fn total(&self) -> Option<u64> {
+        self.total
+    }
+}
+
+/// Error details of a progress record
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq, Eq)]
+pub struct ErrorDetail {
+
+    #[serde(default)]
+    message: String,
+
+}
+
+impl ErrorDetail {
+
+    /// Return error message
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+/// Progress record of pull, push and build streams
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+pub struct Progress {
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    id: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    status: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    stream: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    progress: Option<String>,
+
+    #[serde(rename = "progressDetail", default, skip_serializing_if = "Option::is_none")]
+    progress_detail: Option<ProgressDetail>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+
+    #[serde(rename = "errorDetail", default, skip_serializing_if = "Option::is_none")]
+    error_detail: Option<ErrorDetail>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    aux: Option<Value>,
+
+}
+
+impl Progress {
+
+    /// Return layer id the record refers to
+    pub fn id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+
+    /// Return status, e.g. `Downloading` or `Pull complete`
+    pub fn status(&self) -> Option<&str> {
+        self.status.as_deref()
+    }
+
+    /// Return build output
+    pub fn stream(&self) -> Option<&str> {
+        self.stream.as_deref()
+    }
+
+    /// Return human readable progress bar
+    pub fn progress(&self) -> Option<&str> {
+        self.progress.as_deref()
+    }
+
+    /// Return progress details
+    pub fn progress_detail(&self) -> Option<&ProgressDetail> {
+        self.progress_detail.as_ref()
+    }
+
+    /// Return error message if the operation failed
+    pub fn error(&self) -> Option<&str> {
+        self.error_detail.as_ref()
+            .map(ErrorDetail::message)
+            .or(self.error.as_deref())
+    }
+
+    /// Return auxiliary data, e.g. the pushed digest
+    pub fn aux(&self) -> Option<&Value> {
+        self.aux.as_ref()
+    }
+
+}
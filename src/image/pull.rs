@@ -0,0 +1,282 @@
+use std::collections::{HashMap, HashSet};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use futures::stream::{self, Stream, StreamExt};
+use serde::Serialize;
+use super::Progress;
+
+use crate::client::{DockerClient, DockerError, ErrorMessage};
+
+/// Summary of an image pull, derived from the progress stream
+#[derive(Serialize, Debug, Default, Clone, PartialEq, Eq)]
+pub struct PullSummary {
+
+    digest: Option<String>,
+
+    status: String,
+
+    layers_downloaded: usize,
+
+    layers_cached: usize,
+
+    total_bytes: u64,
+
+}
+
+impl PullSummary {
+
+    /// Build summary from pull progress records
+    pub fn from_progress(records: &[Progress]) -> Self {
+        let mut tally = PullTally::default();
+        for record in records {
+            tally.add(record);
+        }
+
+        tally.summary()
+    }
+
+    /// Return digest of the pulled manifest, e.g. `sha256:...`
+    pub fn digest(&self) -> Option<&str> {
+        self.digest.as_deref()
+    }
+
+    /// Return final status, e.g. `Image is up to date for alpine:latest`
+    pub fn status(&self) -> &str {
+        &self.status
+    }
+
+    /// Return count of downloaded layers
+    pub fn layers_downloaded(&self) -> usize {
+        self.layers_downloaded
+    }
+
+    /// Return count of layers already present
+    pub fn layers_cached(&self) -> usize {
+        self.layers_cached
+    }
+
+    /// Return downloaded bytes, compressed layer sizes
+    pub fn total_bytes(&self) -> u64 {
+        self.total_bytes
+    }
+
+    /// Return `true` if nothing had to be downloaded
+    pub fn up_to_date(&self) -> bool {
+        self.layers_downloaded == 0
+    }
+
+}
+
+/// Running counters of a pull, to build a `PullSummary` without keeping the records.
+#[derive(Debug, Default)]
+struct PullTally {
+    digest: Option<String>,
+    status: String,
+    downloaded: HashSet<String>,
+    cached: HashSet<String>,
+    sizes: HashMap<String, u64>,
+}
+
+impl PullTally {
+
+    /// Count a progress record.
+    fn add(&mut self, record: &Progress) {
+        let status = match record.status() {
+            Some(status) => status,
+            None => return,
+        };
+
+        if let Some(digest) = status.strip_prefix("Digest: ") {
+            self.digest = Some(digest.trim().to_string());
+        } else if let Some(status) = status.strip_prefix("Status: ") {
+            self.status = status.trim().to_string();
+        }
+
+        let id = match record.id() {
+            Some(id) => id,
+            None => return,
+        };
+
+        match status {
+            "Already exists" => { self.cached.insert(id.to_string()); },
+            "Pull complete" => { self.downloaded.insert(id.to_string()); },
+            "Downloading" => {
+                if let Some(total) = record.progress_detail().and_then(|detail| detail.total()) {
+                    let size = self.sizes.entry(id.to_string()).or_insert(0);
+                    *size = (*size).max(total);
+                }
+            },
+            _ => {},
+        }
+    }
+
+    /// Return summary of the records counted so far.
+    fn summary(&self) -> PullSummary {
+        PullSummary {
+            digest: self.digest.clone(),
+            status: self.status.clone(),
+            layers_downloaded: self.downloaded.len(),
+            layers_cached: self.cached.len(),
+            total_bytes: self.downloaded.iter()
+                .filter_map(|id| self.sizes.get(id))
+                .sum(),
+        }
+    }
+
+}
+
+/// Event of one image in a `MultiPullStream`
+#[derive(Debug)]
+pub enum PullEvent {
+
+    /// Progress record of the pull
+    Progress(Progress),
+
+    /// Pull finished, last event of the image
+    Done(PullSummary),
+
+    /// Pull failed, last event of the image
+    Failed(DockerError),
+
+}
+
+/// Pull event tagged with the image reference
+#[derive(Debug)]
+pub struct ImagePullEvent {
+
+    reference: String,
+
+    event: PullEvent,
+
+}
+
+impl ImagePullEvent {
+
+    /// Return image reference, as passed to `DockerClient::pull_images`
+    pub fn reference(&self) -> &str {
+        &self.reference
+    }
+
+    /// Return event
+    pub fn event(&self) -> &PullEvent {
+        &self.event
+    }
+
+    /// Return event, dropping the reference
+    pub fn into_event(self) -> PullEvent {
+        self.event
+    }
+
+}
+
+type EventStream = Pin<Box<dyn Stream<Item = ImagePullEvent> + Send>>;
+
+/// Merged progress of several image pulls
+///
+/// Pulls start when the stream is polled, at most `parallelism` at a time. Every image
+/// ends with `PullEvent::Done` or `PullEvent::Failed`, a failed pull does not stop the others.
+/// Dropping the stream cancels the pulls in progress.
+pub struct MultiPullStream {
+    references: Vec<String>,
+    events: EventStream,
+}
+
+impl MultiPullStream {
+
+    pub(crate) fn new(client: &DockerClient, references: Vec<String>, parallelism: usize) -> Self {
+        let pulls: Vec<EventStream> = references.iter()
+            .map(|reference| pull_events(client.clone(), reference.clone()))
+            .collect();
+
+        MultiPullStream {
+            references,
+            events: Box::pin(stream::iter(pulls).flatten_unordered(parallelism.max(1))),
+        }
+    }
+
+    /// Return references of the pulled images
+    pub fn references(&self) -> Vec<&str> {
+        self.references.iter().map(String::as_str).collect()
+    }
+
+    /// Drive all pulls to the end, dropping progress, and return outcome by reference
+    pub async fn finish(mut self) -> HashMap<String, Result<PullSummary, DockerError>> {
+        let mut outcomes = HashMap::new();
+
+        while let Some(event) = self.next().await {
+            match event.event {
+                PullEvent::Progress(_) => {},
+                PullEvent::Done(summary) => { outcomes.insert(event.reference, Ok(summary)); },
+                PullEvent::Failed(e) => { outcomes.insert(event.reference, Err(e)); },
+            }
+        }
+
+        outcomes
+    }
+
+}
+
+impl Stream for MultiPullStream {
+    type Item = ImagePullEvent;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.get_mut().events.poll_next_unpin(cx)
+    }
+}
+
+impl std::fmt::Debug for MultiPullStream {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MultiPullStream")
+            .field("references", &self.references)
+            .finish()
+    }
+}
+
+type ProgressStream = Pin<Box<dyn Stream<Item = Result<Progress, DockerError>> + Send>>;
+
+enum PullState {
+    Start,
+    Pulling(ProgressStream, Box<PullTally>),
+}
+
+/// Events of one pull, ending with `Done` or `Failed`.
+fn pull_events(client: DockerClient, reference: String) -> EventStream {
+    let tag = reference.clone();
+
+    let events = stream::unfold(Some(PullState::Start), move |state| {
+        let client = client.clone();
+        let reference = reference.clone();
+
+        async move {
+            let mut state = state?;
+            loop {
+                state = match state {
+                    PullState::Start => match client.pull_progress(&reference).await {
+                        Ok(progress) => PullState::Pulling(progress, Box::default()),
+                        Err(e) => return Some((PullEvent::Failed(e), None)),
+                    },
+                    PullState::Pulling(mut progress, mut tally) => {
+                        match progress.next().await {
+                            Some(Ok(record)) => match record.error() {
+                                Some(error) => {
+                                    let error = DockerError::ServerError(ErrorMessage { message: error.to_string() });
+                                    return Some((PullEvent::Failed(error), None));
+                                },
+                                None => {
+                                    tally.add(&record);
+                                    return Some((PullEvent::Progress(record), Some(PullState::Pulling(progress, tally))));
+                                },
+                            },
+                            // A line which can not be decoded does not mean the pull failed.
+                            Some(Err(DockerError::Deserialize { .. })) => PullState::Pulling(progress, tally),
+                            Some(Err(e)) => return Some((PullEvent::Failed(e), None)),
+                            None => return Some((PullEvent::Done(tally.summary()), None)),
+                        }
+                    },
+                };
+            }
+        }
+    });
+
+    Box::pin(events.map(move |event| ImagePullEvent { reference: tag.clone(), event }))
+}
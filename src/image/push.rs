@@ -0,0 +1,35 @@
+use serde::{Deserialize, Serialize};
+
+/// Pushed image structure, taken from the final `aux` progress record
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct PushedImage {
+
+    #[serde(rename = "Tag", default)]
+    tag: String,
+
+    #[serde(rename = "Digest")]
+    digest: String,
+
+    #[serde(rename = "Size", default)]
+    size: u64,
+
+}
+
+impl PushedImage {
+
+    /// Return pushed tag
+    pub fn tag(&self) -> &str {
+        &self.tag
+    }
+
+    /// Return manifest digest, e.g. `sha256:...`
+    pub fn digest(&self) -> &str {
+        &self.digest
+    }
+
+    /// Return manifest size in bytes
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+}
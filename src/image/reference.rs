@@ -0,0 +1,25 @@
+/// Split image reference into repository and tag or digest.
+///
+/// `alpine:3.19` gives `("alpine", Some("3.19"))`, `alpine@sha256:...` gives the
+/// digest, and a registry port like `localhost:5000/alpine` is not taken for a tag.
+pub(crate) fn split_reference(reference: &str) -> (&str, Option<&str>) {
+    if let Some(pos) = reference.find('@') {
+        return (&reference[..pos], Some(&reference[pos + 1..]));
+    }
+
+    match reference.rfind(':') {
+        Some(pos) if !reference[pos + 1..].contains('/') => (&reference[..pos], Some(&reference[pos + 1..])),
+        _ => (reference, None),
+    }
+}
+
+/// Return registry of image reference, `docker.io` for Docker Hub images.
+///
+/// Like the docker CLI, the first component is a registry if it has a dot or a port,
+/// or is `localhost`: `ghcr.io/acme/api` is on `ghcr.io`, `acme/api` on Docker Hub.
+pub(crate) fn registry(reference: &str) -> &str {
+    match reference.split_once('/') {
+        Some((first, _)) if first.contains('.') || first.contains(':') || first == "localhost" => first,
+        _ => "docker.io",
+    }
+}
@@ -0,0 +1,54 @@
+use serde::{Deserialize, Serialize};
+use crate::additionals::serde_helpers::nullable_default;
+
+/// Removed image structure
+///
+/// Each item either untags a reference or deletes an image layer.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct RemovedImage {
+
+    #[serde(rename = "Untagged", skip_serializing_if = "Option::is_none")]
+    untagged: Option<String>,
+
+    #[serde(rename = "Deleted", skip_serializing_if = "Option::is_none")]
+    deleted: Option<String>,
+
+}
+
+impl RemovedImage {
+
+    /// Return untagged reference
+    pub fn untagged(&self) -> Option<&str> {
+        self.untagged.as_deref()
+    }
+
+    /// Return deleted image id
+    pub fn deleted(&self) -> Option<&str> {
+        self.deleted.as_deref()
+    }
+}
+
+/// Pruned images structure
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct PrunedImages {
+
+    #[serde(rename = "ImagesDeleted", deserialize_with = "nullable_default", default)]
+    images_deleted: Vec<RemovedImage>,
+
+    #[serde(rename = "SpaceReclaimed")]
+    space_reclaimed: i64,
+
+}
+
+impl PrunedImages {
+
+    /// Return untagged references and deleted images
+    pub fn images_deleted(&self) -> &Vec<RemovedImage> {
+        &self.images_deleted
+    }
+
+    /// Return disk space reclaimed in bytes
+    pub fn space_reclaimed(&self) -> i64 {
+        self.space_reclaimed
+    }
+}
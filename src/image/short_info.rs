@@ -1,20 +1,21 @@
 use std::collections::HashMap;
-use serde::{Deserialize, Deserializer};
+use serde::{Deserialize, Deserializer, Serialize};
+use super::ImageId;
 
 /// Short image info
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 pub struct ShortImageInfo {
 
     #[serde(rename = "Id")]
-    id: String,
+    id: ImageId,
 
     #[serde(rename = "ParentId")]
     parent_id: String,
 
-    #[serde(rename = "RepoTags")]
+    #[serde(rename = "RepoTags", deserialize_with = "nullable_vector", default)]
     repo_tags: Vec<String>,
 
-    #[serde(rename = "RepoDigests", deserialize_with = "nullable_vector")]
+    #[serde(rename = "RepoDigests", deserialize_with = "nullable_vector", default)]
     repo_digests: Vec<String>,
 
     #[serde(rename = "Created")]
@@ -23,24 +24,27 @@ pub struct ShortImageInfo {
     #[serde(rename = "Size")]
     size: i64,
 
-    #[serde(rename = "SharedSize")]
+    #[serde(rename = "SharedSize", default)]
     shared_size: i64,
 
-    #[serde(rename = "VirtualSize")]
+    #[serde(rename = "VirtualSize", default)]
     virtual_size: i64,
 
-    #[serde(rename = "Labels", deserialize_with = "nullable_priority_hash")]
+    #[serde(rename = "Labels", deserialize_with = "nullable_priority_hash", default)]
     labels: HashMap<String, String>,
 
-    #[serde(rename = "Containers")]
+    #[serde(rename = "Containers", default)]
     containers: i64,
 
+    #[serde(flatten, skip_serializing)]
+    extra: HashMap<String, serde_json::Value>,
+
 }
 
 impl ShortImageInfo {
 
     /// Return id of image
-    pub fn id(&self) -> &str {
+    pub fn id(&self) -> &ImageId {
         &self.id
     }
 
@@ -64,6 +68,12 @@ impl ShortImageInfo {
         self.created
     }
 
+    /// Return created as `DateTime<Utc>`
+    #[cfg(feature = "chrono")]
+    pub fn created_datetime(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        crate::additionals::timestamp::from_unix(self.created)
+    }
+
     /// Return size of image
     pub fn size(&self) -> i64 {
         self.size
@@ -88,6 +98,11 @@ impl ShortImageInfo {
     pub fn containers(&self) -> i64 {
         self.containers
     }
+
+    /// Return fields unknown to this crate, e.g. added by newer daemons
+    pub fn extra(&self) -> &HashMap<String, serde_json::Value> {
+        &self.extra
+    }
 }
 
 fn nullable_priority_hash<'de, D>(deserializer: D) -> Result<HashMap<String, String>, D::Error>
@@ -33,6 +33,15 @@
 //!     }
 //! }
 //! ```
+//!
+//! # Features
+//!
+//! * `unix-socket` - connect to the daemon over a unix socket.
+//! * `chrono` - timestamps as `chrono::DateTime`.
+//! * `simd-json` - parse response bodies with simd-json, for clients listing
+//!   thousands of containers or images.
+//! * `blocking` - synchronous client in `docker_client::blocking`, for CLI tools and
+//!   build scripts which don't use async.
 
 //#![deny(warnings)]
 
@@ -64,6 +73,17 @@ pub mod volume;
 pub mod additionals;
 pub mod networks;
 pub mod exec;
+pub mod events;
+pub mod swarm;
+pub mod gc;
+pub mod system;
+pub mod testing;
+pub mod reconcile;
+pub mod models;
+#[cfg(feature = "blocking")]
+pub mod blocking;
+#[cfg(all(feature = "terminal", unix))]
+pub mod terminal;
 
 pub use client::DockerError;
 pub use client::DockerClient;
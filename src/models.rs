@@ -0,0 +1,108 @@
+//! Models generated from the Docker engine API 1.44 swagger spec.
+//!
+//! Do not edit, run `cargo xtask codegen` instead.
+
+#![allow(missing_docs)]
+
+use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+pub struct Volume {
+
+    /// Name of the volume.
+    #[serde(rename = "Name", default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Name of the volume driver used by the volume.
+    #[serde(rename = "Driver", default, skip_serializing_if = "Option::is_none")]
+    pub driver: Option<String>,
+
+    /// Mount path of the volume on the host.
+    #[serde(rename = "Mountpoint", default, skip_serializing_if = "Option::is_none")]
+    pub mountpoint: Option<String>,
+
+    /// Date/Time the volume was created.
+    #[serde(rename = "CreatedAt", default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+
+    /// Low-level details about the volume, provided by the volume driver.
+    /// Details are returned as a map with key/value pairs:
+    /// `{"key":"value","key2":"value2"}`.
+    ///
+    /// The `Status` field is optional, and is omitted if the volume driver
+    /// does not support this feature.
+    #[serde(rename = "Status", default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<HashMap<String, serde_json::Value>>,
+
+    /// User-defined key/value metadata.
+    #[serde(rename = "Labels", default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<HashMap<String, String>>,
+
+    /// The level at which the volume exists. Either `global` for cluster-wide,
+    /// or `local` for machine level.
+    #[serde(rename = "Scope", default, skip_serializing_if = "Option::is_none")]
+    pub scope: Option<String>,
+
+    /// The driver specific options used when creating the volume.
+    #[serde(rename = "Options", default, skip_serializing_if = "Option::is_none")]
+    pub options: Option<HashMap<String, String>>,
+
+    /// Usage details about the volume. This information is used by the
+    /// `GET /system/df` endpoint, and omitted in other endpoints.
+    #[serde(rename = "UsageData", default, skip_serializing_if = "Option::is_none")]
+    pub usage_data: Option<VolumeUsageData>,
+}
+
+/// Volume configuration
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+pub struct VolumeCreateOptions {
+
+    /// The new volume's name. If not specified, Docker generates a name.
+    #[serde(rename = "Name", default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Name of the volume driver to use.
+    #[serde(rename = "Driver", default, skip_serializing_if = "Option::is_none")]
+    pub driver: Option<String>,
+
+    /// A mapping of driver options and values. These options are
+    /// passed directly to the driver and are driver specific.
+    #[serde(rename = "DriverOpts", default, skip_serializing_if = "Option::is_none")]
+    pub driver_opts: Option<HashMap<String, String>>,
+
+    /// User-defined key/value metadata.
+    #[serde(rename = "Labels", default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<HashMap<String, String>>,
+}
+
+/// Volume list response
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+pub struct VolumeListResponse {
+
+    /// List of volumes
+    #[serde(rename = "Volumes", default, skip_serializing_if = "Option::is_none")]
+    pub volumes: Option<Vec<Volume>>,
+
+    /// Warnings that occurred when fetching the list of volumes.
+    #[serde(rename = "Warnings", default, skip_serializing_if = "Option::is_none")]
+    pub warnings: Option<Vec<String>>,
+}
+
+/// Usage details about the volume. This information is used by the
+/// `GET /system/df` endpoint, and omitted in other endpoints.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+pub struct VolumeUsageData {
+
+    /// Amount of disk space used by the volume (in bytes). This information
+    /// is only available for volumes created with the `"local"` volume
+    /// driver. For volumes created with other volume drivers, this field
+    /// is set to `-1` ("not available")
+    #[serde(rename = "Size", default, skip_serializing_if = "Option::is_none")]
+    pub size: Option<i64>,
+
+    /// The number of containers referencing this volume. This field
+    /// is set to `-1` if the reference-count is not available.
+    #[serde(rename = "RefCount", default, skip_serializing_if = "Option::is_none")]
+    pub ref_count: Option<i64>,
+}
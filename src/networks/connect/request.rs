@@ -35,6 +35,7 @@ impl RequestBuilder {
 
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Request {
     id: String,
 
@@ -111,24 +111,97 @@ impl RequestBuilder {
         builder
     }
 
-    pub fn build(&self) -> Request {
+    /// Fail if a network with the same name exists
+    pub fn check_duplicate(mut self, v: bool) -> Self {
+        self.check_duplicate = v;
+
+        self
+    }
+
+    /// Network driver, e.g. `bridge` or `overlay`
+    pub fn driver<T>(mut self, driver: T) -> Self
+        where T: Into<String>
+    {
+        self.driver = driver.into();
+
+        self
+    }
+
+    /// Restrict external access to the network
+    pub fn internal(mut self, v: bool) -> Self {
+        self.internal = v;
+
+        self
+    }
+
+    /// Allow containers to attach to a swarm scoped network manually
+    pub fn attachable(mut self, v: bool) -> Self {
+        self.attachable = v;
+
+        self
+    }
+
+    /// Create the swarm routing-mesh network
+    pub fn ingress(mut self, v: bool) -> Self {
+        self.ingress = v;
+
+        self
+    }
+
+    /// IP address management config
+    pub fn ip_am(mut self, ip_am: IPAM) -> Self {
+        self.ip_am = ip_am;
+
+        self
+    }
+
+    /// Enable IPv6
+    pub fn enable_ipv6(mut self, v: bool) -> Self {
+        self.enable_ipv6 = v;
+
+        self
+    }
+
+    /// Add driver option
+    pub fn option<T, U>(mut self, k: T, v: U) -> Self
+        where
+            T: Into<String>,
+            U: Into<String>
+    {
+        self.options.insert(k.into(), v.into());
+
+        self
+    }
+
+    /// Add label
+    pub fn label<T, U>(mut self, k: T, v: U) -> Self
+        where
+            T: Into<String>,
+            U: Into<String>
+    {
+        self.labels.insert(k.into(), v.into());
+
+        self
+    }
+
+    pub fn build(self) -> Request {
         Request {
-            name: self.name.clone(),
+            name: self.name,
             check_duplicate: self.check_duplicate,
-            driver: self.driver.clone(),
+            driver: self.driver,
             internal: self.internal,
             attachable: self.attachable,
             ingress: self.ingress,
-            ip_am: self.ip_am.clone(),
+            ip_am: self.ip_am,
             enable_ipv6: self.enable_ipv6,
-            options: self.options.clone(),
-            labels: self.labels.clone()
+            options: self.options,
+            labels: self.labels
         }
     }
     
 }
 
-#[derive(Serialize, Deserialize, Clone)]
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq)]
 pub struct IPAM {
 
     #[serde(rename = "String")]
@@ -141,7 +214,7 @@ pub struct IPAM {
     options: HashMap<String, String>
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq)]
 pub struct Request {
 
     #[serde(rename = "Name")]
@@ -177,6 +250,11 @@ pub struct Request {
 
 impl Request {
 
+    /// Return name of the network
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
     pub fn get_path(&self) -> String {
         String::from("/networks/create")
     }
@@ -1,24 +1,30 @@
 
 use serde::{Serialize, Deserialize};
 
-#[derive(Debug, Serialize, Deserialize)]
+/// Response of network creation
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
 pub struct CreatedNetwork {
 
     #[serde(rename = "Id")]
     id: String,
 
-    #[serde(rename = "Warning")]
+    #[serde(rename = "Warning", default, skip_serializing_if = "String::is_empty")]
     warning: String,
 }
 
 impl CreatedNetwork {
 
-    pub fn id(&self) -> String {
-        self.id.clone()
+    /// Return id of the created network
+    pub fn id(&self) -> &str {
+        &self.id
     }
 
-    pub fn warning(&self) -> String {
-        self.warning.clone()
+    /// Return warning reported by the daemon, `None` if the network was created cleanly
+    pub fn warning(&self) -> Option<&str> {
+        match self.warning.as_str() {
+            "" => None,
+            warning => Some(warning),
+        }
     }
 
-}
\ No newline at end of file
+}
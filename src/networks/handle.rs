@@ -0,0 +1,57 @@
+use crate::{DockerClient, DockerError};
+use crate::networks::create::CreatedNetwork;
+use crate::networks::inspect::NetworkInfo;
+
+/// Handle to a network bound to a client.
+///
+/// Returned by [DockerClient::network](../struct.DockerClient.html#method.network).
+#[derive(Debug, Clone)]
+pub struct NetworkRef<'a> {
+    client: &'a DockerClient,
+    name: String,
+}
+
+impl<'a> NetworkRef<'a> {
+
+    pub(crate) fn new(client: &'a DockerClient, name: String) -> Self {
+        NetworkRef {
+            client,
+            name
+        }
+    }
+
+    /// Return name or id of the network
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Create the network with default options
+    pub async fn create(&self) -> Result<CreatedNetwork, DockerError> {
+        let request = crate::networks::create::RequestBuilder::with_name(self.name.as_str()).build();
+
+        self.client.create_network(request).await
+    }
+
+    /// Return low-level information about the network
+    pub async fn inspect(&self) -> Result<NetworkInfo, DockerError> {
+        let request = crate::networks::inspect::RequestBuilder::with_name(self.name.as_str()).build();
+
+        self.client.inspect_network(request).await
+    }
+
+    /// Remove the network
+    pub async fn remove(&self) -> Result<(), DockerError> {
+        self.client.remove_network(self.name.as_str()).await
+    }
+
+    /// Connect a container to the network
+    pub async fn connect<T>(&self, container: T) -> Result<(), DockerError>
+        where T: Into<String>
+    {
+        let request = crate::networks::connect::RequestBuilder::with_name(self.name.as_str())
+            .container(container)
+            .build();
+
+        self.client.connect_container_to_network(request).await
+    }
+}
@@ -29,6 +29,22 @@ impl RequestBuilder {
         builder
     }
 
+    /// Include swarm services and peers in the response, requires a swarm manager
+    pub fn verbose(mut self, verbose: bool) -> Self {
+        self.verbose = verbose;
+
+        self
+    }
+
+    /// Filter the network by scope: `swarm`, `global` or `local`
+    pub fn scope<T>(mut self, scope: T) -> Self
+        where T: Into<String>
+    {
+        self.scope = scope.into();
+
+        self
+    }
+
     pub fn build(self) -> Request {
         Request {
             id: self.id,
@@ -39,6 +55,7 @@ impl RequestBuilder {
 
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Request {
 
     id: String,
@@ -0,0 +1,393 @@
+use std::collections::HashMap;
+use serde::{Deserialize, Deserializer, Serialize};
+
+/// IPAM config structure
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IPAMPool {
+
+    #[serde(rename = "Subnet", default)]
+    subnet: String,
+
+    #[serde(rename = "IPRange", default, skip_serializing_if = "String::is_empty")]
+    ip_range: String,
+
+    #[serde(rename = "Gateway", default)]
+    gateway: String,
+
+}
+
+impl IPAMPool {
+
+    /// Return subnet in CIDR format
+    pub fn subnet(&self) -> &str {
+        &self.subnet
+    }
+
+    /// Return IP range
+    pub fn ip_range(&self) -> &str {
+        &self.ip_range
+    }
+
+    /// Return gateway
+    pub fn gateway(&self) -> &str {
+        &self.gateway
+    }
+}
+
+/// IPAM info structure
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IPAMInfo {
+
+    #[serde(rename = "Driver")]
+    driver: String,
+
+    #[serde(rename = "Config", deserialize_with = "nullable_priority_vec", default)]
+    config: Vec<IPAMPool>,
+
+    #[serde(rename = "Options", deserialize_with = "nullable_priority_hash", default)]
+    options: HashMap<String, String>,
+
+}
+
+impl IPAMInfo {
+
+    /// Return IPAM driver
+    pub fn driver(&self) -> &str {
+        &self.driver
+    }
+
+    /// Return address pools
+    pub fn config(&self) -> &Vec<IPAMPool> {
+        &self.config
+    }
+
+    /// Return driver options
+    pub fn options(&self) -> &HashMap<String, String> {
+        &self.options
+    }
+}
+
+/// Container endpoint structure
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NetworkContainer {
+
+    #[serde(rename = "Name")]
+    name: String,
+
+    #[serde(rename = "EndpointID")]
+    endpoint_id: String,
+
+    #[serde(rename = "MacAddress")]
+    mac_address: String,
+
+    #[serde(rename = "IPv4Address")]
+    ipv4_address: String,
+
+    #[serde(rename = "IPv6Address")]
+    ipv6_address: String,
+
+}
+
+impl NetworkContainer {
+
+    /// Return name of the container
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Return endpoint id
+    pub fn endpoint_id(&self) -> &str {
+        &self.endpoint_id
+    }
+
+    /// Return MAC address
+    pub fn mac_address(&self) -> &str {
+        &self.mac_address
+    }
+
+    /// Return IPv4 address in CIDR format
+    pub fn ipv4_address(&self) -> &str {
+        &self.ipv4_address
+    }
+
+    /// Return IPv6 address in CIDR format
+    pub fn ipv6_address(&self) -> &str {
+        &self.ipv6_address
+    }
+}
+
+/// Task of a swarm service attached to the network
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ServiceTask {
+
+    #[serde(rename = "Name", default)]
+    name: String,
+
+    #[serde(rename = "EndpointID", default)]
+    endpoint_id: String,
+
+    #[serde(rename = "EndpointIP", default)]
+    endpoint_ip: String,
+
+    #[serde(rename = "Info", deserialize_with = "nullable_priority_hash", default)]
+    info: HashMap<String, String>,
+
+}
+
+impl ServiceTask {
+
+    /// Return name of the task
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Return endpoint id
+    pub fn endpoint_id(&self) -> &str {
+        &self.endpoint_id
+    }
+
+    /// Return IP address of the task on the network
+    pub fn endpoint_ip(&self) -> &str {
+        &self.endpoint_ip
+    }
+
+    /// Return driver specific info, e.g. `Host IP`
+    pub fn info(&self) -> &HashMap<String, String> {
+        &self.info
+    }
+}
+
+/// Swarm service attached to the network, only returned in verbose mode
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NetworkService {
+
+    #[serde(rename = "VIP", default)]
+    vip: String,
+
+    #[serde(rename = "Ports", deserialize_with = "nullable_priority_vec", default)]
+    ports: Vec<String>,
+
+    #[serde(rename = "LocalLBIndex", default)]
+    local_lb_index: u64,
+
+    #[serde(rename = "Tasks", deserialize_with = "nullable_priority_vec", default)]
+    tasks: Vec<ServiceTask>,
+
+}
+
+impl NetworkService {
+
+    /// Return virtual IP of the service
+    pub fn vip(&self) -> &str {
+        &self.vip
+    }
+
+    /// Return published ports
+    pub fn ports(&self) -> &Vec<String> {
+        &self.ports
+    }
+
+    /// Return index of the local load balancer
+    pub fn local_lb_index(&self) -> u64 {
+        self.local_lb_index
+    }
+
+    /// Return tasks of the service
+    pub fn tasks(&self) -> &Vec<ServiceTask> {
+        &self.tasks
+    }
+}
+
+/// Swarm node participating in the network
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NetworkPeer {
+
+    #[serde(rename = "Name", default)]
+    name: String,
+
+    #[serde(rename = "IP", default)]
+    ip: String,
+
+}
+
+impl NetworkPeer {
+
+    /// Return name of the node
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Return IP address of the node
+    pub fn ip(&self) -> &str {
+        &self.ip
+    }
+}
+
+/// Network info structure
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NetworkInfo {
+
+    #[serde(rename = "Name")]
+    name: String,
+
+    #[serde(rename = "Id")]
+    id: String,
+
+    #[serde(rename = "Created")]
+    created: String,
+
+    #[serde(rename = "Scope")]
+    scope: String,
+
+    #[serde(rename = "Driver")]
+    driver: String,
+
+    #[serde(rename = "EnableIPv6")]
+    enable_ipv6: bool,
+
+    #[serde(rename = "IPAM")]
+    ip_am: IPAMInfo,
+
+    #[serde(rename = "Internal")]
+    internal: bool,
+
+    #[serde(rename = "Attachable")]
+    attachable: bool,
+
+    #[serde(rename = "Ingress")]
+    ingress: bool,
+
+    #[serde(rename = "Containers", deserialize_with = "nullable_priority_hash", default)]
+    containers: HashMap<String, NetworkContainer>,
+
+    #[serde(rename = "Options", deserialize_with = "nullable_priority_hash", default)]
+    options: HashMap<String, String>,
+
+    #[serde(rename = "Labels", deserialize_with = "nullable_priority_hash", default)]
+    labels: HashMap<String, String>,
+
+    #[serde(rename = "Services", deserialize_with = "nullable_priority_hash", default, skip_serializing_if = "HashMap::is_empty")]
+    services: HashMap<String, NetworkService>,
+
+    #[serde(rename = "Peers", deserialize_with = "nullable_priority_vec", default, skip_serializing_if = "Vec::is_empty")]
+    peers: Vec<NetworkPeer>,
+
+    #[serde(flatten, skip_serializing)]
+    extra: HashMap<String, serde_json::Value>,
+
+}
+
+impl NetworkInfo {
+
+    /// Return name of the network
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Return id of the network
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Return creation time as RFC 3339 string
+    pub fn created(&self) -> &str {
+        &self.created
+    }
+
+    /// Return creation time as `DateTime<Utc>`
+    #[cfg(feature = "chrono")]
+    pub fn created_datetime(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        crate::additionals::timestamp::parse_rfc3339(&self.created)
+    }
+
+    /// Return scope, `local` or `swarm`
+    pub fn scope(&self) -> &str {
+        &self.scope
+    }
+
+    /// Return network driver
+    pub fn driver(&self) -> &str {
+        &self.driver
+    }
+
+    /// Return `true` if IPv6 is enabled
+    pub fn enable_ipv6(&self) -> bool {
+        self.enable_ipv6
+    }
+
+    /// Return IPAM info
+    pub fn ip_am(&self) -> &IPAMInfo {
+        &self.ip_am
+    }
+
+    /// Return `true` if the network is internal
+    pub fn internal(&self) -> bool {
+        self.internal
+    }
+
+    /// Return `true` if containers can be attached manually
+    pub fn attachable(&self) -> bool {
+        self.attachable
+    }
+
+    /// Return `true` if it is the swarm routing-mesh network
+    pub fn ingress(&self) -> bool {
+        self.ingress
+    }
+
+    /// Return connected containers by container id
+    pub fn containers(&self) -> &HashMap<String, NetworkContainer> {
+        &self.containers
+    }
+
+    /// Return driver options
+    pub fn options(&self) -> &HashMap<String, String> {
+        &self.options
+    }
+
+    /// Return labels
+    pub fn labels(&self) -> &HashMap<String, String> {
+        &self.labels
+    }
+
+    /// Return swarm services by name, empty unless inspected in verbose mode
+    pub fn services(&self) -> &HashMap<String, NetworkService> {
+        &self.services
+    }
+
+    /// Return swarm nodes participating in the network
+    pub fn peers(&self) -> &Vec<NetworkPeer> {
+        &self.peers
+    }
+
+    /// Return IP addresses of service tasks by task name
+    pub fn task_ips(&self) -> HashMap<&str, &str> {
+        self.services.values()
+            .flat_map(|service| service.tasks.iter())
+            .map(|task| (task.name.as_str(), task.endpoint_ip.as_str()))
+            .collect()
+    }
+
+    /// Return fields unknown to this crate, e.g. added by newer daemons
+    pub fn extra(&self) -> &HashMap<String, serde_json::Value> {
+        &self.extra
+    }
+}
+
+fn nullable_priority_hash<'de, D, T>(deserializer: D) -> Result<HashMap<String, T>, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: Deserialize<'de>
+{
+    let opt = Option::deserialize(deserializer)?;
+    Ok(opt.unwrap_or_default())
+}
+
+fn nullable_priority_vec<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: Deserialize<'de>
+{
+    let opt = Option::deserialize(deserializer)?;
+    Ok(opt.unwrap_or_default())
+}
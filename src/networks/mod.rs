@@ -1,3 +1,6 @@
 pub mod create;
 pub mod inspect;
-pub mod connect;
\ No newline at end of file
+pub mod connect;
+mod handle;
+
+pub use handle::NetworkRef;
\ No newline at end of file
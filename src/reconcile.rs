@@ -0,0 +1,267 @@
+//!
+//! Declarative containers.
+//!
+//! The module provides [ContainerSpec](struct.ContainerSpec.html), the desired state of a
+//! named container. Reconciling a spec creates the container if it is missing and recreates
+//! it only when the spec, the image behind its reference or its networks changed, like
+//! `docker compose up` does for a single service.
+//!
+//! The spec is recorded on the container as a hash in label `docker-client.spec-hash`.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # use docker_client::DockerClient;
+//! use docker_client::container::Config;
+//! use docker_client::reconcile::ContainerSpec;
+//!
+//! # #[tokio::main]
+//! async fn main() {
+//!     let client = DockerClient::new();
+//!
+//!     let config = Config::with_image("nginx:1.25").env("NGINX_PORT=80").build();
+//!     let spec = ContainerSpec::new("web", config).network("frontend");
+//!
+//!     match client.reconcile(spec).await {
+//!         Ok(web) => { println!("{}: {:?} {:?}", web.id(), web.action(), web.drift()); },
+//!         Err(e) => { println!("Error: {:?}", e); },
+//!     }
+//! }
+//! ```
+
+use serde_json::Value;
+
+use crate::client::{DockerClient, DockerError, ErrorMessage};
+use crate::container::{Config, ContainerId, Create, Remover};
+use crate::container::inspect::{Inspect, ContainerInfo};
+use crate::image::ImageId;
+use crate::networks::connect::RequestBuilder;
+
+/// Label holding the hash of the spec a container was created from
+pub const SPEC_HASH_LABEL: &str = "docker-client.spec-hash";
+
+/// Desired state of a named container
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContainerSpec {
+
+    name: String,
+
+    config: Config,
+
+    networks: Vec<String>,
+
+}
+
+impl ContainerSpec {
+
+    /// Creates a new `ContainerSpec` of container `name`, the config must set an image
+    pub fn new<T>(name: T, config: Config) -> Self
+        where T: Into<String>
+    {
+        ContainerSpec { name: name.into(), config, networks: Vec::new() }
+    }
+
+    /// Connect the container to network `name`, may be called several times
+    pub fn network<T>(mut self, name: T) -> Self
+        where T: Into<String>
+    {
+        self.networks.push(name.into());
+
+        self
+    }
+
+    /// Return name of the container
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Return hash of the config and networks, independent of the order of map keys
+    pub fn hash(&self) -> String {
+        let mut canonical = String::new();
+        write_canonical(&serde_json::to_value(&self.config).unwrap_or(Value::Null), &mut canonical);
+        for network in &self.networks {
+            canonical.push('\n');
+            canonical.push_str(network);
+        }
+
+        format!("{:016x}", fnv1a(canonical.as_bytes()))
+    }
+
+    /// Return what differs between the spec and the container
+    fn drift(&self, info: &ContainerInfo, hash: &str, image: &ImageId) -> Vec<Drift> {
+        let mut drift = Vec::new();
+
+        if info.config().labels().get(SPEC_HASH_LABEL).map(String::as_str) != Some(hash) {
+            drift.push(Drift::Spec);
+        }
+        if info.image() != image {
+            drift.push(Drift::Image);
+        }
+
+        let connected = info.network_settings().networks();
+        if self.networks.iter().any(|network| !connected.contains_key(network)) {
+            drift.push(Drift::Networks);
+        }
+
+        drift
+    }
+
+    pub(crate) async fn run(self, client: &DockerClient) -> Result<Reconciled, DockerError> {
+        let image = match self.config.image() {
+            Some(image) => client.ensure_image(image, None).await?,
+            None => return Err(DockerError::BadParameters(ErrorMessage {
+                message: format!("spec of container {} has no image", self.name),
+            })),
+        };
+        let hash = self.hash();
+
+        let info = match client.inspect_container(Inspect::container(self.name.as_str())).await {
+            Ok(info) => info,
+            Err(DockerError::NotFound(_)) => {
+                let id = self.create(client, hash).await?;
+                return Ok(Reconciled { id, action: ReconcileAction::Created, drift: Vec::new() });
+            },
+            Err(e) => return Err(e),
+        };
+
+        let drift = self.drift(&info, &hash, image.id());
+        if drift.is_empty() {
+            let action = match info.state().running() {
+                true => ReconcileAction::Unchanged,
+                false => {
+                    client.start_container(info.id(), "").await?;
+                    ReconcileAction::Started
+                },
+            };
+
+            return Ok(Reconciled { id: info.id().clone(), action, drift });
+        }
+
+        let remover = Remover::new().id(info.id()).with_force_delete(true).build();
+        match client.remove_container(remover).await {
+            Ok(_) | Err(DockerError::NotFound(_)) => {},
+            Err(e) => return Err(e),
+        }
+
+        let id = self.create(client, hash).await?;
+        Ok(Reconciled { id, action: ReconcileAction::Recreated, drift })
+    }
+
+    async fn create(self, client: &DockerClient, hash: String) -> Result<ContainerId, DockerError> {
+        let request = Create::new()
+            .name(self.name.as_str())
+            .config(self.config.with_label(SPEC_HASH_LABEL.to_string(), hash))
+            .build();
+
+        let created = client.create_container(request).await?;
+        for network in &self.networks {
+            let request = RequestBuilder::with_name(network.as_str()).container(created.id().as_str()).build();
+            client.connect_container_to_network(request).await?;
+        }
+        client.start_container(created.id(), "").await?;
+
+        Ok(created.id().clone())
+    }
+
+}
+
+/// What was done to bring a container to its spec
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ReconcileAction {
+
+    /// Container did not exist and was created
+    Created,
+
+    /// Container drifted from the spec, it was removed and created again
+    Recreated,
+
+    /// Container matched the spec but was stopped, it was started
+    Started,
+
+    /// Container matched the spec and was running
+    Unchanged,
+
+}
+
+/// Difference between a container and its spec
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Drift {
+
+    /// Container was created from another spec, or not by `reconcile`
+    Spec,
+
+    /// Image reference of the spec now points to another image
+    Image,
+
+    /// Container is not connected to all networks of the spec
+    Networks,
+
+}
+
+/// Container brought to its spec
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Reconciled {
+
+    id: ContainerId,
+
+    action: ReconcileAction,
+
+    drift: Vec<Drift>,
+
+}
+
+impl Reconciled {
+
+    /// Return ID of the container
+    pub fn id(&self) -> &ContainerId {
+        &self.id
+    }
+
+    /// Return what was done to the container
+    pub fn action(&self) -> ReconcileAction {
+        self.action
+    }
+
+    /// Return why the container was recreated, empty unless `ReconcileAction::Recreated`
+    pub fn drift(&self) -> &Vec<Drift> {
+        &self.drift
+    }
+
+}
+
+/// Write JSON with object keys sorted, so equal configs give equal strings.
+fn write_canonical(value: &Value, out: &mut String) {
+    match value {
+        Value::Object(map) => {
+            let mut keys: Vec<&String> = map.keys().collect();
+            keys.sort();
+
+            out.push('{');
+            for (i, key) in keys.into_iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                out.push_str(&Value::String(key.clone()).to_string());
+                out.push(':');
+                write_canonical(&map[key], out);
+            }
+            out.push('}');
+        },
+        Value::Array(values) => {
+            out.push('[');
+            for (i, value) in values.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_canonical(value, out);
+            }
+            out.push(']');
+        },
+        _ => out.push_str(&value.to_string()),
+    }
+}
+
+/// 64-bit FNV-1a, stable across Rust releases unlike `DefaultHasher`.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, byte| (hash ^ u64::from(*byte)).wrapping_mul(0x0000_0100_0000_01b3))
+}
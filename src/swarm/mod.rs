@@ -0,0 +1,17 @@
+//!
+//! Swarm module.
+//!
+//! The module provides types to manage swarm services, tasks, secrets and configs.
+//!
+
+mod version;
+mod service;
+mod task;
+mod secret;
+mod unlock;
+
+pub use version::ObjectVersion;
+pub use service::{Service, CreatedService, UpdatedService};
+pub use task::{Task, TaskState, TaskStatus, TaskContainerStatus};
+pub use secret::{Secret, SwarmConfig};
+pub(crate) use unlock::UnlockKey;
@@ -0,0 +1,155 @@
+use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use super::ObjectVersion;
+
+/// Swarm secret structure, the secret data is never returned by the daemon
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Secret {
+
+    #[serde(rename = "ID")]
+    id: String,
+
+    #[serde(rename = "Version", default)]
+    version: ObjectVersion,
+
+    #[serde(rename = "CreatedAt", default)]
+    created_at: String,
+
+    #[serde(rename = "UpdatedAt", default)]
+    updated_at: String,
+
+    #[serde(rename = "Spec", default)]
+    spec: Value,
+
+}
+
+impl Secret {
+
+    /// Return id of the secret
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Return current version of the secret
+    pub fn version(&self) -> ObjectVersion {
+        self.version
+    }
+
+    /// Return creation time as RFC 3339 string
+    pub fn created_at(&self) -> &str {
+        &self.created_at
+    }
+
+    /// Return time of the last update as RFC 3339 string
+    pub fn updated_at(&self) -> &str {
+        &self.updated_at
+    }
+
+    /// Return name of the secret
+    pub fn name(&self) -> &str {
+        self.spec["Name"].as_str().unwrap_or("")
+    }
+
+    /// Return labels of the secret
+    pub fn labels(&self) -> HashMap<String, String> {
+        labels(&self.spec)
+    }
+
+    /// Return spec of the secret
+    pub fn spec(&self) -> &Value {
+        &self.spec
+    }
+
+    /// Return spec with labels replaced by `labels`
+    pub(crate) fn spec_with_labels(&self, labels: HashMap<String, String>) -> Value {
+        with_labels(&self.spec, labels)
+    }
+
+}
+
+/// Swarm config structure
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct SwarmConfig {
+
+    #[serde(rename = "ID")]
+    id: String,
+
+    #[serde(rename = "Version", default)]
+    version: ObjectVersion,
+
+    #[serde(rename = "CreatedAt", default)]
+    created_at: String,
+
+    #[serde(rename = "UpdatedAt", default)]
+    updated_at: String,
+
+    #[serde(rename = "Spec", default)]
+    spec: Value,
+
+}
+
+impl SwarmConfig {
+
+    /// Return id of the config
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Return current version of the config
+    pub fn version(&self) -> ObjectVersion {
+        self.version
+    }
+
+    /// Return creation time as RFC 3339 string
+    pub fn created_at(&self) -> &str {
+        &self.created_at
+    }
+
+    /// Return time of the last update as RFC 3339 string
+    pub fn updated_at(&self) -> &str {
+        &self.updated_at
+    }
+
+    /// Return name of the config
+    pub fn name(&self) -> &str {
+        self.spec["Name"].as_str().unwrap_or("")
+    }
+
+    /// Return labels of the config
+    pub fn labels(&self) -> HashMap<String, String> {
+        labels(&self.spec)
+    }
+
+    /// Return base64 encoded data of the config
+    pub fn data(&self) -> &str {
+        self.spec["Data"].as_str().unwrap_or("")
+    }
+
+    /// Return spec of the config
+    pub fn spec(&self) -> &Value {
+        &self.spec
+    }
+
+    /// Return spec with labels replaced by `labels`
+    pub(crate) fn spec_with_labels(&self, labels: HashMap<String, String>) -> Value {
+        with_labels(&self.spec, labels)
+    }
+
+}
+
+fn labels(spec: &Value) -> HashMap<String, String> {
+    spec["Labels"].as_object()
+        .map(|labels| {
+            labels.iter()
+                .filter_map(|(k, v)| v.as_str().map(|v| (k.clone(), v.to_string())))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn with_labels(spec: &Value, labels: HashMap<String, String>) -> Value {
+    let mut spec = spec.clone();
+    spec["Labels"] = serde_json::to_value(labels).unwrap();
+    spec
+}
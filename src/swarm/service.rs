@@ -0,0 +1,123 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use crate::additionals::serde_helpers::nullable_priority_vec;
+use super::ObjectVersion;
+
+/// Swarm service structure
+///
+/// The spec is kept as raw JSON so it can be submitted back unchanged.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Service {
+
+    #[serde(rename = "ID")]
+    id: String,
+
+    #[serde(rename = "Version", default)]
+    version: ObjectVersion,
+
+    #[serde(rename = "CreatedAt", default)]
+    created_at: String,
+
+    #[serde(rename = "UpdatedAt", default)]
+    updated_at: String,
+
+    #[serde(rename = "Spec", default)]
+    spec: Value,
+
+}
+
+impl Service {
+
+    /// Return id of the service
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Return current version of the service
+    pub fn version(&self) -> ObjectVersion {
+        self.version
+    }
+
+    /// Return creation time as RFC 3339 string
+    pub fn created_at(&self) -> &str {
+        &self.created_at
+    }
+
+    /// Return time of the last update as RFC 3339 string
+    pub fn updated_at(&self) -> &str {
+        &self.updated_at
+    }
+
+    /// Return name of the service
+    pub fn name(&self) -> &str {
+        self.spec["Name"].as_str().unwrap_or("")
+    }
+
+    /// Return spec of the service
+    pub fn spec(&self) -> &Value {
+        &self.spec
+    }
+
+    /// Return force update counter of the task template
+    pub fn force_update(&self) -> u64 {
+        self.spec["TaskTemplate"]["ForceUpdate"].as_u64().unwrap_or(0)
+    }
+
+    /// Return spec with the force update counter incremented
+    pub(crate) fn force_updated_spec(&self) -> Value {
+        let mut spec = self.spec.clone();
+        let counter = self.force_update() + 1;
+
+        match spec.get_mut("TaskTemplate").and_then(Value::as_object_mut) {
+            Some(template) => { template.insert("ForceUpdate".to_string(), counter.into()); },
+            None => { spec["TaskTemplate"] = serde_json::json!({ "ForceUpdate": counter }); },
+        }
+
+        spec
+    }
+
+}
+
+/// Response of service creation
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq, Eq)]
+pub struct CreatedService {
+
+    #[serde(rename = "ID")]
+    id: String,
+
+    #[serde(rename = "Warnings", deserialize_with = "nullable_priority_vec", default)]
+    warnings: Vec<String>,
+
+}
+
+impl CreatedService {
+
+    /// Return ID of the created service
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Return warnings reported by the daemon, e.g. about an image which could not be resolved
+    pub fn warnings(&self) -> &Vec<String> {
+        &self.warnings
+    }
+
+}
+
+/// Response of service update
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq, Eq)]
+pub struct UpdatedService {
+
+    #[serde(rename = "Warnings", deserialize_with = "nullable_priority_vec", default)]
+    warnings: Vec<String>,
+
+}
+
+impl UpdatedService {
+
+    /// Return warnings reported by the daemon
+    pub fn warnings(&self) -> &Vec<String> {
+        &self.warnings
+    }
+
+}
@@ -0,0 +1,334 @@
+use std::fmt;
+use std::str::FromStr;
+use serde::{Deserialize, Serialize};
+use super::ObjectVersion;
+
+/// State of a swarm task
+///
+/// Unknown states reported by newer daemons are kept in `Other`.
+///
+/// # Examples
+///
+/// ```rust
+/// use docker_client::swarm::TaskState;
+///
+/// let state: TaskState = "running".parse().unwrap();
+///
+/// assert_eq!(state, TaskState::Running);
+/// assert!(TaskState::Failed.is_terminal());
+/// ```
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq, Eq, Hash)]
+#[serde(from = "String", into = "String")]
+pub enum TaskState {
+
+    /// Task was created
+    #[default]
+    New,
+
+    /// Task was allocated resources
+    Allocated,
+
+    /// Task waits for a node
+    Pending,
+
+    /// Task was assigned to a node
+    Assigned,
+
+    /// Task was accepted by the node
+    Accepted,
+
+    /// Task is being prepared, e.g. image is pulled
+    Preparing,
+
+    /// Task is ready to start
+    Ready,
+
+    /// Task is starting
+    Starting,
+
+    /// Task is running
+    Running,
+
+    /// Task exited successfully
+    Complete,
+
+    /// Task was shut down
+    Shutdown,
+
+    /// Task failed
+    Failed,
+
+    /// Task was rejected by the node
+    Rejected,
+
+    /// Task is marked for removal
+    Remove,
+
+    /// Node of the task is down
+    Orphaned,
+
+    /// State unknown to this crate
+    Other(String),
+
+}
+
+impl TaskState {
+
+    /// Return state as string slice
+    pub fn as_str(&self) -> &str {
+        match self {
+            TaskState::New => "new",
+            TaskState::Allocated => "allocated",
+            TaskState::Pending => "pending",
+            TaskState::Assigned => "assigned",
+            TaskState::Accepted => "accepted",
+            TaskState::Preparing => "preparing",
+            TaskState::Ready => "ready",
+            TaskState::Starting => "starting",
+            TaskState::Running => "running",
+            TaskState::Complete => "complete",
+            TaskState::Shutdown => "shutdown",
+            TaskState::Failed => "failed",
+            TaskState::Rejected => "rejected",
+            TaskState::Remove => "remove",
+            TaskState::Orphaned => "orphaned",
+            TaskState::Other(state) => state.as_str(),
+        }
+    }
+
+    /// Return `true` if the task will not run again
+    pub fn is_terminal(&self) -> bool {
+        matches!(self,
+            TaskState::Complete | TaskState::Shutdown | TaskState::Failed
+            | TaskState::Rejected | TaskState::Remove | TaskState::Orphaned)
+    }
+
+}
+
+impl From<String> for TaskState {
+    fn from(state: String) -> Self {
+        match state.as_str() {
+            "new" => TaskState::New,
+            "allocated" => TaskState::Allocated,
+            "pending" => TaskState::Pending,
+            "assigned" => TaskState::Assigned,
+            "accepted" => TaskState::Accepted,
+            "preparing" => TaskState::Preparing,
+            "ready" => TaskState::Ready,
+            "starting" => TaskState::Starting,
+            "running" => TaskState::Running,
+            "complete" => TaskState::Complete,
+            "shutdown" => TaskState::Shutdown,
+            "failed" => TaskState::Failed,
+            "rejected" => TaskState::Rejected,
+            "remove" => TaskState::Remove,
+            "orphaned" => TaskState::Orphaned,
+            _ => TaskState::Other(state),
+        }
+    }
+}
+
+impl From<TaskState> for String {
+    fn from(state: TaskState) -> Self {
+        match state {
+            TaskState::Other(state) => state,
+            state => state.as_str().to_string(),
+        }
+    }
+}
+
+impl FromStr for TaskState {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(TaskState::from(s.to_string()))
+    }
+}
+
+impl fmt::Display for TaskState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Container of a swarm task
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq, Eq)]
+pub struct TaskContainerStatus {
+
+    #[serde(rename = "ContainerID", default)]
+    container_id: String,
+
+    #[serde(rename = "PID", default)]
+    pid: i64,
+
+    #[serde(rename = "ExitCode", default)]
+    exit_code: i64,
+
+}
+
+impl TaskContainerStatus {
+
+    /// Return id of the container
+    pub fn container_id(&self) -> &str {
+        &self.container_id
+    }
+
+    /// Return process id of the container
+    pub fn pid(&self) -> i64 {
+        self.pid
+    }
+
+    /// Return exit code of the container
+    pub fn exit_code(&self) -> i64 {
+        self.exit_code
+    }
+
+}
+
+/// Status of a swarm task
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq, Eq)]
+pub struct TaskStatus {
+
+    #[serde(rename = "Timestamp", default)]
+    timestamp: String,
+
+    #[serde(rename = "State", default)]
+    state: TaskState,
+
+    #[serde(rename = "Message", default)]
+    message: String,
+
+    #[serde(rename = "Err", default, skip_serializing_if = "String::is_empty")]
+    err: String,
+
+    #[serde(rename = "ContainerStatus", default)]
+    container_status: Option<TaskContainerStatus>,
+
+}
+
+impl TaskStatus {
+
+    /// Return time of the status as RFC 3339 string
+    pub fn timestamp(&self) -> &str {
+        &self.timestamp
+    }
+
+    /// Return current state
+    pub fn state(&self) -> &TaskState {
+        &self.state
+    }
+
+    /// Return status message
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Return error message, `None` if the task did not fail
+    pub fn err(&self) -> Option<&str> {
+        match self.err.as_str() {
+            "" => None,
+            err => Some(err),
+        }
+    }
+
+    /// Return container status
+    pub fn container_status(&self) -> Option<&TaskContainerStatus> {
+        self.container_status.as_ref()
+    }
+
+}
+
+/// Swarm task structure
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct Task {
+
+    #[serde(rename = "ID")]
+    id: String,
+
+    #[serde(rename = "Version", default)]
+    version: ObjectVersion,
+
+    #[serde(rename = "CreatedAt", default)]
+    created_at: String,
+
+    #[serde(rename = "UpdatedAt", default)]
+    updated_at: String,
+
+    #[serde(rename = "Name", default)]
+    name: String,
+
+    #[serde(rename = "ServiceID", default)]
+    service_id: String,
+
+    #[serde(rename = "Slot", default)]
+    slot: Option<u64>,
+
+    #[serde(rename = "NodeID", default)]
+    node_id: String,
+
+    #[serde(rename = "Status", default)]
+    status: TaskStatus,
+
+    #[serde(rename = "DesiredState", default)]
+    desired_state: TaskState,
+
+}
+
+impl Task {
+
+    /// Return id of the task
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Return version of the task
+    pub fn version(&self) -> ObjectVersion {
+        self.version
+    }
+
+    /// Return creation time as RFC 3339 string
+    pub fn created_at(&self) -> &str {
+        &self.created_at
+    }
+
+    /// Return time of the last update as RFC 3339 string
+    pub fn updated_at(&self) -> &str {
+        &self.updated_at
+    }
+
+    /// Return name of the task
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Return id of the service
+    pub fn service_id(&self) -> &str {
+        &self.service_id
+    }
+
+    /// Return slot of a replicated service task
+    pub fn slot(&self) -> Option<u64> {
+        self.slot
+    }
+
+    /// Return id of the node running the task
+    pub fn node_id(&self) -> &str {
+        &self.node_id
+    }
+
+    /// Return status of the task
+    pub fn status(&self) -> &TaskStatus {
+        &self.status
+    }
+
+    /// Return current state of the task
+    pub fn current_state(&self) -> &TaskState {
+        &self.status.state
+    }
+
+    /// Return state the orchestrator wants the task in
+    pub fn desired_state(&self) -> &TaskState {
+        &self.desired_state
+    }
+
+}
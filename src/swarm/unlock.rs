@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+
+/// Unlock key of an autolock-enabled swarm
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq, Eq)]
+pub(crate) struct UnlockKey {
+
+    #[serde(rename = "UnlockKey", default)]
+    key: String,
+
+}
+
+impl UnlockKey {
+
+    pub(crate) fn new(key: String) -> Self {
+        UnlockKey { key }
+    }
+
+    pub(crate) fn into_key(self) -> String {
+        self.key
+    }
+
+}
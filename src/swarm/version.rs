@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+
+/// Version of a swarm object, required to update it
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ObjectVersion {
+
+    #[serde(rename = "Index", default)]
+    index: u64,
+
+}
+
+impl ObjectVersion {
+
+    /// Return version index
+    pub fn index(&self) -> u64 {
+        self.index
+    }
+
+}
+
+impl From<u64> for ObjectVersion {
+    fn from(index: u64) -> Self {
+        ObjectVersion { index }
+    }
+}
@@ -0,0 +1,192 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::additionals::serde_helpers::nullable_default;
+use crate::container::{ShortContainerInfo, ContainerState};
+use crate::image::ShortImageInfo;
+use crate::volume::VolumeInfo;
+
+/// Disk usage of the daemon, see `DockerClient::disk_usage`
+///
+/// Reclaimable sizes count what pruning would remove: images and volumes unused
+/// by containers, and build cache records neither in use nor shared.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct DiskUsage {
+
+    #[serde(rename = "LayersSize", default)]
+    layers_size: i64,
+
+    #[serde(rename = "Images", default, deserialize_with = "nullable_default")]
+    images: Vec<ShortImageInfo>,
+
+    #[serde(rename = "Containers", default, deserialize_with = "nullable_default")]
+    containers: Vec<ShortContainerInfo>,
+
+    #[serde(rename = "Volumes", default, deserialize_with = "nullable_default")]
+    volumes: Vec<VolumeInfo>,
+
+    #[serde(rename = "BuildCache", default, deserialize_with = "nullable_default")]
+    build_cache: Vec<BuildCache>,
+
+}
+
+impl DiskUsage {
+
+    /// Return size of all image layers in bytes
+    pub fn layers_size(&self) -> i64 {
+        self.layers_size
+    }
+
+    /// Return images with their sizes and number of containers using them
+    pub fn images(&self) -> &Vec<ShortImageInfo> {
+        &self.images
+    }
+
+    /// Return containers with their sizes
+    pub fn containers(&self) -> &Vec<ShortContainerInfo> {
+        &self.containers
+    }
+
+    /// Return volumes with their usage data
+    pub fn volumes(&self) -> &Vec<VolumeInfo> {
+        &self.volumes
+    }
+
+    /// Return build cache records
+    pub fn build_cache(&self) -> &Vec<BuildCache> {
+        &self.build_cache
+    }
+
+    /// Return bytes of images used by no container, layers shared with other images excluded
+    pub fn reclaimable_images(&self) -> i64 {
+        self.images.iter()
+            .filter(|image| image.containers() == 0)
+            .map(|image| image.size() - image.shared_size().max(0))
+            .sum()
+    }
+
+    /// Return bytes written by containers which are neither running, paused nor restarting
+    pub fn reclaimable_containers(&self) -> i64 {
+        self.containers.iter()
+            .filter(|container| !matches!(container.state(), ContainerState::Running | ContainerState::Paused | ContainerState::Restarting))
+            .filter_map(|container| container.size_rw())
+            .sum()
+    }
+
+    /// Return bytes of volumes referenced by no container
+    pub fn reclaimable_volumes(&self) -> i64 {
+        self.volumes.iter()
+            .filter_map(|volume| volume.usage_data())
+            .filter(|usage| usage.ref_count() == 0)
+            .map(|usage| usage.size().max(0))
+            .sum()
+    }
+
+    /// Return bytes of build cache records neither in use nor shared
+    pub fn reclaimable_build_cache(&self) -> i64 {
+        self.build_cache.iter()
+            .filter(|record| !record.in_use() && !record.shared())
+            .map(|record| record.size())
+            .sum()
+    }
+
+}
+
+/// Build cache record, see `DiskUsage::build_cache`
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct BuildCache {
+
+    #[serde(rename = "ID")]
+    id: String,
+
+    #[serde(rename = "Parents", default, deserialize_with = "nullable_default")]
+    parents: Vec<String>,
+
+    #[serde(rename = "Type", default)]
+    cache_type: String,
+
+    #[serde(rename = "Description", default)]
+    description: String,
+
+    #[serde(rename = "InUse", default)]
+    in_use: bool,
+
+    #[serde(rename = "Shared", default)]
+    shared: bool,
+
+    #[serde(rename = "Size", default)]
+    size: i64,
+
+    #[serde(rename = "CreatedAt", default)]
+    created_at: String,
+
+    #[serde(rename = "LastUsedAt", default)]
+    last_used_at: Option<String>,
+
+    #[serde(rename = "UsageCount", default)]
+    usage_count: i64,
+
+    #[serde(flatten, skip_serializing)]
+    extra: HashMap<String, serde_json::Value>,
+
+}
+
+impl BuildCache {
+
+    /// Return id of the record
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Return ids of the parent records
+    pub fn parents(&self) -> &Vec<String> {
+        &self.parents
+    }
+
+    /// Return type of the record, e.g. `regular`, `source.local` or `exec.cachemount`
+    pub fn cache_type(&self) -> &str {
+        &self.cache_type
+    }
+
+    /// Return description of the record, e.g. the build step it was created by
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    /// Return `true` if a build currently uses the record
+    pub fn in_use(&self) -> bool {
+        self.in_use
+    }
+
+    /// Return `true` if the record is shared with other records
+    pub fn shared(&self) -> bool {
+        self.shared
+    }
+
+    /// Return size of the record in bytes
+    pub fn size(&self) -> i64 {
+        self.size
+    }
+
+    /// Return creation time as RFC 3339 string
+    pub fn created_at(&self) -> &str {
+        &self.created_at
+    }
+
+    /// Return time of the last use as RFC 3339 string, `None` if never used
+    pub fn last_used_at(&self) -> Option<&str> {
+        self.last_used_at.as_deref()
+    }
+
+    /// Return number of builds which used the record
+    pub fn usage_count(&self) -> i64 {
+        self.usage_count
+    }
+
+    /// Return fields unknown to this crate, e.g. the deprecated `Parent`
+    pub fn extra(&self) -> &HashMap<String, serde_json::Value> {
+        &self.extra
+    }
+
+}
@@ -0,0 +1,493 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::additionals::serde_helpers::nullable_default;
+
+/// System information of the daemon, see `DockerClient::info`
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct SystemInfo {
+
+    #[serde(rename = "ID", default)]
+    id: String,
+
+    #[serde(rename = "Name", default)]
+    name: String,
+
+    #[serde(rename = "ServerVersion", default)]
+    server_version: String,
+
+    #[serde(rename = "Containers", default)]
+    containers: u64,
+
+    #[serde(rename = "ContainersRunning", default)]
+    containers_running: u64,
+
+    #[serde(rename = "ContainersPaused", default)]
+    containers_paused: u64,
+
+    #[serde(rename = "ContainersStopped", default)]
+    containers_stopped: u64,
+
+    #[serde(rename = "Images", default)]
+    images: u64,
+
+    #[serde(rename = "Driver", default)]
+    driver: String,
+
+    #[serde(rename = "DriverStatus", default, deserialize_with = "nullable_default")]
+    driver_status: Vec<(String, String)>,
+
+    #[serde(rename = "DockerRootDir", default)]
+    docker_root_dir: String,
+
+    #[serde(rename = "LoggingDriver", default)]
+    logging_driver: String,
+
+    #[serde(rename = "CgroupDriver", default)]
+    cgroup_driver: String,
+
+    #[serde(rename = "CgroupVersion", default)]
+    cgroup_version: String,
+
+    #[serde(rename = "DefaultRuntime", default)]
+    default_runtime: String,
+
+    #[serde(rename = "NCPU", default)]
+    ncpu: u64,
+
+    #[serde(rename = "MemTotal", default)]
+    mem_total: u64,
+
+    #[serde(rename = "MemoryLimit", default)]
+    memory_limit: bool,
+
+    #[serde(rename = "SwapLimit", default)]
+    swap_limit: bool,
+
+    #[serde(rename = "CpuCfsPeriod", default)]
+    cpu_cfs_period: bool,
+
+    #[serde(rename = "CpuCfsQuota", default)]
+    cpu_cfs_quota: bool,
+
+    #[serde(rename = "CPUShares", default)]
+    cpu_shares: bool,
+
+    #[serde(rename = "CPUSet", default)]
+    cpu_set: bool,
+
+    #[serde(rename = "PidsLimit", default)]
+    pids_limit: bool,
+
+    #[serde(rename = "OomKillDisable", default)]
+    oom_kill_disable: bool,
+
+    #[serde(rename = "IPv4Forwarding", default)]
+    ipv4_forwarding: bool,
+
+    #[serde(rename = "KernelVersion", default)]
+    kernel_version: String,
+
+    #[serde(rename = "OperatingSystem", default)]
+    operating_system: String,
+
+    #[serde(rename = "OSVersion", default)]
+    os_version: String,
+
+    #[serde(rename = "OSType", default)]
+    os_type: String,
+
+    #[serde(rename = "Architecture", default)]
+    architecture: String,
+
+    #[serde(rename = "IndexServerAddress", default)]
+    index_server_address: String,
+
+    #[serde(rename = "HttpProxy", default)]
+    http_proxy: String,
+
+    #[serde(rename = "HttpsProxy", default)]
+    https_proxy: String,
+
+    #[serde(rename = "NoProxy", default)]
+    no_proxy: String,
+
+    #[serde(rename = "Labels", default, deserialize_with = "nullable_default")]
+    labels: Vec<String>,
+
+    #[serde(rename = "ExperimentalBuild", default)]
+    experimental_build: bool,
+
+    #[serde(rename = "LiveRestoreEnabled", default)]
+    live_restore_enabled: bool,
+
+    #[serde(rename = "Debug", default)]
+    debug: bool,
+
+    #[serde(rename = "SecurityOptions", default, deserialize_with = "nullable_default")]
+    security_options: Vec<String>,
+
+    #[serde(rename = "Swarm", default)]
+    swarm: SwarmInfo,
+
+    #[serde(rename = "Warnings", default, deserialize_with = "nullable_default")]
+    warnings: Vec<String>,
+
+    #[serde(flatten, skip_serializing)]
+    extra: HashMap<String, serde_json::Value>,
+
+}
+
+impl SystemInfo {
+
+    /// Return id of the daemon
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Return host name of the daemon
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Return version of the engine, e.g. `25.0.3`
+    pub fn server_version(&self) -> &str {
+        &self.server_version
+    }
+
+    /// Return number of containers
+    pub fn containers(&self) -> u64 {
+        self.containers
+    }
+
+    /// Return number of running containers
+    pub fn containers_running(&self) -> u64 {
+        self.containers_running
+    }
+
+    /// Return number of paused containers
+    pub fn containers_paused(&self) -> u64 {
+        self.containers_paused
+    }
+
+    /// Return number of stopped containers
+    pub fn containers_stopped(&self) -> u64 {
+        self.containers_stopped
+    }
+
+    /// Return number of images, intermediate layers included
+    pub fn images(&self) -> u64 {
+        self.images
+    }
+
+    /// Return storage driver, e.g. `overlay2`
+    pub fn driver(&self) -> &str {
+        &self.driver
+    }
+
+    /// Return `(name, value)` pairs describing the storage driver
+    pub fn driver_status(&self) -> &Vec<(String, String)> {
+        &self.driver_status
+    }
+
+    /// Return root directory of the daemon data, e.g. `/var/lib/docker`
+    pub fn docker_root_dir(&self) -> &str {
+        &self.docker_root_dir
+    }
+
+    /// Return default logging driver of containers, e.g. `json-file`
+    pub fn logging_driver(&self) -> &str {
+        &self.logging_driver
+    }
+
+    /// Return cgroup driver, `cgroupfs`, `systemd` or `none`
+    pub fn cgroup_driver(&self) -> &str {
+        &self.cgroup_driver
+    }
+
+    /// Return cgroup version of the host, `1` or `2`
+    pub fn cgroup_version(&self) -> &str {
+        &self.cgroup_version
+    }
+
+    /// Return default OCI runtime of containers, e.g. `runc`
+    pub fn default_runtime(&self) -> &str {
+        &self.default_runtime
+    }
+
+    /// Return number of CPUs of the host
+    pub fn ncpu(&self) -> u64 {
+        self.ncpu
+    }
+
+    /// Return physical memory of the host in bytes
+    pub fn mem_total(&self) -> u64 {
+        self.mem_total
+    }
+
+    /// Return `true` if memory limits of containers are supported
+    pub fn memory_limit(&self) -> bool {
+        self.memory_limit
+    }
+
+    /// Return `true` if swap limits of containers are supported
+    pub fn swap_limit(&self) -> bool {
+        self.swap_limit
+    }
+
+    /// Return `true` if CPU CFS period and quota of containers are supported
+    pub fn cpu_cfs(&self) -> bool {
+        self.cpu_cfs_period && self.cpu_cfs_quota
+    }
+
+    /// Return `true` if CPU shares of containers are supported
+    pub fn cpu_shares(&self) -> bool {
+        self.cpu_shares
+    }
+
+    /// Return `true` if CPU sets of containers are supported
+    pub fn cpu_set(&self) -> bool {
+        self.cpu_set
+    }
+
+    /// Return `true` if PID limits of containers are supported
+    pub fn pids_limit(&self) -> bool {
+        self.pids_limit
+    }
+
+    /// Return `true` if the OOM killer can be disabled for containers
+    pub fn oom_kill_disable(&self) -> bool {
+        self.oom_kill_disable
+    }
+
+    /// Return `true` if IPv4 forwarding is enabled on the host
+    pub fn ipv4_forwarding(&self) -> bool {
+        self.ipv4_forwarding
+    }
+
+    /// Return kernel version of the host
+    pub fn kernel_version(&self) -> &str {
+        &self.kernel_version
+    }
+
+    /// Return operating system of the host, e.g. `Ubuntu 22.04.4 LTS`
+    pub fn operating_system(&self) -> &str {
+        &self.operating_system
+    }
+
+    /// Return version of the operating system of the host
+    pub fn os_version(&self) -> &str {
+        &self.os_version
+    }
+
+    /// Return operating system type of the daemon, `linux` or `windows`
+    pub fn os_type(&self) -> &str {
+        &self.os_type
+    }
+
+    /// Return architecture of the host, e.g. `x86_64`
+    pub fn architecture(&self) -> &str {
+        &self.architecture
+    }
+
+    /// Return address of the default registry
+    pub fn index_server_address(&self) -> &str {
+        &self.index_server_address
+    }
+
+    /// Return `HTTP_PROXY` of the daemon
+    pub fn http_proxy(&self) -> &str {
+        &self.http_proxy
+    }
+
+    /// Return `HTTPS_PROXY` of the daemon
+    pub fn https_proxy(&self) -> &str {
+        &self.https_proxy
+    }
+
+    /// Return `NO_PROXY` of the daemon
+    pub fn no_proxy(&self) -> &str {
+        &self.no_proxy
+    }
+
+    /// Return labels of the daemon as `key=value`
+    pub fn labels(&self) -> &Vec<String> {
+        &self.labels
+    }
+
+    /// Return `true` if the engine is an experimental build
+    pub fn experimental_build(&self) -> bool {
+        self.experimental_build
+    }
+
+    /// Return `true` if containers keep running while the daemon is down
+    pub fn live_restore_enabled(&self) -> bool {
+        self.live_restore_enabled
+    }
+
+    /// Return `true` if the daemon runs in debug mode
+    pub fn debug(&self) -> bool {
+        self.debug
+    }
+
+    /// Return security options of the daemon, e.g. `name=seccomp,profile=builtin`
+    pub fn security_options(&self) -> &Vec<String> {
+        &self.security_options
+    }
+
+    /// Return swarm state of the node
+    pub fn swarm(&self) -> &SwarmInfo {
+        &self.swarm
+    }
+
+    /// Return warnings about the configuration of the host
+    pub fn warnings(&self) -> &Vec<String> {
+        &self.warnings
+    }
+
+    /// Return fields unknown to this crate, e.g. `Plugins` or `RegistryConfig`
+    pub fn extra(&self) -> &HashMap<String, serde_json::Value> {
+        &self.extra
+    }
+
+}
+
+/// Swarm state of the node, see `SystemInfo::swarm`
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq, Eq)]
+pub struct SwarmInfo {
+
+    #[serde(rename = "NodeID", default)]
+    node_id: String,
+
+    #[serde(rename = "NodeAddr", default)]
+    node_addr: String,
+
+    #[serde(rename = "LocalNodeState", default)]
+    local_node_state: LocalNodeState,
+
+    #[serde(rename = "ControlAvailable", default)]
+    control_available: bool,
+
+    #[serde(rename = "Error", default)]
+    error: String,
+
+    #[serde(rename = "Nodes", default, skip_serializing_if = "Option::is_none")]
+    nodes: Option<u64>,
+
+    #[serde(rename = "Managers", default, skip_serializing_if = "Option::is_none")]
+    managers: Option<u64>,
+
+}
+
+impl SwarmInfo {
+
+    /// Return id of the node, empty if the node is not in a swarm
+    pub fn node_id(&self) -> &str {
+        &self.node_id
+    }
+
+    /// Return address of the node in the swarm
+    pub fn node_addr(&self) -> &str {
+        &self.node_addr
+    }
+
+    /// Return state of the node in the swarm
+    pub fn local_node_state(&self) -> &LocalNodeState {
+        &self.local_node_state
+    }
+
+    /// Return `true` if the node is a manager
+    pub fn control_available(&self) -> bool {
+        self.control_available
+    }
+
+    /// Return error of the node, empty if none
+    pub fn error(&self) -> &str {
+        &self.error
+    }
+
+    /// Return number of nodes of the swarm, reported to managers only
+    pub fn nodes(&self) -> Option<u64> {
+        self.nodes
+    }
+
+    /// Return number of managers of the swarm, reported to managers only
+    pub fn managers(&self) -> Option<u64> {
+        self.managers
+    }
+
+}
+
+/// State of the node in a swarm
+///
+/// Unknown states reported by newer daemons are kept in `Other`.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[serde(from = "String", into = "String")]
+pub enum LocalNodeState {
+
+    /// Node is not in a swarm
+    #[default]
+    Inactive,
+
+    /// Node is joining a swarm
+    Pending,
+
+    /// Node is in a swarm
+    Active,
+
+    /// Node failed to join or run the swarm
+    Error,
+
+    /// Swarm is locked, see `DockerClient::unlock_swarm`
+    Locked,
+
+    /// State unknown to this crate
+    Other(String),
+
+}
+
+impl LocalNodeState {
+
+    /// Return state as string slice
+    pub fn as_str(&self) -> &str {
+        match self {
+            LocalNodeState::Inactive => "inactive",
+            LocalNodeState::Pending => "pending",
+            LocalNodeState::Active => "active",
+            LocalNodeState::Error => "error",
+            LocalNodeState::Locked => "locked",
+            LocalNodeState::Other(state) => state.as_str(),
+        }
+    }
+
+}
+
+impl From<String> for LocalNodeState {
+    fn from(state: String) -> Self {
+        match state.as_str() {
+            "inactive" | "" => LocalNodeState::Inactive,
+            "pending" => LocalNodeState::Pending,
+            "active" => LocalNodeState::Active,
+            "error" => LocalNodeState::Error,
+            "locked" => LocalNodeState::Locked,
+            _ => LocalNodeState::Other(state),
+        }
+    }
+}
+
+impl From<LocalNodeState> for String {
+    fn from(state: LocalNodeState) -> Self {
+        match state {
+            LocalNodeState::Other(state) => state,
+            state => state.as_str().to_string(),
+        }
+    }
+}
+
+impl fmt::Display for LocalNodeState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
@@ -0,0 +1,13 @@
+//!
+//! System module.
+//!
+//! The module provides types of the daemon itself: its version, state and disk usage.
+//!
+
+mod version;
+mod info;
+mod disk_usage;
+
+pub use version::{Version, Component};
+pub use info::{SystemInfo, SwarmInfo, LocalNodeState};
+pub use disk_usage::{DiskUsage, BuildCache};
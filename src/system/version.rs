@@ -0,0 +1,165 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::additionals::serde_helpers::nullable_default;
+use crate::client::ApiVersion;
+
+/// Version of the daemon and its components, see `DockerClient::version`
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct Version {
+
+    #[serde(rename = "Platform", default)]
+    platform: Option<Platform>,
+
+    #[serde(rename = "Components", default, deserialize_with = "nullable_default")]
+    components: Vec<Component>,
+
+    #[serde(rename = "Version", default)]
+    version: String,
+
+    #[serde(rename = "ApiVersion")]
+    api_version: ApiVersion,
+
+    #[serde(rename = "MinAPIVersion", default)]
+    min_api_version: Option<ApiVersion>,
+
+    #[serde(rename = "GitCommit", default)]
+    git_commit: String,
+
+    #[serde(rename = "GoVersion", default)]
+    go_version: String,
+
+    #[serde(rename = "Os", default)]
+    os: String,
+
+    #[serde(rename = "Arch", default)]
+    arch: String,
+
+    #[serde(rename = "KernelVersion", default)]
+    kernel_version: String,
+
+    #[serde(rename = "Experimental", default)]
+    experimental: bool,
+
+    #[serde(rename = "BuildTime", default)]
+    build_time: String,
+
+}
+
+impl Version {
+
+    /// Return name of the platform, e.g. `Docker Engine - Community`
+    pub fn platform(&self) -> Option<&str> {
+        self.platform.as_ref().map(|platform| platform.name.as_str())
+    }
+
+    /// Return components of the daemon: `Engine`, `containerd`, `runc`...
+    pub fn components(&self) -> &Vec<Component> {
+        &self.components
+    }
+
+    /// Return component `name` if the daemon reports it
+    pub fn component(&self, name: &str) -> Option<&Component> {
+        self.components.iter().find(|component| component.name == name)
+    }
+
+    /// Return version of the engine, e.g. `25.0.3`
+    pub fn version(&self) -> &str {
+        &self.version
+    }
+
+    /// Return newest API version of the daemon
+    pub fn api_version(&self) -> ApiVersion {
+        self.api_version
+    }
+
+    /// Return oldest API version accepted by the daemon, unknown to API versions before 1.25
+    pub fn min_api_version(&self) -> Option<ApiVersion> {
+        self.min_api_version
+    }
+
+    /// Return `true` if the daemon accepts requests of API version `version`
+    pub fn supports(&self, version: ApiVersion) -> bool {
+        version <= self.api_version && self.min_api_version.is_none_or(|min| min <= version)
+    }
+
+    /// Return commit the engine was built from
+    pub fn git_commit(&self) -> &str {
+        &self.git_commit
+    }
+
+    /// Return Go version the engine was built with, e.g. `go1.21.6`
+    pub fn go_version(&self) -> &str {
+        &self.go_version
+    }
+
+    /// Return operating system of the daemon, e.g. `linux`
+    pub fn os(&self) -> &str {
+        &self.os
+    }
+
+    /// Return architecture of the daemon, e.g. `amd64`
+    pub fn arch(&self) -> &str {
+        &self.arch
+    }
+
+    /// Return kernel version of the host
+    pub fn kernel_version(&self) -> &str {
+        &self.kernel_version
+    }
+
+    /// Return `true` if experimental features of the daemon are enabled
+    pub fn experimental(&self) -> bool {
+        self.experimental
+    }
+
+    /// Return build time of the engine, e.g. `2024-02-06T21:14:25.000000000+00:00`
+    pub fn build_time(&self) -> &str {
+        &self.build_time
+    }
+
+}
+
+/// Platform of the daemon.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq, Eq)]
+struct Platform {
+
+    #[serde(rename = "Name", default)]
+    name: String,
+
+}
+
+/// Component of the daemon, e.g. `containerd`
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq, Eq)]
+pub struct Component {
+
+    #[serde(rename = "Name", default)]
+    name: String,
+
+    #[serde(rename = "Version", default)]
+    version: String,
+
+    #[serde(rename = "Details", default, deserialize_with = "nullable_default")]
+    details: HashMap<String, String>,
+
+}
+
+impl Component {
+
+    /// Return name of the component
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Return version of the component
+    pub fn version(&self) -> &str {
+        &self.version
+    }
+
+    /// Return details of the component, e.g. `GitCommit`
+    pub fn details(&self) -> &HashMap<String, String> {
+        &self.details
+    }
+
+}
@@ -0,0 +1,139 @@
+//!
+//! Terminal integration of TTY sessions.
+//!
+//! Available with the `terminal` feature on unix. The module wires a hijacked TTY
+//! connection of an attached container or exec instance to the current terminal:
+//! the terminal is put in raw mode, stdin is forwarded, output is written to stdout
+//! and the remote TTY is resized whenever the terminal is, like `docker run -it`.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! # use docker_client::DockerClient;
+//! use docker_client::terminal;
+//! use tokio::io::{AsyncRead, AsyncWrite};
+//!
+//! async fn interact<S>(client: &DockerClient, session: S) -> std::io::Result<()>
+//!     where S: AsyncRead + AsyncWrite + Unpin
+//! {
+//!     terminal::run(session, |rows, columns| client.resize_container_tty("shell", rows, columns)).await
+//! }
+//! ```
+
+use std::future::Future;
+use std::io;
+use tokio::io::{AsyncRead, AsyncWrite, AsyncWriteExt};
+use tokio::signal::unix::{signal, SignalKind};
+
+use crate::client::DockerError;
+
+/// Terminal in raw mode, the previous mode is restored on drop
+pub struct RawMode {
+
+    fd: libc::c_int,
+
+    original: libc::termios,
+
+}
+
+impl RawMode {
+
+    /// Put stdin in raw mode: no echo, no line buffering and no signals on control keys,
+    /// so every key reaches the container
+    pub fn enable() -> io::Result<Self> {
+        let fd = libc::STDIN_FILENO;
+
+        // SAFETY: termios is plain data, filled by tcgetattr before it is read.
+        let mut original: libc::termios = unsafe { std::mem::zeroed() };
+        if unsafe { libc::tcgetattr(fd, &mut original) } != 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        let mut raw = original;
+        // SAFETY: raw is a valid termios copied from the current settings.
+        unsafe { libc::cfmakeraw(&mut raw) };
+        if unsafe { libc::tcsetattr(fd, libc::TCSANOW, &raw) } != 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        Ok(RawMode { fd, original })
+    }
+
+}
+
+impl Drop for RawMode {
+    fn drop(&mut self) {
+        // SAFETY: original holds the settings read in `enable`.
+        unsafe { libc::tcsetattr(self.fd, libc::TCSANOW, &self.original) };
+    }
+}
+
+/// Return `true` if stdin is a terminal
+pub fn is_terminal() -> bool {
+    // SAFETY: isatty only inspects the descriptor.
+    unsafe { libc::isatty(libc::STDIN_FILENO) == 1 }
+}
+
+/// Return size of the terminal as `(rows, columns)`, `None` if stdout is not a terminal
+pub fn size() -> Option<(u16, u16)> {
+    // SAFETY: winsize is plain data, filled by the ioctl before it is read.
+    let mut size: libc::winsize = unsafe { std::mem::zeroed() };
+    match unsafe { libc::ioctl(libc::STDOUT_FILENO, libc::TIOCGWINSZ, &mut size) } {
+        0 if size.ws_row > 0 && size.ws_col > 0 => Some((size.ws_row, size.ws_col)),
+        _ => None,
+    }
+}
+
+/// Run an interactive session until the remote side closes the output
+///
+/// `session` is a hijacked TTY connection, its output is not multiplexed.
+/// `resize` is called with `(rows, columns)` at start and on every `SIGWINCH`,
+/// its errors are ignored as the session may be ending. Raw mode is only
+/// enabled when stdin is a terminal.
+pub async fn run<S, F, Fut>(session: S, mut resize: F) -> io::Result<()>
+    where
+        S: AsyncRead + AsyncWrite + Unpin,
+        F: FnMut(u16, u16) -> Fut,
+        Fut: Future<Output = Result<(), DockerError>>
+{
+    let _raw = match is_terminal() {
+        true => Some(RawMode::enable()?),
+        false => None,
+    };
+
+    if let Some((rows, columns)) = size() {
+        let _ = resize(rows, columns).await;
+    }
+
+    let mut window_changes = signal(SignalKind::window_change())?;
+
+    let (mut reader, mut writer) = tokio::io::split(session);
+    let mut stdin = tokio::io::stdin();
+    let mut stdout = tokio::io::stdout();
+
+    // On EOF of stdin the write half is shut down, so the container reads EOF like with `docker run -i`.
+    let input = async {
+        tokio::io::copy(&mut stdin, &mut writer).await?;
+        writer.shutdown().await
+    };
+    let output = tokio::io::copy(&mut reader, &mut stdout);
+    tokio::pin!(input, output);
+
+    // Input ends on EOF of stdin, the session goes on until the container closes the output.
+    let mut input_done = false;
+
+    loop {
+        tokio::select! {
+            result = &mut output => return result.map(|_| ()),
+            result = &mut input, if !input_done => {
+                input_done = true;
+                result?;
+            },
+            Some(_) = window_changes.recv() => {
+                if let Some((rows, columns)) = size() {
+                    let _ = resize(rows, columns).await;
+                }
+            },
+        }
+    }
+}
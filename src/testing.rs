@@ -0,0 +1,383 @@
+//!
+//! Containers for integration tests.
+//!
+//! The module provides [GenericContainer](struct.GenericContainer.html), which pulls an image
+//! if it is missing, runs it with its exposed ports published on random host ports, waits
+//! until it is ready and returns a [RunningContainer](struct.RunningContainer.html). The
+//! container is removed when the `RunningContainer` is dropped.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use docker_client::DockerClient;
+//! use docker_client::testing::{GenericContainer, WaitFor};
+//!
+//! # #[tokio::main]
+//! async fn main() {
+//!     let client = DockerClient::new();
+//!
+//!     let redis = GenericContainer::new("redis:7-alpine")
+//!         .with_exposed_port(6379)
+//!         .with_wait_for(WaitFor::message("Ready to accept connections"));
+//!
+//!     match redis.start(&client).await {
+//!         Ok(redis) => {
+//!             let address = format!("{}:{}", redis.host(), redis.host_port(6379).unwrap());
+//!             println!("redis at {}", address);
+//!         },
+//!         Err(e) => { println!("Error: {:?}", e); },
+//!     }
+//! }
+//! ```
+
+use std::collections::HashMap;
+use std::fmt;
+use std::time::Duration;
+use futures::StreamExt;
+
+use crate::client::{DockerClient, DockerError, ErrorMessage};
+use crate::container::{Config, Create, Remover, ContainerId};
+use crate::container::inspect::{Inspect, HealthStatus};
+use crate::container::logs::RequestBuilder;
+use crate::additionals::host::host_config::HostConfigBuilder;
+use crate::additionals::port::ExposedPort;
+
+/// Interval of inspecting a container waiting to be healthy
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Condition of a started container to be ready
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WaitFor {
+
+    /// Ready as soon as it is started
+    Nothing,
+
+    /// Ready when `message` was logged `times` times, on stdout or stderr
+    LogMessage { message: String, times: usize },
+
+    /// Ready when the health check of the image passes
+    Healthy,
+
+    /// Ready after a fixed delay
+    Duration(Duration),
+
+}
+
+impl WaitFor {
+
+    /// Ready when `message` was logged once
+    pub fn message<T>(message: T) -> Self
+        where T: Into<String>
+    {
+        WaitFor::LogMessage { message: message.into(), times: 1 }
+    }
+
+}
+
+/// Container to start for a test
+#[derive(Debug, Clone)]
+pub struct GenericContainer {
+
+    image: String,
+
+    name: Option<String>,
+
+    env: Vec<String>,
+
+    cmd: Vec<String>,
+
+    labels: HashMap<String, String>,
+
+    exposed_ports: Vec<String>,
+
+    wait_for: WaitFor,
+
+    startup_timeout: Duration,
+
+}
+
+impl GenericContainer {
+
+    /// Creates a new `GenericContainer` of image, e.g. `postgres:16`
+    pub fn new<T>(image: T) -> Self
+        where T: Into<String>
+    {
+        GenericContainer {
+            image: image.into(),
+            name: None,
+            env: Vec::new(),
+            cmd: Vec::new(),
+            labels: HashMap::new(),
+            exposed_ports: Vec::new(),
+            wait_for: WaitFor::Nothing,
+            startup_timeout: Duration::from_secs(60),
+        }
+    }
+
+    /// Set name of the container, by default the daemon picks a random one
+    pub fn with_name<T>(mut self, name: T) -> Self
+        where T: Into<String>
+    {
+        self.name = Some(name.into());
+
+        self
+    }
+
+    /// Set environment variable
+    pub fn with_env<K, V>(mut self, key: K, value: V) -> Self
+        where
+            K: Into<String>,
+            V: Into<String>
+    {
+        self.env.push(format!("{}={}", key.into(), value.into()));
+
+        self
+    }
+
+    /// Replace command of the image
+    pub fn with_cmd<I, T>(mut self, cmd: I) -> Self
+        where
+            I: IntoIterator<Item = T>,
+            T: Into<String>
+    {
+        self.cmd = cmd.into_iter().map(Into::into).collect();
+
+        self
+    }
+
+    /// Set label
+    pub fn with_label<K, V>(mut self, key: K, value: V) -> Self
+        where
+            K: Into<String>,
+            V: Into<String>
+    {
+        self.labels.insert(key.into(), value.into());
+
+        self
+    }
+
+    /// Publish TCP container port on a random host port
+    pub fn with_exposed_port(mut self, port: u16) -> Self {
+        self.exposed_ports.push(format!("{}/tcp", port));
+
+        self
+    }
+
+    /// Set readiness condition, `WaitFor::Nothing` by default
+    pub fn with_wait_for(mut self, wait_for: WaitFor) -> Self {
+        self.wait_for = wait_for;
+
+        self
+    }
+
+    /// Set time to pull, start and wait for the container, 60 seconds by default
+    pub fn with_startup_timeout(mut self, timeout: Duration) -> Self {
+        self.startup_timeout = timeout;
+
+        self
+    }
+
+    /// Pull the image if missing, start the container and wait until it is ready
+    ///
+    /// Return `DockerError::Timeout` if the container is not ready within the startup timeout.
+    /// The container is removed if it fails to become ready.
+    pub async fn start(self, client: &DockerClient) -> Result<RunningContainer, DockerError> {
+        let timeout = self.startup_timeout;
+
+        match tokio::time::timeout(timeout, self.run(client)).await {
+            Ok(result) => result,
+            Err(_) => Err(DockerError::Timeout),
+        }
+    }
+
+    async fn run(self, client: &DockerClient) -> Result<RunningContainer, DockerError> {
+        let image = client.image(self.image.as_str());
+        match image.inspect().await {
+            Ok(_) => {},
+            Err(DockerError::NotFound(_)) => { image.pull().await?; },
+            Err(e) => return Err(e),
+        }
+
+        let created = client.create_container(self.create_request()).await?;
+
+        // From here the container is removed on drop, also when it fails to start.
+        let mut container = RunningContainer {
+            client: client.clone(),
+            id: created.id().clone(),
+            host: client.daemon_host(),
+            ports: HashMap::new(),
+            removed: false,
+        };
+
+        client.start_container(container.id(), "").await?;
+        self.wait(client, container.id()).await?;
+
+        let info = client.inspect_container(Inspect::container(container.id())).await?;
+        container.ports = info.network_settings().ports().keys()
+            .filter_map(|port| Some((port.clone(), info.network_settings().host_port_for(port)?)))
+            .collect();
+
+        Ok(container)
+    }
+
+    fn create_request(&self) -> Create {
+        let mut config = Config::with_image(self.image.as_str());
+        let mut host_config = HostConfigBuilder::new();
+
+        for env in &self.env {
+            config = config.env(env.as_str());
+        }
+        for cmd in &self.cmd {
+            config = config.cmd(cmd.as_str());
+        }
+        for (key, value) in &self.labels {
+            config = config.label(key.as_str(), value.as_str());
+        }
+        for port in &self.exposed_ports {
+            config = config.expose_port(port.as_str());
+            // Empty host port makes the daemon pick a free one.
+            host_config = host_config.bind_port(port.clone(), None, String::new());
+        }
+
+        let mut request = Create::new().config(config.host_config(host_config.build()).build());
+        if let Some(name) = &self.name {
+            request = request.name(name.as_str());
+        }
+
+        request.build()
+    }
+
+    async fn wait(&self, client: &DockerClient, id: &ContainerId) -> Result<(), DockerError> {
+        match &self.wait_for {
+            WaitFor::Nothing => Ok(()),
+            WaitFor::Duration(delay) => {
+                tokio::time::sleep(*delay).await;
+                Ok(())
+            },
+            WaitFor::LogMessage { message, times } => {
+                let request = RequestBuilder::container(id)
+                    .stdout(true)
+                    .stderr(true)
+                    .follow(true)
+                    .build();
+
+                let mut lines = client.container_log_stream(request).await?;
+                let mut seen = 0;
+                while seen < *times {
+                    match lines.next().await {
+                        Some(line) => {
+                            if line?.message().contains(message.as_str()) {
+                                seen += 1;
+                            }
+                        },
+                        None => return Err(exited(id)),
+                    }
+                }
+
+                Ok(())
+            },
+            WaitFor::Healthy => loop {
+                let info = client.inspect_container(Inspect::container(id)).await?;
+                let state = info.state();
+
+                match state.health_status() {
+                    HealthStatus::Healthy => return Ok(()),
+                    _ if !state.running() => return Err(exited(id)),
+                    _ => tokio::time::sleep(POLL_INTERVAL).await,
+                }
+            },
+        }
+    }
+
+}
+
+fn exited(id: &ContainerId) -> DockerError {
+    DockerError::ServerError(ErrorMessage {
+        message: format!("container {} exited before it was ready", id),
+    })
+}
+
+/// Started container, removed with its anonymous volumes on drop
+pub struct RunningContainer {
+
+    client: DockerClient,
+
+    id: ContainerId,
+
+    host: String,
+
+    ports: HashMap<ExposedPort, u16>,
+
+    removed: bool,
+
+}
+
+impl RunningContainer {
+
+    /// Return ID of the container
+    pub fn id(&self) -> &ContainerId {
+        &self.id
+    }
+
+    /// Return host name where published ports are reachable
+    pub fn host(&self) -> &str {
+        &self.host
+    }
+
+    /// Return host port published for TCP container `port`
+    pub fn host_port(&self, port: u16) -> Option<u16> {
+        self.ports.get(&ExposedPort::tcp(port)).copied()
+    }
+
+    /// Return client the container was started with
+    pub fn client(&self) -> &DockerClient {
+        &self.client
+    }
+
+    /// Remove the container now and return the error, if any, instead of ignoring it on drop
+    pub async fn remove(mut self) -> Result<(), DockerError> {
+        self.removed = true;
+        self.client.remove_container(remover(&self.id)).await
+    }
+
+}
+
+fn remover(id: &ContainerId) -> Remover {
+    Remover::new().id(id).with_force_delete(true).with_remove_volumes(true).build()
+}
+
+impl Drop for RunningContainer {
+
+    /// Remove the container, blocking until the daemon answers.
+    ///
+    /// The request runs on its own thread and runtime, so it completes even when
+    /// the test runtime is shutting down. Pooled connections are driven by the runtime
+    /// that opened them, which is blocked here, so the removal uses a client with its own pool.
+    fn drop(&mut self) {
+        if self.removed {
+            return;
+        }
+
+        let client = self.client.clone().with_max_idle_connections(0);
+        let remover = remover(&self.id);
+
+        let removal = std::thread::spawn(move || {
+            let runtime = tokio::runtime::Builder::new_current_thread().enable_all().build();
+            if let Ok(runtime) = runtime {
+                let _ = runtime.block_on(client.remove_container(remover));
+            }
+        });
+        let _ = removal.join();
+    }
+
+}
+
+impl fmt::Debug for RunningContainer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RunningContainer")
+            .field("id", &self.id)
+            .field("host", &self.host)
+            .field("ports", &self.ports)
+            .finish()
+    }
+}
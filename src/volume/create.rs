@@ -1,6 +1,8 @@
 use std::collections::HashMap;
 use serde::Serialize;
 
+use crate::models::VolumeCreateOptions;
+
 /// Volume creator builder
 #[derive(Debug, Default)]
 pub struct VolumeCreatorBuilder {
@@ -11,28 +13,27 @@ pub struct VolumeCreatorBuilder {
 }
 
 /// Volume creator structure
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(transparent)]
 pub struct VolumeCreator {
-    #[serde(skip_serializing_if = "String::is_empty", rename = "Name")]
-    name: String,
-
-    #[serde(skip_serializing_if = "String::is_empty", rename = "Driver")]
-    driver: String,
-
-    #[serde(skip_serializing_if = "HashMap::is_empty", rename = "DriverOpts")]
-    driver_opts: HashMap<String, String>,
-
-    #[serde(skip_serializing_if = "HashMap::is_empty", rename = "Labels")]
-    labels: HashMap<String, String>,
+    options: VolumeCreateOptions,
 }
 
+// Options hold strings and string maps only.
+impl Eq for VolumeCreator {}
+
 impl VolumeCreator {
 
-    /// Get builder
+    /// Creates a new default instance of `VolumeCreatorBuilder` to construct a `VolumeCreator`.
     pub fn builder() -> VolumeCreatorBuilder {
         VolumeCreatorBuilder::default()
     }
 
+    /// Return name of the volume, empty for a volume named by the daemon
+    pub fn name(&self) -> &str {
+        self.options.name.as_deref().unwrap_or("")
+    }
+
 }
 
 impl VolumeCreatorBuilder {
@@ -85,10 +86,12 @@ impl VolumeCreatorBuilder {
     /// Build VolumeCreator
     pub fn build(self) -> VolumeCreator {
         VolumeCreator {
-            name: self.name,
-            driver: self.driver,
-            driver_opts: self.driver_opts,
-            labels: self.labels,
+            options: VolumeCreateOptions {
+                name: Some(self.name).filter(|name| !name.is_empty()),
+                driver: Some(self.driver).filter(|driver| !driver.is_empty()),
+                driver_opts: Some(self.driver_opts).filter(|opts| !opts.is_empty()),
+                labels: Some(self.labels).filter(|labels| !labels.is_empty()),
+            },
         }
     }
 }
\ No newline at end of file
@@ -1,13 +1,27 @@
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use crate::additionals::serde_helpers::nullable_priority_vec;
 
 /// Deleted info structure
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
 pub struct DeletedInfo {
 
-    #[serde(rename = "VolumesDeleted")]
+    #[serde(rename = "VolumesDeleted", deserialize_with = "nullable_priority_vec", default)]
     volumes_deleted: Vec<String>,
 
     #[serde(rename = "SpaceReclaimed")]
     space_reclaimed: i64,
 }
+
+impl DeletedInfo {
+
+    /// Return names of the deleted volumes
+    pub fn volumes_deleted(&self) -> &Vec<String> {
+        &self.volumes_deleted
+    }
+
+    /// Return disk space reclaimed in bytes
+    pub fn space_reclaimed(&self) -> i64 {
+        self.space_reclaimed
+    }
+}
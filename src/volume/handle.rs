@@ -0,0 +1,63 @@
+use std::pin::Pin;
+use futures::Stream;
+use hyper::body::Bytes;
+
+use crate::{DockerClient, DockerError};
+use crate::volume::{VolumeCreator, VolumeInfo};
+
+/// Handle to a volume bound to a client.
+///
+/// Returned by [DockerClient::volume](../struct.DockerClient.html#method.volume).
+#[derive(Debug, Clone)]
+pub struct VolumeRef<'a> {
+    client: &'a DockerClient,
+    name: String,
+}
+
+impl<'a> VolumeRef<'a> {
+
+    pub(crate) fn new(client: &'a DockerClient, name: String) -> Self {
+        VolumeRef {
+            client,
+            name
+        }
+    }
+
+    /// Return name of the volume
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Create the volume with default driver
+    pub async fn create(&self) -> Result<(), DockerError> {
+        let creator = VolumeCreator::builder()
+            .name(self.name.as_str())
+            .build();
+
+        self.client.create_volume(creator).await
+    }
+
+    /// Return low-level information about the volume
+    pub async fn inspect(&self) -> Result<VolumeInfo, DockerError> {
+        self.client.inspect_volume(self.name.as_str()).await
+    }
+
+    /// Remove the volume
+    pub async fn remove(&self, force: bool) -> Result<(), DockerError> {
+        self.client.remove_volume(self.name.as_str(), force).await
+    }
+
+    /// Return stream of the tar archive of the volume contents, see `DockerClient::export_volume`
+    pub async fn export(&self) -> Result<Pin<Box<dyn Stream<Item = Result<Bytes, DockerError>> + Send>>, DockerError> {
+        self.client.export_volume(self.name.as_str()).await
+    }
+
+    /// Restore a streamed tar archive into the volume, see `DockerClient::import_volume`
+    pub async fn import<A, B>(&self, archive: A) -> Result<(), DockerError>
+        where
+            A: Stream<Item = Result<B, DockerError>> + Send + 'static,
+            B: Into<Bytes> + 'static
+    {
+        self.client.import_volume(self.name.as_str(), archive).await
+    }
+}
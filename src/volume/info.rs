@@ -1,8 +1,8 @@
 use std::collections::HashMap;
-use serde::{Deserialize, Deserializer};
+use serde::{Deserialize, Deserializer, Serialize};
 
 /// Usage data structure
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
 pub struct UsageData {
     #[serde(rename = "Size")]
     size: i64,
@@ -12,7 +12,7 @@ pub struct UsageData {
 }
 
 /// Volume info structure
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
 pub struct VolumeInfo {
     #[serde(rename = "Name")]
     name: String,
@@ -23,23 +23,98 @@ pub struct VolumeInfo {
     #[serde(rename = "Mountpoint")]
     mountpoint: String,
 
-    #[serde(rename = "CreatedAt")]
+    #[serde(rename = "CreatedAt", default)]
     created: String,
 
     #[serde(rename = "Status", deserialize_with = "nullable_priority_hash", default = "Default::default")]
     status: HashMap<String, String>,
 
-    #[serde(rename = "Labels", deserialize_with = "nullable_priority_hash")]
+    #[serde(rename = "Labels", deserialize_with = "nullable_priority_hash", default)]
     labels: HashMap<String, String>,
 
     #[serde(rename = "Scope")]
     scope: String,
 
-    #[serde(rename = "Options", deserialize_with = "nullable_priority_hash")]
+    #[serde(rename = "Options", deserialize_with = "nullable_priority_hash", default)]
     options: HashMap<String, String>,
 
-    #[serde(rename = "UsageData")]
+    #[serde(rename = "UsageData", default)]
     usage_data: Option<UsageData>,
+
+    #[serde(flatten, skip_serializing)]
+    extra: HashMap<String, serde_json::Value>,
+}
+
+impl UsageData {
+
+    /// Return disk space used by the volume in bytes, `-1` if not available
+    pub fn size(&self) -> i64 {
+        self.size
+    }
+
+    /// Return number of containers referencing the volume, `-1` if not available
+    pub fn ref_count(&self) -> i64 {
+        self.ref_count
+    }
+}
+
+impl VolumeInfo {
+
+    /// Return name of the volume
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Return volume driver
+    pub fn driver(&self) -> &str {
+        &self.driver
+    }
+
+    /// Return mount point on the host
+    pub fn mountpoint(&self) -> &str {
+        &self.mountpoint
+    }
+
+    /// Return driver specific status
+    pub fn status(&self) -> &HashMap<String, String> {
+        &self.status
+    }
+
+    /// Return labels
+    pub fn labels(&self) -> &HashMap<String, String> {
+        &self.labels
+    }
+
+    /// Return scope, `local` or `global`
+    pub fn scope(&self) -> &str {
+        &self.scope
+    }
+
+    /// Return driver options
+    pub fn options(&self) -> &HashMap<String, String> {
+        &self.options
+    }
+
+    /// Return usage data, only set by `docker system df`
+    pub fn usage_data(&self) -> Option<&UsageData> {
+        self.usage_data.as_ref()
+    }
+
+    /// Return creation time as RFC 3339 string
+    pub fn created(&self) -> &str {
+        &self.created
+    }
+
+    /// Return creation time as `DateTime<Utc>`
+    #[cfg(feature = "chrono")]
+    pub fn created_datetime(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        crate::additionals::timestamp::parse_rfc3339(&self.created)
+    }
+
+    /// Return fields unknown to this crate, e.g. added by newer daemons
+    pub fn extra(&self) -> &HashMap<String, serde_json::Value> {
+        &self.extra
+    }
 }
 
 fn nullable_priority_hash<'de, D>(deserializer: D) -> Result<HashMap<String, String>, D::Error>
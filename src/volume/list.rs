@@ -1,17 +1,30 @@
 use crate::volume::VolumeInfo;
-use serde::{Deserialize, Deserializer};
+use serde::{Deserialize, Deserializer, Serialize};
 
 /// Volumes list structure
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
 pub struct VolumesList {
 
-    #[serde(rename = "Volumes", deserialize_with = "nullable_priority_seq_info")]
+    #[serde(rename = "Volumes", deserialize_with = "nullable_priority_seq_info", default)]
     volumes: Vec<VolumeInfo>,
 
-    #[serde(rename = "Warnings", deserialize_with = "nullable_priority_seq_str")]
+    #[serde(rename = "Warnings", deserialize_with = "nullable_priority_seq_str", default)]
     warnings: Vec<String>,
 }
 
+impl VolumesList {
+
+    /// Return volumes
+    pub fn volumes(&self) -> &Vec<VolumeInfo> {
+        &self.volumes
+    }
+
+    /// Return warnings reported by the daemon
+    pub fn warnings(&self) -> &Vec<String> {
+        &self.warnings
+    }
+}
+
 fn nullable_priority_seq_info<'de, D>(deserializer: D) -> Result<Vec<VolumeInfo>, D::Error>
     where D: Deserializer<'de>
 {
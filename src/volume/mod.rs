@@ -4,8 +4,10 @@ mod create;
 mod info;
 mod delete;
 mod list;
+mod handle;
 
 pub use create::VolumeCreator;
 pub use info::VolumeInfo;
 pub use delete::DeletedInfo;
-pub use list::VolumesList;
\ No newline at end of file
+pub use list::VolumesList;
+pub use handle::VolumeRef;
\ No newline at end of file
@@ -0,0 +1,289 @@
+//! Deserialization of daemon responses.
+//!
+//! Every file in `tests/fixtures` is a response body of the named endpoint,
+//! suffixed with the API version it follows. Add a file here when a model
+//! changes or a daemon release changes a payload.
+
+use docker_client::container::{ShortContainerInfo, CreatedContainer, FSChanges, WaitStatus, Stats, PrunedContainers};
+use docker_client::container::inspect::ContainerInfo;
+use docker_client::container::processes_list::TopList;
+use docker_client::image::{ShortImageInfo, ImageInfo, ImageHistory, RemovedImage, PrunedImages, DistributionInfo};
+use docker_client::volume::{VolumeInfo, VolumesList, DeletedInfo};
+use docker_client::networks::inspect::NetworkInfo;
+use docker_client::networks::create::CreatedNetwork;
+use docker_client::exec::inspect::ExecStatus;
+use docker_client::events::Event;
+use docker_client::additionals::port::ExposedPort;
+use docker_client::swarm::{Service, CreatedService, Task, Secret, SwarmConfig};
+use docker_client::system::{Version, SystemInfo, LocalNodeState, DiskUsage};
+use docker_client::client::ApiVersion;
+
+macro_rules! fixture {
+    ($ty:ty, $file:expr) => {
+        serde_json::from_str::<$ty>(include_str!(concat!("fixtures/", $file)))
+            .unwrap_or_else(|e| panic!("{}: {}", $file, e))
+    };
+}
+
+#[test]
+fn container_inspect() {
+    let info = fixture!(ContainerInfo, "container_inspect_v1.40.json");
+    assert!(info.host_config().is_some());
+
+    let info = fixture!(ContainerInfo, "container_inspect_v1.44.json");
+    assert!(info.host_config().is_some());
+    assert_eq!(info.mounts()[1].mount_type(), &docker_client::additionals::mount::MountType::Bind);
+    assert_eq!(info.network_settings().host_port_for(&ExposedPort::tcp(80)), Some(8080));
+    assert_eq!(info.network_settings().bindings_for(&ExposedPort::tcp(80))[1].host_ip(), Some("::"));
+    assert!(info.network_settings().bindings_for(&ExposedPort::udp(80)).is_empty());
+}
+
+#[test]
+fn container_list() {
+    let containers = fixture!(Vec<ShortContainerInfo>, "container_list_v1.40.json");
+    assert_eq!(containers.len(), 2);
+    assert_eq!(containers[0].mounts()[1].source(), "/srv/config");
+    assert_eq!(containers[0].host_port_for(&ExposedPort::tcp(2222)), Some(3333));
+    assert!(containers[1].ports().is_empty());
+}
+
+#[test]
+fn container_create() {
+    fixture!(CreatedContainer, "container_create_v1.40.json");
+
+    let created = fixture!(CreatedContainer, "container_create_v1.44.json");
+    assert!(created.warnings().is_empty());
+}
+
+#[test]
+fn container_top() {
+    let top = fixture!(TopList, "container_top_v1.40.json");
+    assert_eq!(top.entries().len(), 2);
+}
+
+#[test]
+fn container_changes() {
+    let changes = fixture!(Vec<FSChanges>, "container_changes_v1.40.json");
+    assert_eq!(changes.len(), 3);
+}
+
+#[test]
+fn container_wait() {
+    fixture!(WaitStatus, "container_wait_v1.44.json");
+}
+
+#[test]
+fn container_stats() {
+    let stats = fixture!(Stats, "container_stats_v1.40.json");
+    assert_eq!(stats.cpu_stats().online_cpus(), 4);
+
+    let stats = fixture!(Stats, "container_stats_v1.44.json");
+    assert_eq!(stats.memory_usage(), 8192000 - 1409024);
+}
+
+#[test]
+fn image_inspect() {
+    let info = fixture!(ImageInfo, "image_inspect_v1.40.json");
+    assert_eq!(info.platform(), "linux/amd64");
+
+    let info = fixture!(ImageInfo, "image_inspect_v1.44.json");
+    assert_eq!(info.platform(), "linux/arm64/v8");
+}
+
+#[test]
+fn image_list() {
+    let images = fixture!(Vec<ShortImageInfo>, "image_list_v1.40.json");
+    assert_eq!(images.len(), 2);
+}
+
+#[test]
+fn image_history() {
+    let history = fixture!(Vec<ImageHistory>, "image_history_v1.40.json");
+    assert_eq!(history.len(), 2);
+}
+
+#[test]
+fn image_remove() {
+    let removed = fixture!(Vec<RemovedImage>, "image_remove_v1.40.json");
+    assert_eq!(removed.len(), 2);
+}
+
+#[test]
+fn distribution_inspect() {
+    let info = fixture!(DistributionInfo, "distribution_inspect_v1.44.json");
+    assert!(info.digest_for("linux/arm/v7").is_some());
+}
+
+#[test]
+fn volume_list() {
+    let list = fixture!(VolumesList, "volume_list_v1.40.json");
+    assert_eq!(list.volumes().len(), 2);
+}
+
+#[test]
+fn volume_inspect() {
+    fixture!(VolumeInfo, "volume_inspect_v1.44.json");
+}
+
+#[test]
+fn volume_prune() {
+    fixture!(DeletedInfo, "volume_prune_v1.40.json");
+}
+
+#[test]
+fn container_prune() {
+    let pruned = fixture!(PrunedContainers, "container_prune_v1.44.json");
+    assert_eq!(pruned.containers_deleted().len(), 2);
+}
+
+#[test]
+fn image_prune() {
+    let pruned = fixture!(PrunedImages, "image_prune_v1.44.json");
+    assert_eq!(pruned.images_deleted()[0].untagged(), Some("ci-build:7f3a"));
+    assert_eq!(pruned.images_deleted().len(), 3);
+}
+
+#[test]
+fn network_inspect() {
+    let info = fixture!(NetworkInfo, "network_inspect_v1.40.json");
+    assert_eq!(info.containers().len(), 1);
+
+    let networks = fixture!(Vec<NetworkInfo>, "network_list_v1.44.json");
+    assert_eq!(networks.len(), 2);
+}
+
+#[test]
+fn network_create() {
+    let created = fixture!(CreatedNetwork, "network_create_v1.44.json");
+    assert_eq!(created.warning(), None);
+}
+
+#[test]
+fn exec_inspect() {
+    fixture!(ExecStatus, "exec_inspect_v1.40.json");
+}
+
+#[test]
+fn event() {
+    let event = fixture!(Event, "event_v1.44.json");
+    assert_eq!(event.actor().attribute("exitCode"), Some("137"));
+}
+
+#[test]
+fn swarm() {
+    let service = fixture!(Service, "service_inspect_v1.44.json");
+    assert_eq!(service.name(), "hopeful_cori");
+
+    let tasks = fixture!(Vec<Task>, "task_list_v1.44.json");
+    assert_eq!(tasks.len(), 1);
+
+    let secret = fixture!(Secret, "secret_inspect_v1.44.json");
+    assert_eq!(secret.labels()["foo"], "bar");
+
+    let config = fixture!(SwarmConfig, "config_inspect_v1.44.json");
+    assert!(config.labels().is_empty());
+}
+
+#[test]
+fn service_create() {
+    let created = fixture!(CreatedService, "service_create_v1.44.json");
+    assert_eq!(created.id(), "ak7w3gjqoa3kuz8xcpnyy0pvl");
+    assert_eq!(created.warnings().len(), 1);
+}
+
+#[cfg(feature = "simd-json")]
+#[test]
+fn simd_json_lists() {
+    fn simd<T: serde::de::DeserializeOwned>(fixture: &str) -> T {
+        simd_json::serde::from_slice(&mut fixture.as_bytes().to_vec()).unwrap()
+    }
+
+    let containers: Vec<ShortContainerInfo> = simd(include_str!("fixtures/container_list_v1.40.json"));
+    assert_eq!(containers, fixture!(Vec<ShortContainerInfo>, "container_list_v1.40.json"));
+
+    let images: Vec<ShortImageInfo> = simd(include_str!("fixtures/image_list_v1.40.json"));
+    assert_eq!(images, fixture!(Vec<ShortImageInfo>, "image_list_v1.40.json"));
+
+    let info: ContainerInfo = simd(include_str!("fixtures/container_inspect_v1.44.json"));
+    assert_eq!(info.id(), fixture!(ContainerInfo, "container_inspect_v1.44.json").id());
+}
+
+#[test]
+fn network_settings_unpublished_port() {
+    use docker_client::additionals::network::NetworkSettings;
+
+    let settings: NetworkSettings = serde_json::from_str(r#"{"Networks":{},"Ports":{"5432/tcp":null}}"#).unwrap();
+    assert!(settings.ports()[&ExposedPort::tcp(5432)].is_empty());
+    assert_eq!(settings.host_port_for(&ExposedPort::tcp(5432)), None);
+}
+
+#[test]
+fn newer_daemon_values() {
+    use docker_client::additionals::mount::MountType;
+    use docker_client::container::inspect::HealthStatus;
+
+    let mut raw: serde_json::Value = serde_json::from_str(include_str!("fixtures/container_inspect_v1.44.json")).unwrap();
+    raw["State"]["Health"]["Status"] = "degraded".into();
+    raw["Mounts"][1]["Type"] = "overlay".into();
+    raw["ImageManifestDescriptor"] = serde_json::json!({"mediaType": "application/vnd.oci.image.manifest.v1+json"});
+
+    let info: ContainerInfo = serde_json::from_value(raw).unwrap();
+    assert_eq!(info.state().health_status(), HealthStatus::Other("degraded".to_string()));
+    assert_eq!(info.mounts()[1].mount_type(), &MountType::Other("overlay".to_string()));
+    assert_eq!(info.extra()["ImageManifestDescriptor"]["mediaType"], "application/vnd.oci.image.manifest.v1+json");
+    assert!(!info.extra().contains_key("State"));
+    assert!(serde_json::to_value(&info).unwrap().get("ImageManifestDescriptor").is_none());
+}
+
+#[test]
+fn system_version() {
+    let version = fixture!(Version, "version_v1.44.json");
+
+    assert_eq!(version.api_version(), ApiVersion::new(1, 44));
+    assert_eq!(version.min_api_version(), Some(ApiVersion::new(1, 24)));
+    assert_eq!(version.platform(), Some("Docker Engine - Community"));
+    assert_eq!(version.component("containerd").map(|component| component.version()), Some("1.6.28"));
+    assert_eq!(version.components().len(), 4);
+    assert!(version.supports(ApiVersion::new(1, 40)));
+    assert!(!version.supports(ApiVersion::new(1, 45)));
+    assert!(!version.supports(ApiVersion::new(1, 12)));
+}
+
+#[test]
+fn system_info() {
+    let info = fixture!(SystemInfo, "info_v1.44.json");
+
+    assert_eq!(info.containers(), 14);
+    assert_eq!(info.containers_running() + info.containers_paused() + info.containers_stopped(), info.containers());
+    assert_eq!(info.driver(), "overlay2");
+    assert_eq!(info.driver_status()[0], ("Backing Filesystem".to_string(), "extfs".to_string()));
+    assert_eq!(info.cgroup_version(), "2");
+    assert_eq!(info.ncpu(), 8);
+    assert!(!info.swap_limit());
+    assert_eq!(info.swarm().local_node_state(), &LocalNodeState::Inactive);
+    assert_eq!(info.swarm().nodes(), None);
+    assert!(info.extra().contains_key("RegistryConfig"));
+
+    let mut raw: serde_json::Value = serde_json::from_str(include_str!("fixtures/info_v1.44.json")).unwrap();
+    raw["Swarm"]["LocalNodeState"] = "draining".into();
+    let info: SystemInfo = serde_json::from_value(raw).unwrap();
+    assert_eq!(info.swarm().local_node_state(), &LocalNodeState::Other("draining".to_string()));
+}
+
+#[test]
+fn system_disk_usage() {
+    let usage = fixture!(DiskUsage, "system_df_v1.44.json");
+
+    assert_eq!(usage.layers_size(), 7182600);
+    assert_eq!(usage.images().len(), 2);
+    assert_eq!(usage.containers()[0].size_rw(), Some(12288));
+    assert_eq!(usage.containers()[1].size_root_fs(), Some(1595396));
+    assert_eq!(usage.volumes()[1].usage_data().map(|data| data.ref_count()), Some(0));
+    assert_eq!(usage.build_cache()[1].parents(), &vec!["k8ql4nrq8y4a3rq9k5a6cvb0w".to_string()]);
+    assert_eq!(usage.build_cache()[1].last_used_at(), None);
+
+    assert_eq!(usage.reclaimable_images(), 5591300 - 1591300);
+    assert_eq!(usage.reclaimable_containers(), 12288);
+    assert_eq!(usage.reclaimable_volumes(), 52428800);
+    assert_eq!(usage.reclaimable_build_cache(), 8388608);
+}
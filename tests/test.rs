@@ -2,10 +2,12 @@ extern crate docker_client;
 
 use docker_client::{DockerClient, DockerError};
 use docker_client::container::{Remover, Killer, Config, HealthCheck, WaitCondition, Create};
-use docker_client::volume::VolumeCreator;
+use docker_client::volume::{VolumeCreator, VolumeInfo};
 //use docker_client::container::Request;
 use docker_client::container::inspect::Inspect;
-use docker_client::container::processes_list::ProcessesList;
+use docker_client::container::processes_list::{ProcessesList, TopList};
+use docker_client::container::Stats;
+use docker_client::additionals::filters::Filters;
 use std::path::Path;
 
 fn client() -> DockerClient {
@@ -41,8 +43,8 @@ fn client() -> DockerClient {
 //         Err(DockerError::NotFound(m)) => { println!("Container not found: {}.", m.message); },
 //         Err(DockerError::NotRunning(m)) => { println!("Container not running: {}.", m.message); },
 //         Err(DockerError::ServerError(m)) => { println!("Server error: {}.", m.message); },
-//         Err(DockerError::UnknownStatus) => { println!("Unknown response status."); },
-//         Err(DockerError::ClosedConnection) => { println!("Connection closed."); },
+//         Err(DockerError::UnknownStatus { status, .. }) => { println!("Unknown response status {}.", status); },
+//         Err(DockerError::ConnectionRefused) => { println!("Daemon is not running."); },
 //         _ => {}
 //     }
 // }
@@ -243,6 +245,48 @@ async fn test_export_container() {
     }
 }
 
+#[tokio::test]
+async fn test_container_archive() {
+    let client = client();
+
+    match client.container_archive("test", "/etc/hostname").await {
+        Ok(archive) => println!("{} bytes", archive.len()),
+        Err(e) => println!("Error {:?}", e),
+    }
+}
+
+#[tokio::test]
+async fn test_save_image_binary_body() {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    let archive: Vec<u8> = (0..=255u8).cycle().take(4096).collect();
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+
+    let body = archive.clone();
+    tokio::spawn(async move {
+        let (mut socket, _) = listener.accept().await.unwrap();
+
+        let mut request = Vec::new();
+        let mut buffer = [0u8; 1024];
+        while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+            let read = socket.read(&mut buffer).await.unwrap();
+            request.extend_from_slice(&buffer[..read]);
+        }
+        assert!(request.starts_with(b"GET /images/alpine/get "));
+
+        let header = format!("HTTP/1.1 200 OK\r\nContent-Type: application/x-tar\r\nContent-Length: {}\r\n\r\n", body.len());
+        socket.write_all(header.as_bytes()).await.unwrap();
+        socket.write_all(&body).await.unwrap();
+    });
+
+    let client = DockerClient::stream(format!("tcp://{}", address), None);
+    let saved = client.save_image("alpine").await.unwrap();
+
+    assert_eq!(saved.as_ref(), archive.as_slice());
+}
+
 #[tokio::test]
 async fn test_image_list() {
     let client = client();
@@ -312,4 +356,3154 @@ async fn test_pull_image() {
         Ok(_) => {},
         Err(e) => println!("Error {:?}", e)
     }
-}
\ No newline at end of file
+}
+#[tokio::test]
+async fn test_exec_shell() {
+    let client = client();
+
+    match client.exec_shell("test", "echo $HOME && ls").await {
+        Ok(output) => println!("{} ({})", output.stdout(), output.exit_code()),
+        Err(e) => println!("Error {:?}", e)
+    }
+}
+
+#[tokio::test]
+async fn test_container_stats() {
+    let client = client();
+
+    match client.container_stats("test").await {
+        Ok(stats) => println!("{:.2}% {:.2}%", stats.cpu_percent(), stats.memory_percent()),
+        Err(e) => println!("Error {:?}", e)
+    }
+}
+
+#[test]
+fn test_stats_metrics() {
+    let previous: Stats = serde_json::from_str(r#"{
+        "read": "2021-01-01T00:00:00Z",
+        "networks": { "eth0": { "rx_bytes": 1000, "tx_bytes": 500 } }
+    }"#).unwrap();
+
+    let stats: Stats = serde_json::from_str(r#"{
+        "read": "2021-01-01T00:00:02Z",
+        "cpu_stats": { "cpu_usage": { "total_usage": 400 }, "system_cpu_usage": 2000, "online_cpus": 2 },
+        "precpu_stats": { "cpu_usage": { "total_usage": 200 }, "system_cpu_usage": 1000 },
+        "memory_stats": { "usage": 300, "limit": 1000, "stats": { "cache": 100 } },
+        "networks": { "eth0": { "rx_bytes": 3000, "tx_bytes": 1500 } }
+    }"#).unwrap();
+
+    assert_eq!(stats.cpu_percent(), 40.0);
+    assert_eq!(stats.memory_usage(), 200);
+    assert_eq!(stats.memory_percent(), 20.0);
+
+    let rates = stats.network_rates(&previous, std::time::Duration::from_secs(2));
+    assert_eq!(rates["eth0"].rx_bytes_per_sec, 1000.0);
+    assert_eq!(rates["eth0"].tx_bytes_per_sec, 500.0);
+}
+
+#[tokio::test]
+async fn test_streaming_bodies() {
+    use docker_client::client::MockTransport;
+    use futures::StreamExt;
+    use hyper::Method;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::sync::oneshot;
+
+    // The daemon writes a second sample only once the first one was received.
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+    let (received, next) = oneshot::channel::<()>();
+    tokio::spawn(async move {
+        let (mut socket, _) = listener.accept().await.unwrap();
+        let mut buffer = [0u8; 1024];
+        let _ = socket.read(&mut buffer).await.unwrap();
+
+        let chunk = |line: &str| format!("{:x}\r\n{}\n\r\n", line.len() + 1, line);
+        socket.write_all(b"HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n").await.unwrap();
+        socket.write_all(chunk(r#"{"read":"2021-01-01T00:00:01Z"}"#).as_bytes()).await.unwrap();
+        next.await.unwrap();
+        socket.write_all(chunk(r#"{"read":"2021-01-01T00:00:02Z"}"#).as_bytes()).await.unwrap();
+        socket.write_all(b"0\r\n\r\n").await.unwrap();
+    });
+
+    let client = DockerClient::stream(format!("tcp://{}", address), None);
+    let mut stats = client.container_stats_stream("web").await.unwrap();
+    assert!(stats.next().await.unwrap().is_ok());
+    received.send(()).unwrap();
+    assert!(stats.next().await.unwrap().is_ok());
+    assert!(stats.next().await.is_none());
+
+    let archive: &[u8] = b"tar archive of the container";
+    let mock = MockTransport::new()
+        .with_response(Method::GET, "/containers/web/export", 200, archive)
+        .with_response(Method::GET, "/containers/missing/export", 404, r#"{"message":"No such container: missing"}"#);
+    let client = DockerClient::from_transport(mock);
+
+    let path = std::env::temp_dir().join("docker-client-test-export.tar");
+    client.export_container("web", &path).await.unwrap();
+    assert_eq!(std::fs::read(&path).unwrap(), archive);
+    std::fs::remove_file(&path).unwrap();
+
+    match client.export_container_stream("missing").await {
+        Err(DockerError::NotFound(e)) => assert_eq!(e.message, "No such container: missing"),
+        Err(e) => panic!("expected NotFound, got {:?}", e),
+        Ok(_) => panic!("expected NotFound"),
+    }
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn test_stats_timestamps() {
+    let stats: Stats = serde_json::from_str(r#"{
+        "read": "2021-01-01T00:00:02.5Z",
+        "preread": "0001-01-01T00:00:00Z"
+    }"#).unwrap();
+
+    assert_eq!(stats.read_datetime().unwrap().timestamp(), 1609459202);
+    assert!(stats.preread_datetime().is_none());
+}
+
+#[test]
+fn test_volume_info_round_trip() {
+    let info: VolumeInfo = serde_json::from_str(r#"{
+        "Name": "volume-test",
+        "Driver": "local",
+        "Mountpoint": "/var/lib/docker/volumes/volume-test/_data",
+        "CreatedAt": "2021-01-01T00:00:00Z",
+        "Labels": null,
+        "Scope": "local",
+        "Options": null
+    }"#).unwrap();
+
+    let cached = serde_json::to_string(&info).unwrap();
+    let restored: VolumeInfo = serde_json::from_str(&cached).unwrap();
+
+    assert_eq!(restored, info);
+}
+
+#[test]
+fn test_volume_creator_body() {
+    let creator = VolumeCreator::builder()
+        .name("pgdata")
+        .driver_opt("type", "tmpfs")
+        .label("com.example.team", "db")
+        .build();
+
+    assert_eq!(creator.name(), "pgdata");
+    assert_eq!(
+        serde_json::to_value(&creator).unwrap(),
+        serde_json::json!({"Name": "pgdata", "DriverOpts": {"type": "tmpfs"}, "Labels": {"com.example.team": "db"}})
+    );
+
+    // Unset options are left to the daemon.
+    let anonymous = VolumeCreator::builder().build();
+    assert_eq!(anonymous.name(), "");
+    assert_eq!(serde_json::to_string(&anonymous).unwrap(), "{}");
+}
+
+#[tokio::test]
+async fn test_image_handle() {
+    let client = client();
+
+    let image = client.image("alpine:latest");
+
+    match image.pull().await {
+        Ok(_) => {},
+        Err(e) => { println!("Error {:?}", e); return; }
+    }
+
+    match image.inspect().await {
+        Ok(info) => { dbg!(info); },
+        Err(e) => println!("Error {:?}", e),
+    }
+
+    match image.history().await {
+        Ok(history) => { dbg!(history); },
+        Err(e) => println!("Error {:?}", e),
+    }
+
+    match image.tag("docker-client-test", "latest").await {
+        Ok(tagged) => { let _ = tagged.remove(false).await; },
+        Err(e) => println!("Error {:?}", e),
+    }
+}
+
+#[tokio::test]
+async fn test_volume_and_network_handles() {
+    let client = client();
+
+    let volume = client.volume("volume-handle-test");
+    match volume.create().await {
+        Ok(_) => {
+            let _ = volume.inspect().await;
+            let _ = volume.remove(false).await;
+        },
+        Err(e) => println!("Error {:?}", e),
+    }
+
+    let network = client.network("network-handle-test");
+    match network.create().await {
+        Ok(_) => {
+            match network.inspect().await {
+                Ok(info) => { dbg!(info); },
+                Err(e) => println!("Error {:?}", e),
+            }
+            let _ = network.remove().await;
+        },
+        Err(e) => println!("Error {:?}", e),
+    }
+}
+
+#[tokio::test]
+async fn test_graceful_shutdown() {
+    let client = client();
+
+    match client.graceful_shutdown("test", std::time::Duration::from_secs(1), false).await {
+        Ok(_) => {},
+        Err(e) => println!("Error {:?}", e),
+    }
+}
+
+#[tokio::test]
+async fn test_stop_with_timeout() {
+    let client = client();
+
+    match client.stop_container_with_timeout("123", std::time::Duration::from_millis(1500)).await {
+        Ok(()) => {},
+        Err(_) => {}
+    }
+}
+
+#[tokio::test]
+async fn test_start_detach_keys() {
+    use docker_client::client::MockTransport;
+    use docker_client::container::StartOptions;
+    use hyper::Method;
+
+    let mock = MockTransport::new()
+        .with_response(Method::POST, "/containers/web/start", 204, "");
+    let client = DockerClient::from_transport(mock.clone());
+
+    client.start_container_with("web", StartOptions::new().detach_keys("ctrl-p,ctrl-q")).await.unwrap();
+    client.start_container_with("web", StartOptions::new().detach_keys("")).await.unwrap();
+    client.start_container_with("web", StartOptions::new()).await.unwrap();
+
+    client.start_container("web", "ctrl-p,ctrl-q").await.unwrap();
+    client.start_container("web", "").await.unwrap();
+
+    let queries: Vec<Option<String>> = mock.requests().iter()
+        .map(|request| request.query().map(String::from))
+        .collect();
+    let detach_keys = Some("detachKeys=ctrl-p%2Cctrl-q".to_string());
+    assert_eq!(queries, vec![detach_keys.clone(), None, None, detach_keys, None]);
+}
+
+#[tokio::test]
+async fn test_stop_wait() {
+    use docker_client::client::MockTransport;
+    use hyper::Method;
+    use std::time::Duration;
+
+    let mock = MockTransport::new()
+        .with_response(Method::POST, "/containers/web/stop", 204, "");
+    let client = DockerClient::from_transport(mock.clone());
+
+    client.stop_container("web", Some(12)).await.unwrap();
+    client.stop_container("web", Some(-1)).await.unwrap();
+    client.stop_container("web", None).await.unwrap();
+    client.stop_container_with_timeout("web", Duration::from_millis(1500)).await.unwrap();
+
+    let queries: Vec<Option<String>> = mock.requests().iter()
+        .map(|request| request.query().map(String::from))
+        .collect();
+    assert_eq!(queries, vec![Some("t=12".to_string()), Some("t=-1".to_string()), None, Some("t=2".to_string())]);
+}
+
+#[tokio::test]
+async fn test_restart() {
+    use docker_client::client::MockTransport;
+    use hyper::Method;
+    use std::time::Duration;
+
+    let mock = MockTransport::new()
+        .with_response(Method::POST, "/containers/web/restart", 204, "")
+        .with_response(Method::POST, "/containers/missing/restart", 404, r#"{"message":"No such container: missing"}"#);
+    let client = DockerClient::from_transport(mock.clone());
+
+    client.restart_container("web", Some(Duration::from_millis(1500))).await.unwrap();
+    client.restart_container("web", None).await.unwrap();
+
+    match client.restart_container("missing", None).await {
+        Err(DockerError::NotFound(message)) => assert_eq!(message.message, "No such container: missing"),
+        other => panic!("expected NotFound, got {:?}", other),
+    }
+
+    let requests = mock.requests();
+    assert_eq!(requests[0].query(), Some("t=2"));
+    assert_eq!(requests[1].query(), None);
+}
+
+#[test]
+fn test_filters_query() {
+    let filters = Filters::new()
+        .label("env", Some("prod".to_string()))
+        .dangling(true)
+        .build();
+
+    assert_eq!(
+        serde_json::to_string(&filters).unwrap(),
+        r#"{"dangling":["true"],"label":["env=prod"]}"#
+    );
+    assert_eq!(
+        filters.query().unwrap(),
+        "filters=%7B%22dangling%22%3A%5B%22true%22%5D%2C%22label%22%3A%5B%22env%3Dprod%22%5D%7D"
+    );
+    assert_eq!(Filters::default().query(), None);
+
+    // The label getter of earlier releases keeps working.
+    #[allow(deprecated)]
+    {
+        let labels = Filters::new().label("env", Some("prod".to_string())).label("ci", None).build().label();
+        assert_eq!(labels.get("env"), Some(&Some("prod".to_string())));
+        assert_eq!(labels.get("ci"), Some(&None));
+        assert_eq!(labels.len(), 2);
+    }
+}
+
+#[tokio::test]
+async fn test_filtered_lists() {
+    let client = client();
+    let filters = Filters::new().dangling(true).build();
+
+    match client.volumes_list(filters.clone()).await {
+        Ok(list) => { dbg!(list); },
+        Err(e) => println!("Error {:?}", e),
+    }
+
+    let request = docker_client::image::list::Request::builder().filters(filters).build();
+    match client.images_list(request).await {
+        Ok(list) => { dbg!(list); },
+        Err(e) => println!("Error {:?}", e),
+    }
+}
+
+#[test]
+fn test_ids() {
+    let json = r#"{"Id":"4fa6e0f0c6786287e131c3852c58a2e01cc697a68231826813597e4994f1d6e2","Warnings":[]}"#;
+    let created: docker_client::container::CreatedContainer = serde_json::from_str(json).unwrap();
+
+    assert_eq!(created.id().short(), "4fa6e0f0c678");
+    assert_eq!(created.id().to_string().len(), 64);
+
+    let image = docker_client::image::ImageId::from("sha256:a24bb4013296f61e89ba57005a7b3e52274d8edd3ae2077d04395f806b63d83e".to_string());
+    assert_eq!(image.short(), "a24bb4013296");
+}
+
+#[test]
+fn test_container_event() {
+    let json = r#"{"status":"die","id":"abc","Type":"container","Action":"die","Actor":{"ID":"abc","Attributes":{"exitCode":"137","name":"test"}},"scope":"local","time":1618000000,"timeNano":1618000000000000000}"#;
+    let event: docker_client::events::Event = serde_json::from_str(json).unwrap();
+
+    match docker_client::events::ContainerEvent::from_event(event) {
+        Some(docker_client::events::ContainerEvent::Die { exit_code, event }) => {
+            assert_eq!(exit_code, Some(137));
+            assert_eq!(event.actor().attribute("name"), Some("test"));
+        },
+        other => panic!("unexpected event {:?}", other),
+    }
+}
+
+#[test]
+fn test_health_status() {
+    let json = r#"{"Status":"unhealthy","FailingStreak":3,"Log":[{"Start":"2021-04-01T10:00:00Z","End":"2021-04-01T10:00:01Z","ExitCode":1,"Output":"curl: (7) connection refused"}]}"#;
+    let health: docker_client::container::inspect::Health = serde_json::from_str(json).unwrap();
+
+    assert_eq!(health.status(), docker_client::container::inspect::HealthStatus::Unhealthy);
+    assert_eq!(health.failing_streak(), 3);
+    assert_eq!(health.log()[0].exit_code(), 1);
+}
+
+#[test]
+fn test_container_state() {
+    use docker_client::container::ContainerState;
+
+    let states: Vec<ContainerState> = serde_json::from_str(r#"["exited","removing","frozen"]"#).unwrap();
+
+    assert_eq!(states, vec![
+        ContainerState::Exited,
+        ContainerState::Removing,
+        ContainerState::Other("frozen".to_string()),
+    ]);
+    assert_eq!(serde_json::to_string(&states).unwrap(), r#"["exited","removing","frozen"]"#);
+}
+
+#[test]
+fn test_top_entries() {
+    let json = r#"{"Titles":["UID","PID","PPID","C","STIME","TTY","TIME","CMD"],"Processes":[["root","13642","882","0","17:03","pts/0","00:00:00","/bin/bash"]]}"#;
+    let top: TopList = serde_json::from_str(json).unwrap();
+    let entries = top.entries();
+
+    assert_eq!(entries[0].pid(), Some(13642));
+    assert_eq!(entries[0].ppid(), Some(882));
+    assert_eq!(entries[0].user(), Some("root"));
+    assert_eq!(entries[0].cpu(), Some(0.0));
+    assert_eq!(entries[0].command(), Some("/bin/bash"));
+    assert_eq!(entries[0].column("TTY"), Some("pts/0"));
+}
+
+#[tokio::test]
+async fn test_log_helpers() {
+    let client = client();
+
+    match client.tail_logs("test", 5).await {
+        Ok(lines) => assert!(lines.len() <= 5),
+        Err(e) => println!("Error {:?}", e),
+    }
+
+    match client.logs_since("test", std::time::Duration::from_secs(60)).await {
+        Ok(lines) => { dbg!(lines); },
+        Err(e) => println!("Error {:?}", e),
+    }
+}
+
+#[tokio::test]
+async fn test_log_lines() {
+    let client = client();
+    let request = docker_client::container::logs::Request::container("test")
+        .timestamps(true)
+        .tail(10)
+        .build();
+
+    match client.container_log_lines(request).await {
+        Ok(lines) => {
+            for line in lines {
+                assert!(line.timestamp().is_some());
+            }
+        },
+        Err(e) => println!("Error {:?}", e),
+    }
+}
+
+#[test]
+fn test_events_filter() {
+    let filters = docker_client::events::EventsFilter::new()
+        .event_type("container")
+        .event("die")
+        .label("app", Some("web"))
+        .build();
+
+    assert_eq!(
+        serde_json::to_string(&filters).unwrap(),
+        r#"{"event":["die"],"label":["app=web"],"type":["container"]}"#
+    );
+}
+
+#[tokio::test]
+async fn test_exists_and_running() {
+    let client = client();
+
+    match client.container_exists("docker-client-missing-container").await {
+        Ok(exists) => assert!(!exists),
+        Err(e) => println!("Error {:?}", e),
+    }
+
+    match client.is_running("docker-client-missing-container").await {
+        Ok(running) => assert!(!running),
+        Err(e) => println!("Error {:?}", e),
+    }
+}
+
+#[test]
+fn test_host_config_annotations() {
+    use docker_client::additionals::host::host_config::{HostConfig, HostConfigBuilder};
+
+    let config = HostConfigBuilder::new()
+        .annotation("io.kubernetes.cri.sandbox-id", "abc")
+        .build();
+    let json = serde_json::to_string(&config).unwrap();
+    assert!(json.contains(r#""Annotations":{"io.kubernetes.cri.sandbox-id":"abc"}"#));
+
+    let inspected: HostConfig = serde_json::from_str(r#"{"Binds":null,"PortBindings":{},"AutoRemove":false,"Annotations":{"a":"b"}}"#).unwrap();
+    assert_eq!(inspected.annotations().get("a").map(String::as_str), Some("b"));
+    assert!(inspected.binds().is_empty());
+}
+
+#[tokio::test]
+async fn test_api_version_gate() {
+    use docker_client::client::ApiVersion;
+    use docker_client::additionals::host::host_config::HostConfigBuilder;
+
+    let client = client().with_api_version(ApiVersion::new(1, 40));
+    let request = Create::new()
+        .config(
+            Config::with_image("alpine")
+                .host_config(HostConfigBuilder::new().cgroupns_mode("private").build())
+                .build()
+        )
+        .build();
+
+    match client.create_container(request).await {
+        Err(DockerError::BadParameters(e)) => assert!(e.message.contains("1.41")),
+        other => panic!("unexpected result {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_cloned_clients() {
+    use docker_client::client::ApiVersion;
+
+    let client = client().with_api_version(ApiVersion::new(1, 41));
+    let older = client.clone().with_api_version(ApiVersion::new(1, 40));
+
+    assert_eq!(client.api_version(), Some(ApiVersion::new(1, 41)));
+    assert_eq!(older.api_version(), Some(ApiVersion::new(1, 40)));
+
+    let tasks: Vec<_> = (0..4).map(|_| {
+        let client = client.clone();
+        tokio::spawn(async move { client.get_image_list().await })
+    }).collect();
+
+    for task in tasks {
+        match task.await.unwrap() {
+            Ok(list) => println!("{} images", list.len()),
+            Err(e) => println!("Error {:?}", e),
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_strict_mode() {
+    use docker_client::client::{DeserializeMode, MockTransport};
+    use hyper::Method;
+
+    let mock = MockTransport::new()
+        .with_response(Method::POST, "/containers/web/wait", 200, r#"{"StatusCode": 137, "Error": null, "Runtime": {"Signal": 9}}"#);
+
+    let client = DockerClient::from_transport(mock.clone()).with_deserialize_mode(DeserializeMode::Strict);
+    match client.wait_container("web", WaitCondition::NotRunning).await {
+        Err(DockerError::UnknownFields(fields)) => assert_eq!(fields, vec!["Runtime".to_string()]),
+        other => panic!("expected UnknownFields, got {:?}", other),
+    }
+
+    let client = DockerClient::from_transport(mock).with_deserialize_mode(DeserializeMode::Lenient);
+    assert_eq!(client.wait_container("web", WaitCondition::NotRunning).await.unwrap().status_code(), 137);
+}
+
+#[tokio::test]
+async fn test_strict_port_bindings() {
+    use docker_client::client::{DeserializeMode, MockTransport};
+    use hyper::Method;
+
+    let mock = MockTransport::new()
+        .with_response(Method::GET, "/containers/web/json", 200, include_str!("fixtures/container_inspect_v1.44.json"));
+    let client = DockerClient::from_transport(mock).with_deserialize_mode(DeserializeMode::Strict);
+
+    // Other models still drift from the daemon, port bindings must round-trip.
+    match client.inspect_container(Inspect::container("web")).await {
+        Ok(_) => {},
+        Err(DockerError::UnknownFields(fields)) => {
+            assert!(fields.iter().all(|field| !field.starts_with("NetworkSettings.Ports")), "{:?}", fields);
+            assert!(fields.iter().all(|field| !field.starts_with("HostConfig.PortBindings")), "{:?}", fields);
+        },
+        Err(e) => panic!("unexpected error {:?}", e),
+    }
+}
+
+#[tokio::test]
+async fn test_strict_aliases() {
+    use docker_client::client::{DeserializeMode, MockTransport};
+    use hyper::Method;
+
+    let body = include_str!("fixtures/system_df_v1.44.json")
+        .replace("SizeRw", "SizeRW")
+        .replace("SizeRootFs", "SizeRootFS");
+    let mock = MockTransport::new()
+        .with_response(Method::GET, "/system/df", 200, body);
+    let client = DockerClient::from_transport(mock).with_deserialize_mode(DeserializeMode::Strict);
+
+    match client.disk_usage().await {
+        Ok(usage) => assert_eq!(usage.containers()[0].size_rw(), Some(12288)),
+        Err(DockerError::UnknownFields(fields)) => {
+            assert!(fields.iter().all(|field| !field.contains("SizeR")), "{:?}", fields);
+        },
+        Err(e) => panic!("unexpected error {:?}", e),
+    }
+}
+
+#[test]
+fn test_auth_debug_redacted() {
+    let auth = docker_client::client::Auth::builder()
+        .username("user")
+        .password("hunter2")
+        .email("user@example.com")
+        .build();
+
+    let client = DockerClient::with_auth(auth.clone());
+
+    assert!(!format!("{:?}", auth).contains("hunter2"));
+    assert!(!format!("{:?}", client).contains("hunter2"));
+}
+
+#[test]
+fn test_token_auth_payload() {
+    let auth = docker_client::client::Auth::builder()
+        .identity_token("token")
+        .server_address("ghcr.io")
+        .build();
+
+    assert_eq!(
+        serde_json::to_string(&auth).unwrap(),
+        r#"{"serveraddress":"ghcr.io","identitytoken":"token"}"#
+    );
+
+    // Standard base64 of this token has `+` and `/`, which the daemon can not decode.
+    let auth = docker_client::client::Auth::builder()
+        .identity_token("eyJ>>>???~~~")
+        .build();
+    let client = DockerClient::with_auth(auth);
+
+    assert!(!client.registry_auth().contains(|c| c == '+' || c == '/'));
+    assert_eq!(
+        base64::decode_config(client.registry_auth(), base64::URL_SAFE).unwrap(),
+        br#"{"identitytoken":"eyJ>>>???~~~"}"#.to_vec()
+    );
+}
+
+#[test]
+fn test_push_progress_digest() {
+    use docker_client::image::{Progress, PushedImage};
+
+    let body = r#"{"status":"The push refers to repository [localhost:5000/alpine]"}
+{"status":"Pushed","progressDetail":{},"id":"8d3ac3489996"}
+{"status":"latest: digest: sha256:e7d88de73db3d3fd9b2d63aa7f447a10fd0220b7cbf39803c803f2af9ba256b3 size: 528"}
+{"progressDetail":{},"aux":{"Tag":"latest","Digest":"sha256:e7d88de73db3d3fd9b2d63aa7f447a10fd0220b7cbf39803c803f2af9ba256b3","Size":528}}"#;
+
+    let records: Vec<Progress> = body.lines().map(|line| serde_json::from_str(line).unwrap()).collect();
+    let pushed: PushedImage = serde_json::from_value(records[3].aux().unwrap().clone()).unwrap();
+
+    assert_eq!(records[1].status(), Some("Pushed"));
+    assert_eq!(pushed.digest(), "sha256:e7d88de73db3d3fd9b2d63aa7f447a10fd0220b7cbf39803c803f2af9ba256b3");
+    assert_eq!(pushed.size(), 528);
+}
+
+#[test]
+fn test_pull_summary() {
+    use docker_client::image::{Progress, PullSummary};
+
+    let body = r#"{"status":"Pulling from library/alpine","id":"latest"}
+{"status":"Already exists","progressDetail":{},"id":"a0d0a0d46f8b"}
+{"status":"Pulling fs layer","progressDetail":{},"id":"31e352740f53"}
+{"status":"Downloading","progressDetail":{"current":1024,"total":2813316},"progress":"[>   ]","id":"31e352740f53"}
+{"status":"Download complete","progressDetail":{},"id":"31e352740f53"}
+{"status":"Pull complete","progressDetail":{},"id":"31e352740f53"}
+{"status":"Digest: sha256:82d1e9d7ed48a7523bdebc18cf6290bdb97b82302a8a9c27d4fe885949ea94d1"}
+{"status":"Status: Downloaded newer image for alpine:latest"}"#;
+
+    let records: Vec<Progress> = body.lines().map(|line| serde_json::from_str(line).unwrap()).collect();
+    let summary = PullSummary::from_progress(&records);
+
+    assert_eq!(summary.digest(), Some("sha256:82d1e9d7ed48a7523bdebc18cf6290bdb97b82302a8a9c27d4fe885949ea94d1"));
+    assert_eq!(summary.status(), "Downloaded newer image for alpine:latest");
+    assert_eq!(summary.layers_downloaded(), 1);
+    assert_eq!(summary.layers_cached(), 1);
+    assert_eq!(summary.total_bytes(), 2813316);
+}
+
+#[tokio::test]
+async fn test_build_image() {
+    use docker_client::image::build::BuildOptions;
+
+    let client = client();
+    let options = BuildOptions::builder()
+        .tag("docker-client-test:latest")
+        .build_arg("VERSION", "1")
+        .label("com.example.test", "true")
+        .build();
+
+    // empty context, the daemon reports a missing Dockerfile
+    match client.build_image(options, Vec::new()).await {
+        Ok(image) => { dbg!(image); },
+        Err(e) => println!("Error {:?}", e),
+    }
+}
+
+#[test]
+fn test_distribution_platforms() {
+    let json = r#"{"Descriptor":{"mediaType":"application/vnd.docker.distribution.manifest.list.v2+json","digest":"sha256:def822f9851ca422481ec6fee59a9966f12b351c62ccb9aca841526ffaa9f748","size":1638},"Platforms":[{"architecture":"amd64","os":"linux"},{"architecture":"arm","os":"linux","variant":"v7"}]}"#;
+    let info: docker_client::image::DistributionInfo = serde_json::from_str(json).unwrap();
+
+    assert!(info.descriptor().is_manifest_list());
+    assert_eq!(info.platforms()[1].to_string(), "linux/arm/v7");
+    assert_eq!(info.digest_for("linux/arm/v7"), Some("sha256:def822f9851ca422481ec6fee59a9966f12b351c62ccb9aca841526ffaa9f748"));
+    assert_eq!(info.digest_for("linux/arm64"), None);
+}
+
+#[test]
+fn test_image_info_platform() {
+    let json = r#"{"Id":"sha256:a24bb4013296f61e89ba57005a7b3e52274d8edd3ae2077d04395f806b63d83e","RepoTags":["busybox:latest"],"RepoDigests":null,"Created":"2021-06-08T17:21:28.459522553Z","Size":1235829,"Os":"linux","Architecture":"arm64","Variant":"v8","Config":{"Env":["PATH=/bin"],"Cmd":["sh"],"ExposedPorts":{"80/tcp":{}},"Labels":null,"Volumes":null},"RootFS":{"Type":"layers","Layers":["sha256:5b8c72934dfc08c7d2bd707e93197550f06c0751023dabb3a045b723c5e7b373"]}}"#;
+    let info: docker_client::image::ImageInfo = serde_json::from_str(json).unwrap();
+
+    assert_eq!(info.platform(), "linux/arm64/v8");
+    assert_eq!(info.config().cmd(), &vec!["sh".to_string()]);
+    assert_eq!(info.config().exposed_ports(), vec!["80/tcp"]);
+    assert!(info.config().labels().is_empty());
+    assert_eq!(info.root_fs().fs_type(), "layers");
+    assert_eq!(info.layers().len(), 1);
+}
+
+#[test]
+fn test_created_network_warning() {
+    let created: docker_client::networks::create::CreatedNetwork = serde_json::from_str(r#"{"Id":"22be93d5babb089c5aab8dbc369042fad48ff791584ca2da2100db837a1c7c30","Warning":""}"#).unwrap();
+    assert_eq!(created.id(), "22be93d5babb089c5aab8dbc369042fad48ff791584ca2da2100db837a1c7c30");
+    assert_eq!(created.warning(), None);
+
+    let created: docker_client::networks::create::CreatedNetwork = serde_json::from_str(r#"{"Id":"22be93d5babb","Warning":"overlay network is not encrypted"}"#).unwrap();
+    assert_eq!(created.warning(), Some("overlay network is not encrypted"));
+
+    let created: docker_client::networks::create::CreatedNetwork = serde_json::from_str(r#"{"Id":"22be93d5babb"}"#).unwrap();
+    assert_eq!(created.warning(), None);
+}
+
+#[test]
+fn test_network_verbose_services() {
+    use docker_client::networks::inspect::{NetworkInfo, RequestBuilder};
+
+    let request = RequestBuilder::with_name("overlay").verbose(true).scope("swarm").build();
+    assert_eq!(request.get_path(), "/networks/overlay?verbose=true&scope=swarm");
+
+    let json = r#"{"Name":"overlay","Id":"7d86d31b1478e7cca9ebed7e73aa0fdeec46c5ca29497431d3007d2d9e15ed99","Created":"2021-06-08T17:21:28.459522553Z","Scope":"swarm","Driver":"overlay","EnableIPv6":false,"IPAM":{"Driver":"default","Config":null,"Options":null},"Internal":false,"Attachable":true,"Ingress":false,"Containers":null,"Options":null,"Labels":null,"Peers":[{"Name":"node-1","IP":"10.0.0.2"}],"Services":{"web":{"VIP":"10.0.1.2","Ports":[],"LocalLBIndex":257,"Tasks":[{"Name":"web.1.jbeopj1j2vf5","EndpointID":"a4b5","EndpointIP":"10.0.1.3","Info":{"Host IP":"10.0.0.2"}}]}}}"#;
+    let info: NetworkInfo = serde_json::from_str(json).unwrap();
+
+    assert_eq!(info.services()["web"].vip(), "10.0.1.2");
+    assert_eq!(info.services()["web"].tasks()[0].info()["Host IP"], "10.0.0.2");
+    assert_eq!(info.task_ips()["web.1.jbeopj1j2vf5"], "10.0.1.3");
+    assert_eq!(info.peers()[0].ip(), "10.0.0.2");
+}
+
+#[tokio::test]
+async fn test_service_force_update() {
+    let json = r#"{"ID":"9mnpnzenvg8p8tdbtq4wvbkcz","Version":{"Index":19},"CreatedAt":"2016-06-07T21:05:51.880065305Z","UpdatedAt":"2016-06-07T21:07:29.962229872Z","Spec":{"Name":"hopeful_cori","TaskTemplate":{"ContainerSpec":{"Image":"redis"},"ForceUpdate":1},"Mode":{"Replicated":{"Replicas":1}}}}"#;
+    let service: docker_client::swarm::Service = serde_json::from_str(json).unwrap();
+
+    assert_eq!(service.name(), "hopeful_cori");
+    assert_eq!(service.version().index(), 19);
+    assert_eq!(service.force_update(), 1);
+
+    let client = DockerClient::new();
+    match client.force_update_service("missing-service").await {
+        Ok(_) => panic!("service should not exist"),
+        Err(e) => println!("{:?}", e),
+    }
+}
+
+#[tokio::test]
+async fn test_node_ps() {
+    use docker_client::swarm::{Task, TaskState};
+
+    let json = r#"[{"ID":"0kzzo1i0y4jz6027t0k7aezc7","Version":{"Index":71},"CreatedAt":"2016-06-07T21:07:31.171892745Z","UpdatedAt":"2016-06-07T21:07:31.376370513Z","Name":"hopeful_cori.1","ServiceID":"9mnpnzenvg8p8tdbtq4wvbkcz","Slot":1,"NodeID":"60gvrl6tm78dmak4yl7srz94v","Status":{"Timestamp":"2016-06-07T21:07:31.290032978Z","State":"running","Message":"started","ContainerStatus":{"ContainerID":"e5d62702a1b48d01c3e02ca1e0212a250801fa8d67caca0b6f35919ebc12f035","PID":677}},"DesiredState":"shutdown"}]"#;
+    let tasks: Vec<Task> = serde_json::from_str(json).unwrap();
+
+    assert_eq!(tasks[0].current_state(), &TaskState::Running);
+    assert_eq!(tasks[0].desired_state(), &TaskState::Shutdown);
+    assert_eq!(tasks[0].status().container_status().unwrap().pid(), 677);
+    assert_eq!(tasks[0].status().err(), None);
+
+    let client = DockerClient::new();
+    match client.node_ps("self").await {
+        Ok(tasks) => println!("{:?}", tasks),
+        Err(e) => println!("{:?}", e),
+    }
+}
+
+#[tokio::test]
+async fn test_secret_and_config_labels() {
+    use docker_client::swarm::{Secret, SwarmConfig};
+
+    let secret: Secret = serde_json::from_str(r#"{"ID":"ktnbjxoalbkvbvedmg1urrz8h","Version":{"Index":11},"CreatedAt":"2016-11-05T01:20:17.327670065Z","UpdatedAt":"2016-11-05T01:20:17.327670065Z","Spec":{"Name":"app-dev.crt","Labels":{"foo":"bar"}}}"#).unwrap();
+    assert_eq!(secret.name(), "app-dev.crt");
+    assert_eq!(secret.version().index(), 11);
+    assert_eq!(secret.labels()["foo"], "bar");
+
+    let config: SwarmConfig = serde_json::from_str(r#"{"ID":"ktnbjxoalbkvbvedmg1urrz8h","Version":{"Index":11},"Spec":{"Name":"server.conf","Data":"VEhJUyBJUyBOT1QgQSBSRUFMIENFUlRJRklDQVRFCg=="}}"#).unwrap();
+    assert!(config.labels().is_empty());
+    assert_eq!(config.data(), "VEhJUyBJUyBOT1QgQSBSRUFMIENFUlRJRklDQVRFCg==");
+
+    let client = DockerClient::new();
+    match client.update_secret("missing-secret", std::collections::HashMap::new()).await {
+        Ok(_) => panic!("secret should not exist"),
+        Err(e) => println!("{:?}", e),
+    }
+}
+
+#[tokio::test]
+async fn test_swarm_unlock_key() {
+    let client = DockerClient::new();
+
+    match client.swarm_unlock_key().await {
+        Ok(key) => println!("{}", key),
+        Err(e) => println!("{:?}", e),
+    }
+}
+
+#[tokio::test]
+async fn test_attach_output() {
+    use docker_client::container::attach::AttachOptions;
+
+    let options = AttachOptions::container("test-attach").stream(false).logs(true).build();
+    assert_eq!(options.get_path(), "/containers/test-attach/attach?logs=true&stream=false&stdin=false&stdout=true&stderr=true");
+
+    let client = DockerClient::new();
+    match client.attach_output(options).await {
+        Ok(lines) => println!("{:?}", lines),
+        Err(e) => println!("{:?}", e),
+    }
+}
+
+#[tokio::test]
+async fn test_attach_container() {
+    use docker_client::container::attach::AttachOptions;
+    use docker_client::container::logs::LogStream;
+    use futures::StreamExt;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+
+    let frame = |stream: u8, data: &[u8]| {
+        let mut frame = vec![stream, 0, 0, 0];
+        frame.extend_from_slice(&(data.len() as u32).to_be_bytes());
+        frame.extend_from_slice(data);
+        frame
+    };
+
+    tokio::spawn(async move {
+        let (mut socket, _) = listener.accept().await.unwrap();
+
+        let mut request = Vec::new();
+        let mut buffer = [0u8; 1024];
+        while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+            let read = socket.read(&mut buffer).await.unwrap();
+            request.extend_from_slice(&buffer[..read]);
+        }
+
+        let request = String::from_utf8_lossy(&request).to_lowercase();
+        assert!(request.starts_with("post /containers/shell/attach?logs=false&stream=true&stdin=true&stdout=true&stderr=true "));
+        assert!(request.contains("upgrade: tcp\r\n"));
+
+        socket.write_all(b"HTTP/1.1 101 UPGRADED\r\nContent-Type: application/vnd.docker.raw-stream\r\nConnection: Upgrade\r\nUpgrade: tcp\r\n\r\n").await.unwrap();
+
+        // Echo stdin on stdout until it is closed, then say goodbye on stderr.
+        let mut stdin = Vec::new();
+        socket.read_to_end(&mut stdin).await.unwrap();
+        socket.write_all(&frame(1, &stdin)).await.unwrap();
+        socket.write_all(&frame(2, b"bye\n")).await.unwrap();
+    });
+
+    let client = DockerClient::stream(format!("tcp://{}", address), None);
+    let options = AttachOptions::container("shell").stdin(true).build();
+
+    let mut attached = client.attach_container(options).await.unwrap();
+    attached.write_all(b"echo hello\n").await.unwrap();
+    attached.shutdown().await.unwrap();
+
+    let frames: Vec<(LogStream, Vec<u8>)> = attached
+        .map(|frame| frame.unwrap())
+        .map(|frame| (frame.stream(), frame.data().to_vec()))
+        .collect()
+        .await;
+
+    assert_eq!(frames, vec![
+        (LogStream::Stdout, b"echo hello\n".to_vec()),
+        (LogStream::Stderr, b"bye\n".to_vec()),
+    ]);
+}
+
+#[test]
+fn test_consuming_builders() {
+    let inspect = Inspect::container("test").with_size(true);
+    assert_eq!(inspect.get_path(), "/containers/test/json?size=true");
+
+    let top = ProcessesList::container("test").with_ps_args("aux");
+    assert_eq!(top.get_path(), "/containers/test/top?ps_args=aux");
+
+    // In-place setters of earlier releases keep working.
+    #[allow(deprecated)]
+    {
+        let mut inspect = Inspect::container("test");
+        inspect.size(true);
+        assert_eq!(inspect.get_path(), "/containers/test/json?size=true");
+
+        let mut top = ProcessesList::container("test");
+        top.ps_args("aux".to_string());
+        assert_eq!(top.get_path(), "/containers/test/top?ps_args=aux");
+    }
+
+    let filters = Filters::new().status("exited").label("ci", None).build();
+    assert_eq!(filters.get("status"), Some(&vec!["exited".to_string()]));
+
+    let network = docker_client::networks::create::RequestBuilder::with_name("test-network")
+        .driver("overlay")
+        .attachable(true)
+        .label("env", "ci")
+        .build();
+    assert_eq!(network.get_path(), "/networks/create");
+}
+
+#[tokio::test]
+async fn test_dropped_stream_closes_connection() {
+    use futures::StreamExt;
+    use std::time::Duration;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+
+    let server = tokio::spawn(async move {
+        let (mut socket, _) = listener.accept().await.unwrap();
+
+        let mut request = Vec::new();
+        let mut buffer = [0u8; 1024];
+        while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+            let read = socket.read(&mut buffer).await.unwrap();
+            request.extend_from_slice(&buffer[..read]);
+        }
+
+        let event = include_str!("fixtures/event_v1.44.json").trim().to_string() + "\n";
+        let response = format!(
+            "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nTransfer-Encoding: chunked\r\n\r\n{:x}\r\n{}\r\n",
+            event.len(), event
+        );
+        socket.write_all(response.as_bytes()).await.unwrap();
+
+        // The response never ends, only the client can close the connection.
+        tokio::time::timeout(Duration::from_secs(5), socket.read(&mut buffer)).await
+    });
+
+    let client = DockerClient::stream(format!("tcp://{}", address), None);
+    let mut events = client.events(Filters::new().build()).await.unwrap();
+
+    let event = events.next().await.unwrap().unwrap();
+    assert_eq!(event.action(), "die");
+    drop(events);
+
+    match server.await.unwrap() {
+        Ok(read) => assert_eq!(read.unwrap(), 0),
+        Err(_) => panic!("connection was not closed after the stream was dropped"),
+    }
+}
+
+#[tokio::test]
+async fn test_events_split_across_chunks() {
+    use futures::StreamExt;
+    use std::time::Duration;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+
+    tokio::spawn(async move {
+        let (mut socket, _) = listener.accept().await.unwrap();
+
+        let mut request = Vec::new();
+        let mut buffer = [0u8; 1024];
+        while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+            let read = socket.read(&mut buffer).await.unwrap();
+            request.extend_from_slice(&buffer[..read]);
+        }
+
+        let event = include_str!("fixtures/event_v1.44.json").trim();
+        let start = event.replace("\"die\"", "\"start\"");
+        let body = format!("{}\n\n{}\n{}", start, event, event);
+        let (head, tail) = body.split_at(start.len() / 2);
+
+        socket.write_all(b"HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n").await.unwrap();
+        for chunk in [head, tail].iter() {
+            socket.write_all(format!("{:x}\r\n{}\r\n", chunk.len(), chunk).as_bytes()).await.unwrap();
+            socket.flush().await.unwrap();
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+        socket.write_all(b"0\r\n\r\n").await.unwrap();
+    });
+
+    let client = DockerClient::stream(format!("tcp://{}", address), None);
+    let events = client.events(Filters::new().build()).await.unwrap();
+
+    let actions: Vec<String> = events
+        .map(|event| event.unwrap().action().to_string())
+        .collect()
+        .await;
+
+    assert_eq!(actions, vec!["start", "die", "die"]);
+}
+
+#[tokio::test]
+async fn test_events_invalid_line() {
+    use docker_client::client::MockTransport;
+    use futures::StreamExt;
+    use hyper::Method;
+
+    let event = include_str!("fixtures/event_v1.44.json").trim();
+    let mut body = format!("{}\n{{\"Type\": 42}}\n", event).into_bytes();
+    body.extend_from_slice(b"\"\xff\"\n");
+    body.extend_from_slice(event.as_bytes());
+
+    let mock = MockTransport::new()
+        .with_response(Method::GET, "/events", 200, body);
+    let client = DockerClient::from_transport(mock);
+
+    // Lines which can not be decoded are reported and the stream goes on.
+    let events: Vec<Result<String, DockerError>> = client.events(Filters::new().build()).await.unwrap()
+        .map(|event| event.map(|event| event.action().to_string()))
+        .collect()
+        .await;
+    assert_eq!(events.len(), 4);
+    assert_eq!(events[0].as_ref().unwrap(), "die");
+    match &events[1] {
+        Err(DockerError::Deserialize { body, .. }) => assert_eq!(body, r#"{"Type": 42}"#),
+        other => panic!("expected Deserialize, got {:?}", other),
+    }
+    assert!(matches!(events[2], Err(DockerError::Deserialize { .. })));
+    assert_eq!(events[3].as_ref().unwrap(), "die");
+}
+
+#[tokio::test]
+async fn test_events_request() {
+    use docker_client::client::MockTransport;
+    use docker_client::events::{EventsFilter, EventsRequest};
+    use futures::StreamExt;
+    use hyper::Method;
+    use std::time::{Duration, UNIX_EPOCH};
+
+    let event = include_str!("fixtures/event_v1.44.json").trim();
+    let mock = MockTransport::new()
+        .with_response(Method::GET, "/events", 200, format!("{}\n{}\n", event, event));
+    let client = DockerClient::from_transport(mock.clone());
+
+    let request = EventsRequest::new()
+        .since(UNIX_EPOCH + Duration::from_secs(1700000000))
+        .until(UNIX_EPOCH + Duration::from_nanos(1700000060_000000042))
+        .filters(EventsFilter::new().event("die").build());
+
+    // The daemon closes the stream once `until` is reached.
+    let actions: Vec<String> = client.events(request).await.unwrap()
+        .map(|event| event.unwrap().action().to_string())
+        .collect()
+        .await;
+    assert_eq!(actions, vec!["die", "die"]);
+
+    assert_eq!(
+        mock.requests()[0].query(),
+        Some("since=1700000000.000000000&until=1700000060.000000042&filters=%7B%22event%22%3A%5B%22die%22%5D%7D")
+    );
+
+    let _events = client.events(Filters::new().build()).await.unwrap();
+    assert_eq!(mock.requests()[1].query(), None);
+}
+
+#[tokio::test]
+async fn test_read_timeout() {
+    use std::time::Duration;
+    use tokio::net::TcpListener;
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+
+    // Accept the connection and never respond, like a daemon stuck on a lock.
+    tokio::spawn(async move {
+        let (socket, _) = listener.accept().await.unwrap();
+        tokio::time::sleep(Duration::from_secs(5)).await;
+        drop(socket);
+    });
+
+    let client = DockerClient::stream(format!("tcp://{}", address), None)
+        .with_connect_timeout(Duration::from_secs(1))
+        .with_read_timeout(Duration::from_millis(100));
+
+    assert_eq!(client.connect_timeout(), Some(Duration::from_secs(1)));
+    assert_eq!(client.read_timeout(), Some(Duration::from_millis(100)));
+
+    match client.get_image_list().await {
+        Err(DockerError::Timeout) => {},
+        other => panic!("unexpected result {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_call_timeout() {
+    use docker_client::container::WaitCondition;
+    use std::time::Duration;
+    use tokio::io::AsyncReadExt;
+    use tokio::net::TcpListener;
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+
+    // Never respond to the wait, and report when the client closes the connection.
+    let server = tokio::spawn(async move {
+        let (mut socket, _) = listener.accept().await.unwrap();
+        let mut buffer = [0u8; 1024];
+        let _ = socket.read(&mut buffer).await.unwrap();
+        tokio::time::timeout(Duration::from_secs(2), socket.read(&mut buffer)).await
+    });
+
+    let client = DockerClient::stream(format!("tcp://{}", address), None)
+        .with_read_timeout(Duration::from_secs(5));
+    let bounded = client.clone().with_timeout(Duration::from_millis(100));
+
+    assert_eq!(client.timeout(), None);
+    assert_eq!(bounded.timeout(), Some(Duration::from_millis(100)));
+
+    match bounded.wait_container("job", WaitCondition::NotRunning).await {
+        Err(DockerError::Timeout) => {},
+        other => panic!("unexpected result {:?}", other),
+    }
+
+    match server.await.unwrap() {
+        Ok(read) => assert_eq!(read.unwrap(), 0),
+        Err(_) => panic!("request was not cancelled"),
+    }
+}
+
+#[tokio::test]
+async fn test_circuit_breaker() {
+    use docker_client::client::CircuitBreaker;
+    use std::time::Duration;
+
+    // Reserve a port and close it, so connections are refused.
+    let address = std::net::TcpListener::bind("127.0.0.1:0").unwrap().local_addr().unwrap();
+
+    let client = DockerClient::stream(format!("tcp://{}", address), None)
+        .with_circuit_breaker(CircuitBreaker::new(2, Duration::from_millis(200)));
+    let clone = client.clone();
+
+    for _ in 0..2 {
+        match client.get_image_list().await {
+            Err(DockerError::ConnectionRefused) => {},
+            other => panic!("unexpected result {:?}", other),
+        }
+    }
+
+    assert!(client.circuit_breaker().unwrap().is_open());
+    match clone.get_image_list().await {
+        Err(DockerError::Unavailable) => {},
+        other => panic!("unexpected result {:?}", other),
+    }
+
+    tokio::time::sleep(Duration::from_millis(250)).await;
+    assert!(!client.circuit_breaker().unwrap().is_open());
+
+    match client.get_image_list().await {
+        Err(DockerError::ConnectionRefused) => {},
+        other => panic!("unexpected result {:?}", other),
+    }
+    assert!(client.circuit_breaker().unwrap().is_open());
+}
+
+#[tokio::test]
+async fn test_connection_errors() {
+    use tokio::io::AsyncWriteExt;
+
+    let address = std::net::TcpListener::bind("127.0.0.1:0").unwrap().local_addr().unwrap();
+    match DockerClient::stream(format!("tcp://{}", address), None).get_image_list().await {
+        Err(DockerError::ConnectionRefused) => {},
+        other => panic!("expected ConnectionRefused, got {:?}", other),
+    }
+
+    // The daemon closes the connection in the middle of the response.
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        let (mut socket, _) = listener.accept().await.unwrap();
+        let mut buffer = [0u8; 1024];
+        let _ = tokio::io::AsyncReadExt::read(&mut socket, &mut buffer).await.unwrap();
+        socket.write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 100\r\n\r\n[").await.unwrap();
+    });
+
+    match DockerClient::stream(format!("tcp://{}", address), None).get_image_list().await {
+        Err(DockerError::Io(_)) => {},
+        other => panic!("expected Io, got {:?}", other),
+    }
+
+    #[cfg(feature = "unix-socket")]
+    match DockerClient::unix("/tmp/docker-client-missing.sock", None).get_image_list().await {
+        Err(DockerError::SocketNotFound) => {},
+        other => panic!("expected SocketNotFound, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_endpoint_parsing() {
+    use docker_client::client::{Endpoint, EndpointError};
+    use std::path::PathBuf;
+
+    let parse = |s: &str| s.parse::<Endpoint>();
+
+    assert_eq!(parse("tcp://docker.local:2376"), Ok(Endpoint::Tcp { host: "docker.local".into(), port: 2376 }));
+    assert_eq!(parse("http://docker.local/"), Ok(Endpoint::Tcp { host: "docker.local".into(), port: 2375 }));
+    assert_eq!(parse("https://docker.local"), Ok(Endpoint::Https { host: "docker.local".into(), port: 2376 }));
+    assert_eq!(parse("tcp://[::1]:2375"), Ok(Endpoint::Tcp { host: "::1".into(), port: 2375 }));
+    assert_eq!(parse("unix:///var/run/docker.sock"), Ok(Endpoint::Unix(PathBuf::from("/var/run/docker.sock"))));
+    assert_eq!(parse("npipe:////./pipe/docker_engine"), Ok(Endpoint::NamedPipe("//./pipe/docker_engine".into())));
+    assert_eq!(parse("ssh://me@build:2222"), Ok(Endpoint::Ssh { user: Some("me".into()), host: "build".into(), port: 2222 }));
+    assert_eq!(parse("ssh://build"), Ok(Endpoint::Ssh { user: None, host: "build".into(), port: 22 }));
+    assert_eq!(parse("fd://"), Ok(Endpoint::Fd(String::new())));
+
+    assert_eq!(parse("ftp://docker.local"), Err(EndpointError::UnsupportedScheme("ftp".into())));
+    assert_eq!(parse("docker.local:2375"), Err(EndpointError::InvalidAddress("docker.local:2375".into())));
+    assert_eq!(parse("tcp://docker.local:port"), Err(EndpointError::InvalidAddress("tcp://docker.local:port".into())));
+    assert_eq!(parse("unix://"), Err(EndpointError::InvalidAddress("unix://".into())));
+
+    for url in &["tcp://[::1]:2375", "https://docker.local:2376", "ssh://me@build:22", "npipe:////./pipe/docker_engine"] {
+        assert_eq!(parse(url).unwrap().to_string(), *url);
+    }
+
+    assert!(DockerClient::connect(parse("tcp://127.0.0.1:2375").unwrap()).is_ok());
+    #[cfg(not(feature = "tls"))]
+    match DockerClient::connect(parse("https://docker.local").unwrap()) {
+        Err(EndpointError::UnsupportedTransport(Endpoint::Https { .. })) => {},
+        other => panic!("unexpected result {:?}", other.map(|_| ())),
+    }
+    #[cfg(feature = "tls")]
+    {
+        use docker_client::client::DockerEnv;
+
+        // Certificates are passed explicitly, the environment is shared by parallel tests.
+        let certs = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/tls");
+        let env = DockerEnv::from_vars(vec![
+            ("DOCKER_HOST", "https://docker.local"),
+            ("DOCKER_CERT_PATH", certs.to_str().unwrap()),
+            ("DOCKER_TLS_VERIFY", "1"),
+        ]).unwrap();
+        assert!(DockerClient::from_docker_env(&env).is_ok());
+    }
+}
+
+#[test]
+fn test_docker_env() {
+    use docker_client::client::{DockerEnv, Endpoint, EndpointError, ApiVersion};
+    use std::path::Path;
+
+    let env = DockerEnv::from_vars(vec![
+        ("DOCKER_HOST", "tcp://127.0.0.1:2375"),
+        ("DOCKER_API_VERSION", "1.41"),
+        ("DOCKER_CONFIG", "/etc/docker-cli"),
+        ("DOCKER_TLS_VERIFY", ""),
+        ("PATH", "/usr/bin"),
+    ]).unwrap();
+
+    assert_eq!(env.endpoint(), &Endpoint::Tcp { host: "127.0.0.1".into(), port: 2375 });
+    assert!(!env.tls_verify());
+    assert_eq!(env.cert_path(), None);
+
+    let client = DockerClient::from_docker_env(&env).unwrap();
+    assert_eq!(client.api_version(), Some(ApiVersion::new(1, 41)));
+    assert_eq!(client.config_dir(), Some(Path::new("/etc/docker-cli")));
+
+    let env = DockerEnv::from_vars(vec![
+        ("DOCKER_HOST", "tcp://10.0.0.2:2376"),
+        ("DOCKER_CERT_PATH", "/certs"),
+    ]).unwrap();
+    assert_eq!(env.endpoint(), &Endpoint::Https { host: "10.0.0.2".into(), port: 2376 });
+    assert_eq!(env.cert_path().map(|p| p.as_path()), Some(Path::new("/certs")));
+    assert!(!env.tls_verify());
+
+    let env = DockerEnv::from_vars(vec![("DOCKER_HOST", "https://10.0.0.2"), ("DOCKER_CONFIG", "/cli")]).unwrap();
+    assert_eq!(env.cert_path().map(|p| p.as_path()), Some(Path::new("/cli")));
+
+    assert_eq!(
+        DockerEnv::from_vars(vec![("DOCKER_API_VERSION", "latest")]),
+        Err(EndpointError::InvalidVariable("DOCKER_API_VERSION".into(), "latest".into()))
+    );
+    assert_eq!(
+        DockerEnv::from_vars(vec![("DOCKER_HOST", "ftp://host")]),
+        Err(EndpointError::UnsupportedScheme("ftp".into()))
+    );
+}
+
+#[tokio::test]
+async fn test_health_events() {
+    use docker_client::container::inspect::HealthStatus;
+    use futures::StreamExt;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+
+    let mut container: serde_json::Value = serde_json::from_str(include_str!("fixtures/container_inspect_v1.44.json")).unwrap();
+    container["State"]["Health"] = serde_json::json!({"Status": "starting", "FailingStreak": 0, "Log": []});
+    let container = container.to_string();
+
+    let event = include_str!("fixtures/event_v1.44.json").trim();
+    let events: String = ["health_status: healthy", "health_status: healthy", "health_status: unhealthy", "start", "health_status: healthy"]
+        .iter()
+        .map(|action| event.replace("\"Action\":\"die\"", &format!("\"Action\":\"{}\"", action)) + "\n")
+        .collect();
+
+    tokio::spawn(async move {
+        for _ in 0..2 {
+            let (mut socket, _) = listener.accept().await.unwrap();
+
+            let mut request = Vec::new();
+            let mut buffer = [0u8; 1024];
+            while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+                let read = socket.read(&mut buffer).await.unwrap();
+                request.extend_from_slice(&buffer[..read]);
+            }
+
+            let body = match request.starts_with(b"GET /events") {
+                true => &events,
+                false => &container,
+            };
+            let response = format!("HTTP/1.1 200 OK\r\nContent-Length: {}\r\n\r\n{}", body.len(), body);
+            socket.write_all(response.as_bytes()).await.unwrap();
+        }
+    });
+
+    let client = DockerClient::stream(format!("tcp://{}", address), None);
+    let statuses: Vec<HealthStatus> = client.health_events("web").await.unwrap()
+        .map(Result::unwrap)
+        .collect()
+        .await;
+
+    assert_eq!(statuses, vec![
+        HealthStatus::Starting,
+        HealthStatus::Healthy,
+        HealthStatus::Unhealthy,
+        HealthStatus::Starting,
+        HealthStatus::Healthy,
+    ]);
+}
+
+#[tokio::test]
+async fn test_multi_logs() {
+    use docker_client::container::multi_logs::MultiLogs;
+    use futures::StreamExt;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    fn frame(stream: u8, payload: &str) -> Vec<u8> {
+        let mut frame = vec![stream, 0, 0, 0];
+        frame.extend_from_slice(&(payload.len() as u32).to_be_bytes());
+        frame.extend_from_slice(payload.as_bytes());
+        frame
+    }
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+
+    tokio::spawn(async move {
+        loop {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            tokio::spawn(async move {
+                let mut request = Vec::new();
+                let mut buffer = [0u8; 1024];
+                while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+                    let read = socket.read(&mut buffer).await.unwrap();
+                    request.extend_from_slice(&buffer[..read]);
+                }
+                let request = String::from_utf8_lossy(&request);
+                assert!(request.contains("timestamps=true"));
+
+                let body = if request.starts_with("GET /containers/web/logs") {
+                    [
+                        frame(1, "2024-02-06T09:00:01.000000000Z listening\n"),
+                        frame(1, "2024-02-06T09:00:03.000000000Z GET /"),
+                        frame(1, " 200\n"),
+                    ].concat()
+                } else {
+                    [
+                        frame(1, "2024-02-06T09:00:02.000000000Z ready\n"),
+                        frame(2, "2024-02-06T09:00:04.000000000Z slow query\n"),
+                    ].concat()
+                };
+
+                let mut response = format!("HTTP/1.1 200 OK\r\nContent-Length: {}\r\n\r\n", body.len()).into_bytes();
+                response.extend_from_slice(&body);
+                socket.write_all(&response).await.unwrap();
+            });
+        }
+    });
+
+    let client = DockerClient::stream(format!("tcp://{}", address), None);
+    let logs = client.multi_logs(MultiLogs::containers(vec!["web", "db"])).await.unwrap();
+    assert_eq!(logs.containers(), vec!["web", "db"]);
+
+    let lines: Vec<(String, String)> = logs
+        .map(|line| {
+            let line = line.unwrap();
+            (line.container().to_string(), line.line().message().to_string())
+        })
+        .collect()
+        .await;
+
+    let web: Vec<&str> = lines.iter().filter(|(c, _)| c == "web").map(|(_, m)| m.as_str()).collect();
+    let db: Vec<&str> = lines.iter().filter(|(c, _)| c == "db").map(|(_, m)| m.as_str()).collect();
+    assert_eq!(web, vec!["listening", "GET / 200"]);
+    assert_eq!(db, vec!["ready", "slow query"]);
+}
+
+#[tokio::test]
+async fn test_multi_logs_sources() {
+    use docker_client::client::MockTransport;
+    use docker_client::container::multi_logs::MultiLogs;
+    use futures::StreamExt;
+    use hyper::Method;
+
+    fn frame(payload: &str) -> Vec<u8> {
+        let mut frame = vec![1, 0, 0, 0];
+        frame.extend_from_slice(&(payload.len() as u32).to_be_bytes());
+        frame.extend_from_slice(payload.as_bytes());
+        frame
+    }
+
+    let mock = MockTransport::new()
+        .with_response(Method::GET, "/containers/web/logs", 200, frame("listening\n"))
+        .with_response(Method::GET, "/containers/db/logs", 200, frame("2024-02-06T09:00:02.000000000Z ready\n"));
+    let client = DockerClient::from_transport(mock);
+
+    // Lines available at the same time without a timestamp come last.
+    let lines: Vec<String> = client.multi_logs(MultiLogs::containers(vec!["web", "db"])).await.unwrap()
+        .map(|line| line.unwrap().container().to_string())
+        .collect()
+        .await;
+    assert_eq!(lines, vec!["db", "web"]);
+
+    // The logs of a missing container end with an error tagged with its name, the others go on.
+    let results: Vec<_> = client.multi_logs(MultiLogs::containers(vec!["gone", "db"])).await.unwrap().collect().await;
+    assert_eq!(results.len(), 2);
+    let error = results.iter().find_map(|result| result.as_ref().err()).unwrap();
+    assert_eq!(error.container(), "gone");
+    assert!(matches!(error.error(), DockerError::NotFound(_)));
+    assert_eq!(results.iter().find_map(|result| result.as_ref().ok()).unwrap().container(), "db");
+}
+
+#[tokio::test]
+async fn test_watchdog() {
+    use docker_client::container::watchdog::{Watchdog, Remedy, Trigger};
+    use std::sync::{Arc, Mutex};
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+
+    let event = include_str!("fixtures/event_v1.44.json").trim();
+    // The clock of the daemon runs ahead of the one of the watchdog.
+    let ahead = |event: String| event.replace("1707211351402370331", "4102444800000000000");
+    let events = [
+        event.to_string(),
+        // Caused by the restart, ignored until the container is started again.
+        ahead(event.replace("\"Action\":\"die\"", "\"Action\":\"health_status: unhealthy\"")),
+        ahead(event.replace("\"exitCode\":\"137\"", "\"exitCode\":\"143\"")),
+        ahead(event.replace("\"Action\":\"die\"", "\"Action\":\"start\"")),
+        // A new failure after the restart.
+        ahead(event.replace("\"exitCode\":\"137\"", "\"exitCode\":\"1\"")),
+        // Not watched.
+        event.replace("\"name\":\"web\"", "\"name\":\"db\"").replace("4fa6e0f0", "9c1d2e3f"),
+    ].join("\n") + "\n";
+
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    let seen = requests.clone();
+
+    tokio::spawn(async move {
+        loop {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let seen = seen.clone();
+            let events = events.clone();
+            tokio::spawn(async move {
+                let mut request = Vec::new();
+                let mut buffer = [0u8; 1024];
+                while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+                    let read = socket.read(&mut buffer).await.unwrap();
+                    request.extend_from_slice(&buffer[..read]);
+                }
+                let request = String::from_utf8_lossy(&request);
+                let line = request.lines().next().unwrap().to_string();
+
+                let (status, body) = match line.split(' ').nth(1).unwrap().split('?').next().unwrap() {
+                    "/events" => ("200 OK", events.as_str()),
+                    "/containers/create" => ("201 Created", include_str!("fixtures/container_create_v1.40.json")),
+                    _ => ("204 No Content", ""),
+                };
+                let response = format!("HTTP/1.1 {}\r\nContent-Length: {}\r\n\r\n{}", status, body.len(), body);
+                seen.lock().unwrap().push(line);
+                socket.write_all(response.as_bytes()).await.unwrap();
+            });
+        }
+    });
+
+    let incidents = Arc::new(Mutex::new(Vec::new()));
+    let reported = incidents.clone();
+
+    let watchdog = Watchdog::new()
+        .watch("web", Remedy::Restart)
+        .stop_timeout(std::time::Duration::from_secs(3))
+        .on_incident(move |incident| {
+            assert!(incident.error().is_none());
+            reported.lock().unwrap().push((incident.container().to_string(), incident.trigger().clone()));
+        });
+
+    let client = DockerClient::stream(format!("tcp://{}", address), None);
+    client.run_watchdog(watchdog).await.unwrap();
+
+    assert_eq!(*incidents.lock().unwrap(), vec![
+        ("web".to_string(), Trigger::Died { exit_code: Some(137) }),
+        ("web".to_string(), Trigger::Died { exit_code: Some(1) }),
+    ]);
+
+    let paths = |requests: &[String]| -> Vec<String> {
+        requests.iter()
+            .map(|line| line.split(' ').nth(1).unwrap().split('?').next().unwrap().to_string())
+            .collect()
+    };
+    let restarted = requests.lock().unwrap().drain(..).collect::<Vec<_>>();
+    assert_eq!(paths(&restarted), vec![
+        "/events", "/containers/web/stop", "/containers/web/start", "/containers/web/stop", "/containers/web/start",
+    ]);
+
+    // A recreated container keeps the watched name, whatever the request sets.
+    let worker = Create::new().config(Config::with_image("nginx:1.25").build()).build();
+    let watchdog = Watchdog::new().watch("web", Remedy::Recreate(Box::new(worker)));
+    client.run_watchdog(watchdog).await.unwrap();
+
+    let recreated = requests.lock().unwrap().clone();
+    assert_eq!(paths(&recreated)[..4], ["/events", "/containers/web", "/containers/create", "/containers/e90e34656806/start"]);
+    assert!(recreated[2].starts_with("POST /containers/create?name=web "));
+}
+
+#[tokio::test]
+async fn test_volume_export_import() {
+    use docker_client::client::MockTransport;
+    use futures::StreamExt;
+    use hyper::Method;
+
+    let mock = MockTransport::new()
+        .with_response(Method::GET, "/volumes/pgdata", 200, include_str!("fixtures/volume_inspect_v1.44.json"))
+        .with_response(Method::POST, "/volumes/create", 201, include_str!("fixtures/volume_inspect_v1.44.json"))
+        .with_response(Method::GET, "/images/busybox:latest/json", 200, include_str!("fixtures/image_inspect_v1.44.json"))
+        .with_response(Method::POST, "/containers/create", 201, include_str!("fixtures/container_create_v1.40.json"))
+        .with_response(Method::GET, "/containers/e90e34656806/archive", 200, "volume-archive")
+        .with_response(Method::PUT, "/containers/e90e34656806/archive", 200, "")
+        .with_response(Method::DELETE, "/containers/e90e34656806", 204, "");
+    let client = DockerClient::from_transport(mock.clone());
+
+    let paths = |requests: &[docker_client::client::MockRequest]| -> Vec<String> {
+        requests.iter().map(|request| format!("{} {}", request.method(), request.path())).collect()
+    };
+
+    // The helper container is removed once the archive stream ends.
+    let archive = client.volume("pgdata").export().await.unwrap();
+    assert_eq!(mock.requests().len(), 4);
+    let archive: Vec<_> = archive.collect().await;
+
+    let requests = mock.requests();
+    assert_eq!(paths(&requests), vec![
+        "GET /volumes/pgdata", "GET /images/busybox:latest/json", "POST /containers/create",
+        "GET /containers/e90e34656806/archive", "DELETE /containers/e90e34656806",
+    ]);
+    assert!(String::from_utf8_lossy(requests[2].body()).contains("\"pgdata:/volume:ro\""));
+    assert_eq!(requests[3].query(), Some("path=%2Fvolume%2F."));
+
+    // The exported stream is uploaded as it is, a missing volume is created first.
+    client.volume("restored").import(futures::stream::iter(archive)).await.unwrap();
+
+    let requests = mock.requests().split_off(5);
+    assert_eq!(paths(&requests), vec![
+        "GET /volumes/restored", "POST /volumes/create", "GET /images/busybox:latest/json", "POST /containers/create",
+        "PUT /containers/e90e34656806/archive", "DELETE /containers/e90e34656806",
+    ]);
+    assert!(String::from_utf8_lossy(requests[3].body()).contains("\"restored:/volume:rw\""));
+    assert_eq!(requests[4].query(), Some("path=%2Fvolume"));
+    assert_eq!(requests[4].body().as_ref(), b"volume-archive");
+
+    // Exports never create a volume, paths are not taken for volume names.
+    match client.export_volume("missing").await {
+        Err(DockerError::NotFound(_)) => {},
+        other => panic!("expected NotFound, got {:?}", other.map(|_| ())),
+    }
+    for name in &["/etc", "../etc/passwd", ""] {
+        match client.export_volume(*name).await {
+            Err(DockerError::BadParameters(_)) => {},
+            other => panic!("expected BadParameters, got {:?}", other.map(|_| ())),
+        }
+    }
+    assert_eq!(mock.requests().len(), 12);
+}
+
+#[tokio::test]
+async fn test_collect_garbage() {
+    use docker_client::gc::GarbageCollector;
+    use std::sync::{Arc, Mutex};
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    let seen = requests.clone();
+
+    tokio::spawn(async move {
+        loop {
+            let (mut socket, _) = listener.accept().await.unwrap();
+
+            let mut request = Vec::new();
+            let mut buffer = [0u8; 1024];
+            while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+                let read = socket.read(&mut buffer).await.unwrap();
+                request.extend_from_slice(&buffer[..read]);
+            }
+            let line = String::from_utf8_lossy(&request).lines().next().unwrap().to_string();
+
+            let (status, body) = match line.split(' ').nth(1).unwrap().split('?').next().unwrap() {
+                "/containers/prune" => ("200 OK", include_str!("fixtures/container_prune_v1.44.json")),
+                "/images/prune" => ("200 OK", include_str!("fixtures/image_prune_v1.44.json")),
+                "/volumes" => ("200 OK", include_str!("fixtures/volume_list_v1.40.json")),
+                "/volumes/tardis" => ("409 Conflict", r#"{"message": "volume is in use"}"#),
+                _ => ("204 No Content", ""),
+            };
+            seen.lock().unwrap().push(line);
+
+            let response = format!("HTTP/1.1 {}\r\nContent-Length: {}\r\n\r\n{}", status, body.len(), body);
+            socket.write_all(response.as_bytes()).await.unwrap();
+        }
+    });
+
+    let gc = GarbageCollector::new()
+        .label("ci", None)
+        .older_than(std::time::Duration::from_secs(3600));
+
+    let client = DockerClient::stream(format!("tcp://{}", address), None);
+    let report = client.collect_garbage(gc).await.unwrap();
+
+    assert_eq!(report.containers_deleted().len(), 2);
+    assert_eq!(report.images_deleted().len(), 3);
+    assert_eq!(report.volumes_deleted(), &vec!["anonymous".to_string()]);
+    assert_eq!(report.space_reclaimed(), 2048 + 73400320);
+
+    let requests = requests.lock().unwrap();
+    let query = |filters: docker_client::additionals::filters::FiltersBuilder| filters.build().query().unwrap();
+    let labelled = || Filters::new().label("ci", None);
+    assert!(requests[0].starts_with(&format!("POST /containers/prune?{} ", query(labelled().until("3600s")))));
+    assert!(requests[1].starts_with(&format!("POST /images/prune?{} ", query(labelled().dangling(true).until("3600s")))));
+    assert!(requests[2].starts_with(&format!("GET /volumes?{} ", query(labelled().dangling(true)))));
+    assert!(requests[3].starts_with("DELETE /volumes/tardis"));
+    assert!(requests[4].starts_with("DELETE /volumes/anonymous"));
+}
+
+/// Start a fake daemon for `GenericContainer` on its own thread and return its address and request lines.
+///
+/// With `keep_alive` a connection serves requests until the client closes it, like the daemon does.
+fn generic_container_daemon(keep_alive: bool) -> (std::net::SocketAddr, std::sync::Arc<std::sync::Mutex<Vec<String>>>) {
+    use std::io::{Read, Write};
+    use std::sync::{Arc, Mutex};
+
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap();
+
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    let seen = requests.clone();
+
+    std::thread::spawn(move || {
+        for socket in listener.incoming() {
+            let mut socket = socket.unwrap();
+            let seen = seen.clone();
+
+            std::thread::spawn(move || {
+                let mut request = Vec::new();
+                let mut buffer = [0u8; 1024];
+                loop {
+                    let head = loop {
+                        if let Some(end) = request.windows(4).position(|w| w == b"\r\n\r\n") {
+                            break end + 4;
+                        }
+                        match socket.read(&mut buffer) {
+                            Ok(0) | Err(_) => return,
+                            Ok(read) => request.extend_from_slice(&buffer[..read]),
+                        }
+                    };
+                    let headers = String::from_utf8_lossy(&request[..head]).to_string();
+                    let length = headers.lines()
+                        .filter_map(|line| line.split_once(':'))
+                        .find(|(name, _)| name.eq_ignore_ascii_case("content-length"))
+                        .map(|(_, value)| value.trim().parse::<usize>().unwrap())
+                        .unwrap_or(0);
+                    while request.len() < head + length {
+                        match socket.read(&mut buffer) {
+                            Ok(0) | Err(_) => return,
+                            Ok(read) => request.extend_from_slice(&buffer[..read]),
+                        }
+                    }
+                    request.drain(..head + length);
+
+                    let line = headers.lines().next().unwrap().to_string();
+                    let (status, body): (&str, Vec<u8>) = match line.split(' ').nth(1).unwrap().split('?').next().unwrap() {
+                        "/images/redis:7-alpine/json" => ("200 OK", include_bytes!("fixtures/image_inspect_v1.44.json").to_vec()),
+                        "/containers/create" => ("201 Created", include_bytes!("fixtures/container_create_v1.40.json").to_vec()),
+                        "/containers/e90e34656806/logs" => {
+                            let payload = b"1:M 06 Feb 2024 09:00:00.000 * Ready to accept connections tcp\n";
+                            let mut frame = vec![1, 0, 0, 0];
+                            frame.extend_from_slice(&(payload.len() as u32).to_be_bytes());
+                            frame.extend_from_slice(payload);
+                            ("200 OK", frame)
+                        },
+                        "/containers/e90e34656806/json" => ("200 OK", include_bytes!("fixtures/container_inspect_v1.44.json").to_vec()),
+                        _ => ("204 No Content", Vec::new()),
+                    };
+                    seen.lock().unwrap().push(line);
+
+                    let mut response = format!("HTTP/1.1 {}\r\nContent-Length: {}\r\n\r\n", status, body.len()).into_bytes();
+                    response.extend_from_slice(&body);
+                    if socket.write_all(&response).is_err() || !keep_alive {
+                        return;
+                    }
+                }
+            });
+        }
+    });
+
+    (address, requests)
+}
+
+/// Start and drop a `GenericContainer` against `generic_container_daemon`.
+async fn check_generic_container(keep_alive: bool) {
+    use docker_client::testing::{GenericContainer, WaitFor};
+
+    // Removal on drop blocks the test runtime, so the daemon runs on its own threads.
+    let (address, requests) = generic_container_daemon(keep_alive);
+
+    let client = DockerClient::stream(format!("tcp://{}", address), None);
+    let redis = GenericContainer::new("redis:7-alpine")
+        .with_exposed_port(80)
+        .with_env("REDIS_ARGS", "--save ''")
+        .with_wait_for(WaitFor::message("Ready to accept connections"))
+        .start(&client)
+        .await
+        .unwrap();
+
+    assert_eq!(redis.host(), "127.0.0.1");
+    assert_eq!(redis.host_port(80), Some(8080));
+    assert_eq!(redis.host_port(443), None);
+    drop(redis);
+
+    let requests: Vec<String> = requests.lock().unwrap().iter()
+        .map(|line| line.rsplit_once(' ').unwrap().0.to_string())
+        .collect();
+    assert_eq!(requests.len(), 6);
+    assert_eq!(requests[0], "GET /images/redis:7-alpine/json");
+    assert_eq!(requests[1], "POST /containers/create");
+    assert_eq!(requests[2], "POST /containers/e90e34656806/start");
+    assert!(requests[3].starts_with("GET /containers/e90e34656806/logs?"));
+    assert!(requests[3].contains("follow=true"));
+    assert!(requests[4].starts_with("GET /containers/e90e34656806/json"));
+    assert!(requests[5].starts_with("DELETE /containers/e90e34656806?"));
+    assert!(requests[5].contains("force=true"));
+}
+
+#[tokio::test]
+async fn test_generic_container() {
+    check_generic_container(false).await;
+}
+
+#[tokio::test]
+async fn test_generic_container_keep_alive() {
+    check_generic_container(true).await;
+}
+
+#[tokio::test]
+async fn test_pull_images() {
+    use docker_client::image::PullEvent;
+    use futures::StreamExt;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+
+    tokio::spawn(async move {
+        loop {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            tokio::spawn(async move {
+                let mut request = Vec::new();
+                let mut buffer = [0u8; 1024];
+                while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+                    let read = socket.read(&mut buffer).await.unwrap();
+                    request.extend_from_slice(&buffer[..read]);
+                }
+                let request = String::from_utf8_lossy(&request);
+
+                let (status, body) = if request.contains("fromImage=alpine&tag=3.19") {
+                    ("200 OK", concat!(
+                        r#"{"status":"Pulling from library/alpine","id":"3.19"}"#, "\n",
+                        r#"{"status":"Downloading","progressDetail":{"current":1024,"total":3409007},"id":"4abcf2066143"}"#, "\n",
+                        r#"{"status":"Verifying Checksum","progressDetail":"#, "\n",
+                        r#"{"status":"Pull complete","progressDetail":{},"id":"4abcf2066143"}"#, "\n",
+                        r#"{"status":"Digest: sha256:c5b1261d6d3e43071626931fc004f70149baeba2c8ec672bd4f27761f8e1ad6b"}"#, "\n",
+                        r#"{"status":"Status: Downloaded newer image for alpine:3.19"}"#, "\n",
+                    ))
+                } else if request.contains("fromImage=private/app") {
+                    ("200 OK", concat!(
+                        r#"{"status":"Pulling from private/app","id":"latest"}"#, "\n",
+                        r#"{"errorDetail":{"message":"unauthorized"},"error":"unauthorized"}"#, "\n",
+                    ))
+                } else {
+                    ("404 Not Found", r#"{"message":"manifest unknown"}"#)
+                };
+
+                let response = format!("HTTP/1.1 {}\r\nContent-Length: {}\r\n\r\n{}", status, body.len(), body);
+                socket.write_all(response.as_bytes()).await.unwrap();
+            });
+        }
+    });
+
+    let client = DockerClient::stream(format!("tcp://{}", address), None);
+    let references = ["alpine:3.19", "private/app", "missing:1"];
+
+    let pulls = client.pull_images(&references, 2);
+    assert_eq!(pulls.references(), references.to_vec());
+
+    let events: Vec<_> = pulls.collect().await;
+    let alpine: Vec<&PullEvent> = events.iter().filter(|e| e.reference() == "alpine:3.19").map(|e| e.event()).collect();
+    // The line which can not be decoded is skipped, the pull goes on.
+    assert_eq!(alpine.len(), 6);
+    match alpine[5] {
+        PullEvent::Done(summary) => {
+            assert_eq!(summary.layers_downloaded(), 1);
+            assert_eq!(summary.total_bytes(), 3409007);
+            assert_eq!(summary.status(), "Downloaded newer image for alpine:3.19");
+        },
+        event => panic!("unexpected {:?}", event),
+    }
+
+    let outcomes = client.pull_images(&references, 0).finish().await;
+    assert_eq!(outcomes.len(), 3);
+    assert!(outcomes["alpine:3.19"].is_ok());
+    assert!(matches!(&outcomes["private/app"], Err(DockerError::ServerError(e)) if e.message == "unauthorized"));
+    assert!(matches!(&outcomes["missing:1"], Err(DockerError::NotFound(_))));
+}
+
+#[tokio::test]
+async fn test_digest_verification() {
+    use docker_client::image::create::RequestBuilder;
+    use std::sync::{Arc, Mutex};
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    let seen = requests.clone();
+
+    tokio::spawn(async move {
+        loop {
+            let (mut socket, _) = listener.accept().await.unwrap();
+
+            let mut request = Vec::new();
+            let mut buffer = [0u8; 1024];
+            while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+                let read = socket.read(&mut buffer).await.unwrap();
+                request.extend_from_slice(&buffer[..read]);
+            }
+            let line = String::from_utf8_lossy(&request).lines().next().unwrap().to_string();
+
+            let body = match line.starts_with("POST /images/create") {
+                true => "{\"status\":\"Status: Image is up to date for nginx:1.25\"}\n",
+                false => include_str!("fixtures/image_inspect_v1.44.json"),
+            };
+            seen.lock().unwrap().push(line.split(' ').nth(1).unwrap().to_string());
+
+            let response = format!("HTTP/1.1 200 OK\r\nContent-Length: {}\r\n\r\n{}", body.len(), body);
+            socket.write_all(response.as_bytes()).await.unwrap();
+        }
+    });
+
+    let pinned = "sha256:4c0fdaa8b6341bfdeca5f18f7837462c80cff90527ee35ef185571e1c327beac";
+    let other = "sha256:0000000000000000000000000000000000000000000000000000000000000000";
+    let client = DockerClient::stream(format!("tcp://{}", address), None);
+
+    let image = client.ensure_image("nginx:1.25", Some(pinned)).await.unwrap();
+    assert!(image.has_digest(pinned));
+    assert_eq!(*requests.lock().unwrap(), vec!["/images/nginx:1.25/json"]);
+    requests.lock().unwrap().clear();
+
+    match client.ensure_image("nginx:1.25", Some(other)).await {
+        Err(DockerError::DigestMismatch { expected, found }) => {
+            assert_eq!(expected, other);
+            assert_eq!(found, vec![format!("nginx@{}", pinned)]);
+        },
+        result => panic!("unexpected {:?}", result),
+    }
+    assert_eq!(*requests.lock().unwrap(), vec![
+        "/images/nginx:1.25/json",
+        "/images/create?fromImage=nginx&tag=1.25",
+        "/images/nginx:1.25/json",
+    ]);
+
+    let request = RequestBuilder::new().image("nginx").tag("1.25").digest(pinned).build();
+    let summary = client.pull_image(request).await.unwrap();
+    assert!(summary.up_to_date());
+}
+
+#[tokio::test]
+async fn test_get_or_create_network() {
+    use docker_client::networks::create::RequestBuilder;
+    use std::sync::{Arc, Mutex};
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+
+    // Responses in order: missing, created, then missing, created concurrently, found.
+    let responses = Arc::new(Mutex::new(vec![
+        ("404 Not Found", r#"{"message":"network net01 not found"}"#),
+        ("201 Created", include_str!("fixtures/network_create_v1.44.json")),
+        ("404 Not Found", r#"{"message":"network net01 not found"}"#),
+        ("409 Conflict", r#"{"message":"network with name net01 already exists"}"#),
+        ("200 OK", include_str!("fixtures/network_inspect_v1.40.json")),
+    ].into_iter()));
+
+    tokio::spawn(async move {
+        loop {
+            let (mut socket, _) = listener.accept().await.unwrap();
+
+            let mut request = Vec::new();
+            let mut buffer = [0u8; 1024];
+            while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+                let read = socket.read(&mut buffer).await.unwrap();
+                request.extend_from_slice(&buffer[..read]);
+            }
+
+            let (status, body) = responses.lock().unwrap().next().unwrap();
+            let response = format!("HTTP/1.1 {}\r\nContent-Length: {}\r\n\r\n{}", status, body.len(), body);
+            socket.write_all(response.as_bytes()).await.unwrap();
+        }
+    });
+
+    let client = DockerClient::stream(format!("tcp://{}", address), None);
+
+    let id = client.get_or_create_network(RequestBuilder::with_name("net01").build()).await.unwrap();
+    assert_eq!(id, "22be93d5babb089c5aab8dbc369042fad48ff791584ca2da2100db837a1c7c30");
+
+    let id = client.get_or_create_network(RequestBuilder::with_name("net01").build()).await.unwrap();
+    assert_eq!(id, "7d86d31b1478e7cca9ebed7e73aa0fdeec46c5ca29497431d3007d2d9e15ed99");
+}
+
+#[tokio::test]
+async fn test_get_or_create_volume() {
+    use std::sync::{Arc, Mutex};
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+
+    let volume = include_str!("fixtures/volume_inspect_v1.44.json");
+
+    // Responses in order: missing, created, then found, then created without lookup.
+    let responses = Arc::new(Mutex::new(vec![
+        ("404 Not Found", r#"{"message":"get web-data: no such volume"}"#),
+        ("201 Created", volume),
+        ("200 OK", volume),
+        ("201 Created", volume),
+    ].into_iter()));
+    let requests = Arc::new(Mutex::new(Vec::new()));
+
+    let recorded = requests.clone();
+    tokio::spawn(async move {
+        loop {
+            let (mut socket, _) = listener.accept().await.unwrap();
+
+            let mut request = Vec::new();
+            let mut buffer = [0u8; 1024];
+            while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+                let read = socket.read(&mut buffer).await.unwrap();
+                request.extend_from_slice(&buffer[..read]);
+            }
+            let line = String::from_utf8_lossy(&request).lines().next().unwrap_or_default().to_string();
+            recorded.lock().unwrap().push(line);
+
+            let (status, body) = responses.lock().unwrap().next().unwrap();
+            let response = format!("HTTP/1.1 {}\r\nContent-Length: {}\r\n\r\n{}", status, body.len(), body);
+            socket.write_all(response.as_bytes()).await.unwrap();
+        }
+    });
+
+    let client = DockerClient::stream(format!("tcp://{}", address), None);
+
+    let info = client.get_or_create_volume(VolumeCreator::builder().name("web-data").build()).await.unwrap();
+    assert_eq!(info.name(), "web-data");
+
+    let info = client.get_or_create_volume(VolumeCreator::builder().name("web-data").build()).await.unwrap();
+    assert_eq!(info.mountpoint(), "/var/lib/docker/volumes/web-data/_data");
+
+    client.get_or_create_volume(VolumeCreator::builder().build()).await.unwrap();
+
+    let requests = requests.lock().unwrap();
+    assert_eq!(requests.len(), 4);
+    assert!(requests[0].starts_with("GET /volumes/web-data "));
+    assert!(requests[1].starts_with("POST /volumes/create "));
+    assert!(requests[2].starts_with("GET /volumes/web-data "));
+    assert!(requests[3].starts_with("POST /volumes/create "));
+}
+
+#[tokio::test]
+async fn test_ensure_running() {
+    use docker_client::container::EnsureAction;
+    use std::sync::{Arc, Mutex};
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+
+    let running = include_str!("fixtures/container_inspect_v1.44.json").to_string();
+    let paused = running.replacen(r#""Paused": false"#, r#""Paused": true"#, 1);
+
+    // Responses in order: missing, then stopped, then running, then paused.
+    let responses = Arc::new(Mutex::new(vec![
+        ("404 Not Found", r#"{"message":"No such container: cache"}"#.to_string()),
+        ("201 Created", include_str!("fixtures/container_create_v1.40.json").to_string()),
+        ("204 No Content", String::new()),
+        ("200 OK", include_str!("fixtures/container_inspect_v1.40.json").to_string()),
+        ("204 No Content", String::new()),
+        ("200 OK", running),
+        ("200 OK", paused),
+        ("204 No Content", String::new()),
+    ].into_iter()));
+    let requests = Arc::new(Mutex::new(Vec::new()));
+
+    let recorded = requests.clone();
+    tokio::spawn(async move {
+        loop {
+            let (mut socket, _) = listener.accept().await.unwrap();
+
+            let mut request = Vec::new();
+            let mut buffer = [0u8; 1024];
+            while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+                let read = socket.read(&mut buffer).await.unwrap();
+                request.extend_from_slice(&buffer[..read]);
+            }
+            let line = String::from_utf8_lossy(&request).lines().next().unwrap_or_default().to_string();
+            recorded.lock().unwrap().push(line);
+
+            let (status, body) = responses.lock().unwrap().next().unwrap();
+            let response = format!("HTTP/1.1 {}\r\nContent-Length: {}\r\n\r\n{}", status, body.len(), body);
+            socket.write_all(response.as_bytes()).await.unwrap();
+        }
+    });
+
+    let client = DockerClient::stream(format!("tcp://{}", address), None);
+    let request = Create::new().config(Config::with_image("redis:7-alpine").build()).build();
+
+    let ensured = client.ensure_running("cache", request.clone()).await.unwrap();
+    assert_eq!(ensured.action(), EnsureAction::Created);
+    assert_eq!(ensured.id().as_str(), "e90e34656806");
+
+    let ensured = client.ensure_running("cache", request.clone()).await.unwrap();
+    assert_eq!(ensured.action(), EnsureAction::Started);
+
+    let ensured = client.ensure_running("cache", request.clone()).await.unwrap();
+    assert_eq!(ensured.action(), EnsureAction::AlreadyRunning);
+
+    let ensured = client.ensure_running("cache", request).await.unwrap();
+    assert_eq!(ensured.action(), EnsureAction::Unpaused);
+
+    let requests = requests.lock().unwrap();
+    assert_eq!(requests.len(), 8);
+    assert!(requests[1].starts_with("POST /containers/create?name=cache "));
+    assert!(requests[2].starts_with("POST /containers/e90e34656806/start "));
+    assert!(requests[4].starts_with("POST /containers/ba033ac4401106a3b513bc9d639eee123ad78ca3616b921167cd74b20e25ed39/start "));
+    assert!(requests[7].ends_with("/unpause HTTP/1.1"));
+}
+
+#[tokio::test]
+async fn test_reconcile() {
+    use docker_client::reconcile::{ContainerSpec, Drift, ReconcileAction, SPEC_HASH_LABEL};
+    use std::sync::{Arc, Mutex};
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    let spec = ContainerSpec::new("web", Config::with_image("nginx:1.25").label("a", "1").label("b", "2").build())
+        .network("bridge");
+
+    let reordered = ContainerSpec::new("web", Config::with_image("nginx:1.25").label("b", "2").label("a", "1").build())
+        .network("bridge");
+    assert_eq!(spec.hash(), reordered.hash());
+    assert_ne!(spec.hash(), spec.clone().network("backend").hash());
+
+    let image = include_str!("fixtures/image_inspect_v1.44.json").to_string();
+    let mut current: serde_json::Value = serde_json::from_str(include_str!("fixtures/container_inspect_v1.44.json")).unwrap();
+    current["Config"]["Labels"][SPEC_HASH_LABEL] = serde_json::Value::String(spec.hash());
+    let unchanged = current.to_string();
+    current["Image"] = serde_json::Value::String("sha256:0000000000000000000000000000000000000000000000000000000000000000".to_string());
+    let outdated = current.to_string();
+
+    // Responses in order: missing, then unchanged, then built from an older image.
+    let responses = Arc::new(Mutex::new(vec![
+        ("200 OK", image.clone()),
+        ("404 Not Found", r#"{"message":"No such container: web"}"#.to_string()),
+        ("201 Created", include_str!("fixtures/container_create_v1.40.json").to_string()),
+        ("200 OK", String::new()),
+        ("204 No Content", String::new()),
+        ("200 OK", image.clone()),
+        ("200 OK", unchanged),
+        ("200 OK", image),
+        ("200 OK", outdated),
+        ("204 No Content", String::new()),
+        ("201 Created", include_str!("fixtures/container_create_v1.40.json").to_string()),
+        ("200 OK", String::new()),
+        ("204 No Content", String::new()),
+    ].into_iter()));
+    let requests = Arc::new(Mutex::new(Vec::new()));
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+
+    let recorded = requests.clone();
+    tokio::spawn(async move {
+        loop {
+            let (mut socket, _) = listener.accept().await.unwrap();
+
+            let mut request = Vec::new();
+            let mut buffer = [0u8; 1024];
+            while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+                let read = socket.read(&mut buffer).await.unwrap();
+                request.extend_from_slice(&buffer[..read]);
+            }
+            let line = String::from_utf8_lossy(&request).lines().next().unwrap_or_default().to_string();
+            recorded.lock().unwrap().push(line);
+
+            let (status, body) = responses.lock().unwrap().next().unwrap();
+            let response = format!("HTTP/1.1 {}\r\nContent-Length: {}\r\n\r\n{}", status, body.len(), body);
+            socket.write_all(response.as_bytes()).await.unwrap();
+        }
+    });
+
+    let client = DockerClient::stream(format!("tcp://{}", address), None);
+
+    let web = client.reconcile(spec.clone()).await.unwrap();
+    assert_eq!(web.action(), ReconcileAction::Created);
+    assert_eq!(web.id().as_str(), "e90e34656806");
+
+    let web = client.reconcile(spec.clone()).await.unwrap();
+    assert_eq!(web.action(), ReconcileAction::Unchanged);
+
+    let web = client.reconcile(spec).await.unwrap();
+    assert_eq!(web.action(), ReconcileAction::Recreated);
+    assert_eq!(web.drift(), &vec![Drift::Image]);
+
+    let requests = requests.lock().unwrap();
+    assert_eq!(requests.len(), 13);
+    assert!(requests[2].starts_with("POST /containers/create?name=web "));
+    assert!(requests[3].starts_with("POST /networks/bridge/connect "));
+    assert!(requests[9].starts_with("DELETE /containers/4fa6e0f0c6786287e131c3852c58a2e01cc697a68231826813597e4994f1d6e2"));
+    assert!(requests[10].starts_with("POST /containers/create?name=web "));
+}
+
+#[tokio::test]
+async fn test_inspect_raw() {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+
+    // A field the crate does not model.
+    let mut body: serde_json::Value = serde_json::from_str(include_str!("fixtures/image_inspect_v1.44.json")).unwrap();
+    body["Descriptor"] = serde_json::json!({ "mediaType": "application/vnd.oci.image.index.v1+json", "size": 10 });
+    let body = body.to_string();
+
+    let served = body.clone();
+    tokio::spawn(async move {
+        loop {
+            let (mut socket, _) = listener.accept().await.unwrap();
+
+            let mut request = Vec::new();
+            let mut buffer = [0u8; 1024];
+            while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+                let read = socket.read(&mut buffer).await.unwrap();
+                request.extend_from_slice(&buffer[..read]);
+            }
+
+            let response = format!("HTTP/1.1 200 OK\r\nContent-Length: {}\r\n\r\n{}", served.len(), served);
+            socket.write_all(response.as_bytes()).await.unwrap();
+        }
+    });
+
+    let client = DockerClient::stream(format!("tcp://{}", address), None);
+
+    let image = client.inspect_image_raw("nginx:1.25").await.unwrap();
+    assert_eq!(image.id().as_str(), "sha256:b690f5f0a2d535cee5e08631aa508fef339c43bb91d5b1f7d77a1a05cea021a8");
+    assert_eq!(image.bytes().as_ref(), body.as_bytes());
+    assert_eq!(image.json().unwrap()["Descriptor"]["size"], 10);
+
+    let (info, _) = image.into_parts();
+    assert_eq!(info, client.inspect_image("nginx:1.25").await.unwrap());
+}
+
+#[tokio::test]
+async fn test_wait_container_with_timeout() {
+    use std::time::{Duration, Instant};
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+
+    // The first wait never gets an answer, the second one exits at once.
+    tokio::spawn(async move {
+        let mut held = Vec::new();
+        loop {
+            let (mut socket, _) = listener.accept().await.unwrap();
+
+            let mut request = Vec::new();
+            let mut buffer = [0u8; 1024];
+            while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+                let read = socket.read(&mut buffer).await.unwrap();
+                request.extend_from_slice(&buffer[..read]);
+            }
+
+            if String::from_utf8_lossy(&request).starts_with("POST /containers/sleeper/wait") {
+                held.push(socket);
+                continue;
+            }
+
+            let body = include_str!("fixtures/container_wait_v1.44.json");
+            let response = format!("HTTP/1.1 200 OK\r\nContent-Length: {}\r\n\r\n{}", body.len(), body);
+            socket.write_all(response.as_bytes()).await.unwrap();
+        }
+    });
+
+    let client = DockerClient::stream(format!("tcp://{}", address), None);
+
+    let started = Instant::now();
+    let result = client.wait_container_with_timeout("sleeper", WaitCondition::NotRunning, Duration::from_millis(200)).await;
+    assert!(matches!(result, Err(DockerError::Timeout)));
+    assert!(started.elapsed() < Duration::from_secs(5));
+
+    let status = client.wait_container_with_timeout("job", WaitCondition::NotRunning, Duration::from_secs(5)).await.unwrap();
+    assert_eq!(status.status_code(), 137);
+}
+
+#[tokio::test]
+async fn test_ping() {
+    use docker_client::client::ApiVersion;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+
+    tokio::spawn(async move {
+        loop {
+            let (mut socket, _) = listener.accept().await.unwrap();
+
+            let mut request = Vec::new();
+            let mut buffer = [0u8; 1024];
+            while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+                let read = socket.read(&mut buffer).await.unwrap();
+                request.extend_from_slice(&buffer[..read]);
+            }
+
+            // The ping is not versioned, daemons answer it whatever the version of the client.
+            let response = match request.starts_with(b"GET /_ping ") {
+                true => "HTTP/1.1 200 OK\r\nApi-Version: 1.44\r\nBuilder-Version: 2\r\nOSType: linux\r\nSwarm: inactive\r\nContent-Length: 2\r\n\r\nOK",
+                false => "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\n\r\n",
+            };
+            socket.write_all(response.as_bytes()).await.unwrap();
+        }
+    });
+
+    let client = DockerClient::stream(format!("tcp://{}", address), None)
+        .with_api_version(ApiVersion::new(1, 41));
+
+    let ping = client.ping().await.unwrap();
+    assert_eq!(ping.api_version(), Some(ApiVersion::new(1, 44)));
+    assert_eq!(ping.builder_version(), Some("2"));
+    assert_eq!(ping.os_type(), Some("linux"));
+    assert_eq!(ping.swarm(), Some("inactive"));
+    assert!(!ping.experimental());
+}
+
+#[tokio::test]
+async fn test_capabilities() {
+    use docker_client::client::ApiVersion;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+
+    let experimental = Arc::new(AtomicBool::new(false));
+
+    let enabled = experimental.clone();
+    tokio::spawn(async move {
+        loop {
+            let (mut socket, _) = listener.accept().await.unwrap();
+
+            let mut request = Vec::new();
+            let mut buffer = [0u8; 1024];
+            while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+                let read = socket.read(&mut buffer).await.unwrap();
+                request.extend_from_slice(&buffer[..read]);
+            }
+            let request = String::from_utf8_lossy(&request).to_string();
+
+            let (status, header, body) = if request.starts_with("GET /_ping ") {
+                ("200 OK", format!("Docker-Experimental: {}\r\n", enabled.load(Ordering::SeqCst)), "OK")
+            } else if request.starts_with("GET /version ") {
+                ("200 OK", String::new(), include_str!("fixtures/version_v1.44.json"))
+            } else if request.starts_with("GET /containers/db/checkpoints ") {
+                ("200 OK", String::new(), r#"[{"Name":"before-upgrade"}]"#)
+            } else {
+                ("404 Not Found", String::new(), r#"{"message":"page not found"}"#)
+            };
+
+            let response = format!("HTTP/1.1 {}\r\n{}Content-Length: {}\r\n\r\n{}", status, header, body.len(), body);
+            socket.write_all(response.as_bytes()).await.unwrap();
+        }
+    });
+
+    let client = DockerClient::stream(format!("tcp://{}", address), None);
+
+    let capabilities = client.capabilities().await.unwrap();
+    assert_eq!(capabilities.api_version(), ApiVersion::new(1, 44));
+    assert_eq!(capabilities.min_api_version(), Some(ApiVersion::new(1, 24)));
+    assert_eq!(capabilities.os(), "linux");
+    assert!(!capabilities.experimental());
+
+    match client.list_checkpoints("db").await {
+        Err(DockerError::FeatureUnavailable(feature)) => assert_eq!(feature, "checkpoints"),
+        other => panic!("expected FeatureUnavailable, got {:?}", other),
+    }
+
+    experimental.store(true, Ordering::SeqCst);
+    assert!(client.capabilities().await.unwrap().experimental());
+
+    let checkpoints = client.list_checkpoints("db").await.unwrap();
+    assert_eq!(checkpoints[0].name(), "before-upgrade");
+}
+
+#[test]
+fn test_upload_path() {
+    use docker_client::container::Upload;
+
+    let upload = Upload::builder().id("ci").path("/srv/app").build();
+    assert_eq!(upload.get_path(), "/containers/ci/archive?path=%2Fsrv%2Fapp");
+
+    let upload = Upload::builder().id("ci").path("/srv/app").copy_uid_gid(true).no_overwrite_dir_non_dir(true).build();
+    assert_eq!(upload.get_path(), "/containers/ci/archive?path=%2Fsrv%2Fapp&copyUIDGID=true&noOverwriteDirNonDir=true");
+}
+
+#[tokio::test]
+async fn test_resize_tty() {
+    use std::sync::{Arc, Mutex};
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+
+    let requests = Arc::new(Mutex::new(Vec::new()));
+
+    let recorded = requests.clone();
+    tokio::spawn(async move {
+        loop {
+            let (mut socket, _) = listener.accept().await.unwrap();
+
+            let mut request = Vec::new();
+            let mut buffer = [0u8; 1024];
+            while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+                let read = socket.read(&mut buffer).await.unwrap();
+                request.extend_from_slice(&buffer[..read]);
+            }
+            let line = String::from_utf8_lossy(&request).lines().next().unwrap_or_default().to_string();
+            let status = if line.starts_with("POST /exec/") { "201 Created" } else { "200 OK" };
+            recorded.lock().unwrap().push(line);
+
+            let response = format!("HTTP/1.1 {}\r\nContent-Length: 0\r\n\r\n", status);
+            socket.write_all(response.as_bytes()).await.unwrap();
+        }
+    });
+
+    let client = DockerClient::stream(format!("tcp://{}", address), None);
+
+    client.resize_container_tty("shell", 40, 120).await.unwrap();
+    client.resize_exec("b3c9f3b1c1e0", 24, 80).await.unwrap();
+
+    let requests = requests.lock().unwrap();
+    assert!(requests[0].starts_with("POST /containers/shell/resize?h=40&w=120 "));
+    assert!(requests[1].starts_with("POST /exec/b3c9f3b1c1e0/resize?h=24&w=80 "));
+}
+
+#[test]
+fn test_go_duration() {
+    use docker_client::additionals::duration::GoDuration;
+    use std::time::Duration;
+
+    let parse = |s: &str| s.parse::<GoDuration>().map(Duration::from);
+
+    assert_eq!(parse("1h30m"), Ok(Duration::from_secs(5400)));
+    assert_eq!(parse("1.5s"), Ok(Duration::from_millis(1500)));
+    assert_eq!(parse("2h45m10.5s"), Ok(Duration::from_millis(9_910_500)));
+    assert_eq!(parse("300ms"), Ok(Duration::from_millis(300)));
+    assert_eq!(parse("1us"), Ok(Duration::from_micros(1)));
+    assert_eq!(parse("1µs"), Ok(Duration::from_micros(1)));
+    assert_eq!(parse("0"), Ok(Duration::from_secs(0)));
+    assert!(parse("").is_err());
+    assert!(parse("10").is_err());
+    assert!(parse("-5s").is_err());
+    assert!(parse("1d").is_err());
+    assert!(parse(".s").is_err());
+
+    for (nanos, formatted) in &[(0, "0s"), (15, "15ns"), (1_500, "1.5µs"), (2_250_000, "2.25ms"), (90_000_000_000, "1m30s"), (3_600_000_000_000, "1h0m0s")] {
+        let duration = GoDuration::from(Duration::from_nanos(*nanos));
+        assert_eq!(duration.to_string(), *formatted);
+        assert_eq!(formatted.parse::<GoDuration>(), Ok(duration));
+    }
+
+    let health_check: HealthCheck = serde_json::from_str(r#"{"Test":["CMD","true"],"Interval":30000000000,"Timeout":5000000000,"Retries":3}"#).unwrap();
+    assert_eq!(health_check.interval().map(|d| d.to_string()), Some("30s".to_string()));
+    assert_eq!(health_check.timeout().map(|d| d.to_string()), Some("5s".to_string()));
+    assert_eq!(health_check.start_period(), None);
+    assert_eq!(health_check.retries(), Some(3));
+
+    let filters = Filters::new().older_than(Duration::from_secs(86400)).build();
+    assert_eq!(filters.get("until"), Some(&vec!["24h0m0s".to_string()]));
+}
+
+#[tokio::test]
+async fn test_response_headers() {
+    use docker_client::client::ApiVersion;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+
+    let stat = base64::encode(r#"{"name":"nginx","size":4096,"mode":2147484141,"mtime":"2024-01-10T09:00:00Z","linkTarget":""}"#);
+
+    tokio::spawn(async move {
+        loop {
+            let (mut socket, _) = listener.accept().await.unwrap();
+
+            let mut request = Vec::new();
+            let mut buffer = [0u8; 1024];
+            while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+                let read = socket.read(&mut buffer).await.unwrap();
+                request.extend_from_slice(&buffer[..read]);
+            }
+            let request = String::from_utf8_lossy(&request).to_string();
+
+            let (status, header, body) = if request.starts_with("GET /_ping ") {
+                ("200 OK", "Api-Version: 1.45\r\nBuilder-Version: 2\r\nDocker-Experimental: false\r\n".to_string(), "OK")
+            } else if request.starts_with("GET /version ") {
+                ("200 OK", String::new(), include_str!("fixtures/version_v1.44.json"))
+            } else if request.starts_with("HEAD /containers/web/archive?path=%2Fetc%2Fnginx ") {
+                ("200 OK", format!("X-Docker-Container-Path-Stat: {}\r\n", stat), "")
+            } else {
+                ("404 Not Found", String::new(), "")
+            };
+
+            let response = format!("HTTP/1.1 {}\r\n{}Content-Length: {}\r\n\r\n{}", status, header, body.len(), body);
+            socket.write_all(response.as_bytes()).await.unwrap();
+        }
+    });
+
+    let client = DockerClient::stream(format!("tcp://{}", address), None);
+
+    let capabilities = client.capabilities().await.unwrap();
+    assert_eq!(capabilities.api_version(), ApiVersion::new(1, 45));
+    assert_eq!(capabilities.builder_version(), Some("2"));
+    assert!(!capabilities.experimental());
+
+    let stat = client.container_path_stat("web", "/etc/nginx").await.unwrap();
+    assert_eq!(stat.name(), "nginx");
+    assert_eq!(stat.size(), 4096);
+    assert_eq!(stat.permissions(), 0o755);
+    assert!(stat.is_dir());
+    assert!(!stat.is_symlink());
+
+    match client.container_path_stat("web", "/missing").await {
+        Err(DockerError::NotFound(message)) => assert_eq!(message.message, "no such container or path: /missing"),
+        other => panic!("expected NotFound, got {:?}", other),
+    }
+}
+
+#[cfg(feature = "tls-rustls")]
+#[tokio::test]
+async fn test_tls() {
+    use docker_client::client::{ApiVersion, DockerEnv, EndpointError, TlsConfig};
+    use std::sync::Arc;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    let certs = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/tls");
+    let pem = |name: &str| std::io::BufReader::new(std::fs::File::open(certs.join(name)).unwrap());
+
+    let mut roots = rustls::RootCertStore::empty();
+    roots.add_parsable_certificates(&rustls_pemfile::certs(&mut pem("ca.pem")).unwrap());
+    let server_certs = rustls_pemfile::certs(&mut pem("server-cert.pem")).unwrap().into_iter().map(rustls::Certificate).collect();
+    let server_key = match rustls_pemfile::read_one(&mut pem("server-key.pem")).unwrap() {
+        Some(rustls_pemfile::Item::ECKey(key)) => rustls::PrivateKey(key),
+        other => panic!("expected EC key, got {:?}", other),
+    };
+    let config = rustls::ServerConfig::builder()
+        .with_safe_defaults()
+        .with_client_cert_verifier(rustls::server::AllowAnyAuthenticatedClient::new(roots).boxed())
+        .with_single_cert(server_certs, server_key)
+        .unwrap();
+    let acceptor = tokio_rustls::TlsAcceptor::from(Arc::new(config));
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+
+    tokio::spawn(async move {
+        loop {
+            let (socket, _) = listener.accept().await.unwrap();
+            let mut socket = match acceptor.accept(socket).await {
+                Ok(socket) => socket,
+                Err(_) => continue,
+            };
+
+            let mut request = Vec::new();
+            let mut buffer = [0u8; 1024];
+            while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+                let read = socket.read(&mut buffer).await.unwrap();
+                request.extend_from_slice(&buffer[..read]);
+            }
+
+            let body = match request.starts_with(b"GET /version ") {
+                true => include_str!("fixtures/version_v1.44.json"),
+                false => "OK",
+            };
+            let response = format!("HTTP/1.1 200 OK\r\nContent-Length: {}\r\n\r\n{}", body.len(), body);
+            socket.write_all(response.as_bytes()).await.unwrap();
+            socket.shutdown().await.unwrap();
+        }
+    });
+
+    let host = format!("tcp://{}", address);
+    let env = DockerEnv::from_vars(vec![
+        ("DOCKER_HOST", host.as_str()),
+        ("DOCKER_TLS_VERIFY", "1"),
+        ("DOCKER_CERT_PATH", certs.to_str().unwrap()),
+    ]).unwrap();
+
+    let client = DockerClient::from_docker_env(&env).unwrap();
+    assert_eq!(client.capabilities().await.unwrap().api_version(), ApiVersion::new(1, 44));
+
+    // The daemon requires a client certificate.
+    let anonymous = DockerClient::https(address.to_string(), &TlsConfig::new(certs.join("ca.pem")), None).unwrap();
+    assert!(anonymous.capabilities().await.is_err());
+
+    match DockerClient::https(address.to_string(), &TlsConfig::from_cert_path(certs.join("missing"), true), None) {
+        Err(EndpointError::InvalidCertificate(_)) => {},
+        other => panic!("expected InvalidCertificate, got {:?}", other.map(|_| ())),
+    }
+
+    #[cfg(feature = "tls-native")]
+    {
+        use docker_client::client::TlsBackend;
+
+        let tls = TlsConfig::from_cert_path(&certs, true).backend(TlsBackend::Native);
+        let client = DockerClient::https(address.to_string(), &tls, None).unwrap();
+        assert_eq!(client.capabilities().await.unwrap().api_version(), ApiVersion::new(1, 44));
+
+        let tls = TlsConfig::new(certs.join("ca.pem")).backend(TlsBackend::Native);
+        assert!(DockerClient::https(address.to_string(), &tls, None).unwrap().capabilities().await.is_err());
+    }
+}
+
+#[tokio::test]
+async fn test_negotiate_version() {
+    use docker_client::client::ApiVersion;
+    use std::sync::{Arc, Mutex};
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    async fn daemon(ping_version: Option<&'static str>) -> (String, Arc<Mutex<Vec<String>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let recorded = requests.clone();
+        tokio::spawn(async move {
+            loop {
+                let (mut socket, _) = listener.accept().await.unwrap();
+
+                let mut request = Vec::new();
+                let mut buffer = [0u8; 1024];
+                while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+                    let read = socket.read(&mut buffer).await.unwrap();
+                    request.extend_from_slice(&buffer[..read]);
+                }
+                let line = String::from_utf8_lossy(&request).lines().next().unwrap_or_default().to_string();
+
+                let (header, body) = match line.split(' ').nth(1).unwrap_or_default() {
+                    "/_ping" => (ping_version.map(|version| format!("Api-Version: {}\r\n", version)).unwrap_or_default(), "OK"),
+                    "/version" => (String::new(), include_str!("fixtures/version_v1.44.json")),
+                    _ => (String::new(), "[]"),
+                };
+                recorded.lock().unwrap().push(line);
+
+                let response = format!("HTTP/1.1 200 OK\r\n{}Content-Length: {}\r\n\r\n{}", header, body.len(), body);
+                socket.write_all(response.as_bytes()).await.unwrap();
+            }
+        });
+
+        (format!("tcp://{}", address), requests)
+    }
+
+    // Newer daemons are capped to the newest version of the crate.
+    let (host, requests) = daemon(Some("1.47")).await;
+    let client = DockerClient::stream(host, None).negotiate_version().await.unwrap();
+    assert_eq!(client.api_version(), Some(ApiVersion::LATEST));
+
+    client.get_image_list().await.unwrap();
+    assert!(requests.lock().unwrap()[1].starts_with(&format!("GET /v{}/images/json", ApiVersion::LATEST)));
+
+    // Older daemons keep their version.
+    let (host, _) = daemon(Some("1.41")).await;
+    let client = DockerClient::stream(host, None).negotiate_version().await.unwrap();
+    assert_eq!(client.api_version(), Some(ApiVersion::new(1, 41)));
+
+    // Without the ping header the version is read from /version.
+    let (host, requests) = daemon(None).await;
+    let client = DockerClient::stream(host, None).negotiate_version().await.unwrap();
+    assert_eq!(client.api_version(), Some(ApiVersion::new(1, 44)));
+    assert!(requests.lock().unwrap()[1].starts_with("GET /version "));
+
+    // A pinned version is kept.
+    let (host, requests) = daemon(Some("1.44")).await;
+    let client = DockerClient::stream(host, None).with_api_version(ApiVersion::new(1, 40)).negotiate_version().await.unwrap();
+    assert_eq!(client.api_version(), Some(ApiVersion::new(1, 40)));
+    assert!(requests.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_client_builder() {
+    use docker_client::client::{ApiVersion, EndpointError};
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::{Duration, Instant};
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+
+    let connections = Arc::new(AtomicUsize::new(0));
+    let accepted = connections.clone();
+    tokio::spawn(async move {
+        loop {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            accepted.fetch_add(1, Ordering::SeqCst);
+
+            tokio::spawn(async move {
+                let mut request = Vec::new();
+                let mut buffer = [0u8; 1024];
+                loop {
+                    while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+                        match socket.read(&mut buffer).await {
+                            Ok(0) | Err(_) => return,
+                            Ok(read) => request.extend_from_slice(&buffer[..read]),
+                        }
+                    }
+
+                    // A hung daemon: the ping is never answered.
+                    if request.starts_with(b"GET /_ping ") {
+                        tokio::time::sleep(Duration::from_secs(60)).await;
+                    }
+                    request.clear();
+
+                    socket.write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 2\r\n\r\n[]").await.unwrap();
+                }
+            });
+        }
+    });
+
+    let client = DockerClient::builder()
+        .host(format!("tcp://{}", address))
+        .api_version(ApiVersion::new(1, 41))
+        .connect_timeout(Duration::from_secs(1))
+        .read_timeout(Duration::from_millis(200))
+        .timeout(Duration::from_secs(5))
+        .user_agent(hyper::header::HeaderValue::from_static("ci/1.0"))
+        .max_idle_connections(1)
+        .build()
+        .unwrap();
+
+    assert_eq!(client.api_version(), Some(ApiVersion::new(1, 41)));
+    assert_eq!(client.connect_timeout(), Some(Duration::from_secs(1)));
+    assert_eq!(client.timeout(), Some(Duration::from_secs(5)));
+    assert_eq!(client.headers()["user-agent"], "ci/1.0");
+
+    client.get_image_list().await.unwrap();
+    client.get_image_list().await.unwrap();
+    assert_eq!(connections.load(Ordering::SeqCst), 1);
+
+    // Connections are pooled by default.
+    let pooled = DockerClient::stream(format!("tcp://{}", address), None);
+    pooled.get_image_list().await.unwrap();
+    pooled.get_image_list().await.unwrap();
+    assert_eq!(connections.load(Ordering::SeqCst), 2);
+
+    let unpooled = pooled.with_max_idle_connections(0);
+    unpooled.get_image_list().await.unwrap();
+    unpooled.get_image_list().await.unwrap();
+    assert_eq!(connections.load(Ordering::SeqCst), 4);
+
+    let started = Instant::now();
+    match client.capabilities().await {
+        Err(DockerError::Timeout) => assert!(started.elapsed() < Duration::from_secs(5)),
+        other => panic!("expected Timeout, got {:?}", other),
+    }
+
+    match DockerClient::builder().host("ssh://build-host").build() {
+        Err(EndpointError::UnsupportedTransport(_)) => {},
+        other => panic!("expected UnsupportedTransport, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_retry_policy() {
+    use docker_client::client::RetryPolicy;
+    use std::collections::HashMap;
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+
+    let requests: Arc<Mutex<HashMap<String, usize>>> = Arc::new(Mutex::new(HashMap::new()));
+    let counted = requests.clone();
+    tokio::spawn(async move {
+        loop {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let counted = counted.clone();
+
+            tokio::spawn(async move {
+                let mut request = Vec::new();
+                let mut buffer = [0u8; 1024];
+                while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+                    match socket.read(&mut buffer).await {
+                        Ok(0) | Err(_) => return,
+                        Ok(read) => request.extend_from_slice(&buffer[..read]),
+                    }
+                }
+
+                let request = String::from_utf8_lossy(&request).to_string();
+                let line = request.lines().next().unwrap().to_string();
+                let count = {
+                    let mut counted = counted.lock().unwrap();
+                    let count = counted.entry(line.clone()).or_insert(0);
+                    *count += 1;
+                    *count
+                };
+
+                // Every call fails twice before it succeeds.
+                let (status, body) = match (line.as_str(), count) {
+                    (_, 1) | (_, 2) => ("503 Service Unavailable", r#"{"message":"daemon is restarting"}"#),
+                    (l, _) if l.starts_with("GET /images/json ") => ("200 OK", "[]"),
+                    _ => ("204 No Content", ""),
+                };
+
+                let response = format!("HTTP/1.1 {}\r\nConnection: close\r\nContent-Length: {}\r\n\r\n{}", status, body.len(), body);
+                socket.write_all(response.as_bytes()).await.unwrap();
+            });
+        }
+    });
+
+    let count = |line: &str| requests.lock().unwrap().get(line).copied().unwrap_or(0);
+
+    let client = DockerClient::stream(format!("tcp://{}", address), None)
+        .with_retry_policy(RetryPolicy::new(3).backoff(Duration::from_millis(10), Duration::from_millis(50)));
+
+    client.get_image_list().await.unwrap();
+    assert_eq!(count("GET /images/json HTTP/1.1"), 3);
+
+    // Starting a container is marked as idempotent.
+    client.start_container("web", "").await.unwrap();
+    assert_eq!(count("POST /containers/web/start HTTP/1.1"), 3);
+
+    // Other POST requests are sent once.
+    assert!(client.pause_container("web").await.is_err());
+    assert_eq!(count("POST /containers/web/pause HTTP/1.1"), 1);
+
+    // Retries are bounded by the policy.
+    let client = DockerClient::stream(format!("tcp://{}", address), None)
+        .with_retry_policy(RetryPolicy::new(1).backoff(Duration::from_millis(10), Duration::from_millis(50)));
+
+    assert!(client.get_volumes_list().await.is_err());
+    assert_eq!(count("GET /volumes HTTP/1.1"), 2);
+
+    let policy = RetryPolicy::new(2);
+    assert_eq!(policy.max_retries(), 2);
+    assert_eq!(policy.initial_backoff(), Duration::from_millis(100));
+    assert_eq!(client.retry_policy().map(|policy| policy.max_retries()), Some(1));
+}
+
+#[tokio::test]
+async fn test_mock_transport() {
+    use docker_client::client::{ApiVersion, MockTransport, RetryPolicy};
+    use docker_client::container::{Config, Create};
+    use hyper::Method;
+    use std::time::Duration;
+
+    let mock = MockTransport::new()
+        .with_response(Method::POST, "/containers/create", 201, r#"{"Id":"4f3b","Warnings":[]}"#)
+        .with_response(Method::GET, "/volumes", 503, r#"{"message":"daemon is restarting"}"#)
+        .with_response(Method::GET, "/volumes", 200, r#"{"Volumes":[],"Warnings":[]}"#);
+
+    let client = DockerClient::from_transport(mock.clone())
+        .with_api_version(ApiVersion::new(1, 41))
+        .with_retry_policy(RetryPolicy::new(1).backoff(Duration::from_millis(1), Duration::from_millis(1)));
+
+    let request = Create::new()
+        .config(Config::with_image("alpine").build())
+        .name("web")
+        .build();
+    assert_eq!(client.create_container(request).await.unwrap().id().as_str(), "4f3b");
+
+    client.get_volumes_list().await.unwrap();
+    client.get_volumes_list().await.unwrap();
+
+    match client.inspect_volume("missing").await {
+        Err(DockerError::NotFound(e)) => assert_eq!(e.message, "no response for GET /volumes/missing"),
+        other => panic!("expected NotFound, got {:?}", other),
+    }
+
+    let requests = mock.requests();
+    assert_eq!(requests.len(), 5);
+    assert_eq!(requests[0].method(), Method::POST);
+    assert_eq!(requests[0].path(), "/containers/create");
+    assert_eq!(requests[0].query(), Some("name=web"));
+    assert!(String::from_utf8_lossy(requests[0].body()).contains(r#""Image":"alpine""#));
+    assert_eq!(requests.iter().filter(|request| request.path() == "/volumes").count(), 3);
+}
+
+#[tokio::test]
+async fn test_malformed_response() {
+    use docker_client::client::MockTransport;
+    use hyper::Method;
+
+    let mock = MockTransport::new()
+        .with_response(Method::GET, "/volumes", 200, r#"{"Volumes":[{"Name":"data""#)
+        .with_response(Method::GET, "/volumes/missing", 404, "<html><body>404 page not found</body></html>\n")
+        .with_response(Method::DELETE, "/volumes/data", 429, r#"{"message":"too many requests"}"#);
+
+    let client = DockerClient::from_transport(mock);
+
+    match client.get_volumes_list().await {
+        Err(DockerError::Deserialize { body, .. }) => assert_eq!(body, r#"{"Volumes":[{"Name":"data""#),
+        other => panic!("expected Deserialize, got {:?}", other),
+    }
+
+    match client.inspect_volume("missing").await {
+        Err(DockerError::NotFound(e)) => assert_eq!(e.message, "<html><body>404 page not found</body></html>"),
+        other => panic!("expected NotFound, got {:?}", other),
+    }
+
+    match client.remove_volume("data", false).await {
+        Err(DockerError::UnknownStatus { status, body }) => {
+            assert_eq!(status, 429);
+            assert_eq!(body, r#"{"message":"too many requests"}"#);
+        },
+        other => panic!("expected UnknownStatus, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_default_headers() {
+    use docker_client::client::MockTransport;
+    use docker_client::volume::VolumeCreator;
+    use hyper::header::{HeaderName, HeaderValue};
+    use hyper::Method;
+
+    let mock = MockTransport::new()
+        .with_response(Method::GET, "/volumes", 200, r#"{"Volumes":[],"Warnings":[]}"#)
+        .with_response(Method::POST, "/volumes/create", 201, include_str!("fixtures/volume_inspect_v1.44.json"));
+
+    let client = DockerClient::from_transport(mock.clone());
+    assert!(client.headers()["user-agent"].to_str().unwrap().starts_with("docker-client/"));
+    client.get_volumes_list().await.unwrap();
+
+    let audited = client.clone()
+        .with_user_agent(HeaderValue::from_static("deploy-bot/2.1"))
+        .with_header(HeaderName::from_static("x-audit-user"), HeaderValue::from_static("ci"))
+        .with_header(HeaderName::from_static("content-type"), HeaderValue::from_static("text/plain"));
+    audited.get_volumes_list().await.unwrap();
+    audited.create_volume(VolumeCreator::builder().name("data").build()).await.unwrap();
+
+    let requests = mock.requests();
+    assert_eq!(requests[0].headers()["user-agent"], client.headers()["user-agent"]);
+    assert!(requests[0].headers().get("x-audit-user").is_none());
+    assert_eq!(requests[1].headers()["user-agent"], "deploy-bot/2.1");
+    assert_eq!(requests[1].headers()["x-audit-user"], "ci");
+    // Headers of the endpoint are kept.
+    assert_eq!(requests[2].headers()["content-type"], "application/json");
+}
+
+#[tokio::test]
+async fn test_docker_config_credentials() {
+    use docker_client::client::{Auth, CredentialsError, DockerConfig};
+    use docker_client::image::create::RequestBuilder;
+    use std::sync::{Arc, Mutex};
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    let dir = std::env::temp_dir().join(format!("docker_config_{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+
+    assert_eq!(DockerConfig::load(&dir).unwrap(), DockerConfig::default());
+
+    std::fs::write(dir.join("config.json"), format!(r#"{{
+        "auths": {{
+            "https://index.docker.io/v1/": {{ "auth": "{}" }},
+            "https://ghcr.io": {{ "identitytoken": "gho_token" }},
+            "localhost:5000": {{ "auth": "{}" }}
+        }},
+        "credHelpers": {{ "registry.example.com": "docker-client-test-missing" }}
+    }}"#, base64::encode("hub-user:hub-pass"), base64::encode("ci:s3cret"))).unwrap();
+
+    let config = DockerConfig::load(&dir).unwrap();
+
+    let hub = config.auth_for_image("alpine:3.19").await.unwrap().unwrap();
+    assert_eq!((hub.username.as_str(), hub.password.as_str()), ("hub-user", "hub-pass"));
+    assert_eq!(hub.server_address.as_deref(), Some("https://index.docker.io/v1/"));
+    assert_eq!(config.auth("docker.io").await.unwrap(), Some(hub));
+
+    let ghcr = config.auth_for_image("ghcr.io/acme/api:1.2").await.unwrap().unwrap();
+    assert_eq!(ghcr.identity_token.as_deref(), Some("gho_token"));
+
+    assert_eq!(config.auth_for_image("quay.io/acme/api").await.unwrap(), None);
+
+    match config.auth_for_image("registry.example.com/app").await {
+        Err(CredentialsError::Helper(program, _)) => assert_eq!(program, "docker-credential-docker-client-test-missing"),
+        other => panic!("expected Helper error, got {:?}", other),
+    }
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+
+    let headers = Arc::new(Mutex::new(Vec::new()));
+    let received = headers.clone();
+    tokio::spawn(async move {
+        loop {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let received = received.clone();
+
+            tokio::spawn(async move {
+                let mut request = Vec::new();
+                let mut buffer = [0u8; 1024];
+                while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+                    match socket.read(&mut buffer).await {
+                        Ok(0) | Err(_) => return,
+                        Ok(read) => request.extend_from_slice(&buffer[..read]),
+                    }
+                }
+
+                let request = String::from_utf8_lossy(&request).to_string();
+                let auth = request.lines()
+                    .find_map(|line| line.strip_prefix("x-registry-auth: "))
+                    .map(|value| String::from_utf8(base64::decode_config(value, base64::URL_SAFE).unwrap()).unwrap());
+                received.lock().unwrap().push(auth);
+
+                let body = "{\"status\":\"Pull complete\"}\n";
+                let response = format!("HTTP/1.1 200 OK\r\nConnection: close\r\nContent-Length: {}\r\n\r\n{}", body.len(), body);
+                socket.write_all(response.as_bytes()).await.unwrap();
+            });
+        }
+    });
+
+    let client = DockerClient::stream(format!("tcp://{}", address), None).with_config_dir(&dir);
+
+    client.pull_image(RequestBuilder::new().image("localhost:5000/app").tag("1.0").build()).await.unwrap();
+    client.pull_image(RequestBuilder::new().image("quay.io/acme/api").tag("1.0").build()).await.unwrap();
+
+    // Credentials of a request come first.
+    let quay = Auth::builder().username("robot").password("token").server_address("quay.io").build();
+    client.pull_image(RequestBuilder::new().image("quay.io/acme/api").tag("1.0").auth(quay.clone()).build()).await.unwrap();
+    assert!(client.push_image_with_auth("localhost:5000/app:1.0", quay).await.is_err());
+
+    let headers = headers.lock().unwrap().clone();
+    assert!(headers[0].as_ref().unwrap().contains(r#""username":"ci""#));
+    assert_eq!(headers[1], None);
+    assert!(headers[2].as_ref().unwrap().contains(r#""username":"robot""#));
+    assert!(headers[3].as_ref().unwrap().contains(r#""username":"robot""#));
+
+    std::fs::write(dir.join("config.json"), "{ not json").unwrap();
+    match DockerConfig::load(&dir) {
+        Err(CredentialsError::InvalidConfig(_)) => {},
+        other => panic!("expected InvalidConfig, got {:?}", other),
+    }
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[tokio::test]
+async fn test_check_auth() {
+    use docker_client::client::{Auth, MockTransport};
+    use hyper::Method;
+
+    let mock = MockTransport::new()
+        .with_response(Method::POST, "/auth", 401, r#"{"message":"unauthorized: incorrect username or password"}"#)
+        .with_response(Method::POST, "/auth", 200, r#"{"Status":"Login Succeeded","IdentityToken":"9cbaf023786cd7"}"#);
+    let client = DockerClient::from_transport(mock.clone());
+
+    let auth = Auth::builder().username("ci").password("s3cret").server_address("registry.example.com").build();
+
+    match client.check_auth(auth.clone()).await {
+        Err(DockerError::Unauthorized(_)) => {},
+        other => panic!("expected Unauthorized, got {:?}", other),
+    }
+    assert_eq!(
+        mock.requests()[0].body(),
+        r#"{"username":"ci","password":"s3cret","serveraddress":"registry.example.com"}"#
+    );
+
+    let status = client.check_auth(auth.clone()).await.unwrap();
+    assert_eq!(status.status(), "Login Succeeded");
+    assert_eq!(status.identity_token(), Some("9cbaf023786cd7"));
+    assert!(!format!("{:?}", status).contains("9cbaf023786cd7"));
+
+    // The identity token replaces the password in later pushes.
+    let client = client.login(auth).await.unwrap();
+    assert!(client.push_image("registry.example.com/api:1.2").await.is_err());
+
+    let push = mock.requests().pop().unwrap();
+    let header = push.headers()["X-Registry-Auth"].to_str().unwrap();
+    let decoded = String::from_utf8(base64::decode_config(header, base64::URL_SAFE).unwrap()).unwrap();
+    assert_eq!(decoded, r#"{"username":"ci","serveraddress":"registry.example.com","identitytoken":"9cbaf023786cd7"}"#);
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn test_blocking_client() {
+    use docker_client::blocking;
+    use docker_client::client::MockTransport;
+    use hyper::Method;
+
+    let mock = MockTransport::new()
+        .with_response(Method::GET, "/version", 200, include_str!("fixtures/version_v1.44.json"))
+        .with_response(Method::POST, "/containers/web/stop", 404, r#"{"message":"No such container: web"}"#);
+    let client = blocking::DockerClient::from_async(DockerClient::from_transport(mock.clone())).unwrap();
+
+    assert_eq!(client.version().unwrap().version(), "25.0.3");
+
+    match client.stop_container("web", None) {
+        Err(DockerError::NotFound(message)) => assert_eq!(message.message, "No such container: web"),
+        other => panic!("expected NotFound, got {:?}", other),
+    }
+
+    let history = client.block_on(|client| client.image_history("alpine"));
+    assert!(history.is_err());
+    assert_eq!(mock.requests()[2].path(), "/images/alpine/history");
+}
+
+#[tokio::test]
+async fn test_proxy() {
+    use docker_client::client::{EndpointError, Proxy};
+    use hyper::Uri;
+    use std::sync::{Arc, Mutex};
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    let proxy = Proxy::from_vars(vec![
+        ("HTTP_PROXY", "http://upper:3128"),
+        ("http_proxy", "http://lower:3128"),
+        ("HTTPS_PROXY", "secure:3129"),
+        ("NO_PROXY", "docker.corp, .internal, 10.1.0.0/16, build:2375"),
+    ]).unwrap().unwrap();
+
+    let proxy_for = |uri: &str| proxy.proxy_for(&uri.parse::<Uri>().unwrap()).map(|uri| uri.to_string());
+    assert_eq!(proxy_for("http://10.0.0.2:2375/info"), Some("http://lower:3128/".to_string()));
+    assert_eq!(proxy_for("https://10.0.0.2:2376/info"), Some("http://secure:3129/".to_string()));
+    assert_eq!(proxy_for("http://docker.corp:2375/info"), None);
+    assert_eq!(proxy_for("http://ci.docker.corp:2375/info"), None);
+    assert_eq!(proxy_for("http://daemon.internal:2375/info"), None);
+    assert_eq!(proxy_for("http://10.1.4.20:2375/info"), None);
+    assert_eq!(proxy_for("http://build:2375/info"), None);
+    assert_eq!(proxy_for("http://build:2376/info"), Some("http://lower:3128/".to_string()));
+    assert_eq!(proxy_for("http://127.0.0.1:2375/info"), None);
+
+    assert_eq!(Proxy::from_vars(vec![("NO_PROXY", "*")]).unwrap(), None);
+    assert_eq!(Proxy::new("socks5://proxy:1080").unwrap_err(), EndpointError::InvalidProxy("socks5://proxy:1080".to_string()));
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+
+    let tunnels = Arc::new(Mutex::new(Vec::new()));
+    let opened = tunnels.clone();
+    tokio::spawn(async move {
+        loop {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let opened = opened.clone();
+
+            tokio::spawn(async move {
+                let mut request = Vec::new();
+                let mut buffer = [0u8; 1024];
+                loop {
+                    while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+                        match socket.read(&mut buffer).await {
+                            Ok(0) | Err(_) => return,
+                            Ok(read) => request.extend_from_slice(&buffer[..read]),
+                        }
+                    }
+
+                    let head = String::from_utf8_lossy(&request).to_string();
+                    request.clear();
+
+                    // The proxy answers the CONNECT, then plays the daemon at the other end of the tunnel.
+                    let response = match head.strip_prefix("CONNECT ") {
+                        Some(rest) => {
+                            opened.lock().unwrap().push(head.clone());
+                            match rest.starts_with("docker.example:2375 ") && head.contains("Proxy-Authorization: Basic dXNlcjpzM2NyZXQ=\r\n") {
+                                true => "HTTP/1.1 200 Connection established\r\n\r\n",
+                                false => "HTTP/1.1 407 Proxy Authentication Required\r\nContent-Length: 0\r\n\r\n",
+                            }
+                        },
+                        None => "HTTP/1.1 200 OK\r\nContent-Length: 2\r\n\r\n[]",
+                    };
+                    socket.write_all(response.as_bytes()).await.unwrap();
+                }
+            });
+        }
+    });
+
+    let client = DockerClient::builder()
+        .host("tcp://docker.example:2375")
+        .proxy(Proxy::new(&format!("http://user:s3cret@{}", address)).unwrap())
+        .build()
+        .unwrap();
+
+    assert!(client.get_image_list().await.unwrap().is_empty());
+    assert_eq!(tunnels.lock().unwrap().len(), 1);
+
+    // Credentials are percent-decoded like curl and the docker CLI do.
+    let encoded = DockerClient::builder()
+        .host("tcp://docker.example:2375")
+        .proxy(Proxy::new(&format!("http://us%65r:s3cr%65t@{}", address)).unwrap())
+        .build()
+        .unwrap();
+
+    assert!(encoded.get_image_list().await.unwrap().is_empty());
+    assert_eq!(tunnels.lock().unwrap().len(), 2);
+
+    let refused = DockerClient::stream("tcp://docker.example:2375", None)
+        .with_proxy(Proxy::new(&address.to_string()).unwrap());
+
+    assert!(refused.get_image_list().await.is_err());
+    assert_eq!(tunnels.lock().unwrap().len(), 3);
+}
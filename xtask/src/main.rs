@@ -0,0 +1,431 @@
+//! Development tasks.
+//!
+//! # Codegen
+//!
+//! Generate serde models from the `definitions` of the Docker engine swagger spec:
+//!
+//! ```text
+//! cargo xtask codegen xtask/swagger-v1.44.yaml src/models.rs
+//! ```
+//!
+//! The spec is an excerpt of the upstream one, copy definitions from
+//! <https://docs.docker.com/reference/api/engine/version/v1.44.yaml> to generate them.
+//!
+//! Every property is generated as an `Option` so responses of older daemons still
+//! deserialize. String enums are kept as `String`, inline objects get their own
+//! struct named after the parent and the property. Properties referring back to
+//! the definition they belong to are boxed.
+
+use std::collections::{BTreeMap, HashSet};
+use std::fmt::Write;
+use std::process::exit;
+
+use serde_yaml::{Mapping, Value};
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    match args.iter().map(String::as_str).collect::<Vec<_>>().as_slice() {
+        ["codegen", spec] => print!("{}", codegen_file(spec)),
+        ["codegen", spec, output] => {
+            if let Err(e) = std::fs::write(output, codegen_file(spec)) {
+                eprintln!("failed to write {}: {}", output, e);
+                exit(1);
+            }
+        },
+        _ => {
+            eprintln!("usage: cargo xtask codegen <swagger.yaml> [output.rs]");
+            exit(2);
+        }
+    }
+}
+
+fn codegen_file(path: &str) -> String {
+    let source = std::fs::read_to_string(path).unwrap_or_else(|e| {
+        eprintln!("failed to read {}: {}", path, e);
+        exit(1);
+    });
+    let spec: Value = serde_yaml::from_str(&source).unwrap_or_else(|e| {
+        eprintln!("failed to parse {}: {}", path, e);
+        exit(1);
+    });
+
+    codegen(&spec)
+}
+
+/// Generate the models module from a parsed swagger spec.
+fn codegen(spec: &Value) -> String {
+    let definitions = match spec.get("definitions").and_then(Value::as_mapping) {
+        Some(definitions) => definitions,
+        None => {
+            eprintln!("spec has no definitions");
+            exit(1);
+        }
+    };
+
+    let mut generator = Generator::new(definitions);
+    for (name, schema) in definitions {
+        if let Some(name) = name.as_str() {
+            generator.current = type_name(name);
+            generator.definition(&type_name(name), schema);
+        }
+    }
+
+    let version = spec.get("info")
+        .and_then(|info| info.get("version"))
+        .and_then(Value::as_str)
+        .unwrap_or("unknown");
+
+    let mut output = String::new();
+    writeln!(output, "//! Models generated from the Docker engine API {} swagger spec.", version).unwrap();
+    writeln!(output, "//!").unwrap();
+    writeln!(output, "//! Do not edit, run `cargo xtask codegen` instead.").unwrap();
+    writeln!(output).unwrap();
+    writeln!(output, "#![allow(missing_docs)]").unwrap();
+    writeln!(output).unwrap();
+    writeln!(output, "use std::collections::HashMap;").unwrap();
+    writeln!(output, "use serde::{{Deserialize, Serialize}};").unwrap();
+
+    for body in generator.structs.values() {
+        writeln!(output).unwrap();
+        output.push_str(body);
+    }
+
+    output
+}
+
+struct Generator<'a> {
+    definitions: &'a Mapping,
+    structs: BTreeMap<String, String>,
+    /// Type name of the top level definition being generated.
+    current: String,
+}
+
+impl<'a> Generator<'a> {
+
+    fn new(definitions: &'a Mapping) -> Self {
+        Generator { definitions, structs: BTreeMap::new(), current: String::new() }
+    }
+
+    /// Generate a top level definition, aliasing non-object schemas.
+    fn definition(&mut self, name: &str, schema: &Value) {
+        let properties = self.properties(schema);
+
+        if properties.is_empty() && !is_object(schema) {
+            let rust_type = self.rust_type(name, "", schema);
+            let mut body = String::new();
+            doc(&mut body, schema, "");
+            writeln!(body, "pub type {} = {};", name, rust_type).unwrap();
+            self.structs.insert(name.to_string(), body);
+            return;
+        }
+
+        self.object(name, schema);
+    }
+
+    /// Generate a struct for an object schema.
+    fn object(&mut self, name: &str, schema: &Value) {
+        if self.structs.contains_key(name) {
+            return;
+        }
+        // Reserve the name first, properties may refer back to it.
+        self.structs.insert(name.to_string(), String::new());
+
+        let mut body = String::new();
+        doc(&mut body, schema, "");
+        writeln!(body, "#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]").unwrap();
+        writeln!(body, "pub struct {} {{", name).unwrap();
+
+        for (property, property_schema) in self.properties(schema) {
+            let rust_type = self.rust_type(name, &property, &property_schema);
+
+            writeln!(body).unwrap();
+            doc(&mut body, &property_schema, "    ");
+            writeln!(
+                body,
+                "    #[serde(rename = \"{}\", default, skip_serializing_if = \"Option::is_none\")]",
+                property
+            ).unwrap();
+            writeln!(body, "    pub {}: Option<{}>,", field_name(&property), rust_type).unwrap();
+        }
+
+        writeln!(body, "}}").unwrap();
+        self.structs.insert(name.to_string(), body);
+    }
+
+    /// Collect properties of a schema, merging `allOf` parts.
+    fn properties(&self, schema: &Value) -> Vec<(String, Value)> {
+        let mut properties = Vec::new();
+
+        if let Some(parts) = schema.get("allOf").and_then(Value::as_sequence) {
+            for part in parts {
+                properties.extend(self.properties(&self.resolve(part)));
+            }
+        }
+
+        if let Some(own) = schema.get("properties").and_then(Value::as_mapping) {
+            for (name, property) in own {
+                if let Some(name) = name.as_str() {
+                    properties.retain(|(existing, _): &(String, Value)| existing != name);
+                    properties.push((name.to_string(), property.clone()));
+                }
+            }
+        }
+
+        properties
+    }
+
+    /// Follow a `$ref` to its definition.
+    fn resolve(&self, schema: &Value) -> Value {
+        match reference(schema) {
+            Some(name) => self.definitions.get(&Value::from(name)).cloned().unwrap_or(Value::Null),
+            None => schema.clone(),
+        }
+    }
+
+    /// Map a schema to the Rust type of a field, boxing references which lead
+    /// back to the current definition.
+    fn rust_type(&mut self, parent: &str, property: &str, schema: &Value) -> String {
+        match reference(schema) {
+            Some(name) if self.reaches(schema, &self.current, &mut HashSet::new()) => {
+                format!("Box<{}>", type_name(name))
+            },
+            _ => self.element_type(parent, property, schema),
+        }
+    }
+
+    /// Whether a schema contains `target` without an indirection of `Vec` or `HashMap`.
+    fn reaches(&self, schema: &Value, target: &str, seen: &mut HashSet<String>) -> bool {
+        if let Some(name) = reference(schema) {
+            let name = type_name(name);
+            if name == target {
+                return true;
+            }
+            return seen.insert(name) && self.reaches(&self.resolve(schema), target, seen);
+        }
+
+        if let Some(parts) = schema.get("allOf").and_then(Value::as_sequence) {
+            if parts.iter().any(|part| self.reaches(part, target, seen)) {
+                return true;
+            }
+        }
+
+        schema.get("properties")
+            .and_then(Value::as_mapping)
+            .is_some_and(|properties| properties.iter().any(|(_, property)| self.reaches(property, target, seen)))
+    }
+
+    /// Map a schema to a Rust type, generating structs for inline objects.
+    fn element_type(&mut self, parent: &str, property: &str, schema: &Value) -> String {
+        if let Some(name) = reference(schema) {
+            return type_name(name);
+        }
+
+        if schema.get("allOf").is_some() || schema.get("properties").is_some() {
+            let name = format!("{}{}", parent, type_name(property));
+            self.object(&name, schema);
+            return name;
+        }
+
+        match schema.get("type").and_then(Value::as_str) {
+            Some("string") => "String".to_string(),
+            Some("boolean") => "bool".to_string(),
+            Some("number") => "f64".to_string(),
+            Some("integer") => match schema.get("format").and_then(Value::as_str) {
+                Some("uint64") => "u64".to_string(),
+                Some("uint32") => "u32".to_string(),
+                Some("uint16") => "u16".to_string(),
+                Some("uint8") => "u8".to_string(),
+                _ => "i64".to_string(),
+            },
+            Some("array") => {
+                let items = schema.get("items").cloned().unwrap_or(Value::Null);
+                format!("Vec<{}>", self.element_type(parent, &format!("{}Item", property), &items))
+            },
+            Some("object") => match schema.get("additionalProperties") {
+                Some(values) if values.is_mapping() => {
+                    format!("HashMap<String, {}>", self.element_type(parent, &format!("{}Value", property), values))
+                },
+                _ => "serde_json::Value".to_string(),
+            },
+            _ => "serde_json::Value".to_string(),
+        }
+    }
+}
+
+fn is_object(schema: &Value) -> bool {
+    schema.get("allOf").is_some()
+        || schema.get("properties").is_some()
+        || (schema.get("type").and_then(Value::as_str) == Some("object")
+            && schema.get("additionalProperties").is_none())
+}
+
+fn reference(schema: &Value) -> Option<&str> {
+    schema.get("$ref")
+        .and_then(Value::as_str)
+        .and_then(|r| r.strip_prefix("#/definitions/"))
+}
+
+/// Write `description` of a schema as doc comment lines.
+fn doc(output: &mut String, schema: &Value, indent: &str) {
+    if let Some(description) = schema.get("description").and_then(Value::as_str) {
+        for line in description.trim().lines() {
+            let line = line.trim_end();
+            if line.is_empty() {
+                writeln!(output, "{}///", indent).unwrap();
+            } else {
+                writeln!(output, "{}/// {}", indent, line).unwrap();
+            }
+        }
+    }
+}
+
+/// Convert a definition or property name to a CamelCase type name.
+fn type_name(name: &str) -> String {
+    name.split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            match chars.next() {
+                Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+/// Convert a property name to a snake_case field name.
+fn field_name(name: &str) -> String {
+    let mut field = String::new();
+    let chars: Vec<char> = name.replace("IPv", "Ipv").chars().collect();
+
+    for (i, c) in chars.iter().enumerate() {
+        if !c.is_ascii_alphanumeric() {
+            if !field.ends_with('_') {
+                field.push('_');
+            }
+            continue;
+        }
+
+        if c.is_ascii_uppercase() && i > 0 {
+            let previous = chars[i - 1];
+            let next_lower = chars.get(i + 1).is_some_and(|n| n.is_ascii_lowercase());
+            if (previous.is_ascii_lowercase() || previous.is_ascii_digit() || (previous.is_ascii_uppercase() && next_lower))
+                && !field.ends_with('_')
+            {
+                field.push('_');
+            }
+        }
+        field.push(c.to_ascii_lowercase());
+    }
+
+    let field = field.trim_matches('_').to_string();
+    match field.as_str() {
+        // Not allowed as raw identifiers.
+        "self" | "super" | "crate" => format!("{}_", field),
+        "as" | "async" | "await" | "box" | "break" | "const" | "continue" | "dyn" | "else" | "enum"
+        | "extern" | "false" | "fn" | "for" | "if" | "impl" | "in" | "let" | "loop" | "match" | "mod"
+        | "move" | "mut" | "pub" | "ref" | "return" | "static" | "struct" | "trait" | "true" | "try"
+        | "type" | "unsafe" | "use" | "where" | "while" | "yield" => format!("r#{}", field),
+        _ => field,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SPEC: &str = r##"
+info:
+  version: "1.44"
+definitions:
+  Node:
+    type: "object"
+    description: "A tree node."
+    properties:
+      Parent:
+        $ref: "#/definitions/Node"
+      Children:
+        type: "array"
+        items:
+          $ref: "#/definitions/Node"
+      Edge:
+        $ref: "#/definitions/Edge"
+      Size:
+        type: "integer"
+        format: "uint64"
+      Labels:
+        type: "object"
+        additionalProperties:
+          type: "string"
+      Usage:
+        type: "object"
+        properties:
+          RefCount:
+            type: "integer"
+  Edge:
+    type: "object"
+    properties:
+      Target:
+        $ref: "#/definitions/Node"
+      Weight:
+        type: "number"
+"##;
+
+    fn spec() -> Value {
+        serde_yaml::from_str(SPEC).unwrap()
+    }
+
+    fn property(spec: &Value, definition: &str, name: &str) -> Value {
+        spec["definitions"][definition]["properties"][name].clone()
+    }
+
+    #[test]
+    fn test_field_name() {
+        assert_eq!(field_name("Name"), "name");
+        assert_eq!(field_name("RefCount"), "ref_count");
+        assert_eq!(field_name("IPv6Address"), "ipv6_address");
+        assert_eq!(field_name("HTTPProxy"), "http_proxy");
+        assert_eq!(field_name("com.docker.stack"), "com_docker_stack");
+        assert_eq!(field_name("Type"), "r#type");
+        assert_eq!(field_name("Self"), "self_");
+        assert_eq!(field_name("self"), "self_");
+        assert_eq!(field_name("super"), "super_");
+        assert_eq!(field_name("Crate"), "crate_");
+    }
+
+    #[test]
+    fn test_rust_type() {
+        let spec = spec();
+        let definitions = spec["definitions"].as_mapping().unwrap();
+        let mut generator = Generator::new(definitions);
+        generator.current = "Node".to_string();
+
+        let rust_type = |generator: &mut Generator, name| {
+            generator.rust_type("Node", name, &property(&spec, "Node", name))
+        };
+        assert_eq!(rust_type(&mut generator, "Parent"), "Box<Node>");
+        assert_eq!(rust_type(&mut generator, "Children"), "Vec<Node>");
+        assert_eq!(rust_type(&mut generator, "Edge"), "Box<Edge>");
+        assert_eq!(rust_type(&mut generator, "Size"), "u64");
+        assert_eq!(rust_type(&mut generator, "Labels"), "HashMap<String, String>");
+        assert_eq!(rust_type(&mut generator, "Usage"), "NodeUsage");
+        assert!(generator.structs["NodeUsage"].contains("pub ref_count: Option<i64>,"));
+
+        generator.current = "Edge".to_string();
+        assert_eq!(generator.rust_type("Edge", "Target", &property(&spec, "Edge", "Target")), "Box<Node>");
+        assert_eq!(generator.rust_type("Edge", "Weight", &property(&spec, "Edge", "Weight")), "f64");
+    }
+
+    #[test]
+    fn test_codegen() {
+        let models = codegen(&spec());
+
+        assert!(models.contains("//! Models generated from the Docker engine API 1.44 swagger spec."));
+        assert!(models.contains("/// A tree node.\n#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]\npub struct Node {"));
+        assert!(models.contains("    #[serde(rename = \"Parent\", default, skip_serializing_if = \"Option::is_none\")]\n    pub parent: Option<Box<Node>>,"));
+        assert!(models.contains("    pub children: Option<Vec<Node>>,"));
+        assert!(models.contains("pub struct Edge {"));
+        assert!(models.contains("    pub target: Option<Box<Node>>,"));
+    }
+}